@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Embeds the git commit SHA into the crate for `odos_sdk::build_info()`.
+//!
+//! The SHA is taken from the `ODOS_SDK_GIT_SHA` environment variable when set,
+//! otherwise from `git rev-parse HEAD` when building from a git checkout.
+//! Builds from a published crate have neither and leave the SHA unset.
+
+use std::{path::Path, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=ODOS_SDK_GIT_SHA");
+
+    let sha = std::env::var("ODOS_SDK_GIT_SHA")
+        .ok()
+        .filter(|sha| !sha.is_empty())
+        .or_else(git_head_sha);
+
+    if let Some(sha) = sha {
+        println!("cargo:rustc-env=ODOS_SDK_GIT_SHA={sha}");
+    }
+}
+
+fn git_head_sha() -> Option<String> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").ok()?;
+    let git_dir = Path::new(&manifest_dir).join(".git");
+    if !git_dir.exists() {
+        return None;
+    }
+
+    println!("cargo:rerun-if-changed={}", git_dir.join("HEAD").display());
+    println!("cargo:rerun-if-changed={}", git_dir.join("refs").display());
+
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(&manifest_dir)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let sha = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!sha.is_empty()).then_some(sha)
+}
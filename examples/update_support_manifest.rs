@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Regenerates `src/support_manifest.json` from the chain table compiled
+//! into the crate.
+//!
+//! Run after changing chain support or bumping the crate version:
+//!
+//! ```sh
+//! cargo run --example update_support_manifest
+//! ```
+
+use std::{fs, path::Path};
+
+use odos_sdk::SupportManifest;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let manifest = SupportManifest::from_chain_table();
+    let json = serde_json::to_string_pretty(&manifest)? + "\n";
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/support_manifest.json");
+    fs::write(&path, json)?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
@@ -2,19 +2,25 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    time::Instant,
+};
 
 use alloy_primitives::{Address, U256};
 use bon::Builder;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::{error_code::TraceId, OdosError, Result};
+use crate::{
+    error_code::TraceId, Chain, OdosChainResult, OdosError, PathId, PathViz, Result, TokenAmount,
+};
 
 #[cfg(feature = "v2")]
 use {
     crate::OdosRouterV2::{inputTokenInfo, outputTokenInfo, swapTokenInfo},
-    crate::OdosV2Router::{swapCall, OdosV2RouterCalls},
+    crate::OdosV2Router::{swapCall, swapMultiCall, OdosV2RouterCalls},
     alloy_primitives::Bytes,
     tracing::debug,
 };
@@ -30,6 +36,10 @@ use {
 /// - **Public**: Standard API available to all users at <https://api.odos.xyz>
 /// - **Enterprise**: Premium API with enhanced features at <https://enterprise-api.odos.xyz>
 ///
+/// [`ApiHost::Custom`] points the client at any other base URL, such as a
+/// proxy in front of the Odos API or a mock server in tests. Since it holds a
+/// [`Url`], `ApiHost` (and so [`Endpoint`]) is `Clone` but not `Copy`.
+///
 /// Use in combination with [`ApiVersion`] via the [`Endpoint`] type for complete
 /// endpoint configuration.
 ///
@@ -44,7 +54,7 @@ use {
 /// // Or use convenience methods
 /// let endpoint = Endpoint::public_v2();
 /// ```
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ApiHost {
     /// Public API endpoint <https://docs.odos.xyz/build/api-docs>
@@ -56,28 +66,46 @@ pub enum ApiHost {
     /// Premium API with enhanced features, higher rate limits, and dedicated support.
     /// Requires an API key obtained through the Odos Enterprise program.
     Enterprise,
+    /// Custom base URL serving the Odos API paths
+    ///
+    /// Request paths such as `sor/quote/v2` are joined onto this URL, so a
+    /// path prefix like `https://proxy.internal/odos` is kept.
+    Custom(Url),
 }
 
 impl ApiHost {
     /// Get the base URL for the API host
     ///
-    /// Returns the root URL for the selected host tier without any path segments.
+    /// Returns the root URL for the selected host tier without any path
+    /// segments. A custom URL is returned with a trailing slash so API paths
+    /// are joined after its last segment.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use odos_sdk::ApiHost;
+    /// use url::Url;
     ///
     /// let public = ApiHost::Public;
     /// assert_eq!(public.base_url().as_str(), "https://api.odos.xyz/");
     ///
     /// let enterprise = ApiHost::Enterprise;
     /// assert_eq!(enterprise.base_url().as_str(), "https://enterprise-api.odos.xyz/");
+    ///
+    /// let proxy = ApiHost::Custom(Url::parse("https://proxy.internal/odos").unwrap());
+    /// assert_eq!(proxy.base_url().as_str(), "https://proxy.internal/odos/");
     /// ```
     pub fn base_url(&self) -> Url {
         match self {
             ApiHost::Public => Url::parse("https://api.odos.xyz/").unwrap(),
             ApiHost::Enterprise => Url::parse("https://enterprise-api.odos.xyz/").unwrap(),
+            ApiHost::Custom(url) => {
+                let mut url = url.clone();
+                if !url.path().ends_with('/') {
+                    url.set_path(&format!("{}/", url.path()));
+                }
+                url
+            }
         }
     }
 }
@@ -121,7 +149,7 @@ impl ApiVersion {
     /// Get the path segment for this version
     ///
     /// Returns the path component to use in API URLs (e.g., "v2", "v3").
-    fn path(&self) -> &'static str {
+    pub(crate) fn path(&self) -> &'static str {
         match self {
             ApiVersion::V2 => "v2",
             ApiVersion::V3 => "v3",
@@ -181,7 +209,7 @@ impl ApiVersion {
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 pub struct Endpoint {
     host: ApiHost,
     version: ApiVersion,
@@ -201,6 +229,22 @@ impl Endpoint {
         Self { host, version }
     }
 
+    /// Endpoint at a custom base URL, such as a proxy or a mock server
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::{ApiVersion, Endpoint};
+    /// use url::Url;
+    ///
+    /// let base_url = Url::parse("http://127.0.0.1:8080").unwrap();
+    /// let endpoint = Endpoint::custom(base_url, ApiVersion::V2);
+    /// assert_eq!(endpoint.quote_url().as_str(), "http://127.0.0.1:8080/sor/quote/v2");
+    /// ```
+    pub const fn custom(base_url: Url, version: ApiVersion) -> Self {
+        Self::new(ApiHost::Custom(base_url), version)
+    }
+
     /// Public API V2 endpoint (default, recommended for production)
     ///
     /// This is the recommended configuration for most production use cases.
@@ -305,8 +349,160 @@ impl Endpoint {
         self.host.base_url().join("sor/assemble").unwrap()
     }
 
+    /// Get the router info URL for a chain
+    ///
+    /// The router info endpoint reports the router address that will pull
+    /// input tokens for swaps on the given chain, i.e. the spender to approve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v3();
+    /// assert_eq!(endpoint.router_info_url(8453).as_str(), "https://api.odos.xyz/info/router/v3/8453");
+    /// ```
+    pub fn router_info_url(&self, chain_id: u64) -> Url {
+        self.host
+            .base_url()
+            .join(&format!("info/router/{}/{chain_id}", self.version.path()))
+            .unwrap()
+    }
+
+    /// Get the supported chains URL
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v3();
+    /// assert_eq!(endpoint.chains_url().as_str(), "https://api.odos.xyz/info/chains");
+    /// ```
+    pub fn chains_url(&self) -> Url {
+        self.host.base_url().join("info/chains").unwrap()
+    }
+
+    /// Get the limit order placement URL
+    ///
+    /// The limit order API is version-independent; orders are always placed
+    /// against the limit order router.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v2();
+    /// assert_eq!(endpoint.limit_order_place_url().as_str(), "https://api.odos.xyz/lo/order/place");
+    /// ```
+    pub fn limit_order_place_url(&self) -> Url {
+        self.host.base_url().join("lo/order/place").unwrap()
+    }
+
+    /// Get the URL listing an owner's limit orders on a chain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::address;
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v2();
+    /// let owner = address!("00000000000000000000000000000000000000aa");
+    /// assert_eq!(
+    ///     endpoint.limit_orders_url(1, owner).as_str(),
+    ///     "https://api.odos.xyz/lo/orders/1/0x00000000000000000000000000000000000000aa"
+    /// );
+    /// ```
+    pub fn limit_orders_url(&self, chain_id: u64, owner: Address) -> Url {
+        self.host
+            .base_url()
+            .join(&format!("lo/orders/{chain_id}/{owner:#x}"))
+            .unwrap()
+    }
+
+    /// Get the limit order cancellation URL
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v2();
+    /// assert_eq!(endpoint.limit_order_cancel_url().as_str(), "https://api.odos.xyz/lo/order/cancel");
+    /// ```
+    pub fn limit_order_cancel_url(&self) -> Url {
+        self.host.base_url().join("lo/order/cancel").unwrap()
+    }
+
+    /// Get the contract info URL for a chain
+    ///
+    /// The contract info endpoint reports the router and executor deployed
+    /// for the configured API version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v2();
+    /// assert_eq!(
+    ///     endpoint.contract_info_url(1).as_str(),
+    ///     "https://api.odos.xyz/info/contract-info/v2/1"
+    /// );
+    /// ```
+    pub fn contract_info_url(&self, chain_id: u64) -> Url {
+        self.host
+            .base_url()
+            .join(&format!(
+                "info/contract-info/{}/{chain_id}",
+                self.version.path()
+            ))
+            .unwrap()
+    }
+
+    /// Get the liquidity sources URL for a chain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v3();
+    /// assert_eq!(
+    ///     endpoint.liquidity_sources_url(1).as_str(),
+    ///     "https://api.odos.xyz/info/liquidity-sources/1"
+    /// );
+    /// ```
+    pub fn liquidity_sources_url(&self, chain_id: u64) -> Url {
+        self.host
+            .base_url()
+            .join(&format!("info/liquidity-sources/{chain_id}"))
+            .unwrap()
+    }
+
+    /// Get the supported tokens URL for a chain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Endpoint;
+    ///
+    /// let endpoint = Endpoint::public_v3();
+    /// assert_eq!(endpoint.tokens_url(1).as_str(), "https://api.odos.xyz/info/tokens/1");
+    /// ```
+    pub fn tokens_url(&self, chain_id: u64) -> Url {
+        self.host
+            .base_url()
+            .join(&format!("info/tokens/{chain_id}"))
+            .unwrap()
+    }
+
     /// Get the API host tier
     ///
+    /// Returns a clone, which copies the URL of an [`ApiHost::Custom`] host.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -315,8 +511,8 @@ impl Endpoint {
     /// let endpoint = Endpoint::public_v2();
     /// assert_eq!(endpoint.host(), ApiHost::Public);
     /// ```
-    pub const fn host(&self) -> ApiHost {
-        self.host
+    pub fn host(&self) -> ApiHost {
+        self.host.clone()
     }
 
     /// Get the API version
@@ -349,15 +545,51 @@ impl Default for Endpoint {
 pub struct InputToken {
     token_address: Address,
     // Odos API error message: "Input Amount should be positive integer in string form with < 64 digits[0x6]"
-    amount: String,
+    #[serde(with = "decimal_u256")]
+    amount: U256,
 }
 
 impl InputToken {
     pub fn new(token_address: Address, amount: U256) -> Self {
         Self {
             token_address,
-            amount: amount.to_string(),
+            amount,
+        }
+    }
+
+    /// Get the input token address
+    pub fn token_address(&self) -> Address {
+        self.token_address
+    }
+
+    /// Get the input amount in base units
+    pub fn amount(&self) -> U256 {
+        self.amount
+    }
+}
+
+/// Serde format for amounts the Odos API expects as decimal strings
+pub(crate) mod decimal_u256 {
+    use alloy_primitives::U256;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        amount: &U256,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<U256, D::Error> {
+        let amount = String::deserialize(deserializer)?;
+        if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(D::Error::custom(format!(
+                "expected a decimal integer string, got '{amount}'"
+            )));
         }
+        U256::from_str_radix(&amount, 10).map_err(D::Error::custom)
     }
 }
 
@@ -367,6 +599,12 @@ impl From<(Address, U256)> for InputToken {
     }
 }
 
+impl From<TokenAmount> for InputToken {
+    fn from(amount: TokenAmount) -> Self {
+        Self::new(amount.token(), amount.amount())
+    }
+}
+
 impl Display for InputToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -378,28 +616,55 @@ impl Display for InputToken {
 }
 
 /// Output token for the Odos quote API
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+///
+/// `proportion` is the share of the output value routed to this token. The
+/// proportions across all output tokens of a request must sum to `1.0`; a
+/// single-output swap uses a proportion of `1.0`.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OutputToken {
     token_address: Address,
-    proportion: u32,
+    proportion: f64,
 }
 
 impl OutputToken {
-    pub fn new(token_address: Address, proportion: u32) -> Self {
+    pub fn new(token_address: Address, proportion: f64) -> Self {
         Self {
             token_address,
             proportion,
         }
     }
+
+    /// Get the output token address
+    pub fn token_address(&self) -> Address {
+        self.token_address
+    }
+
+    /// Get the share of the output value routed to this token
+    pub fn proportion(&self) -> f64 {
+        self.proportion
+    }
 }
 
-impl From<(Address, u32)> for OutputToken {
-    fn from((token_address, proportion): (Address, u32)) -> Self {
+impl From<(Address, f64)> for OutputToken {
+    fn from((token_address, proportion): (Address, f64)) -> Self {
         Self::new(token_address, proportion)
     }
 }
 
+impl Hash for OutputToken {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.token_address.hash(state);
+        hash_f64(self.proportion, state);
+    }
+}
+
+/// Hash a float consistently with `==`, so `0.0` and `-0.0` hash alike
+pub(crate) fn hash_f64<H: Hasher>(value: f64, state: &mut H) {
+    let value = if value == 0.0 { 0.0 } else { value };
+    value.to_bits().hash(state);
+}
+
 impl Display for OutputToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -414,15 +679,17 @@ impl Display for OutputToken {
 ///
 /// # Using Type-Safe Newtypes
 ///
-/// You can use the type-safe [`Slippage`](crate::Slippage), [`Chain`](crate::Chain),
-/// and [`ReferralCode`](crate::ReferralCode) types with their conversion methods:
+/// The chain is a [`Chain`], serialized as its numeric ID, so a request can
+/// only name a chain the SDK has routers for. The type-safe
+/// [`Slippage`](crate::Slippage) and [`ReferralCode`](crate::ReferralCode)
+/// types convert with their accessor methods:
 ///
 /// ```rust
 /// use odos_sdk::{QuoteRequest, Slippage, Chain, ReferralCode};
 /// use alloy_primitives::Address;
 ///
 /// let request = QuoteRequest::builder()
-///     .chain_id(Chain::ethereum().id())
+///     .chain(Chain::ethereum())
 ///     .slippage_limit_percent(Slippage::percent(0.5).unwrap().as_percent())
 ///     .referral_code(ReferralCode::NONE.code())
 ///     // ... other fields
@@ -437,7 +704,9 @@ impl Display for OutputToken {
 #[derive(Builder, Clone, Debug, Default, PartialEq, PartialOrd, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuoteRequest {
-    chain_id: u64,
+    /// Chain to quote on
+    #[serde(rename = "chainId")]
+    chain: Chain,
     input_tokens: Vec<InputToken>,
     output_tokens: Vec<OutputToken>,
     slippage_limit_percent: f64,
@@ -446,33 +715,355 @@ pub struct QuoteRequest {
     simple: bool,
     referral_code: u32,
     disable_rfqs: bool,
+    /// Liquidity sources to exclude from routing
     #[builder(default)]
     source_blacklist: Vec<String>,
+    /// Liquidity sources to restrict routing to; empty allows all sources
+    #[builder(default)]
+    source_whitelist: Vec<String>,
+    /// Request the routing graph in the response (see [`SingleQuoteResponse::path_viz`])
+    #[builder(default)]
+    path_viz: bool,
+    /// Request a rendered image of the routing graph in the response
+    #[builder(default)]
+    path_viz_image: bool,
+    /// Gas price in gwei to compute the quote against; the API uses its own
+    /// estimate when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gas_price: Option<f64>,
+    /// Hint that the input and output tokens are correlated assets (e.g.
+    /// stablecoin to stablecoin), which improves routing between them
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    like_asset: bool,
+}
+
+impl Hash for QuoteRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            chain,
+            input_tokens,
+            output_tokens,
+            slippage_limit_percent,
+            user_addr,
+            compact,
+            simple,
+            referral_code,
+            disable_rfqs,
+            source_blacklist,
+            source_whitelist,
+            path_viz,
+            path_viz_image,
+            gas_price,
+            like_asset,
+        } = self;
+        chain.hash(state);
+        input_tokens.hash(state);
+        output_tokens.hash(state);
+        hash_f64(*slippage_limit_percent, state);
+        user_addr.hash(state);
+        compact.hash(state);
+        simple.hash(state);
+        referral_code.hash(state);
+        disable_rfqs.hash(state);
+        source_blacklist.hash(state);
+        source_whitelist.hash(state);
+        path_viz.hash(state);
+        path_viz_image.hash(state);
+        gas_price.is_some().hash(state);
+        if let Some(gas_price) = gas_price {
+            hash_f64(*gas_price, state);
+        }
+        like_asset.hash(state);
+    }
+}
+
+/// Maximum number of digits in an input amount accepted by the Odos API
+const MAX_AMOUNT_DIGITS: usize = 63;
+
+impl<S: quote_request_builder::State> QuoteRequestBuilder<S> {
+    /// Set the chain from a numeric chain ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosChainError::UnsupportedChain`](crate::OdosChainError::UnsupportedChain) if the SDK has no
+    /// routers for `chain_id`.
+    #[deprecated(
+        since = "11.0.0",
+        note = "Use `chain(Chain)`, e.g. `chain(Chain::from_chain_id(id)?)`"
+    )]
+    pub fn chain_id(
+        self,
+        chain_id: u64,
+    ) -> OdosChainResult<QuoteRequestBuilder<quote_request_builder::SetChain<S>>>
+    where
+        S::Chain: quote_request_builder::IsUnset,
+    {
+        Ok(self.chain(Chain::from_chain_id(chain_id)?))
+    }
+}
+
+impl QuoteRequest {
+    /// Get the chain to quote on
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    /// Tokens and amounts to swap from
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) fn input_tokens(&self) -> &[InputToken] {
+        &self.input_tokens
+    }
+
+    /// Tokens and proportions to swap into
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) fn output_tokens(&self) -> &[OutputToken] {
+        &self.output_tokens
+    }
+
+    /// Copy of the request with the slippage limit cleared, for cache keys
+    /// that ignore it
+    pub(crate) fn without_slippage(&self) -> Self {
+        Self {
+            slippage_limit_percent: 0.0,
+            ..self.clone()
+        }
+    }
+
+    /// Validate the request client-side
+    ///
+    /// Catches requests the API would reject, without spending a round trip
+    /// or rate limit budget. [`OdosClient::quote`](crate::OdosClient::quote)
+    /// calls this automatically unless
+    /// [`ClientConfig::validate_quote_requests`](crate::ClientConfig::validate_quote_requests)
+    /// is disabled.
+    ///
+    /// Checks that:
+    /// - There is at least one input and one output token
+    /// - Each input amount is a positive integer of fewer than 64 digits
+    /// - Each output proportion is positive and finite
+    /// - The slippage limit is between 0% and 100%
+    /// - The gas price, if set, is positive and finite
+    /// - No token appears as both an input and an output
+    /// - No liquidity source is both allowed and blocked
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`] naming the offending field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::{Chain, QuoteRequest};
+    /// use alloy_primitives::{address, Address, U256};
+    ///
+    /// let request = QuoteRequest::builder()
+    ///     .chain(Chain::ethereum())
+    ///     .input_tokens(vec![(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::ZERO).into()])
+    ///     .output_tokens(vec![(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"), 1.0).into()])
+    ///     .slippage_limit_percent(0.5)
+    ///     .user_addr(Address::ZERO)
+    ///     .compact(false)
+    ///     .simple(false)
+    ///     .referral_code(0)
+    ///     .disable_rfqs(false)
+    ///     .build();
+    ///
+    /// let err = request.validate().unwrap_err();
+    /// assert!(err.to_string().contains("input_tokens[0].amount"));
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        if self.input_tokens.is_empty() {
+            return Err(OdosError::invalid_input(
+                "input_tokens: at least one input token is required",
+            ));
+        }
+        if self.output_tokens.is_empty() {
+            return Err(OdosError::invalid_input(
+                "output_tokens: at least one output token is required",
+            ));
+        }
+
+        for (i, input) in self.input_tokens.iter().enumerate() {
+            if input.amount.is_zero() {
+                return Err(OdosError::invalid_input(format!(
+                    "input_tokens[{i}].amount: must be greater than zero"
+                )));
+            }
+            let digits = input.amount.to_string().len();
+            if digits > MAX_AMOUNT_DIGITS {
+                return Err(OdosError::invalid_input(format!(
+                    "input_tokens[{i}].amount: must have fewer than 64 digits, got {digits}"
+                )));
+            }
+        }
+
+        for (i, output) in self.output_tokens.iter().enumerate() {
+            if !output.proportion.is_finite() || output.proportion <= 0.0 {
+                return Err(OdosError::invalid_input(format!(
+                    "output_tokens[{i}].proportion: must be a positive number, got {}",
+                    output.proportion
+                )));
+            }
+        }
+
+        let slippage = self.slippage_limit_percent;
+        if !(0.0..=100.0).contains(&slippage) {
+            return Err(OdosError::invalid_input(format!(
+                "slippage_limit_percent: must be between 0 and 100, got {slippage}"
+            )));
+        }
+
+        if let Some(gas_price) = self.gas_price {
+            if !gas_price.is_finite() || gas_price <= 0.0 {
+                return Err(OdosError::invalid_input(format!(
+                    "gas_price: must be a positive number of gwei, got {gas_price}"
+                )));
+            }
+        }
+
+        for input in &self.input_tokens {
+            if let Some(i) = self
+                .output_tokens
+                .iter()
+                .position(|output| output.token_address == input.token_address)
+            {
+                return Err(OdosError::invalid_input(format!(
+                    "output_tokens[{i}].token_address: {} is also an input token",
+                    input.token_address
+                )));
+            }
+        }
+
+        if let Some(source) = self
+            .source_whitelist
+            .iter()
+            .find(|source| self.source_blacklist.contains(source))
+        {
+            return Err(OdosError::invalid_input(format!(
+                "source_whitelist: '{source}' is also in source_blacklist"
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 /// Single quote response from the Odos quote API: <https://docs.odos.xyz/build/api-docs>
-#[derive(Clone, Debug, PartialEq, PartialOrd, Deserialize, Serialize)]
+///
+/// Fields the V2 or V3 endpoint may omit or return as `null` are optional,
+/// with accessors that default them; fields the SDK does not model are
+/// ignored, so new response fields do not break deserialization.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SingleQuoteResponse {
     block_number: u64,
     data_gas_estimate: u64,
     gas_estimate: f64,
-    gas_estimate_value: f64,
+    /// Gas cost in USD. `None` when the API cannot price the gas token.
+    #[serde(default)]
+    gas_estimate_value: Option<f64>,
     gwei_per_gas: f64,
     in_amounts: Vec<String>,
     in_tokens: Vec<Address>,
     in_values: Vec<f64>,
-    net_out_value: f64,
+    /// Output value in USD net of gas. `None` when the API cannot price it.
+    #[serde(default)]
+    net_out_value: Option<f64>,
     out_amounts: Vec<String>,
     out_tokens: Vec<Address>,
     out_values: Vec<f64>,
-    /// Partner fee percentage. Defaults to 0.0 if not present (V3 API compatibility).
+    /// Partner fee percentage. `None` when the API omits it, as V3 does for
+    /// quotes without a partner fee, or returns `null`.
+    #[serde(default)]
+    partner_fee_percent: Option<f64>,
+    path_id: PathId,
+    /// Routing graph, present when requested with `pathViz`. Kept as raw JSON
+    /// so fields the SDK does not model survive a round trip.
+    #[serde(default)]
+    path_viz: Option<serde_json::Value>,
+    /// Rendered routing graph image, present when requested with `pathVizImage`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path_viz_image: Option<String>,
+    /// Percentage difference between the output and input USD values.
+    /// `None` when the API omits it or returns `null`.
     #[serde(default)]
-    partner_fee_percent: f64,
-    path_id: String,
-    path_viz: Option<String>,
-    percent_diff: f64,
-    price_impact: f64,
+    percent_diff: Option<f64>,
+    /// Price impact percentage. `None` when the API omits it or returns `null`.
+    #[serde(default)]
+    price_impact: Option<f64>,
+    /// When the response was received from the API, set only on responses
+    /// served from [`ClientConfig::quote_cache`](crate::ClientConfig::quote_cache)
+    #[serde(skip)]
+    cached_at: Option<Instant>,
+    /// Chain the quote was requested on; not part of the API response
+    #[serde(skip)]
+    chain_id: Option<u64>,
+}
+
+impl PartialOrd for SingleQuoteResponse {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        /// Fields in declaration order, with the routing graph as JSON text,
+        /// since `serde_json::Value` has no ordering
+        fn key(quote: &SingleQuoteResponse, path_viz: Option<String>) -> impl PartialOrd + '_ {
+            let SingleQuoteResponse {
+                block_number,
+                data_gas_estimate,
+                gas_estimate,
+                gas_estimate_value,
+                gwei_per_gas,
+                in_amounts,
+                in_tokens,
+                in_values,
+                net_out_value,
+                out_amounts,
+                out_tokens,
+                out_values,
+                partner_fee_percent,
+                path_id,
+                path_viz: _,
+                path_viz_image,
+                percent_diff,
+                price_impact,
+                cached_at,
+                chain_id,
+            } = quote;
+            (
+                (
+                    block_number,
+                    data_gas_estimate,
+                    gas_estimate,
+                    gas_estimate_value,
+                    gwei_per_gas,
+                    in_amounts,
+                    in_tokens,
+                    in_values,
+                    net_out_value,
+                    out_amounts,
+                ),
+                (
+                    out_tokens,
+                    out_values,
+                    partner_fee_percent,
+                    path_id,
+                    path_viz,
+                    path_viz_image,
+                    percent_diff,
+                    price_impact,
+                    cached_at,
+                    chain_id,
+                ),
+            )
+        }
+
+        // Equal graphs compare equal even if their JSON text differs in key order
+        let path_viz = |quote: &Self| {
+            (self.path_viz != other.path_viz)
+                .then(|| quote.path_viz.as_ref().map(ToString::to_string))
+                .flatten()
+        };
+        key(self, path_viz(self)).partial_cmp(&key(other, path_viz(other)))
+    }
 }
 
 impl SingleQuoteResponse {
@@ -497,7 +1088,17 @@ impl SingleQuoteResponse {
     }
 
     /// Get the estimated gas cost value of the quote.
+    ///
+    /// Returns `0.0` when the API omitted the value; use
+    /// [`try_gas_estimate_value`](Self::try_gas_estimate_value) to tell the
+    /// two apart.
     pub fn gas_estimate_value(&self) -> f64 {
+        self.gas_estimate_value.unwrap_or_default()
+    }
+
+    /// Get the estimated gas cost value of the quote, or `None` if the API
+    /// omitted it
+    pub fn try_gas_estimate_value(&self) -> Option<f64> {
         self.gas_estimate_value
     }
 
@@ -517,10 +1118,19 @@ impl SingleQuoteResponse {
             .in_amounts_iter()
             .next()
             .ok_or_else(|| OdosError::missing_data("Missing input amount"))?;
-        let amount: u128 = amount_str
-            .parse()
-            .map_err(|_| OdosError::invalid_input("Invalid input amount format"))?;
-        Ok(U256::from(amount))
+        parse_amount(amount_str, "input")
+    }
+
+    /// Get the in amounts of the quote as `U256`s, parsed per element
+    pub fn in_amounts_u256(&self) -> impl Iterator<Item = Result<U256>> + '_ {
+        self.in_amounts
+            .iter()
+            .map(|amount| parse_amount(amount, "input"))
+    }
+
+    /// Get the USD values of the quote inputs
+    pub fn in_values_iter(&self) -> impl Iterator<Item = &f64> {
+        self.in_values.iter()
     }
 
     /// Get the out amount of the quote
@@ -528,11 +1138,26 @@ impl SingleQuoteResponse {
         self.out_amounts.first()
     }
 
+    /// Get the out amount of the quote as a `U256`
+    pub fn out_amount_u256(&self) -> Result<U256> {
+        let amount_str = self
+            .out_amount()
+            .ok_or_else(|| OdosError::missing_data("Missing output amount"))?;
+        parse_amount(amount_str, "output")
+    }
+
     /// Get the out amounts of the quote
     pub fn out_amounts_iter(&self) -> impl Iterator<Item = &String> {
         self.out_amounts.iter()
     }
 
+    /// Get the out amounts of the quote as `U256`s, parsed per element
+    pub fn out_amounts_u256(&self) -> impl Iterator<Item = Result<U256>> + '_ {
+        self.out_amounts
+            .iter()
+            .map(|amount| parse_amount(amount, "output"))
+    }
+
     /// Get the in tokens of the quote
     pub fn in_tokens_iter(&self) -> impl Iterator<Item = &Address> {
         self.in_tokens.iter()
@@ -558,13 +1183,41 @@ impl SingleQuoteResponse {
     }
 
     /// Get the path id of the quote
-    pub fn path_id(&self) -> &str {
+    pub fn path_id(&self) -> &PathId {
         &self.path_id
     }
 
+    /// Get the routing graph of the quote
+    ///
+    /// Returns `Ok(None)` unless the quote was requested with `path_viz` enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::Json`] if the payload does not match [`PathViz`];
+    /// [`path_viz_raw`](Self::path_viz_raw) still exposes it in that case.
+    pub fn path_viz(&self) -> Result<Option<PathViz>> {
+        self.path_viz
+            .as_ref()
+            .filter(|value| !value.is_null())
+            .map(|value| PathViz::deserialize(value).map_err(OdosError::from))
+            .transpose()
+    }
+
+    /// Get the routing graph of the quote as raw JSON
+    pub fn path_viz_raw(&self) -> Option<&serde_json::Value> {
+        self.path_viz.as_ref().filter(|value| !value.is_null())
+    }
+
+    /// Get the rendered routing graph image of the quote
+    ///
+    /// Present only when the quote was requested with `path_viz_image` enabled.
+    pub fn path_viz_image(&self) -> Option<&str> {
+        self.path_viz_image.as_deref()
+    }
+
     /// Get the path id as a vector of bytes
     pub fn path_definition_as_vec_u8(&self) -> Vec<u8> {
-        self.path_id().as_bytes().to_vec()
+        self.path_id().as_str().as_bytes().to_vec()
     }
 
     /// Get the swap input token and amount
@@ -579,26 +1232,104 @@ impl SingleQuoteResponse {
     }
 
     /// Get the price impact of the quote
+    ///
+    /// Returns `0.0` when the API omitted the price impact; use
+    /// [`try_price_impact`](Self::try_price_impact) to tell the two apart.
     pub fn price_impact(&self) -> f64 {
+        self.price_impact.unwrap_or_default()
+    }
+
+    /// Get the price impact of the quote, or `None` if the API omitted it
+    pub fn try_price_impact(&self) -> Option<f64> {
         self.price_impact
     }
 
+    /// Get the percentage difference between the quote's output and input
+    /// values
+    ///
+    /// Returns `0.0` when the API omitted it; use
+    /// [`try_percent_diff`](Self::try_percent_diff) to tell the two apart.
+    pub fn percent_diff(&self) -> f64 {
+        self.percent_diff.unwrap_or_default()
+    }
+
+    /// Get the percentage difference between the quote's output and input
+    /// values, or `None` if the API omitted it
+    pub fn try_percent_diff(&self) -> Option<f64> {
+        self.percent_diff
+    }
+
     /// Get the net output value of the quote.
+    ///
+    /// Returns `0.0` when the API omitted the value; use
+    /// [`try_net_out_value`](Self::try_net_out_value) to tell the two apart.
     pub fn net_out_value(&self) -> f64 {
+        self.net_out_value.unwrap_or_default()
+    }
+
+    /// Get the net output value of the quote, or `None` if the API omitted it
+    pub fn try_net_out_value(&self) -> Option<f64> {
         self.net_out_value
     }
 
     /// Get the partner fee percent applied to the quote.
+    ///
+    /// Returns `0.0` when the API omitted it, which it does for quotes
+    /// without a partner fee.
     pub fn partner_fee_percent(&self) -> f64 {
+        self.partner_fee_percent.unwrap_or_default()
+    }
+
+    /// Get the partner fee percent applied to the quote, or `None` if the
+    /// API omitted it
+    pub fn try_partner_fee_percent(&self) -> Option<f64> {
         self.partner_fee_percent
     }
-}
 
-/// Error response from the Odos API
-///
-/// When the Odos API returns an error, it includes:
-/// - `detail`: Human-readable error message
-/// - `traceId`: UUID for tracking the error in Odos logs; may be `null` for some
+    /// Whether the quote was served from the client's quote cache
+    ///
+    /// A cached quote's path ID is as old as the original response, up to
+    /// the cache TTL, and may have been quoted with a different slippage
+    /// unless the cache keys on it. See
+    /// [`ClientConfig::quote_cache`](crate::ClientConfig::quote_cache).
+    pub fn is_cached(&self) -> bool {
+        self.cached_at.is_some()
+    }
+
+    /// Get when a cached quote was received from the API, or `None` for a
+    /// quote fetched by this call
+    pub fn cached_at(&self) -> Option<Instant> {
+        self.cached_at
+    }
+
+    /// Mark the quote as served from the cache, received at `at`
+    pub(crate) fn served_from_cache(mut self, at: Instant) -> Self {
+        self.cached_at = Some(at);
+        self
+    }
+
+    /// Get the ID of the chain the quote was requested on
+    ///
+    /// The API response does not include the chain, so this is only known
+    /// for quotes returned by [`OdosClient`](crate::OdosClient) and
+    /// [`SwapBuilder`](crate::SwapBuilder); it is `None` for a deserialized
+    /// quote.
+    pub fn chain_id(&self) -> Option<u64> {
+        self.chain_id
+    }
+
+    /// Record the chain the quote was requested on
+    pub(crate) fn quoted_on(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+}
+
+/// Error response from the Odos API
+///
+/// When the Odos API returns an error, it includes:
+/// - `detail`: Human-readable error message
+/// - `traceId`: UUID for tracking the error in Odos logs; may be `null` for some
 ///   error codes (notably [`AlgoInternal`](crate::error_code::OdosErrorCode::AlgoInternal))
 ///   or omitted entirely
 /// - `errorCode`: Numeric error code indicating the specific error type
@@ -657,24 +1388,20 @@ impl TryFrom<OdosV2RouterCalls> for SwapInputs {
                 let swapCall {
                     executor,
                     pathDefinition,
-                    referralCode,
+                    referralCode: _,
                     tokenInfo,
                 } = call;
 
-                let _referral_code = referralCode;
-
                 let swapTokenInfo {
                     inputToken,
                     inputAmount,
                     inputReceiver,
                     outputMin,
-                    outputQuote,
+                    outputQuote: _,
                     outputReceiver,
                     outputToken,
                 } = tokenInfo;
 
-                let _output_quote = outputQuote;
-
                 Ok(Self {
                     executor,
                     path_definition: pathDefinition,
@@ -718,14 +1445,11 @@ impl TryFrom<OdosV3RouterCalls> for SwapInputs {
                     inputAmount,
                     inputReceiver,
                     outputMin,
-                    outputQuote,
+                    outputQuote: _,
                     outputReceiver,
                     outputToken,
                 } = call.tokenInfo;
 
-                let _output_quote = outputQuote;
-                let _referral_info = call.referralInfo;
-
                 Ok(Self {
                     executor: call.executor,
                     path_definition: call.pathDefinition,
@@ -784,12 +1508,262 @@ impl SwapInputs {
         self.output_token_info.tokenAddress
     }
 
+    /// Get the receiver of the swap's output token
+    pub fn output_receiver(&self) -> Address {
+        self.output_token_info.receiver
+    }
+
     /// Get the value out min of the swap
     pub fn value_out_min(&self) -> U256 {
         self.value_out_min
     }
 }
 
+/// Swap inputs decoded from a multi-input or multi-output `swapMulti` call
+///
+/// The single-swap counterpart is [`SwapInputs`]; [`DecodedSwap`] decodes
+/// either from router calldata.
+///
+/// Output relative values are the V2 router's split weights. The V3 router
+/// takes a quoted amount and a minimum per output instead, so for V3 calls
+/// the relative values are zero, [`value_out_min`](Self::value_out_min) is
+/// `None`, and [`output_amount_quotes`](Self::output_amount_quotes) and
+/// [`output_amount_mins`](Self::output_amount_mins) carry the per-output
+/// quoted amounts and minimums.
+///
+/// Available only when the `v2` feature is enabled.
+#[cfg(feature = "v2")]
+#[derive(Clone, Debug)]
+pub struct MultiSwapInputs {
+    executor: Address,
+    path_definition: Bytes,
+    inputs: Vec<inputTokenInfo>,
+    outputs: Vec<outputTokenInfo>,
+    value_out_min: Option<U256>,
+    output_amount_quotes: Option<Vec<U256>>,
+    output_amount_mins: Option<Vec<U256>>,
+}
+
+#[cfg(feature = "v2")]
+impl TryFrom<OdosV2RouterCalls> for MultiSwapInputs {
+    type Error = OdosError;
+
+    fn try_from(swap: OdosV2RouterCalls) -> std::result::Result<Self, Self::Error> {
+        match swap {
+            OdosV2RouterCalls::swapMulti(call) => {
+                debug!(
+                    swap_type = "V2Router",
+                    inputs = call.inputs.len(),
+                    outputs = call.outputs.len(),
+                    value_out_min = %call.valueOutMin,
+                    executor = %call.executor,
+                    "Extracting multi-swap inputs from V2 router call"
+                );
+
+                let swapMultiCall {
+                    inputs,
+                    outputs,
+                    valueOutMin,
+                    pathDefinition,
+                    executor,
+                    referralCode: _,
+                } = call;
+
+                Ok(Self {
+                    executor,
+                    path_definition: pathDefinition,
+                    inputs,
+                    outputs,
+                    value_out_min: Some(valueOutMin),
+                    output_amount_quotes: None,
+                    output_amount_mins: None,
+                })
+            }
+            _ => Err(OdosError::invalid_input("Unexpected OdosV2RouterCalls")),
+        }
+    }
+}
+
+#[cfg(feature = "v3")]
+impl TryFrom<OdosV3RouterCalls> for MultiSwapInputs {
+    type Error = OdosError;
+
+    fn try_from(swap: OdosV3RouterCalls) -> std::result::Result<Self, Self::Error> {
+        match swap {
+            OdosV3RouterCalls::swapMulti(call) => {
+                debug!(
+                    swap_type = "V3Router",
+                    inputs = call.inputs.len(),
+                    outputs = call.outputs.len(),
+                    executor = %call.executor,
+                    "Extracting multi-swap inputs from V3 router call"
+                );
+
+                Ok(Self {
+                    executor: call.executor,
+                    path_definition: call.pathDefinition,
+                    inputs: call
+                        .inputs
+                        .into_iter()
+                        .map(|input| inputTokenInfo {
+                            tokenAddress: input.tokenAddress,
+                            amountIn: input.amountIn,
+                            receiver: input.receiver,
+                        })
+                        .collect(),
+                    output_amount_quotes: Some(
+                        call.outputs
+                            .iter()
+                            .map(|output| output.amountQuote)
+                            .collect(),
+                    ),
+                    output_amount_mins: Some(
+                        call.outputs.iter().map(|output| output.amountMin).collect(),
+                    ),
+                    outputs: call
+                        .outputs
+                        .into_iter()
+                        .map(|output| outputTokenInfo {
+                            tokenAddress: output.tokenAddress,
+                            relativeValue: U256::ZERO,
+                            receiver: output.receiver,
+                        })
+                        .collect(),
+                    value_out_min: None,
+                })
+            }
+            _ => Err(OdosError::invalid_input("Unexpected OdosV3RouterCalls")),
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl MultiSwapInputs {
+    /// Get the executor of the swap
+    pub fn executor(&self) -> Address {
+        self.executor
+    }
+
+    /// Get the path definition of the swap
+    pub fn path_definition(&self) -> &Bytes {
+        &self.path_definition
+    }
+
+    /// Get the input tokens, with their amounts and receivers
+    pub fn inputs(&self) -> &[inputTokenInfo] {
+        &self.inputs
+    }
+
+    /// Get the output tokens, with their relative values and receivers
+    pub fn outputs(&self) -> &[outputTokenInfo] {
+        &self.outputs
+    }
+
+    /// Get the minimum total output value, for V2 router calls
+    pub fn value_out_min(&self) -> Option<U256> {
+        self.value_out_min
+    }
+
+    /// Get the quoted amount of each output, in output order, for V3 router
+    /// calls
+    pub fn output_amount_quotes(&self) -> Option<&[U256]> {
+        self.output_amount_quotes.as_deref()
+    }
+
+    /// Get the minimum amount of each output, in output order, for V3
+    /// router calls
+    pub fn output_amount_mins(&self) -> Option<&[U256]> {
+        self.output_amount_mins.as_deref()
+    }
+}
+
+/// Swap inputs decoded from Odos router calldata
+///
+/// Available only when the `v2` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::{DecodedSwap, TransactionData};
+///
+/// # fn example(transaction: &TransactionData) -> odos_sdk::Result<()> {
+/// let calldata = transaction.data.parse()?;
+/// match DecodedSwap::from_calldata(&calldata)? {
+///     DecodedSwap::Single(swap) => println!("Swap of {}", swap.token_address()),
+///     DecodedSwap::Multi(swap) => println!("Swap of {} tokens", swap.inputs().len()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "v2")]
+#[derive(Clone, Debug)]
+pub enum DecodedSwap {
+    /// A single-input, single-output `swap` call
+    Single(SwapInputs),
+
+    /// A `swapMulti` call
+    Multi(MultiSwapInputs),
+}
+
+#[cfg(feature = "v2")]
+impl DecodedSwap {
+    /// Decode `swap` or `swapMulti` calldata for the V3 router, or, failing
+    /// that, the V2 router
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`] if the calldata is not a `swap` or
+    /// `swapMulti` call for either router.
+    pub fn from_calldata(calldata: &Bytes) -> Result<Self> {
+        #[cfg(feature = "v3")]
+        if let Ok(call) = OdosV3RouterCalls::try_from(calldata) {
+            return Self::try_from(call);
+        }
+
+        let call = OdosV2RouterCalls::try_from(calldata).map_err(|err| {
+            OdosError::invalid_input(format!(
+                "Calldata is not a recognized Odos router call: {err}"
+            ))
+        })?;
+        Self::try_from(call)
+    }
+}
+
+#[cfg(feature = "v2")]
+impl TryFrom<OdosV2RouterCalls> for DecodedSwap {
+    type Error = OdosError;
+
+    fn try_from(swap: OdosV2RouterCalls) -> std::result::Result<Self, Self::Error> {
+        match swap {
+            OdosV2RouterCalls::swap(_) => SwapInputs::try_from(swap).map(Self::Single),
+            OdosV2RouterCalls::swapMulti(_) => MultiSwapInputs::try_from(swap).map(Self::Multi),
+            _ => Err(OdosError::invalid_input("Unexpected OdosV2RouterCalls")),
+        }
+    }
+}
+
+#[cfg(feature = "v3")]
+impl TryFrom<OdosV3RouterCalls> for DecodedSwap {
+    type Error = OdosError;
+
+    fn try_from(swap: OdosV3RouterCalls) -> std::result::Result<Self, Self::Error> {
+        match swap {
+            OdosV3RouterCalls::swap(_) => SwapInputs::try_from(swap).map(Self::Single),
+            OdosV3RouterCalls::swapMulti(_) => MultiSwapInputs::try_from(swap).map(Self::Multi),
+            _ => Err(OdosError::invalid_input("Unexpected OdosV3RouterCalls")),
+        }
+    }
+}
+
+/// Parse a decimal base-unit amount string from a quote response.
+///
+/// Amounts are parsed directly into `U256`, so values above `u128::MAX`
+/// (common for 18-decimal tokens with large supplies) are preserved.
+fn parse_amount(amount: &str, kind: &str) -> Result<U256> {
+    U256::from_str_radix(amount, 10)
+        .map_err(|err| OdosError::invalid_input(format!("Invalid {kind} amount '{amount}': {err}")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -872,6 +1846,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_endpoint_router_info_urls() {
+        assert_eq!(
+            Endpoint::public_v2().router_info_url(1).as_str(),
+            "https://api.odos.xyz/info/router/v2/1"
+        );
+        assert_eq!(
+            Endpoint::enterprise_v3().router_info_url(42161).as_str(),
+            "https://enterprise-api.odos.xyz/info/router/v3/42161"
+        );
+    }
+
     #[test]
     fn test_endpoint_default() {
         let endpoint = Endpoint::default();
@@ -897,6 +1883,56 @@ mod tests {
         assert_ne!(Endpoint::public_v2(), Endpoint::enterprise_v2());
     }
 
+    #[test]
+    fn test_custom_endpoint_urls() {
+        let endpoint = Endpoint::custom(
+            Url::parse("http://127.0.0.1:9000/odos").unwrap(),
+            ApiVersion::V3,
+        );
+        assert_eq!(
+            endpoint.quote_url().as_str(),
+            "http://127.0.0.1:9000/odos/sor/quote/v3"
+        );
+        assert_eq!(
+            endpoint.assemble_url().as_str(),
+            "http://127.0.0.1:9000/odos/sor/assemble"
+        );
+        assert_eq!(
+            endpoint.router_info_url(1).as_str(),
+            "http://127.0.0.1:9000/odos/info/router/v3/1"
+        );
+
+        let root = ApiHost::Custom(Url::parse("http://127.0.0.1:9000").unwrap());
+        assert_eq!(root.base_url().as_str(), "http://127.0.0.1:9000/");
+    }
+
+    #[test]
+    fn test_endpoint_serde_compatibility() {
+        // Built-in hosts keep their existing representation
+        let json = serde_json::to_value(Endpoint::public_v2()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "host": "public", "version": "v2" })
+        );
+        let parsed: Endpoint =
+            serde_json::from_str(r#"{ "host": "enterprise", "version": "v3" }"#).unwrap();
+        assert_eq!(parsed, Endpoint::enterprise_v3());
+
+        let custom = Endpoint::custom(
+            Url::parse("https://proxy.internal/odos/").unwrap(),
+            ApiVersion::V2,
+        );
+        let json = serde_json::to_value(&custom).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "host": { "custom": "https://proxy.internal/odos/" },
+                "version": "v2"
+            })
+        );
+        assert_eq!(serde_json::from_value::<Endpoint>(json).unwrap(), custom);
+    }
+
     #[test]
     fn test_odos_api_error_response_accepts_null_trace_id() {
         let body = r#"{"detail":"x","traceId":null,"errorCode":2999}"#;
@@ -930,4 +1966,728 @@ mod tests {
             crate::error_code::OdosErrorCode::AlgoInternal.code()
         );
     }
+
+    #[test]
+    fn test_output_token_serializes_fractional_proportion() {
+        let token = OutputToken::new(Address::ZERO, 0.7);
+        let json = serde_json::to_value(&token).unwrap();
+        assert_eq!(json["proportion"], serde_json::json!(0.7));
+
+        let single: OutputToken = (Address::ZERO, 1.0).into();
+        assert_eq!(single.proportion(), 1.0);
+    }
+
+    fn quote_with_amounts(in_amount: &str, out_amount: &str) -> SingleQuoteResponse {
+        serde_json::from_value(serde_json::json!({
+            "blockNumber": 1,
+            "dataGasEstimate": 0,
+            "gasEstimate": 0.0,
+            "gasEstimateValue": 0.0,
+            "gweiPerGas": 0.0,
+            "inAmounts": [in_amount],
+            "inTokens": [Address::ZERO],
+            "inValues": [0.0],
+            "netOutValue": 0.0,
+            "outAmounts": [out_amount],
+            "outTokens": [Address::ZERO],
+            "outValues": [0.0],
+            "partnerFeePercent": 0.0,
+            "pathId": "2f6e14ad1cfa589029f413791a0b7f6f",
+            "percentDiff": 0.0,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_quote_amounts_parse_beyond_u128() {
+        let big = "340282366920938463463374607431768211456"; // u128::MAX + 1
+        let quote = quote_with_amounts(big, big);
+
+        let expected = U256::from(u128::MAX) + U256::from(1);
+        assert_eq!(quote.in_amount_u256().unwrap(), expected);
+        assert_eq!(quote.out_amount_u256().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_quote_amounts_parse_leading_zeros() {
+        let quote = quote_with_amounts("000123", "0000000000000000000000000000000000000000001");
+
+        assert_eq!(quote.in_amount_u256().unwrap(), U256::from(123));
+        assert_eq!(quote.out_amount_u256().unwrap(), U256::from(1));
+    }
+
+    #[test]
+    fn test_quote_amounts_u256_iterators() {
+        let mut json = serde_json::to_value(quote_with_amounts("1", "2")).unwrap();
+        json["inAmounts"] = serde_json::json!(["1", "999999999999999999999999999999999999999"]);
+        json["outAmounts"] = serde_json::json!(["7", "not-a-number"]);
+        let quote: SingleQuoteResponse = serde_json::from_value(json).unwrap();
+
+        let in_amounts: Vec<U256> = quote.in_amounts_u256().collect::<Result<_>>().unwrap();
+        assert_eq!(
+            in_amounts,
+            vec![
+                U256::from(1),
+                U256::from_str_radix("999999999999999999999999999999999999999", 10).unwrap(),
+            ]
+        );
+
+        let out_amounts: Vec<Result<U256>> = quote.out_amounts_u256().collect();
+        assert_eq!(out_amounts[0].as_ref().unwrap(), &U256::from(7));
+        assert!(matches!(out_amounts[1], Err(OdosError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_quote_amounts_reject_invalid_format() {
+        let quote = quote_with_amounts("12abc", "-5");
+
+        assert!(matches!(
+            quote.in_amount_u256().unwrap_err(),
+            OdosError::InvalidInput(_)
+        ));
+        assert!(matches!(
+            quote.out_amount_u256().unwrap_err(),
+            OdosError::InvalidInput(_)
+        ));
+    }
+
+    /// Quote response body with `pathViz` enabled, in the shape returned by
+    /// the public API for a USDC -> WETH swap on Base.
+    const PATH_VIZ_QUOTE: &str = r#"{
+        "inTokens": ["0x833589fcd6edb6e08f4c7c32d4f71b54bda02913"],
+        "outTokens": ["0x4200000000000000000000000000000000000006"],
+        "inAmounts": ["1000000000"],
+        "outAmounts": ["384512309871234567"],
+        "gasEstimate": 412345.0,
+        "dataGasEstimate": 0,
+        "gweiPerGas": 0.0123,
+        "gasEstimateValue": 0.0187,
+        "inValues": [999.87],
+        "outValues": [998.91],
+        "netOutValue": 998.89,
+        "priceImpact": -0.0842,
+        "percentDiff": -0.0961,
+        "partnerFeePercent": 0.0,
+        "pathId": "a1b2c3d4e5f60718293a4b5c6d7e8f90",
+        "pathViz": {
+            "nodes": [
+                {"name": "USD Coin", "symbol": "USDC", "decimals": 6, "visible": true, "width": 4.2},
+                {"name": "Wrapped Ether", "symbol": "WETH", "decimals": 18, "visible": true, "width": 4.2}
+            ],
+            "links": [
+                {
+                    "source": 0, "target": 1,
+                    "sourceExtend": false, "targetExtend": false,
+                    "label": "Uniswap V3", "value": 62.5,
+                    "nextValue": 62.5, "stepValue": 62.5,
+                    "in_value": 624.92, "out_value": 624.31, "edge_len": 1,
+                    "sourceToken": {"name": "USD Coin", "symbol": "USDC", "decimals": 6},
+                    "targetToken": {"name": "Wrapped Ether", "symbol": "WETH", "decimals": 18}
+                },
+                {
+                    "source": 0, "target": 1,
+                    "sourceExtend": false, "targetExtend": false,
+                    "label": "Aerodrome Slipstream", "value": 37.5,
+                    "nextValue": 37.5, "stepValue": 37.5,
+                    "in_value": 374.95, "out_value": 374.60, "edge_len": 1,
+                    "sourceToken": {"name": "USD Coin", "symbol": "USDC", "decimals": 6},
+                    "targetToken": {"name": "Wrapped Ether", "symbol": "WETH", "decimals": 18}
+                }
+            ]
+        },
+        "blockNumber": 28123456
+    }"#;
+
+    /// Smallest V3 quote response: optional fields omitted or `null`, plus
+    /// a field the SDK does not model
+    const MINIMAL_V3_QUOTE: &str = r#"{
+        "inTokens": ["0x833589fcd6edb6e08f4c7c32d4f71b54bda02913"],
+        "outTokens": ["0x4200000000000000000000000000000000000006"],
+        "inAmounts": ["1000000000"],
+        "outAmounts": ["384512309871234567"],
+        "gasEstimate": 412345.0,
+        "dataGasEstimate": 0,
+        "gweiPerGas": 0.0123,
+        "gasEstimateValue": null,
+        "inValues": [999.87],
+        "outValues": [998.91],
+        "netOutValue": null,
+        "priceImpact": null,
+        "percentDiff": null,
+        "permit2Message": null,
+        "pathId": "a1b2c3d4e5f60718293a4b5c6d7e8f90",
+        "blockNumber": 28123456
+    }"#;
+
+    /// V2 quote response with every field the SDK models, plus fields it
+    /// does not
+    const MAXIMAL_V2_QUOTE: &str = r#"{
+        "deprecated": "Use /sor/quote/v3",
+        "traceId": "10becdc8-a021-4491-8201-a17b657204e0",
+        "inTokens": ["0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"],
+        "outTokens": ["0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"],
+        "inAmounts": ["1000000000"],
+        "outAmounts": ["384512309871234567"],
+        "gasEstimate": 182345.0,
+        "dataGasEstimate": 1200,
+        "gweiPerGas": 21.5,
+        "gasEstimateValue": 9.87,
+        "inValues": [999.87],
+        "outValues": [998.91],
+        "netOutValue": 989.04,
+        "priceImpact": -0.0842,
+        "percentDiff": -0.0961,
+        "partnerFeePercent": 0.1,
+        "pathId": "a1b2c3d4e5f60718293a4b5c6d7e8f90",
+        "pathViz": {"nodes": [], "links": []},
+        "pathVizImage": "data:image/svg+xml;base64,PHN2Zy8+",
+        "blockNumber": 19000000,
+        "permit2Message": {"domain": {}, "types": {}, "message": {}},
+        "permit2Hash": "0x00"
+    }"#;
+
+    #[test]
+    fn test_minimal_v3_quote_defaults_optional_fields() {
+        let quote: SingleQuoteResponse = serde_json::from_str(MINIMAL_V3_QUOTE).unwrap();
+
+        assert_eq!(quote.try_gas_estimate_value(), None);
+        assert_eq!(quote.try_net_out_value(), None);
+        assert_eq!(quote.try_price_impact(), None);
+        assert_eq!(quote.try_percent_diff(), None);
+        assert_eq!(quote.try_partner_fee_percent(), None);
+
+        assert_eq!(quote.gas_estimate_value(), 0.0);
+        assert_eq!(quote.net_out_value(), 0.0);
+        assert_eq!(quote.price_impact(), 0.0);
+        assert_eq!(quote.percent_diff(), 0.0);
+        assert_eq!(quote.partner_fee_percent(), 0.0);
+        assert_eq!(quote.path_viz().unwrap(), None);
+        assert_eq!(quote.path_viz_image(), None);
+
+        assert_eq!(quote.get_block_number(), 28123456);
+        assert_eq!(
+            quote.out_amount_u256().unwrap(),
+            U256::from(384512309871234567u64)
+        );
+
+        // Omitting the fields entirely is the same as `null`
+        let mut json: serde_json::Value = serde_json::from_str(MINIMAL_V3_QUOTE).unwrap();
+        let object = json.as_object_mut().unwrap();
+        for field in [
+            "gasEstimateValue",
+            "netOutValue",
+            "priceImpact",
+            "percentDiff",
+        ] {
+            object.remove(field);
+        }
+        let omitted: SingleQuoteResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(omitted, quote);
+    }
+
+    #[test]
+    fn test_maximal_v2_quote_keeps_all_fields() {
+        let quote: SingleQuoteResponse = serde_json::from_str(MAXIMAL_V2_QUOTE).unwrap();
+
+        assert_eq!(quote.data_gas_estimate(), 1200);
+        assert_eq!(quote.gas_estimate(), 182345.0);
+        assert_eq!(quote.gwei_per_gas(), 21.5);
+        assert_eq!(quote.try_gas_estimate_value(), Some(9.87));
+        assert_eq!(quote.try_net_out_value(), Some(989.04));
+        assert_eq!(quote.try_price_impact(), Some(-0.0842));
+        assert_eq!(quote.try_percent_diff(), Some(-0.0961));
+        assert_eq!(quote.try_partner_fee_percent(), Some(0.1));
+        assert_eq!(quote.partner_fee_percent(), 0.1);
+        assert!(quote.path_viz().unwrap().unwrap().nodes.is_empty());
+        assert_eq!(
+            quote.path_viz_image(),
+            Some("data:image/svg+xml;base64,PHN2Zy8+")
+        );
+
+        // Unmodelled fields are dropped, modelled ones survive a round trip
+        let reserialized = serde_json::to_value(&quote).unwrap();
+        assert!(reserialized.get("deprecated").is_none());
+        assert!(reserialized.get("permit2Hash").is_none());
+        let round_tripped: SingleQuoteResponse = serde_json::from_value(reserialized).unwrap();
+        assert_eq!(round_tripped, quote);
+    }
+
+    #[test]
+    fn test_path_viz_round_trip() {
+        let quote: SingleQuoteResponse = serde_json::from_str(PATH_VIZ_QUOTE).unwrap();
+
+        let viz = quote.path_viz().unwrap().unwrap();
+        assert_eq!(viz.nodes.len(), 2);
+        assert_eq!(viz.nodes[0].symbol, "USDC");
+        assert_eq!(viz.nodes[1].decimals, Some(18));
+        assert_eq!(viz.links.len(), 2);
+        assert_eq!(viz.links[0].protocol, "Uniswap V3");
+        assert_eq!(viz.links[0].percentage, 62.5);
+        assert_eq!(viz.links[1].out_value, Some(374.60));
+
+        let (from, to) = viz.link_nodes(&viz.links[1]).unwrap();
+        assert_eq!((from.symbol.as_str(), to.symbol.as_str()), ("USDC", "WETH"));
+
+        // Fields the SDK does not model remain reachable and survive a round trip
+        let raw = quote.path_viz_raw().unwrap();
+        assert_eq!(raw["links"][0]["edge_len"], 1);
+
+        let reserialized = serde_json::to_string(&quote).unwrap();
+        let round_tripped: SingleQuoteResponse = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(round_tripped, quote);
+        assert_eq!(quote.path_viz_image(), None);
+    }
+
+    #[test]
+    fn test_quote_ordering_with_path_viz() {
+        let quote: SingleQuoteResponse = serde_json::from_str(PATH_VIZ_QUOTE).unwrap();
+        assert_eq!(
+            quote.partial_cmp(&quote.clone()),
+            Some(std::cmp::Ordering::Equal)
+        );
+
+        let mut json: serde_json::Value = serde_json::from_str(PATH_VIZ_QUOTE).unwrap();
+        let block_number = json["blockNumber"].as_u64().unwrap();
+        json["blockNumber"] = serde_json::json!(block_number + 1);
+        let later: SingleQuoteResponse = serde_json::from_value(json.clone()).unwrap();
+        assert!(quote < later);
+
+        json["blockNumber"] = serde_json::json!(block_number);
+        json["pathViz"]["links"][0]["edge_len"] = serde_json::json!(2);
+        let other_graph: SingleQuoteResponse = serde_json::from_value(json).unwrap();
+        assert_ne!(quote, other_graph);
+        assert!(quote
+            .partial_cmp(&other_graph)
+            .is_some_and(|order| order.is_ne()));
+    }
+
+    #[test]
+    fn test_path_viz_absent_or_null() {
+        let mut json: serde_json::Value = serde_json::from_str(PATH_VIZ_QUOTE).unwrap();
+        json["pathViz"] = serde_json::Value::Null;
+        let quote: SingleQuoteResponse = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(quote.path_viz().unwrap(), None);
+        assert_eq!(quote.path_viz_raw(), None);
+
+        json.as_object_mut().unwrap().remove("pathViz");
+        let quote: SingleQuoteResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(quote.path_viz().unwrap(), None);
+    }
+
+    #[test]
+    fn test_quote_request_path_viz_flags() {
+        let request = QuoteRequest::builder()
+            .chain(Chain::base())
+            .input_tokens(vec![])
+            .output_tokens(vec![])
+            .slippage_limit_percent(0.5)
+            .user_addr(Address::ZERO)
+            .compact(true)
+            .simple(false)
+            .referral_code(0)
+            .disable_rfqs(false)
+            .path_viz(true)
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["pathViz"], true);
+        assert_eq!(json["pathVizImage"], false);
+    }
+
+    #[test]
+    fn test_quote_request_chain_serializes_as_id() {
+        let request = request_with(vec![], vec![], 0.5);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["chainId"], 1);
+
+        let parsed: QuoteRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.chain(), Chain::ethereum());
+        assert_eq!(QuoteRequest::default().chain(), Chain::ethereum());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_chain_id_setter() {
+        let request = QuoteRequest::builder()
+            .chain_id(42161)
+            .unwrap()
+            .input_tokens(vec![])
+            .output_tokens(vec![])
+            .slippage_limit_percent(0.5)
+            .user_addr(Address::ZERO)
+            .compact(true)
+            .simple(false)
+            .referral_code(0)
+            .disable_rfqs(false)
+            .build();
+        assert_eq!(request.chain(), Chain::arbitrum());
+
+        assert!(matches!(
+            QuoteRequest::builder().chain_id(u64::MAX),
+            Err(crate::OdosChainError::UnsupportedChain { .. })
+        ));
+    }
+
+    #[test]
+    fn test_quote_request_like_asset_only_sent_when_enabled() {
+        let mut request = request_with(
+            vec![(USDC, U256::from(1)).into()],
+            vec![(WETH, 1.0).into()],
+            0.5,
+        );
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("likeAsset").is_none());
+
+        request.like_asset = true;
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["likeAsset"], true);
+    }
+
+    #[test]
+    fn test_quote_request_source_lists() {
+        let mut request = request_with(
+            vec![(USDC, U256::from(1)).into()],
+            vec![(WETH, 1.0).into()],
+            0.5,
+        );
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["sourceWhitelist"], serde_json::json!([]));
+        assert_eq!(json["sourceBlacklist"], serde_json::json!([]));
+
+        request.source_whitelist = vec!["Uniswap V3".to_string()];
+        request.source_blacklist = vec!["Hashflow".to_string()];
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["sourceWhitelist"], serde_json::json!(["Uniswap V3"]));
+        assert_eq!(json["sourceBlacklist"], serde_json::json!(["Hashflow"]));
+        assert!(request.validate().is_ok());
+    }
+
+    const USDC: Address = alloy_primitives::address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = alloy_primitives::address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+
+    fn request_with(
+        input_tokens: Vec<InputToken>,
+        output_tokens: Vec<OutputToken>,
+        slippage: f64,
+    ) -> QuoteRequest {
+        QuoteRequest::builder()
+            .chain(Chain::ethereum())
+            .input_tokens(input_tokens)
+            .output_tokens(output_tokens)
+            .slippage_limit_percent(slippage)
+            .user_addr(Address::ZERO)
+            .compact(false)
+            .simple(false)
+            .referral_code(0)
+            .disable_rfqs(false)
+            .build()
+    }
+
+    fn assert_invalid(request: QuoteRequest, field: &str) {
+        match request.validate() {
+            Err(OdosError::InvalidInput(message)) => {
+                assert!(
+                    message.starts_with(field),
+                    "{message:?} should name {field}"
+                )
+            }
+            other => panic!("Expected InvalidInput for {field}, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_quote_request_validate_accepts_well_formed() {
+        let request = request_with(
+            vec![(USDC, U256::from(1_000_000)).into()],
+            vec![(WETH, 1.0).into()],
+            0.5,
+        );
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_quote_request_validate_rejects_malformed() {
+        let input = || vec![InputToken::new(USDC, U256::from(1_000_000))];
+        let output = || vec![OutputToken::new(WETH, 1.0)];
+        let amount = |amount: &str| vec![InputToken::new(USDC, amount.parse().unwrap())];
+
+        assert_invalid(request_with(vec![], output(), 0.5), "input_tokens");
+        assert_invalid(request_with(input(), vec![], 0.5), "output_tokens");
+        assert_invalid(
+            request_with(vec![(USDC, U256::ZERO).into()], output(), 0.5),
+            "input_tokens[0].amount",
+        );
+        assert_invalid(
+            request_with(amount(&"9".repeat(64)), output(), 0.5),
+            "input_tokens[0].amount",
+        );
+        assert_invalid(
+            request_with(input(), vec![(WETH, 0.0).into()], 0.5),
+            "output_tokens[0].proportion",
+        );
+        assert_invalid(
+            request_with(input(), output(), 250.0),
+            "slippage_limit_percent",
+        );
+        assert_invalid(
+            request_with(input(), output(), -1.0),
+            "slippage_limit_percent",
+        );
+        assert_invalid(
+            request_with(input(), output(), f64::NAN),
+            "slippage_limit_percent",
+        );
+        assert_invalid(
+            request_with(input(), vec![(USDC, 1.0).into()], 0.5),
+            "output_tokens[0].token_address",
+        );
+        let mut request = request_with(input(), output(), 0.5);
+        request.source_whitelist = vec!["Uniswap V3".to_string()];
+        request.source_blacklist = vec!["Uniswap V3".to_string()];
+        assert_invalid(request, "source_whitelist");
+        for gas_price in [0.0, -1.0, f64::INFINITY] {
+            let mut request = request_with(input(), output(), 0.5);
+            request.gas_price = Some(gas_price);
+            assert_invalid(request, "gas_price");
+        }
+    }
+
+    #[test]
+    fn test_quote_request_validate_amount_digit_limit() {
+        // 63 digits is the largest amount the API accepts
+        let request = request_with(
+            vec![InputToken::new(USDC, "9".repeat(63).parse().unwrap())],
+            vec![(WETH, 1.0).into()],
+            0.5,
+        );
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_quote_request_wire_format() {
+        let request = request_with(
+            vec![(USDC, U256::from(10).pow(U256::from(30))).into()],
+            vec![(WETH, 1.0).into()],
+            0.5,
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json["userAddr"],
+            "0x0000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            json["inputTokens"][0],
+            serde_json::json!({
+                "tokenAddress": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "amount": format!("1{}", "0".repeat(30)),
+            })
+        );
+
+        let round_trip: QuoteRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(round_trip, request);
+    }
+
+    #[test]
+    fn test_input_token_rejects_non_decimal_amounts() {
+        for amount in ["", "-5", "0x10", "1e18", "12abc"] {
+            let json = serde_json::json!({ "tokenAddress": USDC, "amount": amount });
+            assert!(
+                serde_json::from_value::<InputToken>(json).is_err(),
+                "Expected {amount:?} to be rejected"
+            );
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    mod decoded_swap {
+        use alloy_primitives::{address, hex};
+        use alloy_sol_types::SolCall;
+
+        use super::*;
+        use crate::OdosV2Router::transferRouterFundsCall;
+
+        const EXECUTOR: Address = address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99");
+        const SIGNER: Address = address!("47E2D28169738039755586743E2dfCF3bd643f86");
+        const DAI: Address = address!("6B175474E89094C44Da98b954EedeAC495271d0F");
+
+        /// V2 `swapMulti` of 1,000 USDC and 0.5 WETH into DAI
+        const V2_SWAP_MULTI_CALLDATA: &str = concat!(
+            "7bf2d6d4",
+            "00000000000000000000000000000000000000000000000000000000000000c0",
+            "00000000000000000000000000000000000000000000000000000000000001a0",
+            "00000000000000000000000000000000000000000000008670e9ec6598c00000",
+            "0000000000000000000000000000000000000000000000000000000000000220",
+            "00000000000000000000000076edf8c155a1e0d9b2ad11b04d9671cbc25fee99",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "000000000000000000000000000000000000000000000000000000003b9aca00",
+            "00000000000000000000000076edf8c155a1e0d9b2ad11b04d9671cbc25fee99",
+            "000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+            "00000000000000000000000000000000000000000000000006f05b59d3b20000",
+            "00000000000000000000000076edf8c155a1e0d9b2ad11b04d9671cbc25fee99",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000006b175474e89094c44da98b954eedeac495271d0f",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "0102030000000000000000000000000000000000000000000000000000000000",
+        );
+
+        #[cfg(feature = "v3")]
+        /// V3 `swapMulti` of 1,000 USDC into WETH and DAI
+        const V3_SWAP_MULTI_CALLDATA: &str = concat!(
+            "fef828dc",
+            "00000000000000000000000000000000000000000000000000000000000000e0",
+            "0000000000000000000000000000000000000000000000000000000000000160",
+            "0000000000000000000000000000000000000000000000000000000000000280",
+            "00000000000000000000000076edf8c155a1e0d9b2ad11b04d9671cbc25fee99",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "000000000000000000000000000000000000000000000000000000003b9aca00",
+            "00000000000000000000000076edf8c155a1e0d9b2ad11b04d9671cbc25fee99",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+            "000000000000000000000000000000000000000000000000027f7d0bdb920000",
+            "000000000000000000000000000000000000000000000000027bef8d36cb8000",
+            "00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86",
+            "0000000000000000000000006b175474e89094c44da98b954eedeac495271d0f",
+            "000000000000000000000000000000000000000000000015af1d78b58c400000",
+            "000000000000000000000000000000000000000000000015935c0b4e3d780000",
+            "00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "0102030000000000000000000000000000000000000000000000000000000000",
+        );
+
+        fn decode(calldata: &str) -> Result<DecodedSwap> {
+            DecodedSwap::from_calldata(&hex::decode(calldata).unwrap().into())
+        }
+
+        #[test]
+        fn test_decode_v2_swap_multi() {
+            let DecodedSwap::Multi(swap) = decode(V2_SWAP_MULTI_CALLDATA).unwrap() else {
+                panic!("expected a multi swap");
+            };
+
+            assert_eq!(swap.executor(), EXECUTOR);
+            assert_eq!(swap.path_definition().as_ref(), &[0x01, 0x02, 0x03]);
+            let inputs: Vec<_> = swap
+                .inputs()
+                .iter()
+                .map(|input| (input.tokenAddress, input.amountIn, input.receiver))
+                .collect();
+            assert_eq!(
+                inputs,
+                vec![
+                    (USDC, U256::from(1_000_000_000u64), EXECUTOR),
+                    (WETH, U256::from(500_000_000_000_000_000u64), EXECUTOR),
+                ]
+            );
+            let outputs: Vec<_> = swap
+                .outputs()
+                .iter()
+                .map(|output| (output.tokenAddress, output.relativeValue, output.receiver))
+                .collect();
+            assert_eq!(outputs, vec![(DAI, U256::from(1), SIGNER)]);
+            assert_eq!(
+                swap.value_out_min(),
+                Some(U256::from(2_480_000_000_000_000_000_000u128))
+            );
+            assert_eq!(swap.output_amount_quotes(), None);
+            assert_eq!(swap.output_amount_mins(), None);
+        }
+
+        #[cfg(feature = "v3")]
+        #[test]
+        fn test_decode_v3_swap_multi() {
+            let DecodedSwap::Multi(swap) = decode(V3_SWAP_MULTI_CALLDATA).unwrap() else {
+                panic!("expected a multi swap");
+            };
+
+            assert_eq!(swap.executor(), EXECUTOR);
+            assert_eq!(swap.inputs().len(), 1);
+            assert_eq!(swap.inputs()[0].tokenAddress, USDC);
+            assert_eq!(swap.inputs()[0].amountIn, U256::from(1_000_000_000u64));
+            let outputs: Vec<_> = swap
+                .outputs()
+                .iter()
+                .map(|output| (output.tokenAddress, output.relativeValue, output.receiver))
+                .collect();
+            assert_eq!(
+                outputs,
+                vec![(WETH, U256::ZERO, SIGNER), (DAI, U256::ZERO, SIGNER)]
+            );
+            assert_eq!(swap.value_out_min(), None);
+            assert_eq!(
+                swap.output_amount_quotes(),
+                Some(
+                    &[
+                        U256::from(180_000_000_000_000_000u64),
+                        U256::from(400_000_000_000_000_000_000u128),
+                    ][..]
+                )
+            );
+            assert_eq!(
+                swap.output_amount_mins(),
+                Some(
+                    &[
+                        U256::from(179_000_000_000_000_000u64),
+                        U256::from(398_000_000_000_000_000_000u128),
+                    ][..]
+                )
+            );
+        }
+
+        #[test]
+        fn test_decode_single_swap() {
+            let call = swapCall {
+                tokenInfo: swapTokenInfo {
+                    inputToken: USDC,
+                    inputAmount: U256::from(1_000_000),
+                    inputReceiver: EXECUTOR,
+                    outputToken: WETH,
+                    outputQuote: U256::from(2_000),
+                    outputMin: U256::from(1_990),
+                    outputReceiver: SIGNER,
+                },
+                pathDefinition: Bytes::new(),
+                executor: EXECUTOR,
+                referralCode: 0,
+            };
+
+            let DecodedSwap::Single(swap) =
+                DecodedSwap::from_calldata(&call.abi_encode().into()).unwrap()
+            else {
+                panic!("expected a single swap");
+            };
+            assert_eq!(swap.token_address(), USDC);
+            assert_eq!(swap.output_token_address(), WETH);
+            assert_eq!(swap.value_out_min(), U256::from(1_990));
+        }
+
+        #[test]
+        fn test_decode_rejects_other_calldata() {
+            let transfer = transferRouterFundsCall {
+                tokens: vec![USDC],
+                amounts: vec![U256::from(1)],
+                dest: SIGNER,
+            };
+            assert!(matches!(
+                DecodedSwap::from_calldata(&transfer.abi_encode().into()),
+                Err(OdosError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                decode("deadbeef"),
+                Err(OdosError::InvalidInput(_))
+            ));
+            assert!(
+                MultiSwapInputs::try_from(OdosV2RouterCalls::transferRouterFunds(transfer))
+                    .is_err()
+            );
+        }
+    }
 }
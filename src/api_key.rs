@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fmt, str::FromStr};
+use std::{env, fmt, str::FromStr};
 
 use uuid::Uuid;
 
@@ -67,6 +67,47 @@ impl ApiKey {
     pub fn as_str(&self) -> String {
         self.0.to_string()
     }
+
+    /// Environment variable read by [`from_env`](Self::from_env)
+    pub const ENV_VAR: &'static str = "ODOS_API_KEY";
+
+    /// Read the API key from the `ODOS_API_KEY` environment variable
+    ///
+    /// Returns `Ok(None)` if the variable is unset, and an
+    /// [`OdosError::Configuration`] error if it is set but not a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::ApiKey;
+    ///
+    /// match ApiKey::from_env()? {
+    ///     Some(api_key) => println!("Using API key {api_key}"),
+    ///     None => println!("No API key, using unauthenticated requests"),
+    /// }
+    /// # Ok::<(), odos_sdk::OdosError>(())
+    /// ```
+    pub fn from_env() -> Result<Option<Self>> {
+        let value = match env::var(Self::ENV_VAR) {
+            Ok(value) => value,
+            Err(env::VarError::NotPresent) => return Ok(None),
+            Err(env::VarError::NotUnicode(_)) => {
+                return Err(OdosError::configuration_error(format!(
+                    "{} is not valid unicode",
+                    Self::ENV_VAR
+                )))
+            }
+        };
+
+        Uuid::from_str(value.trim())
+            .map(|uuid| Some(Self(uuid)))
+            .map_err(|e| {
+                OdosError::configuration_error(format!(
+                    "{} is not a valid API key (expected UUID): {e}",
+                    Self::ENV_VAR
+                ))
+            })
+    }
 }
 
 impl FromStr for ApiKey {
@@ -101,9 +142,53 @@ impl fmt::Display for ApiKey {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
     use super::*;
 
+    /// Serializes tests that change the process environment
+    pub(crate) static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Run `f` with the environment variables in `vars` set (or removed for
+    /// `None`), restoring their previous values afterwards
+    pub(crate) fn with_env<T>(vars: &[(&str, Option<&str>)], f: impl FnOnce() -> T) -> T {
+        let _lock = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let previous: Vec<_> = vars
+            .iter()
+            .map(|(name, _)| (*name, env::var_os(name)))
+            .collect();
+        for (name, value) in vars {
+            match value {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+
+        let result = f();
+
+        for (name, value) in previous {
+            match value {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_api_key_from_env() {
+        let uuid = Uuid::new_v4().to_string();
+        let api_key = with_env(&[(ApiKey::ENV_VAR, Some(&uuid))], ApiKey::from_env);
+        assert_eq!(api_key.unwrap().unwrap().as_str(), uuid);
+
+        let api_key = with_env(&[(ApiKey::ENV_VAR, None)], ApiKey::from_env);
+        assert!(api_key.unwrap().is_none());
+
+        let api_key = with_env(&[(ApiKey::ENV_VAR, Some("not-a-uuid"))], ApiKey::from_env);
+        assert!(matches!(api_key, Err(OdosError::Configuration(_))));
+    }
+
     #[test]
     fn test_api_key_new() {
         let uuid = Uuid::new_v4();
@@ -0,0 +1,140 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! ERC-20 approval transactions for the Odos router.
+//!
+//! Swaps with an ERC-20 input pull the tokens through the router, so the
+//! router needs an allowance first. [`build_approval_tx`] encodes a standard
+//! `approve(address,uint256)` call; [`SwapBuilder::approval_transaction`](crate::SwapBuilder::approval_transaction)
+//! builds one for the router a swap will use.
+//!
+//! ```rust
+//! use alloy_primitives::{address, U256};
+//! use odos_sdk::approvals::build_approval_tx;
+//!
+//! let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+//! let owner = address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0");
+//! let router = odos_sdk::ODOS_V2_ETHEREUM_ROUTER;
+//!
+//! let tx = build_approval_tx(usdc, owner, router, U256::from(1_000_000));
+//! assert_eq!(tx.to, Some(usdc.into()));
+//! ```
+//!
+//! ## Tokens that require a zero allowance first
+//!
+//! Some tokens, most notably USDT on Ethereum, revert `approve` when both
+//! the current and the new allowance are non-zero, to narrow the
+//! approve/transferFrom front-running window. For those tokens, set the
+//! allowance to zero before setting the new amount:
+//! [`build_two_step_approval_txs`] returns both transactions in order, and
+//! they must be mined in that order. Calling it for a token whose allowance
+//! is already zero only costs the extra transaction.
+
+use alloy_network::TransactionBuilder;
+use alloy_primitives::{Address, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_sol_types::{sol, SolCall};
+
+sol! {
+    #[allow(missing_docs)]
+    function approve(address spender, uint256 amount) external returns (bool);
+}
+
+/// How much allowance an approval grants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApprovalAmount {
+    /// Exactly the swap's input amount
+    Exact,
+    /// The maximum `uint256`, so later swaps need no further approval
+    Max,
+}
+
+impl ApprovalAmount {
+    /// Get the allowance to approve for a swap spending `input_amount`
+    pub fn resolve(self, input_amount: U256) -> U256 {
+        match self {
+            Self::Exact => input_amount,
+            Self::Max => U256::MAX,
+        }
+    }
+}
+
+/// Build an unsigned `approve(spender, amount)` transaction from `owner` to
+/// `token`
+///
+/// Gas, fees and nonce are left for the caller or provider to fill.
+pub fn build_approval_tx(
+    token: Address,
+    owner: Address,
+    spender: Address,
+    amount: U256,
+) -> TransactionRequest {
+    TransactionRequest::default()
+        .with_from(owner)
+        .with_to(token)
+        .with_input(approveCall { spender, amount }.abi_encode())
+}
+
+/// Build an approval that first resets the allowance to zero, for tokens
+/// such as USDT that reject changing a non-zero allowance
+///
+/// Returns `[approve(spender, 0), approve(spender, amount)]`. Send them in
+/// order and wait for the first to be mined before sending the second.
+pub fn build_two_step_approval_txs(
+    token: Address,
+    owner: Address,
+    spender: Address,
+    amount: U256,
+) -> [TransactionRequest; 2] {
+    [
+        build_approval_tx(token, owner, spender, U256::ZERO),
+        build_approval_tx(token, owner, spender, amount),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const OWNER: Address = address!("742d35cc6634c0532925a3b8d35f3e7a5edd29c0");
+    const ROUTER: Address = address!("cf5540fffcdc3d510b18bfca6d2b9987b0772559");
+
+    #[test]
+    fn test_approve_encoding() {
+        let tx = build_approval_tx(USDC, OWNER, ROUTER, U256::from(1_000_000));
+        let input = tx.input.input().unwrap();
+
+        assert_eq!(tx.from, Some(OWNER));
+        assert_eq!(tx.to, Some(USDC.into()));
+        assert_eq!(input.len(), 4 + 32 + 32);
+        // keccak256("approve(address,uint256)")[..4]
+        assert_eq!(&input[..4], &[0x09, 0x5e, 0xa7, 0xb3]);
+        // Spender, left-padded to 32 bytes
+        assert!(input[4..16].iter().all(|byte| *byte == 0));
+        assert_eq!(&input[16..36], ROUTER.as_slice());
+        // Amount, big-endian
+        assert_eq!(U256::from_be_slice(&input[36..68]), U256::from(1_000_000));
+    }
+
+    #[test]
+    fn test_two_step_approval_resets_first() {
+        let [reset, approve] = build_two_step_approval_txs(USDC, OWNER, ROUTER, U256::MAX);
+
+        let reset = approveCall::abi_decode(reset.input.input().unwrap()).unwrap();
+        assert_eq!(reset.spender, ROUTER);
+        assert_eq!(reset.amount, U256::ZERO);
+
+        let approve = approveCall::abi_decode(approve.input.input().unwrap()).unwrap();
+        assert_eq!(approve.amount, U256::MAX);
+    }
+
+    #[test]
+    fn test_approval_amount_resolve() {
+        assert_eq!(ApprovalAmount::Exact.resolve(U256::from(5)), U256::from(5));
+        assert_eq!(ApprovalAmount::Max.resolve(U256::from(5)), U256::MAX);
+    }
+}
@@ -2,19 +2,24 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
 
 use alloy_network::TransactionBuilder;
 use alloy_primitives::{hex, Address, U256};
 use alloy_rpc_types::TransactionRequest;
 use serde::{Deserialize, Serialize};
 
+use crate::{api::hash_f64, PathId};
+
 /// Request to the Odos Assemble API: <https://docs.odos.xyz/build/api-docs>
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssembleRequest {
     pub user_addr: Address,
-    pub path_id: String,
+    pub path_id: PathId,
     pub simulate: bool,
     pub receiver: Option<Address>,
 }
@@ -35,13 +40,83 @@ impl Display for AssembleRequest {
 }
 
 /// Response from the Odos Assemble API: <https://docs.odos.xyz/build/api-docs>
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+///
+/// Returned in full by [`OdosClient::assemble_full`](crate::OdosClient::assemble_full).
+#[derive(Clone, Debug, PartialEq, PartialOrd, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssemblyResponse {
+    /// Deprecation notice for the requested path, if any
+    #[serde(default)]
+    pub deprecated: Option<String>,
+    /// Block number the swap was assembled at
+    #[serde(default)]
+    pub block_number: Option<u64>,
+    /// Estimated gas used by the swap
+    #[serde(default)]
+    pub gas_estimate: Option<f64>,
+    /// Estimated gas cost in USD
+    #[serde(default)]
+    pub gas_estimate_value: Option<f64>,
+    /// Input tokens and exact amounts of the swap
+    #[serde(default)]
+    pub input_tokens: Vec<AssembledToken>,
+    /// Output tokens and quoted amounts of the swap
+    #[serde(default)]
+    pub output_tokens: Vec<AssembledToken>,
+    /// USD value of the outputs net of gas
+    #[serde(default)]
+    pub net_out_value: Option<f64>,
+    /// USD value of each output, in the order of `output_tokens`
+    #[serde(default)]
+    pub out_values: Vec<f64>,
     pub transaction: TransactionData,
     pub simulation: Option<Simulation>,
 }
 
+/// Token and amount on one side of an assembled swap
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembledToken {
+    /// Token contract address
+    pub token_address: Address,
+    /// Amount in base units
+    #[serde(with = "crate::api::decimal_u256")]
+    pub amount: U256,
+}
+
+impl Hash for AssemblyResponse {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            deprecated,
+            block_number,
+            gas_estimate,
+            gas_estimate_value,
+            input_tokens,
+            output_tokens,
+            net_out_value,
+            out_values,
+            transaction,
+            simulation,
+        } = self;
+        deprecated.hash(state);
+        block_number.hash(state);
+        for value in [gas_estimate, gas_estimate_value, net_out_value] {
+            value.is_some().hash(state);
+            if let Some(value) = value {
+                hash_f64(*value, state);
+            }
+        }
+        input_tokens.hash(state);
+        output_tokens.hash(state);
+        out_values.len().hash(state);
+        for value in out_values {
+            hash_f64(*value, state);
+        }
+        transaction.hash(state);
+        simulation.hash(state);
+    }
+}
+
 impl Display for AssemblyResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -69,6 +144,29 @@ pub struct TransactionData {
     pub nonce: u64,
 }
 
+impl TransactionData {
+    /// Native value to send, parsed with [`parse_value`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`](crate::OdosError::InvalidInput)
+    /// if the API returned a value `parse_value` rejects.
+    pub fn value(&self) -> crate::Result<U256> {
+        parse_value(&self.value)
+    }
+
+    /// Gas limit suggested by the API, or `None` if it reported a negative
+    /// value
+    pub fn gas(&self) -> Option<u128> {
+        u128::try_from(self.gas).ok()
+    }
+
+    /// Gas price in wei the API assembled the transaction with
+    pub fn gas_price(&self) -> u128 {
+        self.gas_price
+    }
+}
+
 /// Convert [`TransactionData`] to a [`TransactionRequest`].
 impl TryFrom<TransactionData> for TransactionRequest {
     type Error = crate::OdosError;
@@ -101,13 +199,19 @@ impl Display for TransactionData {
 }
 
 /// Simulation from the Odos Assemble API: <https://docs.odos.xyz/build/api-docs>
+///
+/// Only present when the assemble request set `simulate`; see
+/// [`OdosClient::assemble_with_simulation`](crate::OdosClient::assemble_with_simulation).
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Simulation {
     is_success: bool,
+    #[serde(default, deserialize_with = "amounts_as_strings")]
     amounts_out: Vec<String>,
+    #[serde(default)]
     gas_estimate: i64,
-    simulation_error: SimulationError,
+    #[serde(default)]
+    simulation_error: Option<SimulationError>,
 }
 
 impl Simulation {
@@ -115,8 +219,34 @@ impl Simulation {
         self.is_success
     }
 
+    /// Output amounts of the simulated swap, in base units, in the order of
+    /// the quote's output tokens
+    pub fn amounts_out(&self) -> &[String] {
+        &self.amounts_out
+    }
+
+    /// Gas used by the simulated swap
+    pub fn gas_estimate(&self) -> i64 {
+        self.gas_estimate
+    }
+
+    /// The reported failure, if the simulation failed
+    pub fn simulation_error(&self) -> Option<&SimulationError> {
+        self.simulation_error.as_ref()
+    }
+
+    /// The reported failure message, or an empty string if there is none
     pub fn error_message(&self) -> &str {
-        &self.simulation_error.error_message
+        self.simulation_error
+            .as_ref()
+            .map_or("", SimulationError::error_message)
+    }
+
+    /// Take the reported failure, or a placeholder if a failed simulation
+    /// did not report one
+    pub(crate) fn into_error(self) -> SimulationError {
+        self.simulation_error
+            .unwrap_or_else(|| SimulationError::new("unknown", "No simulation error reported"))
     }
 }
 
@@ -128,11 +258,32 @@ impl Display for Simulation {
             self.is_success,
             self.amounts_out,
             self.gas_estimate,
-            self.simulation_error.error_message
+            self.error_message()
         )
     }
 }
 
+/// Deserialize amounts the API reports either as decimal strings or as JSON
+/// integers into decimal strings
+fn amounts_as_strings<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        String(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(Vec::<Amount>::deserialize(deserializer)?
+        .into_iter()
+        .map(|amount| match amount {
+            Amount::String(amount) => amount,
+            Amount::Number(amount) => amount.to_string(),
+        })
+        .collect())
+}
+
 /// Simulation error from the Odos Assemble API: <https://docs.odos.xyz/build/api-docs>
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -142,6 +293,19 @@ pub struct SimulationError {
 }
 
 impl SimulationError {
+    /// Create a simulation error from its type and message
+    pub fn new(error_type: impl Into<String>, error_message: impl Into<String>) -> Self {
+        Self {
+            r#type: error_type.into(),
+            error_message: error_message.into(),
+        }
+    }
+
+    /// Kind of failure reported by the API
+    pub fn error_type(&self) -> &str {
+        &self.r#type
+    }
+
     pub fn error_message(&self) -> &str {
         &self.error_message
     }
@@ -153,10 +317,16 @@ impl Display for SimulationError {
     }
 }
 
-/// Parse a value string as U256, supporting both decimal and hexadecimal formats
+/// Parse a value string as U256, supporting decimal and hexadecimal formats
+///
+/// Accepts:
+/// - decimal integers such as `"1000"`,
+/// - hexadecimal with or without the `0x` prefix, such as `"0xff"` or
+///   `"ff"`.
 ///
-/// This function attempts to parse the value as decimal first, then as hexadecimal
-/// (with optional "0x" prefix) if decimal parsing fails.
+/// Decimal takes precedence: an unprefixed string of digits alone is
+/// decimal, and one containing a hex letter is hexadecimal, so `"1e3"` is
+/// `0x1e3`.
 ///
 /// # Arguments
 ///
@@ -165,7 +335,9 @@ impl Display for SimulationError {
 /// # Returns
 ///
 /// * `Ok(U256)` - The parsed value
-/// * `Err(OdosError)` - If the value cannot be parsed in either format
+/// * `Err(OdosError::InvalidInput)` - If the value is empty, negative,
+///   fractional, malformed or too large for a `U256`; the message includes
+///   the offending string
 ///
 /// # Examples
 ///
@@ -184,33 +356,218 @@ impl Display for SimulationError {
 /// // Hexadecimal without prefix
 /// let val = parse_value("ff")?;
 /// assert_eq!(val, U256::from(255));
+///
+/// // Negative and fractional values are rejected
+/// assert!(parse_value("-1").is_err());
+/// assert!(parse_value("0.5").is_err());
 /// # Ok(())
 /// # }
 /// ```
 pub fn parse_value(value: &str) -> crate::Result<U256> {
     use crate::OdosError;
 
-    if value == "0" {
-        return Ok(U256::ZERO);
+    let invalid =
+        |reason: &str| OdosError::invalid_input(format!("Invalid value '{value}': {reason}"));
+
+    if value.is_empty() {
+        return Err(invalid("empty string"));
+    }
+    if value.starts_with('-') {
+        return Err(invalid("negative values are not allowed"));
     }
 
-    // Try parsing as decimal first
-    U256::from_str_radix(value, 10).or_else(|decimal_err| {
-        // If decimal fails, try hexadecimal (with optional "0x" prefix)
-        let hex_value = value.strip_prefix("0x").unwrap_or(value);
-        U256::from_str_radix(hex_value, 16).map_err(|hex_err| {
-            OdosError::invalid_input(format!(
-                "Failed to parse value '{}' as decimal ({}) or hexadecimal ({})",
-                value, decimal_err, hex_err
-            ))
-        })
-    })
+    if let Some(hex_value) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        if hex_value.is_empty() || !hex_value.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid("expected hexadecimal digits after '0x'"));
+        }
+        return U256::from_str_radix(hex_value, 16).map_err(|err| invalid(&err.to_string()));
+    }
+
+    if value.bytes().all(|b| b.is_ascii_digit()) {
+        return U256::from_str_radix(value, 10).map_err(|err| invalid(&err.to_string()));
+    }
+
+    if value.contains('.') {
+        return Err(invalid("fractional values are not allowed"));
+    }
+    if !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid("expected decimal or hexadecimal digits"));
+    }
+    U256::from_str_radix(value, 16).map_err(|err| invalid(&err.to_string()))
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
+    /// Trimmed `POST /sor/assemble` response with a passing simulation
+    pub(crate) const ASSEMBLE_SIMULATION_SUCCESS_FIXTURE: &str = r#"{
+        "transaction": {
+            "to": "0x0D05a7D3448512B78fa8A9e46c4872C88C4a0D05",
+            "from": "0x0000000000000000000000000000000000000001",
+            "data": "0x83bd37f9",
+            "value": "0",
+            "gas": 210000,
+            "gasPrice": 12000000000,
+            "chainId": 1,
+            "nonce": 7
+        },
+        "simulation": {
+            "isSuccess": true,
+            "amountsOut": [412643998796366],
+            "gasEstimate": 176113,
+            "simulationError": null
+        }
+    }"#;
+
+    /// Trimmed `POST /sor/assemble` response with a failing simulation
+    pub(crate) const ASSEMBLE_SIMULATION_FAILURE_FIXTURE: &str = r#"{
+        "transaction": {
+            "to": "0x0D05a7D3448512B78fa8A9e46c4872C88C4a0D05",
+            "from": "0x0000000000000000000000000000000000000001",
+            "data": "0x83bd37f9",
+            "value": "0",
+            "gas": 210000,
+            "gasPrice": 12000000000,
+            "chainId": 1,
+            "nonce": 7
+        },
+        "simulation": {
+            "isSuccess": false,
+            "amountsOut": [],
+            "gasEstimate": 0,
+            "simulationError": {
+                "type": "execution_reverted",
+                "errorMessage": "ERC20: transfer amount exceeds allowance"
+            }
+        }
+    }"#;
+
+    /// Captured `POST /sor/assemble` response, trimmed calldata
+    pub(crate) const ASSEMBLE_FIXTURE: &str = r#"{
+        "deprecated": null,
+        "blockNumber": 21512345,
+        "gasEstimate": 196875,
+        "gasEstimateValue": 3.0214,
+        "inputTokens": [
+            { "tokenAddress": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", "amount": "1000000000" }
+        ],
+        "outputTokens": [
+            { "tokenAddress": "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2", "amount": "297096823183507284" }
+        ],
+        "netOutValue": 996.42,
+        "outValues": [999.4414],
+        "transaction": {
+            "gas": 295312,
+            "gasPrice": 6851234567,
+            "value": "0",
+            "to": "0xCf5540fFFCdC3d510B18bFcA6d2b9987b0772559",
+            "from": "0x47E2D28169738039755586743E2dfCF3bd643f86",
+            "data": "0x83bd37f9",
+            "nonce": 42,
+            "chainId": 1
+        },
+        "simulation": null
+    }"#;
+
+    #[test]
+    fn test_assembly_response_deserializes_captured_response() {
+        let response: AssemblyResponse = serde_json::from_str(ASSEMBLE_FIXTURE).unwrap();
+
+        assert_eq!(response.deprecated, None);
+        assert_eq!(response.block_number, Some(21_512_345));
+        assert_eq!(response.gas_estimate, Some(196_875.0));
+        assert_eq!(
+            response.input_tokens,
+            [AssembledToken {
+                token_address: alloy_primitives::address!(
+                    "a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+                ),
+                amount: U256::from(1_000_000_000u64),
+            }]
+        );
+        assert_eq!(
+            response.output_tokens[0].amount,
+            U256::from(297_096_823_183_507_284u64)
+        );
+        assert_eq!(response.net_out_value, Some(996.42));
+        assert_eq!(response.out_values, [999.4414]);
+        assert_eq!(response.transaction.nonce, 42);
+        assert_eq!(response.simulation, None);
+    }
+
+    #[test]
+    fn test_assembly_response_hash_and_order() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let response: AssemblyResponse = serde_json::from_str(ASSEMBLE_FIXTURE).unwrap();
+        let mut cheaper = response.clone();
+        cheaper.gas_estimate_value = cheaper.gas_estimate_value.map(|value| value / 2.0);
+
+        let hasher = RandomState::new();
+        assert_eq!(
+            hasher.hash_one(&response),
+            hasher.hash_one(response.clone())
+        );
+        assert_ne!(hasher.hash_one(&response), hasher.hash_one(&cheaper));
+        assert!(cheaper < response);
+    }
+
+    #[test]
+    fn test_assembly_response_tolerates_missing_summary_fields() {
+        let response: AssemblyResponse =
+            serde_json::from_str(ASSEMBLE_SIMULATION_SUCCESS_FIXTURE).unwrap();
+
+        assert_eq!(response.block_number, None);
+        assert!(response.output_tokens.is_empty());
+    }
+
+    #[test]
+    fn test_transaction_data_typed_accessors() {
+        let mut transaction = serde_json::from_str::<AssemblyResponse>(ASSEMBLE_FIXTURE)
+            .unwrap()
+            .transaction;
+        transaction.value = "0x2386f26fc10000".to_string();
+
+        assert_eq!(
+            transaction.value().unwrap(),
+            U256::from(10_000_000_000_000_000u64)
+        );
+        assert_eq!(transaction.gas(), Some(295_312));
+        assert_eq!(transaction.gas_price(), 6_851_234_567);
+
+        transaction.gas = -1;
+        assert_eq!(transaction.gas(), None);
+        transaction.value = "1.5".to_string();
+        assert!(transaction.value().is_err());
+    }
+
+    #[test]
+    fn test_simulation_deserializes_fixtures() {
+        let response: AssemblyResponse =
+            serde_json::from_str(ASSEMBLE_SIMULATION_SUCCESS_FIXTURE).unwrap();
+        let simulation = response.simulation.unwrap();
+        assert!(simulation.is_success());
+        assert_eq!(simulation.amounts_out(), ["412643998796366"]);
+        assert_eq!(simulation.gas_estimate(), 176_113);
+        assert_eq!(simulation.simulation_error(), None);
+        assert_eq!(simulation.error_message(), "");
+
+        let response: AssemblyResponse =
+            serde_json::from_str(ASSEMBLE_SIMULATION_FAILURE_FIXTURE).unwrap();
+        let simulation = response.simulation.unwrap();
+        assert!(!simulation.is_success());
+        let error = simulation.simulation_error().unwrap();
+        assert_eq!(error.error_type(), "execution_reverted");
+        assert_eq!(
+            error.error_message(),
+            "ERC20: transfer amount exceeds allowance"
+        );
+    }
+
     #[test]
     fn test_parse_value_zero() {
         let result = parse_value("0").unwrap();
@@ -260,6 +617,17 @@ mod tests {
         assert_ne!(parse_value("1234").unwrap(), U256::from(0x1234));
     }
 
+    #[test]
+    fn test_parse_value_hex_with_exponent_letter() {
+        // `e` is a hex digit, not an exponent
+        assert_eq!(parse_value("1e3").unwrap(), U256::from(0x1e3));
+        assert_eq!(parse_value("e1").unwrap(), U256::from(0xe1));
+        assert_eq!(parse_value("ee").unwrap(), U256::from(0xee));
+        assert_eq!(parse_value("1e").unwrap(), U256::from(0x1e));
+        assert!(parse_value("1.5e3").is_err());
+        assert!(parse_value("1e+3").is_err());
+    }
+
     #[test]
     fn test_parse_value_invalid() {
         // Invalid characters (not valid decimal or hex)
@@ -274,14 +642,39 @@ mod tests {
         let result = parse_value("12@34");
         assert!(result.is_err(), "Special characters should fail");
 
-        // Note: Empty string "" actually succeeds with from_str_radix(10) -> returns 0
-        // This is standard Rust behavior, so we accept it
-        let result = parse_value("");
-        assert_eq!(
-            result.unwrap(),
-            U256::ZERO,
-            "Empty string parses to zero (standard Rust behavior)"
-        );
+        // Empty values
+        assert!(parse_value("").is_err());
+        assert!(parse_value("0x").is_err());
+    }
+
+    #[test]
+    fn test_parse_value_rejects_negative_and_fractional() {
+        for value in ["-1", "-0x1", "0.5", "1.5", "1."] {
+            let err = parse_value(value).unwrap_err();
+            assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+            assert!(
+                err.to_string().contains(&format!("'{value}'")),
+                "error for {value} should name it: {err}"
+            );
+        }
+        assert!(parse_value("-1")
+            .unwrap_err()
+            .to_string()
+            .contains("negative"));
+        assert!(parse_value("1.5")
+            .unwrap_err()
+            .to_string()
+            .contains("fractional"));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_parse_value_round_trips(bytes in proptest::prelude::any::<[u8; 32]>()) {
+            let value = U256::from_be_bytes(bytes);
+
+            proptest::prop_assert_eq!(parse_value(&value.to_string()).unwrap(), value);
+            proptest::prop_assert_eq!(parse_value(&format!("{value:#x}")).unwrap(), value);
+        }
     }
 
     #[test]
@@ -0,0 +1,315 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_network::TransactionBuilder;
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_rpc_types::TransactionRequest;
+
+use crate::{OdosError, OdosV2Router::OdosV2RouterCalls, Result, NATIVE_TOKEN};
+
+#[cfg(feature = "v3")]
+use crate::OdosV3Router::OdosV3RouterCalls;
+
+/// A swap transaction as assembled by the Odos API
+///
+/// The transaction's `value` is set by the API to match the router call and
+/// should not be edited directly. Use [`with_additional_value`](Self::with_additional_value)
+/// to send more native value, which checks the calldata first.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use alloy_primitives::U256;
+/// use odos_sdk::{AssembledSwap, AssemblyRequest, OdosClient};
+///
+/// # async fn example(client: &OdosClient, request: &AssemblyRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let swap = AssembledSwap::new(client.assemble(request).await?);
+/// let tx = swap.with_additional_value(U256::from(1_000))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssembledSwap {
+    transaction: TransactionRequest,
+}
+
+impl AssembledSwap {
+    /// Wrap an assembled swap transaction
+    pub fn new(transaction: TransactionRequest) -> Self {
+        Self { transaction }
+    }
+
+    /// Get the transaction as assembled
+    pub fn transaction(&self) -> &TransactionRequest {
+        &self.transaction
+    }
+
+    /// Get the transaction as assembled, consuming the wrapper
+    pub fn into_transaction(self) -> TransactionRequest {
+        self.transaction
+    }
+
+    /// Get a copy of the transaction that sends `additional` native value on
+    /// top of the assembled value
+    ///
+    /// The router function is decoded from the calldata and the extra value is
+    /// only allowed when the router accepts it. On both the V2 and V3 routers,
+    /// every payable swap function requires `msg.value` to equal the native
+    /// input amount exactly, with one exception: a native input encoded with
+    /// an amount of zero swaps the whole `msg.value`. Extra value then
+    /// increases the swap input; it is not forwarded anywhere else.
+    ///
+    /// | Router call | Extra value |
+    /// |-------------|-------------|
+    /// | `swap`, `swapMulti`, `swapMultiPermit2` with a zero-amount native input | Allowed |
+    /// | The same functions with a fixed native input amount | Rejected: the router reverts |
+    /// | The same functions with only ERC-20 inputs | Rejected: the value would be left in the router |
+    /// | `swapCompact`, `swapMultiCompact` | Rejected: packed calldata cannot be checked |
+    /// | V3 `*WithHook` functions | Rejected: value handling depends on the hook |
+    /// | Non-payable functions such as `swapPermit2` | Rejected: the router reverts |
+    ///
+    /// V3 calldata is only recognized when the `v3` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`] if the calldata is not a router call
+    /// that accepts extra value, or if the total value overflows.
+    pub fn with_additional_value(&self, additional: U256) -> Result<TransactionRequest> {
+        let input = self.transaction.input.input().cloned().unwrap_or_default();
+        check_accepts_excess_value(&input)?;
+
+        let value = self
+            .transaction
+            .value
+            .unwrap_or_default()
+            .checked_add(additional)
+            .ok_or_else(|| {
+                OdosError::invalid_input("Transaction value overflows with additional value")
+            })?;
+
+        Ok(self.transaction.clone().with_value(value))
+    }
+}
+
+impl From<TransactionRequest> for AssembledSwap {
+    fn from(transaction: TransactionRequest) -> Self {
+        Self::new(transaction)
+    }
+}
+
+impl From<AssembledSwap> for TransactionRequest {
+    fn from(swap: AssembledSwap) -> Self {
+        swap.transaction
+    }
+}
+
+/// Check that the router call in `input` accepts `msg.value` beyond its
+/// quoted native input
+fn check_accepts_excess_value(input: &Bytes) -> Result<()> {
+    let (function, inputs) = decode_payable_inputs(input)?;
+
+    if inputs
+        .iter()
+        .any(|(token, amount)| *token == NATIVE_TOKEN && amount.is_zero())
+    {
+        return Ok(());
+    }
+
+    let reason = if inputs.iter().any(|(token, _)| *token == NATIVE_TOKEN) {
+        "the router requires the value to equal the native input amount"
+    } else {
+        "the swap has no native input, so the value would be left in the router"
+    };
+    Err(OdosError::invalid_input(format!(
+        "Router function '{function}' does not accept additional value: {reason}"
+    )))
+}
+
+/// Decode the router function name and its `(token, amount)` inputs
+///
+/// Fails for calldata that is not a payable swap with decodable inputs.
+fn decode_payable_inputs(input: &Bytes) -> Result<(&'static str, Vec<(Address, U256)>)> {
+    #[cfg(feature = "v3")]
+    if let Ok(call) = OdosV3RouterCalls::try_from(input) {
+        return match call {
+            OdosV3RouterCalls::swap(call) => Ok((
+                "swap",
+                vec![(call.tokenInfo.inputToken, call.tokenInfo.inputAmount)],
+            )),
+            OdosV3RouterCalls::swapMulti(call) => Ok((
+                "swapMulti",
+                call.inputs
+                    .iter()
+                    .map(|i| (i.tokenAddress, i.amountIn))
+                    .collect(),
+            )),
+            OdosV3RouterCalls::swapMultiPermit2(call) => Ok((
+                "swapMultiPermit2",
+                call.inputs
+                    .iter()
+                    .map(|i| (i.tokenAddress, i.amountIn))
+                    .collect(),
+            )),
+            _ => Err(unsupported_function(input)),
+        };
+    }
+
+    match OdosV2RouterCalls::try_from(input) {
+        Ok(OdosV2RouterCalls::swap(call)) => Ok((
+            "swap",
+            vec![(call.tokenInfo.inputToken, call.tokenInfo.inputAmount)],
+        )),
+        Ok(OdosV2RouterCalls::swapMulti(call)) => Ok((
+            "swapMulti",
+            call.inputs
+                .iter()
+                .map(|i| (i.tokenAddress, i.amountIn))
+                .collect(),
+        )),
+        Ok(OdosV2RouterCalls::swapMultiPermit2(call)) => Ok((
+            "swapMultiPermit2",
+            call.inputs
+                .iter()
+                .map(|i| (i.tokenAddress, i.amountIn))
+                .collect(),
+        )),
+        Ok(_) => Err(unsupported_function(input)),
+        Err(_) => Err(OdosError::invalid_input(
+            "Calldata is not a recognized Odos router call; additional value cannot be validated",
+        )),
+    }
+}
+
+fn unsupported_function(input: &Bytes) -> OdosError {
+    let selector = input.get(..4).map(alloy_primitives::hex::encode_prefixed);
+    OdosError::invalid_input(format!(
+        "Router function with selector {} does not accept additional value",
+        selector.as_deref().unwrap_or("<none>")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+    use alloy_sol_types::SolCall;
+
+    use super::*;
+    use crate::{
+        OdosRouterV2::{inputTokenInfo, outputTokenInfo, swapTokenInfo},
+        OdosV2Router::{swapCall, swapMultiCall, transferRouterFundsCall},
+    };
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+
+    fn v2_swap(input_token: Address, input_amount: U256) -> AssembledSwap {
+        let call = swapCall {
+            tokenInfo: swapTokenInfo {
+                inputToken: input_token,
+                inputAmount: input_amount,
+                inputReceiver: Address::repeat_byte(0x11),
+                outputToken: USDC,
+                outputQuote: U256::from(2_000),
+                outputMin: U256::from(1_990),
+                outputReceiver: Address::repeat_byte(0x22),
+            },
+            pathDefinition: Bytes::new(),
+            executor: Address::repeat_byte(0x11),
+            referralCode: 0,
+        };
+
+        AssembledSwap::new(
+            TransactionRequest::default()
+                .with_input(call.abi_encode())
+                .with_value(input_amount),
+        )
+    }
+
+    #[test]
+    fn test_zero_amount_native_input_accepts_additional_value() {
+        let swap = v2_swap(NATIVE_TOKEN, U256::ZERO);
+
+        let tx = swap.with_additional_value(U256::from(500)).unwrap();
+        assert_eq!(tx.value, Some(U256::from(500)));
+        assert_eq!(tx.input, swap.transaction().input);
+    }
+
+    #[test]
+    fn test_zero_amount_native_input_in_multi_swap_accepts_additional_value() {
+        let call = swapMultiCall {
+            inputs: vec![
+                inputTokenInfo {
+                    tokenAddress: USDC,
+                    amountIn: U256::from(1_000),
+                    receiver: Address::repeat_byte(0x11),
+                },
+                inputTokenInfo {
+                    tokenAddress: NATIVE_TOKEN,
+                    amountIn: U256::ZERO,
+                    receiver: Address::repeat_byte(0x11),
+                },
+            ],
+            outputs: vec![outputTokenInfo {
+                tokenAddress: USDC,
+                relativeValue: U256::from(1),
+                receiver: Address::repeat_byte(0x22),
+            }],
+            valueOutMin: U256::from(1),
+            pathDefinition: Bytes::new(),
+            executor: Address::repeat_byte(0x11),
+            referralCode: 0,
+        };
+        let swap = AssembledSwap::new(TransactionRequest::default().with_input(call.abi_encode()));
+
+        let tx = swap.with_additional_value(U256::from(7)).unwrap();
+        assert_eq!(tx.value, Some(U256::from(7)));
+    }
+
+    #[test]
+    fn test_fixed_native_input_rejects_additional_value() {
+        let swap = v2_swap(NATIVE_TOKEN, U256::from(1_000));
+
+        let err = swap.with_additional_value(U256::from(1)).unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+        assert!(err.to_string().contains("native input amount"));
+    }
+
+    #[test]
+    fn test_erc20_input_rejects_additional_value() {
+        let swap = v2_swap(USDC, U256::from(1_000));
+
+        let err = swap.with_additional_value(U256::from(1)).unwrap_err();
+        assert!(err.to_string().contains("left in the router"));
+    }
+
+    #[test]
+    fn test_non_swap_calls_reject_additional_value() {
+        let transfer = transferRouterFundsCall {
+            tokens: vec![USDC],
+            amounts: vec![U256::from(1)],
+            dest: Address::repeat_byte(0x33),
+        };
+        let swap =
+            AssembledSwap::new(TransactionRequest::default().with_input(transfer.abi_encode()));
+        assert!(swap.with_additional_value(U256::from(1)).is_err());
+
+        let garbage = AssembledSwap::new(
+            TransactionRequest::default().with_input(Bytes::from_static(&[0xde, 0xad])),
+        );
+        let err = garbage.with_additional_value(U256::from(1)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("not a recognized Odos router call"));
+    }
+
+    #[test]
+    fn test_additional_value_overflow_is_rejected() {
+        let swap = AssembledSwap::new(
+            v2_swap(NATIVE_TOKEN, U256::ZERO)
+                .into_transaction()
+                .with_value(U256::MAX),
+        );
+
+        assert!(swap.with_additional_value(U256::from(1)).is_err());
+    }
+}
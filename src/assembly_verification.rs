@@ -0,0 +1,502 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use alloy_primitives::{Address, U256};
+#[cfg(feature = "v2")]
+use alloy_rpc_types::TransactionRequest;
+
+#[cfg(feature = "v2")]
+use crate::{AssemblyRequest, DecodedSwap, SingleQuoteResponse};
+use crate::{OdosError, Result, Slippage};
+
+/// A difference between an assembled swap and the quote and request it was
+/// assembled from
+///
+/// Reported by [`verify_assembly`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AssemblyDiscrepancy {
+    /// The calldata spends a different input token
+    InputToken {
+        expected: Address,
+        assembled: Address,
+    },
+
+    /// The calldata spends a different input amount
+    InputAmount { expected: U256, assembled: U256 },
+
+    /// The calldata buys a different output token
+    OutputToken {
+        expected: Address,
+        assembled: Address,
+    },
+
+    /// The calldata sends the output to a different receiver
+    OutputReceiver {
+        expected: Address,
+        assembled: Address,
+    },
+
+    /// The calldata's minimum output exceeds the quoted amount, so the
+    /// swap would revert
+    OutputMinAboveQuote { quoted: U256, output_min: U256 },
+
+    /// The calldata's minimum output allows more slippage than requested
+    ///
+    /// Only reported by [`AssemblyVerification::with_max_slippage`].
+    OutputMinBelowSlippage { minimum: U256, output_min: U256 },
+}
+
+impl fmt::Display for AssemblyDiscrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InputToken {
+                expected,
+                assembled,
+            } => write!(f, "input token is {assembled}, expected {expected}"),
+            Self::InputAmount {
+                expected,
+                assembled,
+            } => write!(f, "input amount is {assembled}, expected {expected}"),
+            Self::OutputToken {
+                expected,
+                assembled,
+            } => write!(f, "output token is {assembled}, expected {expected}"),
+            Self::OutputReceiver {
+                expected,
+                assembled,
+            } => write!(f, "output receiver is {assembled}, expected {expected}"),
+            Self::OutputMinAboveQuote { quoted, output_min } => {
+                write!(f, "minimum output {output_min} exceeds the quoted {quoted}")
+            }
+            Self::OutputMinBelowSlippage {
+                minimum,
+                output_min,
+            } => write!(
+                f,
+                "minimum output {output_min} is below {minimum}, the quote less slippage"
+            ),
+        }
+    }
+}
+
+/// Result of checking an assembled swap against its quote and request
+///
+/// Returned by [`verify_assembly`]. A verification with no
+/// [`discrepancies`](Self::discrepancies) is [`is_ok`](Self::is_ok).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyVerification {
+    discrepancies: Vec<AssemblyDiscrepancy>,
+    /// `(quoted, output_min)` of each output with a token-denominated minimum
+    output_mins: Vec<(U256, U256)>,
+}
+
+impl AssemblyVerification {
+    /// Whether the assembled swap matches the quote and request
+    pub fn is_ok(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+
+    /// Get the differences found, empty if the assembly matches
+    pub fn discrepancies(&self) -> &[AssemblyDiscrepancy] {
+        &self.discrepancies
+    }
+
+    /// Also require each minimum output to be at least the quoted amount
+    /// less `slippage`
+    ///
+    /// [`verify_assembly`] cannot infer the slippage the quote was requested
+    /// with, so it only checks that the minimums do not exceed the quote.
+    /// This adds an [`AssemblyDiscrepancy::OutputMinBelowSlippage`] for each
+    /// output whose minimum allows more than `slippage`, allowing one base
+    /// unit for rounding.
+    pub fn with_max_slippage(mut self, slippage: Slippage) -> Self {
+        let keep_bps = U256::from(10_000 - u64::from(slippage.as_bps().min(10_000)));
+        for &(quoted, output_min) in &self.output_mins {
+            let minimum = (quoted * keep_bps / U256::from(10_000)).saturating_sub(U256::from(1));
+            if output_min < minimum {
+                self.discrepancies
+                    .push(AssemblyDiscrepancy::OutputMinBelowSlippage {
+                        minimum,
+                        output_min,
+                    });
+            }
+        }
+        self
+    }
+
+    /// Convert to an error if there are discrepancies
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::AssemblyVerificationFailed`] if the assembly does
+    /// not match.
+    pub fn into_result(self) -> Result<()> {
+        if self.is_ok() {
+            Ok(())
+        } else {
+            Err(OdosError::assembly_verification_failed(self.discrepancies))
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    fn check<T: PartialEq>(
+        &mut self,
+        expected: T,
+        assembled: T,
+        discrepancy: impl FnOnce(T, T) -> AssemblyDiscrepancy,
+    ) {
+        if expected != assembled {
+            self.discrepancies.push(discrepancy(expected, assembled));
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    fn check_output_min(&mut self, quoted: U256, output_min: U256) {
+        if output_min > quoted {
+            self.discrepancies
+                .push(AssemblyDiscrepancy::OutputMinAboveQuote { quoted, output_min });
+        }
+        self.output_mins.push((quoted, output_min));
+    }
+}
+
+/// Check that an assembled swap does what was quoted and requested
+///
+/// Decodes the router calldata of `tx` and compares it with `quote` and
+/// `request`:
+///
+/// - the input token and amount are the request's
+/// - the output tokens are the quote's, sent to the request's output recipient
+/// - no minimum output exceeds the quoted amount
+///
+/// For a `swapMulti`, only the V3 router's per-output minimums are compared
+/// with the quote; the V2 router's aggregate `valueOutMin` is not
+/// denominated in any one token. Chain [`with_max_slippage`](AssemblyVerification::with_max_slippage)
+/// to bound the minimums from below as well.
+///
+/// Discrepancies are reported, not returned as errors, so callers can log
+/// them; use [`AssemblyVerification::into_result`] to fail on any.
+///
+/// Available only when the `v2` feature is enabled.
+///
+/// # Errors
+///
+/// Returns [`OdosError::InvalidInput`] if `tx` has no calldata or its
+/// calldata is not a router `swap` or `swapMulti`, and an error if the
+/// quote's output amounts do not parse.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use odos_sdk::{verify_assembly, AssemblyRequest, OdosClient, SingleQuoteResponse, Slippage};
+///
+/// # async fn example(
+/// #     client: &OdosClient,
+/// #     quote: &SingleQuoteResponse,
+/// #     request: &AssemblyRequest,
+/// # ) -> odos_sdk::Result<()> {
+/// let tx = client.assemble(request).await?;
+/// verify_assembly(quote, request, &tx)?
+///     .with_max_slippage(Slippage::percent(0.5).unwrap())
+///     .into_result()?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "v2")]
+pub fn verify_assembly(
+    quote: &SingleQuoteResponse,
+    request: &AssemblyRequest,
+    tx: &TransactionRequest,
+) -> Result<AssemblyVerification> {
+    let calldata = tx
+        .input
+        .input()
+        .ok_or_else(|| OdosError::invalid_input("Assembled transaction has no calldata"))?;
+    let quoted: Vec<(Address, U256)> = quote
+        .out_tokens_iter()
+        .copied()
+        .zip(quote.out_amounts_u256())
+        .map(|(token, amount)| amount.map(|amount| (token, amount)))
+        .collect::<Result<_>>()?;
+
+    let mut verification = AssemblyVerification {
+        discrepancies: Vec::new(),
+        output_mins: Vec::new(),
+    };
+    let recipient = request.output_recipient();
+
+    match DecodedSwap::from_calldata(calldata)? {
+        DecodedSwap::Single(swap) => {
+            verification.check(
+                request.token_address(),
+                swap.token_address(),
+                |expected, assembled| AssemblyDiscrepancy::InputToken {
+                    expected,
+                    assembled,
+                },
+            );
+            verification.check(
+                request.token_amount(),
+                swap.amount_in(),
+                |expected, assembled| AssemblyDiscrepancy::InputAmount {
+                    expected,
+                    assembled,
+                },
+            );
+            verification.check(recipient, swap.output_receiver(), |expected, assembled| {
+                AssemblyDiscrepancy::OutputReceiver {
+                    expected,
+                    assembled,
+                }
+            });
+
+            match quoted.first() {
+                Some(&(token, amount)) => {
+                    verification.check(
+                        token,
+                        swap.output_token_address(),
+                        |expected, assembled| AssemblyDiscrepancy::OutputToken {
+                            expected,
+                            assembled,
+                        },
+                    );
+                    if token == swap.output_token_address() {
+                        verification.check_output_min(amount, swap.value_out_min());
+                    }
+                }
+                None => {
+                    return Err(OdosError::missing_data("Quote has no output tokens"));
+                }
+            }
+        }
+        DecodedSwap::Multi(swap) => {
+            match swap
+                .inputs()
+                .iter()
+                .find(|input| input.tokenAddress == request.token_address())
+            {
+                Some(input) => verification.check(
+                    request.token_amount(),
+                    input.amountIn,
+                    |expected, assembled| AssemblyDiscrepancy::InputAmount {
+                        expected,
+                        assembled,
+                    },
+                ),
+                None => verification
+                    .discrepancies
+                    .push(AssemblyDiscrepancy::InputToken {
+                        expected: request.token_address(),
+                        assembled: swap
+                            .inputs()
+                            .first()
+                            .map_or(Address::ZERO, |i| i.tokenAddress),
+                    }),
+            }
+
+            let output_mins = swap.output_amount_mins().unwrap_or_default();
+            for (i, output) in swap.outputs().iter().enumerate() {
+                verification.check(recipient, output.receiver, |expected, assembled| {
+                    AssemblyDiscrepancy::OutputReceiver {
+                        expected,
+                        assembled,
+                    }
+                });
+                match quoted
+                    .iter()
+                    .find(|(token, _)| *token == output.tokenAddress)
+                {
+                    Some(&(_, amount)) => {
+                        if let Some(&output_min) = output_mins.get(i) {
+                            verification.check_output_min(amount, output_min);
+                        }
+                    }
+                    None => verification
+                        .discrepancies
+                        .push(AssemblyDiscrepancy::OutputToken {
+                            expected: quoted.first().map_or(Address::ZERO, |&(token, _)| token),
+                            assembled: output.tokenAddress,
+                        }),
+                }
+            }
+        }
+    }
+
+    Ok(verification)
+}
+
+#[cfg(all(test, feature = "v2"))]
+mod tests {
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::{address, Bytes};
+    use alloy_sol_types::SolCall;
+
+    use super::*;
+    use crate::{
+        OdosRouterV2::{inputTokenInfo, outputTokenInfo, swapTokenInfo},
+        OdosV2Router::{swapCall, swapMultiCall},
+        PathId,
+    };
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const SIGNER: Address = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+    const EXECUTOR: Address = address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99");
+
+    /// Quoted output of `crate::sor::tests::quote_json`
+    const QUOTED_WETH: u64 = 297_096_823_183_507;
+
+    fn quote() -> SingleQuoteResponse {
+        serde_json::from_value(crate::sor::tests::quote_json()).unwrap()
+    }
+
+    fn request() -> AssemblyRequest {
+        AssemblyRequest::builder()
+            .chain(alloy_chains::NamedChain::Mainnet)
+            .router_address(crate::ODOS_V2_ETHEREUM_ROUTER)
+            .signer_address(SIGNER)
+            .output_recipient(SIGNER)
+            .token_address(USDC)
+            .token_amount(U256::from(1_000_000u64))
+            .path_id(PathId::parse("2f6e14ad1cfa589029f413791a0b7f6f").unwrap())
+            .build()
+    }
+
+    /// A swap of 1 USDC for the quoted WETH with 0.5% slippage
+    fn swap_call() -> swapCall {
+        swapCall {
+            tokenInfo: swapTokenInfo {
+                inputToken: USDC,
+                inputAmount: U256::from(1_000_000u64),
+                inputReceiver: EXECUTOR,
+                outputToken: WETH,
+                outputQuote: U256::from(QUOTED_WETH),
+                outputMin: U256::from(QUOTED_WETH * 995 / 1000),
+                outputReceiver: SIGNER,
+            },
+            pathDefinition: Bytes::new(),
+            executor: EXECUTOR,
+            referralCode: 0,
+        }
+    }
+
+    fn tx(call: impl SolCall) -> TransactionRequest {
+        TransactionRequest::default().with_input(call.abi_encode())
+    }
+
+    #[test]
+    fn test_matching_assembly_verifies() {
+        let verification = verify_assembly(&quote(), &request(), &tx(swap_call())).unwrap();
+        assert!(verification.is_ok());
+
+        let verification = verification.with_max_slippage(Slippage::percent(0.5).unwrap());
+        assert!(verification.is_ok(), "{:?}", verification.discrepancies());
+        assert!(verification.into_result().is_ok());
+    }
+
+    #[test]
+    fn test_tampered_calldata_is_rejected() {
+        let attacker = address!("000000000000000000000000000000000000dead");
+        let mut call = swap_call();
+        call.tokenInfo.inputAmount = U256::from(2_000_000u64);
+        call.tokenInfo.outputReceiver = attacker;
+        call.tokenInfo.outputMin = U256::from(1);
+
+        let verification = verify_assembly(&quote(), &request(), &tx(call))
+            .unwrap()
+            .with_max_slippage(Slippage::percent(0.5).unwrap());
+        assert_eq!(
+            verification.discrepancies(),
+            &[
+                AssemblyDiscrepancy::InputAmount {
+                    expected: U256::from(1_000_000u64),
+                    assembled: U256::from(2_000_000u64),
+                },
+                AssemblyDiscrepancy::OutputReceiver {
+                    expected: SIGNER,
+                    assembled: attacker,
+                },
+                AssemblyDiscrepancy::OutputMinBelowSlippage {
+                    minimum: U256::from(QUOTED_WETH * 9_950 / 10_000 - 1),
+                    output_min: U256::from(1),
+                },
+            ]
+        );
+
+        let err = verification.into_result().unwrap_err();
+        assert!(matches!(err, OdosError::AssemblyVerificationFailed { .. }));
+        assert!(err.to_string().contains("output receiver is"));
+    }
+
+    #[test]
+    fn test_wrong_tokens_and_inflated_minimum() {
+        let mut call = swap_call();
+        call.tokenInfo.inputToken = WETH;
+        call.tokenInfo.outputToken = USDC;
+        let verification = verify_assembly(&quote(), &request(), &tx(call)).unwrap();
+        assert_eq!(
+            verification.discrepancies(),
+            &[
+                AssemblyDiscrepancy::InputToken {
+                    expected: USDC,
+                    assembled: WETH,
+                },
+                AssemblyDiscrepancy::OutputToken {
+                    expected: WETH,
+                    assembled: USDC,
+                },
+            ]
+        );
+
+        let mut call = swap_call();
+        call.tokenInfo.outputMin = U256::from(QUOTED_WETH + 1);
+        let verification = verify_assembly(&quote(), &request(), &tx(call)).unwrap();
+        assert!(matches!(
+            verification.discrepancies(),
+            [AssemblyDiscrepancy::OutputMinAboveQuote { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_multi_swap_checks_inputs_and_receivers() {
+        let call = swapMultiCall {
+            inputs: vec![inputTokenInfo {
+                tokenAddress: USDC,
+                amountIn: U256::from(1_000_000u64),
+                receiver: EXECUTOR,
+            }],
+            outputs: vec![outputTokenInfo {
+                tokenAddress: WETH,
+                relativeValue: U256::from(1),
+                receiver: EXECUTOR,
+            }],
+            valueOutMin: U256::ZERO,
+            pathDefinition: Bytes::new(),
+            executor: EXECUTOR,
+            referralCode: 0,
+        };
+
+        let verification = verify_assembly(&quote(), &request(), &tx(call)).unwrap();
+        assert_eq!(
+            verification.discrepancies(),
+            &[AssemblyDiscrepancy::OutputReceiver {
+                expected: SIGNER,
+                assembled: EXECUTOR,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_undecodable_calldata_is_an_error() {
+        let tx = TransactionRequest::default().with_input(Bytes::from_static(&[0xde, 0xad]));
+        assert!(matches!(
+            verify_assembly(&quote(), &request(), &tx),
+            Err(OdosError::InvalidInput(_))
+        ));
+        assert!(verify_assembly(&quote(), &request(), &TransactionRequest::default()).is_err());
+    }
+}
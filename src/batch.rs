@@ -0,0 +1,373 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::task::JoinSet;
+
+use crate::{OdosClient, QuoteRequest, Result, SingleQuoteResponse};
+
+/// Pacing for [`OdosClient::quote_many`]
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use odos_sdk::BatchOptions;
+///
+/// let options = BatchOptions {
+///     max_concurrency: 8,
+///     request_delay: Duration::from_millis(50),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// Requests in flight at once; zero is treated as one
+    ///
+    /// Default: 4
+    pub max_concurrency: usize,
+
+    /// Minimum time between the starts of consecutive requests, retries
+    /// after a rate limit included
+    ///
+    /// Default: zero
+    pub request_delay: Duration,
+
+    /// Pause the whole batch when a request is rate limited, then send that
+    /// request again, instead of returning the rate limit error
+    ///
+    /// Default: true
+    pub pause_on_rate_limit: bool,
+
+    /// Pauses a single request may trigger before its rate limit error is
+    /// returned
+    ///
+    /// Default: 3
+    pub max_rate_limit_pauses: u32,
+
+    /// Pause used when a rate limit response has no `Retry-After` header
+    ///
+    /// Default: 1 second
+    pub default_rate_limit_pause: Duration,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 4,
+            request_delay: Duration::ZERO,
+            pause_on_rate_limit: true,
+            max_rate_limit_pauses: 3,
+            default_rate_limit_pause: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Start times shared by the workers of a batch
+#[derive(Debug)]
+struct Pacer {
+    request_delay: Duration,
+    slots: Mutex<Slots>,
+}
+
+#[derive(Debug)]
+struct Slots {
+    /// Earliest start of the next request
+    next_start: Instant,
+    /// End of the current rate limit pause
+    paused_until: Instant,
+}
+
+impl Pacer {
+    fn new(request_delay: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            request_delay,
+            slots: Mutex::new(Slots {
+                next_start: now,
+                paused_until: now,
+            }),
+        }
+    }
+
+    /// Reserve the next start slot, returning how long to wait for it
+    fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        let start = now.max(slots.next_start).max(slots.paused_until);
+        slots.next_start = start + self.request_delay;
+        start - now
+    }
+
+    /// Hold back every request not yet started for `pause`
+    fn pause(&self, pause: Duration) {
+        let until = Instant::now() + pause;
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        slots.paused_until = slots.paused_until.max(until);
+    }
+}
+
+impl OdosClient {
+    /// Get quotes for many requests with bounded concurrency
+    ///
+    /// Requests are sent through [`quote`](Self::quote) by up to
+    /// [`max_concurrency`](BatchOptions::max_concurrency) tasks, spaced by
+    /// [`request_delay`](BatchOptions::request_delay). When a request is
+    /// rate limited, no further request starts until its `Retry-After` has
+    /// passed, and the limited request is sent again, so a 429 slows the
+    /// batch down instead of failing the rest of it. Waits go through
+    /// [`ClientConfig::sleeper`](crate::ClientConfig::sleeper).
+    ///
+    /// Results are in the order of `requests`, one per request. Other
+    /// errors, and rate limits past
+    /// [`max_rate_limit_pauses`](BatchOptions::max_rate_limit_pauses), are
+    /// returned in place without stopping the batch.
+    ///
+    /// # Panics
+    ///
+    /// Must be called within a Tokio runtime, since requests run on spawned
+    /// tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{BatchOptions, OdosClient, QuoteRequest};
+    ///
+    /// # async fn example(requests: Vec<QuoteRequest>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let quotes = client.quote_many(requests, BatchOptions::default()).await;
+    /// for quote in quotes {
+    ///     match quote {
+    ///         Ok(quote) => println!("{:?}", quote.out_amount()),
+    ///         Err(err) => eprintln!("quote failed: {err}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote_many(
+        &self,
+        requests: Vec<QuoteRequest>,
+        options: BatchOptions,
+    ) -> Vec<Result<SingleQuoteResponse>> {
+        let total = requests.len();
+        let queue = Arc::new(Mutex::new(
+            requests.into_iter().enumerate().collect::<VecDeque<_>>(),
+        ));
+        let pacer = Arc::new(Pacer::new(options.request_delay));
+
+        let mut workers = JoinSet::new();
+        for _ in 0..options.max_concurrency.clamp(1, total.max(1)) {
+            let client = self.clone();
+            let queue = Arc::clone(&queue);
+            let pacer = Arc::clone(&pacer);
+            workers.spawn(async move {
+                let mut results = Vec::new();
+                loop {
+                    let next = queue.lock().unwrap_or_else(|e| e.into_inner()).pop_front();
+                    let Some((index, request)) = next else {
+                        break results;
+                    };
+                    let result = client.paced_quote(&request, &pacer, &options).await;
+                    results.push((index, result));
+                }
+            });
+        }
+
+        let mut slots: Vec<Option<Result<SingleQuoteResponse>>> =
+            std::iter::repeat_with(|| None).take(total).collect();
+        while let Some(finished) = workers.join_next().await {
+            let results =
+                finished.unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()));
+            for (index, result) in results {
+                slots[index] = Some(result);
+            }
+        }
+        slots
+            .into_iter()
+            .map(|result| result.expect("every queued request has a result"))
+            .collect()
+    }
+
+    /// Quote `request` in its start slot, pausing the batch on rate limits
+    async fn paced_quote(
+        &self,
+        request: &QuoteRequest,
+        pacer: &Pacer,
+        options: &BatchOptions,
+    ) -> Result<SingleQuoteResponse> {
+        let mut pauses = 0;
+        loop {
+            let wait = pacer.reserve();
+            if !wait.is_zero() {
+                self.config().sleeper.sleep(wait).await;
+            }
+
+            match self.quote(request).await {
+                Err(err)
+                    if err.is_rate_limit()
+                        && options.pause_on_rate_limit
+                        && pauses < options.max_rate_limit_pauses =>
+                {
+                    pauses += 1;
+                    pacer.pause(
+                        err.retry_after()
+                            .unwrap_or(options.default_rate_limit_pause),
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use alloy_primitives::{address, Address, U256};
+    use url::Url;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, Request, Respond, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{
+        sor::tests::quote_json, test_utils::RecordingSleeper, ApiVersion, Chain, ClientConfig,
+        Endpoint, RetryConfig,
+    };
+
+    /// Echoes the input amount as the output amount, rate limiting the
+    /// `limited`-th request
+    struct EchoAmount {
+        hits: AtomicUsize,
+        limited: usize,
+    }
+
+    impl Respond for EchoAmount {
+        fn respond(&self, request: &Request) -> ResponseTemplate {
+            if self.hits.fetch_add(1, Ordering::SeqCst) + 1 == self.limited {
+                return ResponseTemplate::new(429)
+                    .insert_header("retry-after", "2")
+                    .set_body_string("Slow down");
+            }
+            let body: serde_json::Value = request.body_json().unwrap();
+            let mut quote = quote_json();
+            quote["outAmounts"] = serde_json::json!([body["inputTokens"][0]["amount"]]);
+            ResponseTemplate::new(200).set_body_json(quote)
+        }
+    }
+
+    fn request(amount: u64) -> QuoteRequest {
+        QuoteRequest::builder()
+            .chain(Chain::ethereum())
+            .input_tokens(vec![(
+                address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                U256::from(amount),
+            )
+                .into()])
+            .output_tokens(vec![(
+                address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+                1.0,
+            )
+                .into()])
+            .slippage_limit_percent(0.5)
+            .user_addr(Address::ZERO)
+            .compact(false)
+            .simple(false)
+            .referral_code(0)
+            .disable_rfqs(false)
+            .build()
+    }
+
+    fn client(server: &MockServer, sleeper: &RecordingSleeper) -> OdosClient {
+        OdosClient::with_config(ClientConfig {
+            endpoint: Endpoint::custom(Url::parse(&server.uri()).unwrap(), ApiVersion::V2),
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(sleeper.clone()),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    async fn mount(server: &MockServer, limited: usize, expected: u64) {
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(EchoAmount {
+                hits: AtomicUsize::new(0),
+                limited,
+            })
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_batch_pauses_on_rate_limit_and_keeps_order() {
+        let server = MockServer::start().await;
+        mount(&server, 4, 9).await;
+        let sleeper = RecordingSleeper::new();
+        let amounts: Vec<u64> = (1..=8).map(|n| n * 1_000).collect();
+
+        let results = client(&server, &sleeper)
+            .quote_many(
+                amounts.iter().copied().map(request).collect(),
+                BatchOptions {
+                    max_concurrency: 3,
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        let out_amounts: Vec<u64> = results
+            .into_iter()
+            .map(|quote| quote.unwrap().out_amount().unwrap().parse().unwrap())
+            .collect();
+        assert_eq!(out_amounts, amounts);
+        assert!(sleeper
+            .durations()
+            .iter()
+            .any(|pause| *pause > Duration::from_millis(1900) && *pause <= Duration::from_secs(2)));
+    }
+
+    #[tokio::test]
+    async fn test_batch_returns_rate_limit_when_not_pausing() {
+        let server = MockServer::start().await;
+        mount(&server, 2, 3).await;
+        let sleeper = RecordingSleeper::new();
+
+        let results = client(&server, &sleeper)
+            .quote_many(
+                vec![request(1), request(2), request(3)],
+                BatchOptions {
+                    max_concurrency: 1,
+                    request_delay: Duration::from_millis(100),
+                    pause_on_rate_limit: false,
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        assert!(results[0].is_ok());
+        assert!(results[1].as_ref().unwrap_err().is_rate_limit());
+        assert!(results[2].is_ok());
+        // Only the request delay is waited for
+        assert_eq!(sleeper.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_empty_batch() {
+        let server = MockServer::start().await;
+        let results = client(&server, &RecordingSleeper::new())
+            .quote_many(Vec::new(), BatchOptions::default())
+            .await;
+        assert!(results.is_empty());
+    }
+}
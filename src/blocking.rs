@@ -0,0 +1,244 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Blocking client for synchronous code.
+//!
+//! [`OdosClient`] wraps the async [`crate::OdosClient`] and drives it on a
+//! single-threaded tokio runtime it owns, so retries, backoff, timeouts,
+//! rate limiting and the other [`ClientConfig`] settings behave exactly as
+//! they do in async code. Requests, responses and errors are the same types.
+//!
+//! ```rust,no_run
+//! use odos_sdk::{blocking::OdosClient, QuoteRequest};
+//!
+//! # fn example(request: &QuoteRequest) -> odos_sdk::Result<()> {
+//! let client = OdosClient::new()?;
+//! let quote = client.quote(request)?;
+//! println!("Path: {}", quote.path_id());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Panics
+//!
+//! The methods and `drop` panic when called from within an async runtime,
+//! as blocking there would stall it. From async code, use
+//! [`crate::OdosClient`] directly.
+
+use std::sync::Arc;
+
+use alloy_primitives::Address;
+use alloy_rpc_types::TransactionRequest;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    AssemblyRequest, ClientConfig, OdosError, QuoteRequest, Result, RetryConfig,
+    SingleQuoteResponse, TransactionData,
+};
+
+/// Blocking Odos client
+///
+/// Clones share the connection pool and the runtime, like clones of the
+/// async client. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct OdosClient {
+    inner: crate::OdosClient,
+    runtime: Arc<Runtime>,
+}
+
+impl OdosClient {
+    /// Create a blocking client with default configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client or the runtime cannot be created.
+    pub fn new() -> Result<Self> {
+        Self::with_config(ClientConfig::default())
+    }
+
+    /// Create a blocking client with custom configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid, or the HTTP client
+    /// or the runtime cannot be created.
+    pub fn with_config(config: ClientConfig) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                OdosError::configuration_error(format!("Failed to create tokio runtime: {e}"))
+            })?;
+
+        Ok(Self {
+            inner: crate::OdosClient::with_config(config)?,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Create a blocking client with custom retry configuration
+    pub fn with_retry_config(retry_config: RetryConfig) -> Result<Self> {
+        Self::with_config(ClientConfig {
+            retry_config,
+            ..Default::default()
+        })
+    }
+
+    /// Get the client configuration
+    pub fn config(&self) -> &ClientConfig {
+        self.inner.config()
+    }
+
+    /// Get a swap quote, blocking until it arrives
+    ///
+    /// See [`crate::OdosClient::quote`].
+    pub fn quote(&self, quote_request: &QuoteRequest) -> Result<SingleQuoteResponse> {
+        self.runtime.block_on(self.inner.quote(quote_request))
+    }
+
+    /// Assemble the transaction data for a quoted path, blocking until it
+    /// arrives
+    ///
+    /// See [`crate::OdosClient::assemble_tx_data`].
+    pub fn assemble_tx_data(
+        &self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &str,
+    ) -> Result<TransactionData> {
+        self.runtime.block_on(self.inner.assemble_tx_data(
+            signer_address,
+            output_recipient,
+            path_id,
+        ))
+    }
+
+    /// Assemble a transaction request ready for signing, blocking until it
+    /// arrives
+    ///
+    /// See [`crate::OdosClient::assemble`].
+    pub fn assemble(&self, request: &AssemblyRequest) -> Result<TransactionRequest> {
+        self.runtime.block_on(self.inner.assemble(request))
+    }
+
+    /// Get the async client this client drives
+    pub fn as_async(&self) -> &crate::OdosClient {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{
+        sor::tests::{assembly_request, quote_json, quote_request},
+        test_utils::NoopSleeper,
+    };
+
+    /// Start a mock server on a background runtime, mounting `mocks`
+    fn mock_server(mocks: Vec<Mock>) -> (MockServer, tokio::runtime::Runtime) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = runtime.block_on(async {
+            let server = MockServer::start().await;
+            for mock in mocks {
+                mock.mount(&server).await;
+            }
+            server
+        });
+        (server, runtime)
+    }
+
+    fn client(server: &MockServer, retry_config: RetryConfig) -> OdosClient {
+        OdosClient::with_config(ClientConfig {
+            endpoint: crate::Endpoint::custom(
+                url::Url::parse(&server.uri()).unwrap(),
+                crate::ApiVersion::V2,
+            ),
+            retry_config,
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_blocking_quote_and_assemble() {
+        let (server, _runtime) = mock_server(vec![
+            Mock::given(method("POST"))
+                .and(path("/sor/quote/v2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(quote_json())),
+            Mock::given(method("POST"))
+                .and(path("/sor/assemble"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+                ),
+        ]);
+        let client = client(&server, RetryConfig::no_retries());
+
+        let quote = client.quote(&quote_request(None)).unwrap();
+        assert_eq!(quote.path_id().as_str(), "2f6e14ad1cfa589029f413791a0b7f6f");
+
+        let request = assembly_request();
+        let tx_data = client
+            .assemble_tx_data(
+                request.signer_address(),
+                request.output_recipient(),
+                quote.path_id().as_str(),
+            )
+            .unwrap();
+        let tx = client.assemble(&request).unwrap();
+        assert_eq!(tx.to, Some(tx_data.to.into()));
+    }
+
+    #[test]
+    fn test_blocking_client_retries_like_async_client() {
+        let (server, _runtime) = mock_server(vec![
+            Mock::given(method("POST"))
+                .and(path("/sor/quote/v2"))
+                .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+                .up_to_n_times(1)
+                .with_priority(1)
+                .expect(1),
+            Mock::given(method("POST"))
+                .and(path("/sor/quote/v2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(quote_json()))
+                .expect(1),
+        ]);
+        // The default sleeper backs off on the client's own runtime
+        let client = OdosClient::with_config(ClientConfig {
+            endpoint: crate::Endpoint::custom(
+                url::Url::parse(&server.uri()).unwrap(),
+                crate::ApiVersion::V2,
+            ),
+            retry_config: RetryConfig {
+                max_retries: 3,
+                initial_backoff_ms: 10,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(client.quote(&quote_request(None)).is_ok());
+    }
+
+    #[test]
+    fn test_blocking_client_returns_shared_errors() {
+        let (server, _runtime) = mock_server(vec![Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Bad request"))]);
+        let client = client(&server, RetryConfig::default());
+
+        let err = client.quote(&quote_request(None)).unwrap_err();
+        assert!(matches!(err, OdosError::Api { status, .. } if status == 400));
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+/// Crate version this SDK was built as.
+pub(crate) const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Default `User-Agent` sent with every request.
+pub(crate) const DEFAULT_USER_AGENT: &str = concat!("odos-sdk-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Cargo features that can be reported by [`build_info`], in manifest order.
+pub(crate) const FEATURES: &[(&str, bool)] = &[
+    ("default", cfg!(feature = "default")),
+    ("minimal", cfg!(feature = "minimal")),
+    ("v2", cfg!(feature = "v2")),
+    ("v3", cfg!(feature = "v3")),
+    ("limit-orders", cfg!(feature = "limit-orders")),
+    ("signer", cfg!(feature = "signer")),
+    ("multicall", cfg!(feature = "multicall")),
+    ("provider", cfg!(feature = "provider")),
+    ("blocking", cfg!(feature = "blocking")),
+    ("metrics", cfg!(feature = "metrics")),
+    ("test-utils", cfg!(feature = "test-utils")),
+    ("contracts", cfg!(feature = "contracts")),
+];
+
+/// Version and feature set of the compiled SDK.
+///
+/// Intended for startup logging so operators can tell exactly which SDK
+/// build a service is running. Serializes to JSON for structured logs.
+///
+/// # Examples
+///
+/// ```rust
+/// let info = odos_sdk::build_info();
+///
+/// assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+/// println!("{}", serde_json::to_string(&info).unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Crate version from the manifest (e.g. `"11.0.0"`).
+    pub crate_version: &'static str,
+    /// Cargo features enabled in this build.
+    pub enabled_features: Vec<&'static str>,
+    /// Git commit SHA the crate was built from, when known at build time.
+    pub git_sha: Option<&'static str>,
+}
+
+/// Report the SDK version, enabled features, and build commit.
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        crate_version: CRATE_VERSION,
+        enabled_features: FEATURES
+            .iter()
+            .filter_map(|(name, enabled)| enabled.then_some(*name))
+            .collect(),
+        git_sha: option_env!("ODOS_SDK_GIT_SHA"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_version_matches_manifest() {
+        assert_eq!(build_info().crate_version, env!("CARGO_PKG_VERSION"));
+    }
+
+    #[test]
+    fn test_build_info_lists_default_features() {
+        let features = build_info().enabled_features;
+
+        #[cfg(feature = "default")]
+        for feature in ["default", "v2", "v3", "multicall"] {
+            assert!(features.contains(&feature), "missing feature {feature}");
+        }
+
+        #[cfg(not(feature = "v2"))]
+        assert!(!features.contains(&"v2"));
+    }
+
+    #[test]
+    fn test_features_match_manifest() {
+        let manifest = include_str!("../Cargo.toml");
+        let declared: Vec<&str> = manifest
+            .lines()
+            .skip_while(|line| line.trim() != "[features]")
+            .skip(1)
+            .take_while(|line| !line.starts_with('['))
+            .filter_map(|line| line.split_once(" = ").map(|(name, _)| name))
+            .filter(|name| !name.starts_with(['#', ' ']))
+            .collect();
+        let reported: Vec<&str> = FEATURES.iter().map(|(name, _)| *name).collect();
+
+        assert_eq!(reported, declared);
+    }
+
+    #[test]
+    fn test_build_info_serializes() {
+        let json = serde_json::to_value(build_info()).unwrap();
+        assert_eq!(json["crate_version"], env!("CARGO_PKG_VERSION"));
+        assert!(json["enabled_features"].is_array());
+    }
+
+    #[test]
+    fn test_default_user_agent_includes_version() {
+        assert_eq!(
+            DEFAULT_USER_AGENT,
+            format!("odos-sdk-rs/{}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+}
@@ -7,18 +7,34 @@ use alloy_primitives::Address;
 use thiserror::Error;
 
 use crate::{
-    RouterAvailability, ODOS_LO_ARBITRUM_ROUTER, ODOS_LO_AVALANCHE_ROUTER, ODOS_LO_BASE_ROUTER,
-    ODOS_LO_BSC_ROUTER, ODOS_LO_ETHEREUM_ROUTER, ODOS_LO_FRAXTAL_ROUTER, ODOS_LO_LINEA_ROUTER,
-    ODOS_LO_MANTLE_ROUTER, ODOS_LO_OP_ROUTER, ODOS_LO_POLYGON_ROUTER, ODOS_LO_SONIC_ROUTER,
-    ODOS_LO_UNICHAIN_ROUTER, ODOS_LO_ZKSYNC_ROUTER, ODOS_V2_ARBITRUM_ROUTER,
-    ODOS_V2_AVALANCHE_ROUTER, ODOS_V2_BASE_ROUTER, ODOS_V2_BSC_ROUTER, ODOS_V2_ETHEREUM_ROUTER,
-    ODOS_V2_FRAXTAL_ROUTER, ODOS_V2_LINEA_ROUTER, ODOS_V2_MANTLE_ROUTER, ODOS_V2_OP_ROUTER,
-    ODOS_V2_POLYGON_ROUTER, ODOS_V2_SONIC_ROUTER, ODOS_V2_UNICHAIN_ROUTER, ODOS_V2_ZKSYNC_ROUTER,
-    ODOS_V3,
+    contract::deployment, custom_chain::custom_router, RouterAvailability, RouterType,
+    ODOS_LO_ARBITRUM_ROUTER, ODOS_LO_AVALANCHE_ROUTER, ODOS_LO_BASE_ROUTER, ODOS_LO_BSC_ROUTER,
+    ODOS_LO_ETHEREUM_ROUTER, ODOS_LO_FRAXTAL_ROUTER, ODOS_LO_LINEA_ROUTER, ODOS_LO_MANTLE_ROUTER,
+    ODOS_LO_OP_ROUTER, ODOS_LO_POLYGON_ROUTER, ODOS_LO_SONIC_ROUTER, ODOS_LO_UNICHAIN_ROUTER,
+    ODOS_LO_ZKSYNC_ROUTER, ODOS_V2_ARBITRUM_ROUTER, ODOS_V2_AVALANCHE_ROUTER, ODOS_V2_BASE_ROUTER,
+    ODOS_V2_BSC_ROUTER, ODOS_V2_ETHEREUM_ROUTER, ODOS_V2_FRAXTAL_ROUTER, ODOS_V2_LINEA_ROUTER,
+    ODOS_V2_MANTLE_ROUTER, ODOS_V2_OP_ROUTER, ODOS_V2_POLYGON_ROUTER, ODOS_V2_SONIC_ROUTER,
+    ODOS_V2_UNICHAIN_ROUTER, ODOS_V2_ZKSYNC_ROUTER, ODOS_V3,
 };
 
 /// Errors that can occur when working with Odos chains
+///
+/// New variants may be added in minor releases, so matches need a wildcard
+/// arm:
+///
+/// ```rust
+/// use odos_sdk::OdosChainError;
+///
+/// fn describe(error: &OdosChainError) -> &'static str {
+///     match error {
+///         OdosChainError::UnsupportedChain { .. } => "unsupported chain",
+///         OdosChainError::InvalidAddress { .. } => "invalid address",
+///         _ => "router not available",
+///     }
+/// }
+/// ```
 #[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum OdosChainError {
     /// The chain is not supported by Odos protocol
     #[error("Chain {chain:?} is not supported by Odos protocol")]
@@ -39,6 +55,10 @@ pub enum OdosChainError {
     /// Invalid address format
     #[error("Invalid address format: {address}")]
     InvalidAddress { address: String },
+
+    /// A custom chain registration was rejected
+    #[error("Cannot register custom chain {chain:?}: {reason}")]
+    InvalidCustomChain { chain: String, reason: String },
 }
 
 /// Result type for Odos chain operations
@@ -129,6 +149,30 @@ pub trait OdosChain {
     /// ```
     fn v3_router_address(&self) -> OdosChainResult<Address>;
 
+    /// Get the `router_type` router address for this chain
+    ///
+    /// Dispatches to [`lo_router_address`](Self::lo_router_address),
+    /// [`v2_router_address`](Self::v2_router_address) or
+    /// [`v3_router_address`](Self::v3_router_address), including their
+    /// fallback between the V2 and V3 routers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use odos_sdk::{OdosChain, RouterType, ODOS_V3};
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Base.router_address(RouterType::V3)?, ODOS_V3);
+    /// # Ok::<(), odos_sdk::OdosChainError>(())
+    /// ```
+    fn router_address(&self, router_type: RouterType) -> OdosChainResult<Address> {
+        match router_type {
+            RouterType::LimitOrder => self.lo_router_address(),
+            RouterType::V2 => self.v2_router_address(),
+            RouterType::V3 => self.v3_router_address(),
+        }
+    }
+
     /// Check if this chain supports Odos protocol
     ///
     /// # Returns
@@ -241,8 +285,10 @@ impl OdosChain for NamedChain {
             ZkSync => ODOS_LO_ZKSYNC_ROUTER,
             Unichain => ODOS_LO_UNICHAIN_ROUTER,
             _ => {
-                return Err(OdosChainError::LimitOrderNotAvailable {
-                    chain: format!("{self:?}"),
+                return custom_router(*self, RouterType::LimitOrder).ok_or_else(|| {
+                    OdosChainError::LimitOrderNotAvailable {
+                        chain: format!("{self:?}"),
+                    }
                 });
             }
         })
@@ -277,8 +323,10 @@ impl OdosChain for NamedChain {
             ZkSync => ODOS_V2_ZKSYNC_ROUTER,
             Unichain => ODOS_V2_UNICHAIN_ROUTER,
             _ => {
-                return Err(OdosChainError::UnsupportedChain {
-                    chain: format!("{self:?}"),
+                return custom_router(*self, RouterType::V2).ok_or_else(|| {
+                    OdosChainError::UnsupportedChain {
+                        chain: format!("{self:?}"),
+                    }
                 });
             }
         })
@@ -296,87 +344,24 @@ impl OdosChain for NamedChain {
             return self.v2_router_address();
         }
 
-        Ok(ODOS_V3)
+        // Built-in chains share the deterministic V3 deployment
+        Ok(custom_router(*self, RouterType::V3).unwrap_or(ODOS_V3))
     }
 
     fn supports_odos(&self) -> bool {
-        use NamedChain::*;
-        matches!(
-            self,
-            Arbitrum
-                | Avalanche
-                | Base
-                | BinanceSmartChain
-                | Fraxtal
-                | Mainnet
-                | Optimism
-                | Polygon
-                | Linea
-                | Mantle
-                | Sonic
-                | ZkSync
-                | Unichain
-        )
+        deployment(*self).is_some()
     }
 
     fn supports_lo(&self) -> bool {
-        use NamedChain::*;
-        matches!(
-            self,
-            Arbitrum
-                | Avalanche
-                | Base
-                | BinanceSmartChain
-                | Fraxtal
-                | Mainnet
-                | Optimism
-                | Polygon
-                | Linea
-                | Mantle
-                | Sonic
-                | ZkSync
-                | Unichain
-        )
+        deployment(*self).is_some_and(|routers| routers.limit_order)
     }
 
     fn supports_v2(&self) -> bool {
-        use NamedChain::*;
-        matches!(
-            self,
-            Arbitrum
-                | Avalanche
-                | Base
-                | BinanceSmartChain
-                | Fraxtal
-                | Mainnet
-                | Optimism
-                | Polygon
-                | Linea
-                | Mantle
-                | Sonic
-                | ZkSync
-                | Unichain
-        )
+        deployment(*self).is_some_and(|routers| routers.v2)
     }
 
     fn supports_v3(&self) -> bool {
-        use NamedChain::*;
-        matches!(
-            self,
-            Arbitrum
-                | Avalanche
-                | Base
-                | BinanceSmartChain
-                | Fraxtal
-                | Mainnet
-                | Optimism
-                | Polygon
-                | Linea
-                | Mantle
-                | Sonic
-                | ZkSync
-                | Unichain
-        )
+        deployment(*self).is_some_and(|routers| routers.v3)
     }
 }
 
@@ -485,6 +470,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lo_router_addresses_on_every_lo_chain() {
+        use NamedChain::*;
+
+        let expected = [
+            (Mainnet, ODOS_LO_ETHEREUM_ROUTER),
+            (Optimism, ODOS_LO_OP_ROUTER),
+            (BinanceSmartChain, ODOS_LO_BSC_ROUTER),
+            (Unichain, ODOS_LO_UNICHAIN_ROUTER),
+            (Polygon, ODOS_LO_POLYGON_ROUTER),
+            (Sonic, ODOS_LO_SONIC_ROUTER),
+            (Fraxtal, ODOS_LO_FRAXTAL_ROUTER),
+            (ZkSync, ODOS_LO_ZKSYNC_ROUTER),
+            (Mantle, ODOS_LO_MANTLE_ROUTER),
+            (Base, ODOS_LO_BASE_ROUTER),
+            (Arbitrum, ODOS_LO_ARBITRUM_ROUTER),
+            (Avalanche, ODOS_LO_AVALANCHE_ROUTER),
+            (Linea, ODOS_LO_LINEA_ROUTER),
+        ];
+        let lo_chains: Vec<NamedChain> = crate::get_supported_lo_chains().into_iter().collect();
+        assert_eq!(lo_chains, expected.map(|(chain, _)| chain));
+
+        for (named, router) in expected {
+            let chain = crate::Chain::from(named);
+            assert!(named.supports_lo() && chain.supports_lo(), "{named}");
+            assert_eq!(named.lo_router_address().unwrap(), router, "{named}");
+            assert_eq!(chain.lo_router_address().unwrap(), router, "{named}");
+            assert_eq!(
+                crate::get_lo_router_by_chain_id(chain.id()),
+                Some(router),
+                "{named}"
+            );
+        }
+    }
+
     #[test]
     fn test_v2_router_addresses() {
         let chains = [
@@ -0,0 +1,376 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tracing::{debug, warn};
+
+use crate::{OdosError, Result};
+
+/// Circuit breaker settings for [`ClientConfig::circuit_breaker`](crate::ClientConfig::circuit_breaker)
+///
+/// The breaker tracks each endpoint (scheme, host and path) separately:
+///
+/// - **Closed**: requests flow normally. After `failure_threshold`
+///   consecutive failed attempts the circuit opens.
+/// - **Open**: requests fail immediately with [`OdosError::CircuitOpen`]
+///   without reaching the server, until `open_duration` has passed.
+/// - **Half-open**: up to `half_open_probes` requests are let through. The
+///   circuit closes once that many succeed, and reopens on the first failure.
+///
+/// Failures are connection errors, request timeouts and 5xx responses.
+/// Rate limit (429) responses do not count either way, and other responses
+/// count as successes since the server answered.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use odos_sdk::{CircuitBreakerConfig, ClientConfig};
+///
+/// let config = ClientConfig {
+///     circuit_breaker: Some(CircuitBreakerConfig {
+///         failure_threshold: 3,
+///         open_duration: Duration::from_secs(10),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failed attempts that open the circuit
+    ///
+    /// Default: 5
+    pub failure_threshold: u32,
+
+    /// How long the circuit stays open before probing the endpoint again
+    ///
+    /// Default: 30 seconds
+    pub open_duration: Duration,
+
+    /// Successful probes needed to close a half-open circuit, which is also
+    /// the number of probes allowed in flight at once
+    ///
+    /// Default: 1
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Check that the thresholds are usable
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.failure_threshold == 0 || self.half_open_probes == 0 {
+            return Err(OdosError::configuration_error(
+                "Circuit breaker failure_threshold and half_open_probes must be at least 1",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// How a request attempt counts towards the breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    Success,
+    Failure,
+    /// Neither a success nor a failure, such as a rate limit
+    Ignored,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, successes: u32 },
+}
+
+/// Per-endpoint circuit breaker shared by clones of a client
+#[derive(Debug, Clone)]
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    endpoints: Arc<Mutex<HashMap<String, State>>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            endpoints: Arc::default(),
+        }
+    }
+
+    /// Admit a request to `endpoint`, or fail with [`OdosError::CircuitOpen`]
+    ///
+    /// The returned permit must be given the attempt's outcome; dropping it
+    /// unrecorded counts as [`Outcome::Ignored`].
+    pub(crate) fn acquire(&self, endpoint: String) -> Result<Permit<'_>> {
+        let probe = self.admit(&endpoint, now())?;
+        Ok(Permit {
+            breaker: self,
+            endpoint,
+            probe,
+            recorded: false,
+        })
+    }
+
+    /// Admit a request, returning whether it is a half-open probe
+    fn admit(&self, endpoint: &str, now: Instant) -> Result<bool> {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        let state = endpoints
+            .entry(endpoint.to_string())
+            .or_insert(State::Closed { failures: 0 });
+
+        match *state {
+            State::Closed { .. } => Ok(false),
+            State::Open { until } if now < until => Err(OdosError::circuit_open(endpoint, until)),
+            State::Open { .. } => {
+                debug!(endpoint, "Circuit half-open, probing endpoint");
+                *state = State::HalfOpen {
+                    in_flight: 1,
+                    successes: 0,
+                };
+                Ok(true)
+            }
+            State::HalfOpen {
+                in_flight,
+                successes,
+            } if in_flight + successes < self.config.half_open_probes => {
+                *state = State::HalfOpen {
+                    in_flight: in_flight + 1,
+                    successes,
+                };
+                Ok(true)
+            }
+            State::HalfOpen { .. } => Err(OdosError::circuit_open(endpoint, now)),
+        }
+    }
+
+    fn record(&self, endpoint: &str, probe: bool, outcome: Outcome, now: Instant) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(state) = endpoints.get_mut(endpoint) else {
+            return;
+        };
+
+        let open = State::Open {
+            until: now + self.config.open_duration,
+        };
+        match (*state, outcome) {
+            (State::Closed { .. }, Outcome::Success) => {
+                *state = State::Closed { failures: 0 };
+            }
+            (State::Closed { failures }, Outcome::Failure) => {
+                let failures = failures + 1;
+                if failures >= self.config.failure_threshold {
+                    warn!(
+                        endpoint,
+                        failures, "Circuit opened after consecutive failures"
+                    );
+                    *state = open;
+                } else {
+                    *state = State::Closed { failures };
+                }
+            }
+            // Requests admitted while the circuit was closed do not affect
+            // the probes
+            (State::HalfOpen { .. }, _) if !probe => {}
+            (
+                State::HalfOpen {
+                    in_flight,
+                    successes,
+                },
+                outcome,
+            ) => {
+                let in_flight = in_flight.saturating_sub(1);
+                *state = match outcome {
+                    Outcome::Success if successes + 1 >= self.config.half_open_probes => {
+                        debug!(endpoint, "Circuit closed after successful probes");
+                        State::Closed { failures: 0 }
+                    }
+                    Outcome::Success => State::HalfOpen {
+                        in_flight,
+                        successes: successes + 1,
+                    },
+                    Outcome::Failure => {
+                        warn!(endpoint, "Circuit reopened after failed probe");
+                        open
+                    }
+                    Outcome::Ignored => State::HalfOpen {
+                        in_flight,
+                        successes,
+                    },
+                };
+            }
+            // Results arriving while open are stale
+            (State::Open { .. }, _) | (State::Closed { .. }, Outcome::Ignored) => {}
+        }
+    }
+}
+
+/// Admission of one request attempt through a [`CircuitBreaker`]
+pub(crate) struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    endpoint: String,
+    probe: bool,
+    recorded: bool,
+}
+
+impl Permit<'_> {
+    /// Record the attempt's outcome
+    pub(crate) fn record(mut self, outcome: Outcome) {
+        self.recorded = true;
+        self.breaker
+            .record(&self.endpoint, self.probe, outcome, now());
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.breaker
+                .record(&self.endpoint, self.probe, Outcome::Ignored, now());
+        }
+    }
+}
+
+/// Current time from tokio's clock, so tests can pause and advance it
+fn now() -> Instant {
+    tokio::time::Instant::now().into_std()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENDPOINT: &str = "https://api.odos.xyz/sor/quote/v2";
+
+    fn breaker(failure_threshold: u32, half_open_probes: u32) -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold,
+            open_duration: Duration::from_secs(10),
+            half_open_probes,
+        })
+    }
+
+    fn fail(breaker: &CircuitBreaker, now: Instant) {
+        let probe = breaker.admit(ENDPOINT, now).unwrap();
+        breaker.record(ENDPOINT, probe, Outcome::Failure, now);
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = breaker(3, 1);
+        let now = Instant::now();
+
+        fail(&breaker, now);
+        fail(&breaker, now);
+        // A success resets the count
+        breaker.record(ENDPOINT, false, Outcome::Success, now);
+        fail(&breaker, now);
+        fail(&breaker, now);
+        assert!(breaker.admit(ENDPOINT, now).is_ok());
+
+        breaker.record(ENDPOINT, false, Outcome::Failure, now);
+        let err = breaker.admit(ENDPOINT, now).unwrap_err();
+        assert!(matches!(&err, OdosError::CircuitOpen { endpoint, retry_at }
+                if endpoint == ENDPOINT && *retry_at == now + Duration::from_secs(10)));
+
+        // Other endpoints are unaffected
+        assert!(breaker
+            .admit("https://api.odos.xyz/sor/assemble", now)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_ignored_outcomes_do_not_count() {
+        let breaker = breaker(2, 1);
+        let now = Instant::now();
+
+        fail(&breaker, now);
+        breaker.record(ENDPOINT, false, Outcome::Ignored, now);
+        assert!(breaker.admit(ENDPOINT, now).is_ok());
+        breaker.record(ENDPOINT, false, Outcome::Failure, now);
+        assert!(breaker.admit(ENDPOINT, now).is_err());
+    }
+
+    #[test]
+    fn test_half_open_probes_close_circuit() {
+        let breaker = breaker(1, 2);
+        let now = Instant::now();
+        fail(&breaker, now);
+
+        let later = now + Duration::from_secs(10);
+        assert!(breaker.admit(ENDPOINT, later).unwrap());
+        assert!(breaker.admit(ENDPOINT, later).unwrap());
+        // Both probe slots are taken
+        assert!(breaker.admit(ENDPOINT, later).is_err());
+
+        breaker.record(ENDPOINT, true, Outcome::Success, later);
+        assert!(breaker.admit(ENDPOINT, later).is_err());
+        breaker.record(ENDPOINT, true, Outcome::Success, later);
+        assert!(!breaker.admit(ENDPOINT, later).unwrap());
+    }
+
+    #[test]
+    fn test_failed_probe_reopens_circuit() {
+        let breaker = breaker(1, 1);
+        let now = Instant::now();
+        fail(&breaker, now);
+
+        let later = now + Duration::from_secs(10);
+        assert!(breaker.admit(ENDPOINT, later).unwrap());
+        // A result from a request admitted while closed is ignored
+        breaker.record(ENDPOINT, false, Outcome::Success, later);
+        breaker.record(ENDPOINT, true, Outcome::Failure, later);
+
+        assert!(matches!(
+            breaker.admit(ENDPOINT, later),
+            Err(OdosError::CircuitOpen { retry_at, .. }) if retry_at == later + Duration::from_secs(10)
+        ));
+    }
+
+    #[test]
+    fn test_dropped_permit_releases_probe() {
+        let breaker = breaker(1, 1);
+        {
+            let permit = breaker.acquire(ENDPOINT.to_string()).unwrap();
+            permit.record(Outcome::Failure);
+        }
+
+        breaker.endpoints.lock().unwrap().insert(
+            ENDPOINT.to_string(),
+            State::Open {
+                until: Instant::now(),
+            },
+        );
+        drop(breaker.acquire(ENDPOINT.to_string()).unwrap());
+        assert!(breaker.acquire(ENDPOINT.to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(CircuitBreakerConfig::default().validate().is_ok());
+        let config = CircuitBreakerConfig {
+            failure_threshold: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(OdosError::Configuration(_))
+        ));
+    }
+}
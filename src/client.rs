@@ -2,20 +2,43 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant, SystemTime},
+};
 
 use backon::{BackoffBuilder, ExponentialBuilder};
-use reqwest::{Client, RequestBuilder, Response, StatusCode};
-use tokio::time::timeout;
-use tracing::{debug, instrument};
+use bon::bon;
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Client, RequestBuilder, Response, StatusCode,
+};
+use tokio::{
+    sync::Semaphore,
+    time::{error::Elapsed, timeout},
+};
+use tracing::{debug, error, field, instrument, Span};
 
 use crate::{
-    api::OdosApiErrorResponse,
+    api::{ApiHost, ApiVersion, OdosApiErrorResponse},
     api_key::ApiKey,
+    build_info::DEFAULT_USER_AGENT,
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, Outcome},
     error::{ApiErrorBody, OdosError, Result},
-    error_code::OdosErrorCode,
+    error_code::{OdosErrorCode, TraceId},
+    middleware::{RequestHook, ResponseMeta, ResponseObserver},
+    proxy::ProxyConfig,
+    quote_cache::QuoteCacheConfig,
+    rate_limit_info::RateLimitInfo,
+    rate_limiter::{RateLimiter, RateLimiterConfig},
+    retry_budget::RetryBudget,
+    sleep::{Sleeper, TokioSleeper},
 };
 
+/// Header carrying the client-generated trace ID, see
+/// [`ClientConfig::send_client_trace_id`]
+const CLIENT_TRACE_ID_HEADER: &str = "x-trace-id";
+
 /// How a caller-supplied predicate composes with the SDK's default retry
 /// decision tree.
 ///
@@ -30,8 +53,14 @@ use crate::{
 ///   retries when the predicate returns `true`. Useful for blacklisting
 ///   specific error shapes without reimplementing the default policy.
 ///
-/// `max_retries` and the rate-limit / 429 hard-gate apply to every variant.
-#[derive(Debug, Clone, Copy, Default)]
+/// Predicates are closures, so they can capture state such as an allowlist
+/// loaded from configuration or a metrics handle. Build them with
+/// [`RetryPredicate::replace`] and [`RetryPredicate::default_except`].
+///
+/// `max_retries` applies to every variant. Rate limits (429) are not
+/// passed to the predicate; they are retried only under
+/// [`RetryConfig::retry_rate_limits`].
+#[derive(Clone, Default)]
 pub enum RetryPredicate {
     /// Use the SDK's built-in decision tree.
     #[default]
@@ -40,22 +69,72 @@ pub enum RetryPredicate {
     /// Replace the default decision tree entirely. The predicate is the sole
     /// authority on whether to retry. The [`RetryConfig::retry_server_errors`]
     /// flag is bypassed under this variant.
-    Replace(fn(&OdosError) -> bool),
+    Replace(RetryPredicateFn),
 
     /// Run the default decision tree, but veto retries when the predicate
     /// returns `true`. Equivalent to
     /// `!veto(err) && default_should_retry(err)`.
-    DefaultExcept(fn(&OdosError) -> bool),
+    DefaultExcept(RetryPredicateFn),
+}
+
+/// Predicate held by [`RetryPredicate::Replace`] and
+/// [`RetryPredicate::DefaultExcept`]
+pub type RetryPredicateFn = Arc<dyn Fn(&OdosError) -> bool + Send + Sync>;
+
+impl RetryPredicate {
+    /// Replace the default decision tree with `predicate`
+    pub fn replace(predicate: impl Fn(&OdosError) -> bool + Send + Sync + 'static) -> Self {
+        Self::Replace(Arc::new(predicate))
+    }
+
+    /// Keep the default decision tree, but never retry errors matching `veto`
+    pub fn default_except(veto: impl Fn(&OdosError) -> bool + Send + Sync + 'static) -> Self {
+        Self::DefaultExcept(Arc::new(veto))
+    }
+}
+
+impl std::fmt::Debug for RetryPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => f.write_str("Default"),
+            Self::Replace(_) => f.write_str("Replace(<predicate>)"),
+            Self::DefaultExcept(_) => f.write_str("DefaultExcept(<predicate>)"),
+        }
+    }
+}
+
+/// Randomization applied to retry backoff delays
+///
+/// Without jitter, clients that fail together retry together: a fleet of
+/// workers that all hit the same 503 sends its retries in lockstep. Jitter
+/// spreads them out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Jitter {
+    /// Exact exponential delays
+    #[default]
+    None,
+    /// A uniformly random delay between zero and the exponential delay
+    Full,
+    /// A uniformly random delay between the initial backoff and
+    /// [`multiplier`](RetryConfig::multiplier) times the previous delay,
+    /// capped at [`max_backoff_ms`](RetryConfig::max_backoff_ms)
+    ///
+    /// Delays grow with the previous random delay rather than the attempt
+    /// number, so they stay spread out across attempts.
+    Decorrelated,
 }
 
 /// Configuration for retry behavior
 ///
 /// Controls which errors should be retried and how retries are executed.
+/// Backoff starts at `initial_backoff_ms` and grows by `multiplier` per
+/// retry up to `max_backoff_ms`, randomized by `jitter`.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use odos_sdk::{RetryConfig, RetryPredicate};
+/// use std::sync::Arc;
+/// use odos_sdk::{Jitter, RetryBudget, RetryConfig, RetryPredicate};
 ///
 /// // No retries - all errors return immediately
 /// let config = RetryConfig::no_retries();
@@ -70,7 +149,7 @@ pub enum RetryPredicate {
 /// let config = RetryConfig {
 ///     max_retries: 2,
 ///     retry_server_errors: false,
-///     retry_predicate: RetryPredicate::Replace(|err| {
+///     retry_predicate: RetryPredicate::replace(|err| {
 ///         // Custom logic to determine if error should be retried
 ///         err.is_retryable()
 ///     }),
@@ -79,11 +158,35 @@ pub enum RetryPredicate {
 ///
 /// // Keep the default policy but veto a specific error shape
 /// let config = RetryConfig {
-///     retry_predicate: RetryPredicate::DefaultExcept(|err| err.is_rate_limit()),
+///     retry_predicate: RetryPredicate::default_except(|err| err.is_rate_limit()),
+///     ..Default::default()
+/// };
+///
+/// // Spread out retries from many workers
+/// let config = RetryConfig {
+///     jitter: Jitter::Full,
+///     max_backoff_ms: 5_000,
+///     ..Default::default()
+/// };
+///
+/// // Cap retries across all requests during an outage
+/// let config = RetryConfig {
+///     retry_budget: Some(RetryBudget::new(120)),
+///     ..Default::default()
+/// };
+///
+/// // Count transient failures the client recovers from
+/// let config = RetryConfig {
+///     on_retry: Some(Arc::new(|event| {
+///         eprintln!(
+///             "retrying {} after attempt {} in {:?}: {}",
+///             event.endpoint, event.attempt, event.delay, event.error
+///         );
+///     })),
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RetryConfig {
     /// Maximum retry attempts for retryable errors
     pub max_retries: u32,
@@ -91,12 +194,104 @@ pub struct RetryConfig {
     /// Initial backoff duration in milliseconds
     pub initial_backoff_ms: u64,
 
+    /// Maximum backoff duration in milliseconds
+    pub max_backoff_ms: u64,
+
+    /// Factor the backoff grows by per retry; must be finite and at least 1
+    pub multiplier: f32,
+
+    /// Randomization applied to each backoff delay
+    pub jitter: Jitter,
+
     /// Whether to retry server errors (5xx)
     pub retry_server_errors: bool,
 
     /// How a caller-supplied predicate composes with the default decision
     /// tree. See [`RetryPredicate`] for the semantics of each variant.
     pub retry_predicate: RetryPredicate,
+
+    /// Callback fired before each backoff pause, with the failed attempt
+    /// and the delay before the next one
+    pub on_retry: Option<RetryCallback>,
+
+    /// Budget of retries shared across requests; when exhausted, failed
+    /// attempts return their error instead of retrying
+    ///
+    /// Default: None (no cap beyond `max_retries`)
+    pub retry_budget: Option<RetryBudget>,
+
+    /// Opt-in retry of rate limit (429) responses
+    ///
+    /// Default: None (rate limits are returned as [`OdosError::RateLimit`]
+    /// without retrying)
+    pub retry_rate_limits: Option<RateLimitRetryPolicy>,
+}
+
+/// Retry policy for rate limit (429) responses, set on
+/// [`RetryConfig::retry_rate_limits`]
+///
+/// A rate-limited attempt waits for the response's `Retry-After` (or
+/// [`RetryConfig::initial_backoff_ms`] without one), capped at `max_wait`,
+/// then retries. These retries are counted separately from
+/// [`RetryConfig::max_retries`]; once `max_attempts` is reached the
+/// [`OdosError::RateLimit`] is returned. Suited to low-volume jobs; under
+/// sustained load, rate limit globally instead, e.g. with
+/// [`ClientConfig::rate_limiter`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use odos_sdk::{RateLimitRetryPolicy, RetryConfig};
+///
+/// let config = RetryConfig {
+///     retry_rate_limits: Some(RateLimitRetryPolicy {
+///         max_wait: Duration::from_secs(10),
+///         max_attempts: 1,
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitRetryPolicy {
+    /// Longest wait before a retry, whatever `Retry-After` asks for
+    pub max_wait: Duration,
+
+    /// Rate-limited retries allowed per request
+    pub max_attempts: u32,
+}
+
+/// Callback for [`RetryConfig::on_retry`]
+pub type RetryCallback = Arc<dyn Fn(RetryEvent<'_>) + Send + Sync>;
+
+/// A failed attempt about to be retried, passed to [`RetryConfig::on_retry`]
+#[derive(Debug, Clone, Copy)]
+pub struct RetryEvent<'a> {
+    /// Number of the attempt that failed, starting at 1
+    pub attempt: u32,
+    /// Error from the failed attempt
+    pub error: &'a OdosError,
+    /// Backoff delay before the next attempt
+    pub delay: Duration,
+    /// Endpoint requested: scheme, host and path, without the query
+    pub endpoint: &'a str,
+}
+
+impl std::fmt::Debug for RetryConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryConfig")
+            .field("max_retries", &self.max_retries)
+            .field("initial_backoff_ms", &self.initial_backoff_ms)
+            .field("max_backoff_ms", &self.max_backoff_ms)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("retry_server_errors", &self.retry_server_errors)
+            .field("retry_predicate", &self.retry_predicate)
+            .field("on_retry", &self.on_retry.is_some())
+            .field("retry_budget", &self.retry_budget)
+            .field("retry_rate_limits", &self.retry_rate_limits)
+            .finish()
+    }
 }
 
 impl Default for RetryConfig {
@@ -104,8 +299,14 @@ impl Default for RetryConfig {
         Self {
             max_retries: 3,
             initial_backoff_ms: 100,
+            max_backoff_ms: 30_000,
+            multiplier: 2.0,
+            jitter: Jitter::None,
             retry_server_errors: true,
             retry_predicate: RetryPredicate::Default,
+            on_retry: None,
+            retry_budget: None,
+            retry_rate_limits: None,
         }
     }
 }
@@ -134,6 +335,26 @@ impl RetryConfig {
             ..Default::default()
         }
     }
+
+    /// Retries decided entirely by `predicate`
+    ///
+    /// The predicate replaces the default decision tree, see
+    /// [`RetryPredicate::Replace`]. It may capture state:
+    ///
+    /// ```rust
+    /// use odos_sdk::{error_code::OdosErrorCode, RetryConfig};
+    ///
+    /// let allowlist = vec![OdosErrorCode::AlgoTimeout];
+    /// let config = RetryConfig::with_predicate(move |err| {
+    ///     err.error_code().is_some_and(|code| allowlist.contains(code))
+    /// });
+    /// ```
+    pub fn with_predicate(predicate: impl Fn(&OdosError) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            retry_predicate: RetryPredicate::replace(predicate),
+            ..Default::default()
+        }
+    }
 }
 
 /// Configuration for the HTTP client
@@ -157,6 +378,27 @@ impl RetryConfig {
 /// let config = ClientConfig::default();
 /// ```
 ///
+/// ## Validated configuration with the builder
+///
+/// [`ClientConfig::builder`] is the preferred way to build a configuration:
+/// unset fields keep their defaults, and `build` rejects inconsistent
+/// settings up front instead of letting them fail at request time.
+/// ```rust
+/// use std::time::Duration;
+/// use odos_sdk::{ApiKey, ClientConfig, Endpoint};
+///
+/// let config = ClientConfig::builder()
+///     .timeout(Duration::from_secs(20))
+///     .connect_timeout(Duration::from_secs(5))
+///     .endpoint(Endpoint::enterprise_v3())
+///     .api_key(ApiKey::new(uuid::Uuid::new_v4()))
+///     .build()?;
+/// # Ok::<(), odos_sdk::OdosError>(())
+/// ```
+///
+/// The fields stay public, so struct literals with `..Default::default()`
+/// keep working, but they skip the builder's checks.
+///
 /// ## Custom endpoint configuration
 /// ```rust
 /// use odos_sdk::{ClientConfig, Endpoint};
@@ -271,6 +513,137 @@ pub struct ClientConfig {
     /// };
     /// ```
     pub endpoint: crate::Endpoint,
+
+    /// Allow an Enterprise [`endpoint`](Self::endpoint) without an
+    /// [`api_key`](Self::api_key)
+    ///
+    /// By default [`OdosHttpClient::with_config`] rejects that combination,
+    /// since every request would fail with [`OdosError::Unauthorized`]. Set
+    /// this when the key is added elsewhere, e.g. by a proxy in front of the
+    /// API or through [`default_headers`](Self::default_headers).
+    ///
+    /// Default: false
+    pub allow_unauthenticated_enterprise: bool,
+
+    /// Strategy used to wait between retry attempts
+    ///
+    /// Every SDK-driven pause goes through this [`Sleeper`] rather than
+    /// calling `tokio::time::sleep` directly, so tests and alternative
+    /// runtimes can substitute their own timer.
+    ///
+    /// Default: [`TokioSleeper`]
+    pub sleeper: Arc<dyn Sleeper>,
+
+    /// Validate quote requests client-side before sending them
+    ///
+    /// Malformed requests are rejected with [`OdosError::InvalidInput`]
+    /// instead of spending a round trip and rate limit budget on an API
+    /// error. See [`QuoteRequest::validate`](crate::QuoteRequest::validate)
+    /// for the checks performed.
+    ///
+    /// Default: true
+    pub validate_quote_requests: bool,
+
+    /// Optional per-endpoint circuit breaker
+    ///
+    /// When set, an endpoint that keeps failing is cut off for a while and
+    /// requests to it fail fast with [`OdosError::CircuitOpen`]. See
+    /// [`CircuitBreakerConfig`] for the states and what counts as a failure.
+    ///
+    /// Default: None (no circuit breaker)
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+
+    /// Optional client-side rate limit
+    ///
+    /// When set, each request attempt waits for a token from a bucket shared
+    /// by all clones of the client, smoothing concurrent requests to the
+    /// configured rate instead of letting the API reject them with 429s. See
+    /// [`RateLimiterConfig`].
+    ///
+    /// Default: None (no client-side limit)
+    pub rate_limiter: Option<RateLimiterConfig>,
+
+    /// Optional cap on requests in flight at once
+    ///
+    /// When set, each call holds a slot for its whole retry loop, including
+    /// backoff pauses, and further calls wait for a free slot. Slots are
+    /// shared by all clones of the client. Read the current count with
+    /// [`OdosHttpClient::in_flight_requests`].
+    ///
+    /// Default: None (unlimited)
+    pub max_concurrent_requests: Option<usize>,
+
+    /// Optional HTTP(S) proxy for all requests
+    ///
+    /// Overrides any proxy picked up from the `HTTP_PROXY`/`HTTPS_PROXY`
+    /// environment variables. See [`ProxyConfig`].
+    ///
+    /// Default: None (system proxy settings)
+    pub proxy: Option<ProxyConfig>,
+
+    /// `User-Agent` header sent with every request
+    ///
+    /// Odos asks integrators to identify themselves, e.g.
+    /// `"my-app/1.2 odos-sdk-rs/11.0.0"`.
+    ///
+    /// Default: None (`odos-sdk-rs/<crate version>`)
+    pub user_agent: Option<String>,
+
+    /// Headers sent with every request
+    ///
+    /// Applied before per-request headers such as the API key, which take
+    /// precedence on conflict. Only header names are shown in `Debug`
+    /// output, since values may hold credentials.
+    ///
+    /// Default: empty
+    pub default_headers: HeaderMap,
+
+    /// Send a random [`TraceId`] with each request as the `x-trace-id`
+    /// header
+    ///
+    /// One ID is generated per call and reused across its retries. It is
+    /// recorded on the request span as `odos.client_trace_id` and attached
+    /// to API errors (see [`OdosError::client_trace_id`]), so both sides of
+    /// a failed request can be matched up with Odos support.
+    ///
+    /// Default: false
+    pub send_client_trace_id: bool,
+
+    /// Hooks run on every request attempt before it is sent, in order
+    ///
+    /// Add with [`with_request_hook`](Self::with_request_hook).
+    ///
+    /// Default: empty
+    pub request_hooks: Vec<RequestHook>,
+
+    /// Observers called after every request attempt, in order
+    ///
+    /// Add with [`with_response_observer`](Self::with_response_observer).
+    ///
+    /// Default: empty
+    pub response_observers: Vec<ResponseObserver>,
+
+    /// Optional cache of [`OdosClient::quote`](crate::OdosClient::quote)
+    /// responses
+    ///
+    /// When set, identical quote requests made within the TTL are answered
+    /// from memory and marked with
+    /// [`SingleQuoteResponse::is_cached`](crate::SingleQuoteResponse::is_cached).
+    /// See [`QuoteCacheConfig`].
+    ///
+    /// Default: None (no caching)
+    pub quote_cache: Option<QuoteCacheConfig>,
+
+    /// Coalesce identical [`OdosClient::quote`](crate::OdosClient::quote)
+    /// calls in flight
+    ///
+    /// When set, a quote request identical to one already in flight, on
+    /// this client or a clone, waits for that request instead of sending
+    /// its own, and receives a copy of its response or error. Only the
+    /// first caller's request counts against rate limits and retries.
+    ///
+    /// Default: false
+    pub coalesce_quotes: bool,
 }
 
 impl Default for ClientConfig {
@@ -283,6 +656,20 @@ impl Default for ClientConfig {
             pool_idle_timeout: Duration::from_secs(90),
             api_key: None,
             endpoint: crate::Endpoint::public_v2(),
+            allow_unauthenticated_enterprise: false,
+            sleeper: Arc::new(TokioSleeper),
+            validate_quote_requests: true,
+            circuit_breaker: None,
+            rate_limiter: None,
+            max_concurrent_requests: None,
+            proxy: None,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            send_client_trace_id: false,
+            request_hooks: Vec::new(),
+            response_observers: Vec::new(),
+            quote_cache: None,
+            coalesce_quotes: false,
         }
     }
 }
@@ -297,6 +684,26 @@ impl std::fmt::Debug for ClientConfig {
             .field("pool_idle_timeout", &self.pool_idle_timeout)
             .field("api_key", &self.api_key)
             .field("endpoint", &self.endpoint)
+            .field(
+                "allow_unauthenticated_enterprise",
+                &self.allow_unauthenticated_enterprise,
+            )
+            .field("sleeper", &self.sleeper)
+            .field("validate_quote_requests", &self.validate_quote_requests)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("max_concurrent_requests", &self.max_concurrent_requests)
+            .field("proxy", &self.proxy)
+            .field("user_agent", &self.user_agent)
+            .field(
+                "default_headers",
+                &self.default_headers.keys().collect::<Vec<_>>(),
+            )
+            .field("send_client_trace_id", &self.send_client_trace_id)
+            .field("request_hooks", &self.request_hooks.len())
+            .field("response_observers", &self.response_observers.len())
+            .field("quote_cache", &self.quote_cache)
+            .field("coalesce_quotes", &self.coalesce_quotes)
             .finish()
     }
 }
@@ -321,6 +728,280 @@ impl ClientConfig {
             ..Default::default()
         }
     }
+
+    /// Create a default configuration with settings from the environment
+    ///
+    /// | Variable | Values | Sets |
+    /// | -------- | ------ | ---- |
+    /// | `ODOS_API_KEY` | UUID | [`api_key`](Self::api_key), see [`ApiKey::from_env`] |
+    /// | `ODOS_ENDPOINT` | `public`, `enterprise` | host of [`endpoint`](Self::endpoint) |
+    /// | `ODOS_API_VERSION` | `v2`, `v3` | version of [`endpoint`](Self::endpoint) |
+    ///
+    /// Values are case-insensitive. Unset variables keep the defaults
+    /// (no API key, public host, V2). A set but invalid variable fails with
+    /// [`OdosError::Configuration`] rather than being ignored.
+    ///
+    /// # Precedence
+    ///
+    /// Environment variables override the defaults, and fields set in code
+    /// override both:
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use odos_sdk::{ClientConfig, OdosClient};
+    ///
+    /// let config = ClientConfig {
+    ///     timeout: Duration::from_secs(10),
+    ///     ..ClientConfig::from_env()?
+    /// };
+    /// let client = OdosClient::with_config(config)?;
+    /// # Ok::<(), odos_sdk::OdosError>(())
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        let default = Self::default();
+        let host = match env_var("ODOS_ENDPOINT")? {
+            None => default.endpoint.host(),
+            Some(host) => match host.to_ascii_lowercase().as_str() {
+                "public" => ApiHost::Public,
+                "enterprise" => ApiHost::Enterprise,
+                _ => {
+                    return Err(OdosError::configuration_error(format!(
+                        "ODOS_ENDPOINT must be 'public' or 'enterprise', got '{host}'"
+                    )))
+                }
+            },
+        };
+        let version = match env_var("ODOS_API_VERSION")? {
+            None => default.endpoint.version(),
+            Some(version) => match version.to_ascii_lowercase().as_str() {
+                "v2" => ApiVersion::V2,
+                "v3" => ApiVersion::V3,
+                _ => {
+                    return Err(OdosError::configuration_error(format!(
+                        "ODOS_API_VERSION must be 'v2' or 'v3', got '{version}'"
+                    )))
+                }
+            },
+        };
+
+        Ok(Self {
+            api_key: ApiKey::from_env()?,
+            endpoint: crate::Endpoint::new(host, version),
+            ..default
+        })
+    }
+
+    /// Add a hook run on every request attempt, retries included
+    ///
+    /// See [`RequestHook`].
+    pub fn with_request_hook(mut self, hook: RequestHook) -> Self {
+        self.request_hooks.push(hook);
+        self
+    }
+
+    /// Add an observer called after every request attempt, retries included
+    ///
+    /// See [`ResponseObserver`].
+    pub fn with_response_observer(mut self, observer: ResponseObserver) -> Self {
+        self.response_observers.push(observer);
+        self
+    }
+}
+
+#[bon]
+impl ClientConfig {
+    /// Build a configuration, checking that its settings are consistent
+    ///
+    /// Every setter is optional and defaults to the [`Default`] value of the
+    /// matching field. On top of the checks made by
+    /// [`OdosHttpClient::with_config`], `build` rejects:
+    ///
+    /// - a `connect_timeout` longer than `timeout`
+    /// - a `max_connections` of zero
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::Configuration`] naming the first invalid setting.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use odos_sdk::{ClientConfig, OdosError};
+    ///
+    /// let err = ClientConfig::builder()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .connect_timeout(Duration::from_secs(10))
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert!(matches!(err, OdosError::Configuration(_)));
+    /// ```
+    #[builder]
+    pub fn new(
+        #[builder(default = Duration::from_secs(30))] timeout: Duration,
+        #[builder(default = Duration::from_secs(10))] connect_timeout: Duration,
+        #[builder(default)] retry_config: RetryConfig,
+        #[builder(default = 20)] max_connections: usize,
+        #[builder(default = Duration::from_secs(90))] pool_idle_timeout: Duration,
+        api_key: Option<ApiKey>,
+        #[builder(default = crate::Endpoint::public_v2())] endpoint: crate::Endpoint,
+        #[builder(default)] allow_unauthenticated_enterprise: bool,
+        #[builder(default = Arc::new(TokioSleeper))] sleeper: Arc<dyn Sleeper>,
+        #[builder(default = true)] validate_quote_requests: bool,
+        circuit_breaker: Option<CircuitBreakerConfig>,
+        rate_limiter: Option<RateLimiterConfig>,
+        max_concurrent_requests: Option<usize>,
+        proxy: Option<ProxyConfig>,
+        #[builder(into)] user_agent: Option<String>,
+        #[builder(default)] default_headers: HeaderMap,
+        #[builder(default)] send_client_trace_id: bool,
+        #[builder(default)] request_hooks: Vec<RequestHook>,
+        #[builder(default)] response_observers: Vec<ResponseObserver>,
+        quote_cache: Option<QuoteCacheConfig>,
+        #[builder(default)] coalesce_quotes: bool,
+    ) -> Result<Self> {
+        let config = Self {
+            timeout,
+            connect_timeout,
+            retry_config,
+            max_connections,
+            pool_idle_timeout,
+            api_key,
+            endpoint,
+            allow_unauthenticated_enterprise,
+            sleeper,
+            validate_quote_requests,
+            circuit_breaker,
+            rate_limiter,
+            max_concurrent_requests,
+            proxy,
+            user_agent,
+            default_headers,
+            send_client_trace_id,
+            request_hooks,
+            response_observers,
+            quote_cache,
+            coalesce_quotes,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl ClientConfig {
+    /// Check the settings that would otherwise fail at request time, then
+    /// the ones [`OdosHttpClient::with_config`] checks
+    fn validate(&self) -> Result<()> {
+        if self.connect_timeout > self.timeout {
+            return Err(OdosError::configuration_error(format!(
+                "connect_timeout ({:?}) must not exceed timeout ({:?})",
+                self.connect_timeout, self.timeout
+            )));
+        }
+        if self.max_connections == 0 {
+            return Err(OdosError::configuration_error(
+                "max_connections must be at least 1",
+            ));
+        }
+        self.validate_components()
+    }
+
+    /// Check the settings a client cannot be built without
+    fn validate_components(&self) -> Result<()> {
+        let multiplier = self.retry_config.multiplier;
+        if !multiplier.is_finite() || multiplier < 1.0 {
+            return Err(OdosError::configuration_error(format!(
+                "Retry multiplier must be finite and at least 1, got {multiplier}"
+            )));
+        }
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.validate()?;
+        }
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.validate()?;
+        }
+        if let Some(cache) = &self.quote_cache {
+            cache.validate()?;
+        }
+        if let Some(budget) = &self.retry_config.retry_budget {
+            budget.validate()?;
+        }
+        if self.max_concurrent_requests == Some(0) {
+            return Err(OdosError::configuration_error(
+                "max_concurrent_requests must be at least 1",
+            ));
+        }
+        if self.endpoint.host() == ApiHost::Enterprise
+            && self.api_key.is_none()
+            && !self.allow_unauthenticated_enterprise
+        {
+            return Err(OdosError::configuration_error(
+                "Enterprise endpoints require an api_key",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Classify a request attempt for the circuit breaker
+///
+/// Rate limits are ignored, and running out of a total budget says nothing
+/// about the endpoint's health.
+fn breaker_outcome(
+    result: &std::result::Result<reqwest::Result<Response>, Elapsed>,
+    budget_bound: bool,
+) -> Outcome {
+    match result {
+        Ok(Ok(response)) if response.status() == StatusCode::TOO_MANY_REQUESTS => Outcome::Ignored,
+        Ok(Ok(response)) if response.status().is_server_error() => Outcome::Failure,
+        Ok(Ok(_)) => Outcome::Success,
+        Ok(Err(_)) => Outcome::Failure,
+        Err(_) if budget_bound => Outcome::Ignored,
+        Err(_) => Outcome::Failure,
+    }
+}
+
+/// Deadline shared by a sequence of requests
+///
+/// Each request made within the budget is given the smaller of
+/// [`ClientConfig::timeout`] and the time remaining, so the sequence as a
+/// whole cannot overrun the budget.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TimeBudget {
+    total: Duration,
+    /// Read from tokio's clock, so tests can pause and advance it
+    deadline: tokio::time::Instant,
+}
+
+impl TimeBudget {
+    /// Start a budget of `total` from now
+    pub(crate) fn start(total: Duration) -> Self {
+        Self {
+            total,
+            deadline: tokio::time::Instant::now() + total,
+        }
+    }
+
+    /// Time left before the deadline, or a [`TimeoutPhase::Total`] timeout
+    /// error if it has passed
+    ///
+    /// [`TimeoutPhase::Total`]: crate::TimeoutPhase::Total
+    pub(crate) fn remaining(&self) -> Result<Duration> {
+        let remaining = self
+            .deadline
+            .saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            return Err(self.exhausted());
+        }
+        Ok(remaining)
+    }
+
+    fn exhausted(&self) -> OdosError {
+        OdosError::total_timeout(format!(
+            "Total budget of {}ms exhausted",
+            self.total.as_millis()
+        ))
+    }
 }
 
 /// Enhanced HTTP client with retry logic and timeouts
@@ -328,6 +1009,12 @@ impl ClientConfig {
 pub struct OdosHttpClient {
     client: Client,
     config: ClientConfig,
+    circuit_breaker: Option<CircuitBreaker>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    concurrency: Option<Arc<Semaphore>>,
+    rate_limit_info: Arc<RwLock<Option<RateLimitInfo>>>,
+    /// Refuse to send requests, for clients answering from fixtures
+    offline: bool,
 }
 
 impl OdosHttpClient {
@@ -338,45 +1025,265 @@ impl OdosHttpClient {
 
     /// Create a new HTTP client with custom configuration
     pub fn with_config(config: ClientConfig) -> Result<Self> {
-        let client = Client::builder()
+        config.validate_components()?;
+
+        let user_agent = config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+        let user_agent = HeaderValue::from_str(user_agent)
+            .map_err(|e| OdosError::configuration_error(format!("Invalid user agent: {e}")))?;
+
+        let mut builder = Client::builder()
+            .user_agent(user_agent)
+            .default_headers(config.default_headers.clone())
             .timeout(config.timeout)
             .connect_timeout(config.connect_timeout)
             .pool_max_idle_per_host(config.max_connections)
-            .pool_idle_timeout(config.pool_idle_timeout)
-            .build()
-            .map_err(OdosError::Http)?;
+            .pool_idle_timeout(config.pool_idle_timeout);
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(proxy.to_proxy()?);
+        }
+        let client = builder.build()?;
+
+        Ok(Self {
+            client,
+            circuit_breaker: config.circuit_breaker.map(CircuitBreaker::new),
+            rate_limiter: config
+                .rate_limiter
+                .map(|limiter| Arc::new(RateLimiter::new(limiter))),
+            concurrency: config
+                .max_concurrent_requests
+                .map(|permits| Arc::new(Semaphore::new(permits))),
+            rate_limit_info: Arc::default(),
+            offline: false,
+            config,
+        })
+    }
 
-        Ok(Self { client, config })
+    /// Make the client refuse to send any request
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) fn offline(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
+    /// Fail if the client is offline, naming the request it refused
+    fn check_online(&self, request: &reqwest::Request) -> Result<()> {
+        if !self.offline {
+            return Ok(());
+        }
+        Err(OdosError::missing_data(format!(
+            "No fixture answers {} {}; the fixture client only serves quotes and assembly",
+            request.method(),
+            request.url()
+        )))
     }
 
     /// Execute a request with retry logic
-    #[instrument(skip(self, request_builder_fn), level = "debug")]
     pub async fn execute_with_retry<F>(&self, request_builder_fn: F) -> Result<Response>
     where
         F: Fn() -> RequestBuilder + Clone,
     {
-        let initial_backoff_duration =
-            Duration::from_millis(self.config.retry_config.initial_backoff_ms);
+        self.execute_with_retry_within(request_builder_fn, None)
+            .await
+    }
+
+    /// Execute a request with retry logic, bounded by an optional total budget
+    ///
+    /// Attempts and backoff pauses stop at the budget's deadline; running out
+    /// of budget fails with a [`TimeoutPhase::Total`](crate::TimeoutPhase::Total)
+    /// timeout, which is never retried.
+    ///
+    /// The span records the number of attempts made, and the HTTP status,
+    /// Odos error code and trace ID of the last error response, so logs can
+    /// be matched to Odos support tickets. With
+    /// [`ClientConfig::send_client_trace_id`] it also records the ID sent
+    /// in the `x-trace-id` header.
+    #[instrument(
+        skip(self, request_builder_fn),
+        level = "debug",
+        fields(
+            attempts = field::Empty,
+            http.status = field::Empty,
+            odos.error_code = field::Empty,
+            odos.trace_id = field::Empty,
+            odos.client_trace_id = field::Empty,
+        )
+    )]
+    pub(crate) async fn execute_with_retry_within<F>(
+        &self,
+        request_builder_fn: F,
+        budget: Option<TimeBudget>,
+    ) -> Result<Response>
+    where
+        F: Fn() -> RequestBuilder + Clone,
+    {
+        let _slot = match &self.concurrency {
+            Some(semaphore) => {
+                let acquire = semaphore.acquire();
+                let permit = match budget {
+                    Some(budget) => timeout(budget.remaining()?, acquire)
+                        .await
+                        .map_err(|_| budget.exhausted())?,
+                    None => acquire.await,
+                };
+                Some(permit.expect("concurrency semaphore is never closed"))
+            }
+            None => None,
+        };
 
-        // +1 because backon counts total attempts, not retries
-        let backoff = ExponentialBuilder::default()
-            .with_min_delay(initial_backoff_duration)
-            .with_max_delay(Duration::from_secs(30))
-            .with_max_times(self.config.retry_config.max_retries as usize + 1);
+        let mut endpoint = String::new();
+        let result = self
+            .retry_loop(request_builder_fn, budget, &mut endpoint)
+            .await;
+        #[cfg(feature = "metrics")]
+        if let Err(error) = &result {
+            crate::metrics::record_error(&endpoint, error);
+        }
+        result
+    }
+
+    /// Run the attempts of [`Self::execute_with_retry_within`], leaving the
+    /// last endpoint requested in `last_endpoint`
+    async fn retry_loop<F>(
+        &self,
+        request_builder_fn: F,
+        budget: Option<TimeBudget>,
+        last_endpoint: &mut String,
+    ) -> Result<Response>
+    where
+        F: Fn() -> RequestBuilder + Clone,
+    {
+        let client_trace_id = self.config.send_client_trace_id.then(TraceId::random);
+        if let Some(client_trace_id) = client_trace_id {
+            Span::current().record("odos.client_trace_id", field::display(client_trace_id));
+        }
+
+        self.retry_attempts(request_builder_fn, budget, last_endpoint, client_trace_id)
+            .await
+            .map_err(|error| error.with_client_trace_id(client_trace_id))
+    }
 
-        let mut backoff_iter = backoff.build();
+    /// Attempts of [`Self::retry_loop`], sending `client_trace_id` with each
+    async fn retry_attempts<F>(
+        &self,
+        request_builder_fn: F,
+        budget: Option<TimeBudget>,
+        last_endpoint: &mut String,
+        client_trace_id: Option<TraceId>,
+    ) -> Result<Response>
+    where
+        F: Fn() -> RequestBuilder + Clone,
+    {
+        let mut backoff_iter = RetryBackoff::new(&self.config.retry_config, fastrand::Rng::new());
         let mut attempt = 0;
+        let mut rate_limited = 0;
 
         loop {
             attempt += 1;
+            Span::current().record("attempts", attempt);
 
-            let request = match request_builder_fn().build() {
+            let mut request = match request_builder_fn().build() {
                 Ok(req) => req,
-                Err(e) => return Err(OdosError::Http(e)),
+                Err(e) => return Err(e.into()),
+            };
+            self.check_online(&request)?;
+            let endpoint = format!(
+                "{}{}",
+                request.url().origin().ascii_serialization(),
+                request.url().path()
+            );
+            last_endpoint.clone_from(&endpoint);
+
+            // Check the budget before reserving, so an exhausted budget takes
+            // no token, and reserve before admitting, so a request abandoned
+            // for the budget never takes a half-open probe
+            let remaining = budget.map(|budget| budget.remaining()).transpose()?;
+            let wait = self
+                .rate_limiter
+                .as_ref()
+                .map_or(Duration::ZERO, |limiter| limiter.reserve());
+            let refund = || {
+                if let Some(limiter) = &self.rate_limiter {
+                    limiter.refund();
+                }
+            };
+            if let Some((budget, remaining)) = budget.zip(remaining) {
+                if !wait.is_zero() && wait >= remaining {
+                    // Nothing is sent, so leave the token to the next caller
+                    refund();
+                    return Err(budget.exhausted());
+                }
+            }
+
+            let permit = match &self.circuit_breaker {
+                Some(breaker) => match breaker.acquire(endpoint.clone()) {
+                    Ok(permit) => Some(permit),
+                    Err(err) => {
+                        refund();
+                        return Err(err);
+                    }
+                },
+                None => None,
+            };
+
+            if !wait.is_zero() {
+                debug!(
+                    wait_ms = wait.as_millis() as u64,
+                    "Waiting for client-side rate limiter"
+                );
+                self.config.sleeper.sleep(wait).await;
+            }
+
+            let (attempt_timeout, budget_bound) = match budget {
+                Some(budget) => {
+                    let remaining = budget.remaining()?;
+                    (
+                        remaining.min(self.config.timeout),
+                        remaining < self.config.timeout,
+                    )
+                }
+                None => (self.config.timeout, false),
+            };
+
+            if let Some(client_trace_id) = client_trace_id {
+                let value = HeaderValue::from_str(&client_trace_id.to_string())
+                    .expect("a UUID is a valid header value");
+                request.headers_mut().insert(CLIENT_TRACE_ID_HEADER, value);
+            }
+            for hook in &self.config.request_hooks {
+                hook(&mut request);
+            }
+
+            let sent_at = Instant::now();
+            let result = timeout(attempt_timeout, self.client.execute(request)).await;
+            if let Some(permit) = permit {
+                permit.record(breaker_outcome(&result, budget_bound));
+            }
+            let latency = sent_at.elapsed();
+            let status = result
+                .as_ref()
+                .ok()
+                .and_then(|r| r.as_ref().ok())
+                .map(Response::status);
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_attempt(&endpoint, status, latency);
+            if !self.config.response_observers.is_empty() {
+                let meta = ResponseMeta {
+                    endpoint: endpoint.clone(),
+                    attempt,
+                    status,
+                    latency,
+                };
+                for observer in &self.config.response_observers {
+                    observer(&meta);
+                }
+            }
+
+            let rate_limit_info = match &result {
+                Ok(Ok(response)) => self.record_rate_limit_info(response),
+                _ => None,
             };
 
-            let last_error = match timeout(self.config.timeout, self.client.execute(request)).await
-            {
+            let last_error = match result {
                 Ok(Ok(response)) if response.status().is_success() => {
                     return Ok(response);
                 }
@@ -384,27 +1291,67 @@ impl OdosHttpClient {
                     let status = response.status();
 
                     if status == StatusCode::TOO_MANY_REQUESTS {
-                        // Rate limits are never retried - application must handle globally
+                        // Rate limits are only retried when opted in - by
+                        // default the application must handle them globally
                         let retry_after = extract_retry_after(&response);
-                        let body = parse_error_response(response).await;
-                        return Err(OdosError::RateLimit { retry_after, body });
-                    } else {
-                        let body = parse_error_response(response).await;
-                        let error = OdosError::Api { status, body };
-
-                        if !self.should_retry(&error, attempt) {
+                        let mut body = parse_error_response(response).await;
+                        body.client_trace_id = client_trace_id;
+                        record_error_body(status, &body);
+                        let error = OdosError::RateLimit {
+                            retry_after,
+                            body,
+                            rate_limit_info: rate_limit_info.map(Box::new),
+                            endpoint: None,
+                        };
+
+                        let Some(policy) = self.config.retry_config.retry_rate_limits else {
+                            return Err(error);
+                        };
+                        if rate_limited >= policy.max_attempts {
                             return Err(error);
                         }
-
+                        let delay = retry_after
+                            .unwrap_or(Duration::from_millis(
+                                self.config.retry_config.initial_backoff_ms,
+                            ))
+                            .min(policy.max_wait);
+                        if let Some(budget) = budget {
+                            if delay >= budget.remaining()? {
+                                return Err(error);
+                            }
+                        }
+                        if !self.take_retry_budget(attempt) {
+                            return Err(error);
+                        }
+
+                        debug!(
+                            attempt,
+                            delay_ms = delay.as_millis() as u64,
+                            "Rate limited, will retry after Retry-After"
+                        );
+                        self.notify_retry(attempt, &error, delay, &endpoint);
+                        self.config.sleeper.sleep(delay).await;
+                        rate_limited += 1;
+                        continue;
+                    } else {
+                        let mut body = parse_error_response(response).await;
+                        body.client_trace_id = client_trace_id;
+                        record_error_body(status, &body);
+                        let error = OdosError::from_response(status, body);
+
+                        if !self.should_retry(&error, attempt - rate_limited) {
+                            return Err(error);
+                        }
+
                         error
                     }
                 }
                 Ok(Err(e)) => {
                     let is_timeout = e.is_timeout();
                     let is_connect = e.is_connect();
-                    let error = OdosError::Http(e);
+                    let error = OdosError::from(e);
 
-                    if !self.should_retry(&error, attempt) {
+                    if !self.should_retry(&error, attempt - rate_limited) {
                         return Err(error);
                     }
                     debug!(
@@ -418,9 +1365,13 @@ impl OdosHttpClient {
                     error
                 }
                 Err(_) => {
+                    if let Some(budget) = budget.filter(|_| budget_bound) {
+                        return Err(budget.exhausted());
+                    }
+
                     let error = OdosError::timeout_error("Request timed out");
 
-                    if !self.should_retry(&error, attempt) {
+                    if !self.should_retry(&error, attempt - rate_limited) {
                         return Err(error);
                     }
                     debug!(
@@ -433,18 +1384,111 @@ impl OdosHttpClient {
                 }
             };
 
-            if attempt >= self.config.retry_config.max_retries {
+            if attempt - rate_limited >= self.config.retry_config.max_retries {
                 return Err(last_error);
             }
 
             if let Some(delay) = backoff_iter.next() {
-                tokio::time::sleep(delay).await;
+                if let Some(budget) = budget {
+                    if delay >= budget.remaining()? {
+                        return Err(budget.exhausted());
+                    }
+                }
+                if !self.take_retry_budget(attempt) {
+                    return Err(last_error);
+                }
+                self.notify_retry(attempt, &last_error, delay, &endpoint);
+                self.config.sleeper.sleep(delay).await;
             } else {
                 return Err(last_error);
             }
         }
     }
 
+    /// Send a single attempt with no retries, bounded by `attempt_timeout`
+    ///
+    /// Request hooks still run, but the circuit breaker, rate limiter and
+    /// concurrency limit are bypassed, so a probe is not held up behind
+    /// queued requests.
+    pub(crate) async fn execute_once(
+        &self,
+        request_builder: RequestBuilder,
+        attempt_timeout: Duration,
+    ) -> Result<Response> {
+        let mut request = request_builder.build()?;
+        self.check_online(&request)?;
+        for hook in &self.config.request_hooks {
+            hook(&mut request);
+        }
+
+        match timeout(attempt_timeout, self.client.execute(request)).await {
+            Ok(Ok(response)) => {
+                self.record_rate_limit_info(&response);
+                Ok(response)
+            }
+            Ok(Err(e)) if e.is_timeout() => Err(OdosError::timeout_error("Request timed out")),
+            Ok(Err(e)) => Err(e.into()),
+            Err(_) => Err(OdosError::timeout_error("Request timed out")),
+        }
+    }
+
+    /// Store the rate limit headers of `response`, if it has any
+    fn record_rate_limit_info(&self, response: &Response) -> Option<RateLimitInfo> {
+        let info = RateLimitInfo::from_headers(response.headers())?;
+        *self
+            .rate_limit_info
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(info);
+        Some(info)
+    }
+
+    /// Take a token from the retry budget, if one is configured
+    fn take_retry_budget(&self, attempt: u32) -> bool {
+        match &self.config.retry_config.retry_budget {
+            Some(retry_budget) if !retry_budget.try_acquire() => {
+                debug!(attempt, "Retry budget exhausted, not retrying");
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Record a retry and fire the [`RetryConfig::on_retry`] callback, if one
+    /// is configured
+    fn notify_retry(&self, attempt: u32, error: &OdosError, delay: Duration, endpoint: &str) {
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_retry(endpoint);
+        if let Some(on_retry) = &self.config.retry_config.on_retry {
+            on_retry(RetryEvent {
+                attempt,
+                error,
+                delay,
+                endpoint,
+            });
+        }
+    }
+
+    /// Get the number of calls currently holding a concurrency slot
+    ///
+    /// Returns `None` unless [`ClientConfig::max_concurrent_requests`] is
+    /// set. Calls waiting for a slot are not counted.
+    pub fn in_flight_requests(&self) -> Option<usize> {
+        let max = self.config.max_concurrent_requests?;
+        let semaphore = self.concurrency.as_ref()?;
+        Some(max - semaphore.available_permits())
+    }
+
+    /// Get the rate limit headers of the most recent response that had any
+    ///
+    /// Clones of the client share the snapshot. Returns `None` until a
+    /// response carries rate limit headers. See [`RateLimitInfo`].
+    pub fn last_rate_limit_info(&self) -> Option<RateLimitInfo> {
+        *self
+            .rate_limit_info
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Get a reference to the underlying reqwest client
     pub fn inner(&self) -> &Client {
         &self.client
@@ -492,7 +1536,7 @@ impl OdosHttpClient {
             return false;
         }
 
-        match retry_config.retry_predicate {
+        match &retry_config.retry_predicate {
             RetryPredicate::Replace(p) => return p(error),
             RetryPredicate::DefaultExcept(veto) if veto(error) => return false,
             RetryPredicate::Default | RetryPredicate::DefaultExcept(_) => {}
@@ -509,14 +1553,116 @@ impl OdosHttpClient {
     }
 }
 
+/// Backoff delays for one retry loop: exponential from
+/// [`RetryConfig::initial_backoff_ms`], randomized by [`RetryConfig::jitter`]
+#[derive(Debug)]
+struct RetryBackoff {
+    exponential: backon::ExponentialBackoff,
+    jitter: Jitter,
+    rng: fastrand::Rng,
+    min: Duration,
+    max: Duration,
+    multiplier: f32,
+    previous: Duration,
+}
+
+impl RetryBackoff {
+    fn new(config: &RetryConfig, rng: fastrand::Rng) -> Self {
+        let min = Duration::from_millis(config.initial_backoff_ms);
+        let max = Duration::from_millis(config.max_backoff_ms);
+
+        // +1 because backon counts total attempts, not retries
+        let exponential = ExponentialBuilder::default()
+            .with_min_delay(min)
+            .with_max_delay(max)
+            .with_factor(config.multiplier)
+            .with_max_times(config.max_retries as usize + 1)
+            .build();
+
+        Self {
+            exponential,
+            jitter: config.jitter,
+            rng,
+            min,
+            max,
+            multiplier: config.multiplier,
+            previous: min,
+        }
+    }
+}
+
+impl Iterator for RetryBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let delay = self.exponential.next()?;
+
+        Some(match self.jitter {
+            Jitter::None => delay,
+            Jitter::Full => delay.mul_f64(self.rng.f64()),
+            Jitter::Decorrelated => {
+                let upper =
+                    Duration::try_from_secs_f32(self.previous.as_secs_f32() * self.multiplier)
+                        .unwrap_or(self.max)
+                        .clamp(self.min, self.max.max(self.min));
+                let delay = self.min + (upper - self.min).mul_f64(self.rng.f64());
+                self.previous = delay;
+                delay
+            }
+        })
+    }
+}
+
 /// Extract the retry-after header from the response
 fn extract_retry_after(response: &Response) -> Option<Duration> {
     response
         .headers()
         .get("retry-after")
         .and_then(|v| v.to_str().ok())
-        .and_then(|s| s.parse::<u64>().ok())
-        .map(Duration::from_secs)
+        .and_then(|s| parse_retry_after(s, SystemTime::now()))
+}
+
+/// Parse a Retry-After value, either delay seconds or an HTTP-date
+///
+/// A date is converted to the time remaining from `now`, and a date in the
+/// past to zero.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+/// Read an environment variable, treating a non-unicode value as a
+/// configuration error
+fn env_var(name: &str) -> Result<Option<String>> {
+    match std::env::var(name) {
+        Ok(value) => Ok(Some(value.trim().to_string())),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(OdosError::configuration_error(format!(
+            "{name} is not valid unicode"
+        ))),
+    }
+}
+
+/// Record an error response on the current span and as an event
+fn record_error_body(status: StatusCode, body: &ApiErrorBody) {
+    let span = Span::current();
+    span.record("http.status", status.as_u16());
+    span.record("odos.error_code", body.code.code());
+    if let Some(trace_id) = body.trace_id {
+        span.record("odos.trace_id", field::display(trace_id));
+    }
+
+    error!(
+        http.status = status.as_u16(),
+        odos.error_code = body.code.code(),
+        odos.trace_id = body.trace_id.map(field::display),
+        detail = %body.message,
+        "Odos API error response"
+    );
 }
 
 /// Parse structured error response from Odos API into an [`ApiErrorBody`].
@@ -533,6 +1679,7 @@ pub(crate) async fn parse_error_response(response: Response) -> ApiErrorBody {
                 message: format!("Failed to read response body: {e}"),
                 code: OdosErrorCode::Unknown(0),
                 trace_id: None,
+                client_trace_id: None,
             };
         }
     };
@@ -542,11 +1689,13 @@ pub(crate) async fn parse_error_response(response: Response) -> ApiErrorBody {
             message: error_response.detail,
             code: OdosErrorCode::from(error_response.error_code),
             trace_id: error_response.trace_id,
+            client_trace_id: None,
         },
         Err(_) => ApiErrorBody {
             message: body_text,
             code: OdosErrorCode::Unknown(0),
             trace_id: None,
+            client_trace_id: None,
         },
     }
 }
@@ -571,11 +1720,11 @@ impl Default for OdosHttpClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error_code::OdosErrorCode;
-    use std::sync::{Arc, Mutex};
+    use crate::{error_code::OdosErrorCode, test_utils::RecordingSleeper, TimeoutPhase};
+    use std::sync::Mutex;
     use std::time::Duration;
     use wiremock::{
-        matchers::{method, path},
+        matchers::{header, method, path},
         Mock, MockServer, Request, ResponseTemplate,
     };
 
@@ -599,12 +1748,14 @@ mod tests {
     }
 
     /// Helper to create a test client with custom config and an explicit
-    /// [`RetryPredicate`].
-    fn create_test_client_with_predicate(
+    /// [`RetryPredicate`], returning the [`RecordingSleeper`] that captures
+    /// backoff delays instead of waiting on them.
+    fn create_recording_test_client(
         max_retries: u32,
         timeout_ms: u64,
         retry_predicate: RetryPredicate,
-    ) -> OdosHttpClient {
+    ) -> (OdosHttpClient, RecordingSleeper) {
+        let sleeper = RecordingSleeper::new();
         let config = ClientConfig {
             timeout: Duration::from_millis(timeout_ms),
             retry_config: RetryConfig {
@@ -613,9 +1764,20 @@ mod tests {
                 retry_predicate,
                 ..Default::default()
             },
+            sleeper: Arc::new(sleeper.clone()),
             ..Default::default()
         };
-        OdosHttpClient::with_config(config).unwrap()
+        (OdosHttpClient::with_config(config).unwrap(), sleeper)
+    }
+
+    /// Helper to create a test client with custom config and an explicit
+    /// [`RetryPredicate`].
+    fn create_test_client_with_predicate(
+        max_retries: u32,
+        timeout_ms: u64,
+        retry_predicate: RetryPredicate,
+    ) -> OdosHttpClient {
+        create_recording_test_client(max_retries, timeout_ms, retry_predicate).0
     }
 
     /// Helper to create a test client with the default retry predicate.
@@ -631,6 +1793,49 @@ mod tests {
         assert_eq!(config.max_connections, 20);
     }
 
+    #[test]
+    fn test_client_config_from_env() {
+        use crate::api_key::tests::with_env;
+
+        let uuid = uuid::Uuid::new_v4().to_string();
+        let config = with_env(
+            &[
+                ("ODOS_API_KEY", Some(&uuid)),
+                ("ODOS_ENDPOINT", Some("Enterprise")),
+                ("ODOS_API_VERSION", Some("v3")),
+            ],
+            ClientConfig::from_env,
+        )
+        .unwrap();
+        assert_eq!(config.api_key.unwrap().as_str(), uuid);
+        assert_eq!(config.endpoint, crate::Endpoint::enterprise_v3());
+
+        // Unset variables keep the defaults
+        let config = with_env(
+            &[
+                ("ODOS_API_KEY", None),
+                ("ODOS_ENDPOINT", None),
+                ("ODOS_API_VERSION", None),
+            ],
+            ClientConfig::from_env,
+        )
+        .unwrap();
+        assert!(config.api_key.is_none());
+        assert_eq!(config.endpoint, crate::Endpoint::public_v2());
+
+        for (name, value) in [
+            ("ODOS_API_KEY", "not-a-uuid"),
+            ("ODOS_ENDPOINT", "staging"),
+            ("ODOS_API_VERSION", "v4"),
+        ] {
+            let result = with_env(&[(name, Some(value))], ClientConfig::from_env);
+            assert!(
+                matches!(result, Err(OdosError::Configuration(_))),
+                "{name}={value}: {result:?}"
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_client_creation() {
         let client = OdosHttpClient::new();
@@ -693,6 +1898,182 @@ mod tests {
         }
     }
 
+    fn create_rate_limit_retry_client(
+        max_wait: Duration,
+        max_attempts: u32,
+    ) -> (OdosHttpClient, RecordingSleeper) {
+        let sleeper = RecordingSleeper::new();
+        let config = ClientConfig {
+            retry_config: RetryConfig {
+                retry_rate_limits: Some(RateLimitRetryPolicy {
+                    max_wait,
+                    max_attempts,
+                }),
+                ..Default::default()
+            },
+            sleeper: Arc::new(sleeper.clone()),
+            ..Default::default()
+        };
+        (OdosHttpClient::with_config(config).unwrap(), sleeper)
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retry_caps_wait() {
+        let mock_server = MockServer::start().await;
+
+        let attempts = Arc::new(Mutex::new(0));
+        let counter = attempts.clone();
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(move |_req: &Request| {
+                let mut count = counter.lock().unwrap();
+                *count += 1;
+                if *count == 1 {
+                    ResponseTemplate::new(429)
+                        .set_body_string("Rate limit exceeded")
+                        .insert_header("retry-after", "60")
+                } else {
+                    ResponseTemplate::new(200).set_body_string("Success")
+                }
+            })
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let (client, sleeper) = create_rate_limit_retry_client(Duration::from_millis(50), 1);
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+
+        assert!(response.is_ok(), "{response:?}");
+        // Retry-After asked for 60s, the policy caps the wait
+        assert_eq!(sleeper.durations(), [Duration::from_millis(50)]);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retry_gives_up_after_max_attempts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .set_body_string("Rate limit exceeded")
+                    .insert_header("retry-after", "1"),
+            )
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let (client, sleeper) = create_rate_limit_retry_client(Duration::from_secs(5), 2);
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+
+        assert!(matches!(
+            response,
+            Err(OdosError::RateLimit {
+                retry_after: Some(retry_after),
+                ..
+            }) if retry_after == Duration::from_secs(1)
+        ));
+        assert_eq!(sleeper.durations(), [Duration::from_secs(1); 2]);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retries_do_not_use_max_retries() {
+        let mock_server = MockServer::start().await;
+
+        let attempts = Arc::new(Mutex::new(0));
+        let counter = attempts.clone();
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(move |_req: &Request| {
+                let mut count = counter.lock().unwrap();
+                *count += 1;
+                match *count {
+                    1 => ResponseTemplate::new(429),
+                    _ => ResponseTemplate::new(503).set_body_string("Service Unavailable"),
+                }
+            })
+            .expect(4)
+            .mount(&mock_server)
+            .await;
+
+        let (client, sleeper) = create_rate_limit_retry_client(Duration::from_secs(1), 1);
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+
+        assert!(
+            matches!(response, Err(OdosError::Api { status, .. }) if status == StatusCode::SERVICE_UNAVAILABLE)
+        );
+        // The 429 waits the initial backoff, then the three 503 attempts
+        // retry as usual
+        assert_eq!(sleeper.count(), 3);
+        assert_eq!(sleeper.durations()[0], Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_headers_are_captured() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ok"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-limit", "600")
+                    .insert_header("x-ratelimit-remaining", "599")
+                    .insert_header("x-ratelimit-reset", "60"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/plain"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/limited"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("x-ratelimit-limit", "600")
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", "12")
+                    .set_body_string("Rate limit exceeded"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(0, 1000);
+        assert_eq!(client.last_rate_limit_info(), None);
+        let get = |route: &str| {
+            let url = format!("{}{route}", mock_server.uri());
+            let client = client.clone();
+            async move { client.execute_with_retry(|| client.inner().get(&url)).await }
+        };
+
+        get("/ok").await.unwrap();
+        let ok_info = RateLimitInfo {
+            limit: Some(600),
+            remaining: Some(599),
+            reset: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(client.last_rate_limit_info(), Some(ok_info));
+
+        // A response without the headers keeps the previous snapshot
+        get("/plain").await.unwrap();
+        assert_eq!(client.last_rate_limit_info(), Some(ok_info));
+
+        let err = get("/limited").await.unwrap_err();
+        let limited_info = RateLimitInfo {
+            limit: Some(600),
+            remaining: Some(0),
+            reset: Some(Duration::from_secs(12)),
+        };
+        assert_eq!(err.rate_limit_info(), Some(limited_info));
+        assert_eq!(client.last_rate_limit_info(), Some(limited_info));
+    }
+
     #[tokio::test]
     async fn test_rate_limit_without_retry_after() {
         let mock_server = MockServer::start().await;
@@ -763,14 +2144,15 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client = create_test_client(2, 30000);
+        let (client, sleeper) = create_recording_test_client(2, 30000, RetryPredicate::Default);
 
         let response = client
             .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
             .await;
 
-        // Should fail after exhausting retries
+        // Should fail after exhausting retries, backing off once between attempts
         assert!(response.is_err());
+        assert_eq!(sleeper.durations(), vec![Duration::from_millis(10)]);
         if let Err(e) = response {
             assert!(
                 matches!(e, OdosError::Api { status, .. } if status == StatusCode::SERVICE_UNAVAILABLE)
@@ -803,12 +2185,125 @@ mod tests {
         assert!(response.is_err());
         if let Err(e) = response {
             // Accept either OdosError::Http with timeout or OdosError::Timeout
-            let is_timeout = matches!(e, OdosError::Timeout(_))
-                || matches!(e, OdosError::Http(ref err) if err.is_timeout());
+            let is_timeout = matches!(e, OdosError::Timeout { .. })
+                || matches!(e, OdosError::Http { ref source, .. } if source.is_timeout());
             assert!(is_timeout, "Expected timeout error, got: {e:?}");
         }
     }
 
+    #[tokio::test]
+    async fn test_time_budget_reduces_second_stage_timeout() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/quote"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/assemble"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(10)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(0, 5_000);
+        let budget = TimeBudget::start(Duration::from_secs(4));
+
+        client
+            .execute_with_retry_within(
+                || client.inner().get(format!("{}/quote", mock_server.uri())),
+                Some(budget),
+            )
+            .await
+            .unwrap();
+
+        // Spend most of the budget in the first stage. The clock is only
+        // paused around the jump, so the requests do not auto-advance it.
+        tokio::time::pause();
+        tokio::time::advance(Duration::from_millis(3_950)).await;
+        tokio::time::resume();
+
+        let err = client
+            .execute_with_retry_within(
+                || {
+                    client
+                        .inner()
+                        .get(format!("{}/assemble", mock_server.uri()))
+                },
+                Some(budget),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(
+                err,
+                OdosError::Timeout {
+                    phase: TimeoutPhase::Total,
+                    ..
+                }
+            ),
+            "Expected total timeout, got: {err:?}"
+        );
+        // The 5s client timeout alone would have reported a request timeout
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_time_budget_skips_next_stage() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/quote"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(1)))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/assemble"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(3, 5_000);
+        let budget = TimeBudget::start(Duration::from_millis(200));
+
+        let err = client
+            .execute_with_retry_within(
+                || client.inner().get(format!("{}/quote", mock_server.uri())),
+                Some(budget),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OdosError::Timeout {
+                phase: TimeoutPhase::Total,
+                ..
+            }
+        ));
+
+        let err = client
+            .execute_with_retry_within(
+                || {
+                    client
+                        .inner()
+                        .get(format!("{}/assemble", mock_server.uri()))
+                },
+                Some(budget),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OdosError::Timeout {
+                phase: TimeoutPhase::Total,
+                ..
+            }
+        ));
+        // `expect(0)` on the assemble mock is verified when the server drops
+    }
+
     #[tokio::test]
     async fn test_invalid_request_builder_fails_immediately() {
         let client = OdosHttpClient::default();
@@ -827,7 +2322,7 @@ mod tests {
         // Should fail immediately without retrying
         assert!(result.is_err());
         if let Err(e) = result {
-            assert!(matches!(e, OdosError::Http(_)));
+            assert!(matches!(e, OdosError::Http { .. }));
         }
     }
 
@@ -931,7 +2426,7 @@ mod tests {
 
         assert!(response.is_err());
         match response {
-            Err(OdosError::Api { status, body }) => {
+            Err(OdosError::Api { status, body, .. }) => {
                 assert_eq!(body.code, OdosErrorCode::AlgoInternal);
                 assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
             }
@@ -1006,13 +2501,14 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client =
-            create_test_client_with_predicate(3, 30000, RetryPredicate::Replace(|_err| false));
+        let (client, sleeper) =
+            create_recording_test_client(3, 30000, RetryPredicate::replace(|_err| false));
         let response = client
             .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
             .await;
 
         assert!(response.is_err());
+        assert_eq!(sleeper.count(), 0, "vetoed retries must not back off");
     }
 
     #[tokio::test]
@@ -1033,7 +2529,7 @@ mod tests {
             .await;
 
         let client =
-            create_test_client_with_predicate(3, 100, RetryPredicate::Replace(|_err| false));
+            create_test_client_with_predicate(3, 100, RetryPredicate::replace(|_err| false));
         let response = client
             .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
             .await;
@@ -1054,13 +2550,18 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client =
-            create_test_client_with_predicate(3, 30000, RetryPredicate::Replace(|_err| true));
+        let (client, sleeper) =
+            create_recording_test_client(3, 30000, RetryPredicate::replace(|_err| true));
         let response = client
             .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
             .await;
 
         assert!(response.is_err());
+        // Exponential backoff doubles from `initial_backoff_ms` between attempts
+        assert_eq!(
+            sleeper.durations(),
+            vec![Duration::from_millis(10), Duration::from_millis(20)]
+        );
     }
 
     #[tokio::test]
@@ -1083,8 +2584,10 @@ mod tests {
                 max_retries: 3,
                 initial_backoff_ms: 10,
                 retry_server_errors: false,
-                retry_predicate: RetryPredicate::Replace(|_err| true),
+                retry_predicate: RetryPredicate::replace(|_err| true),
+                ..Default::default()
             },
+            sleeper: Arc::new(RecordingSleeper::new()),
             ..Default::default()
         };
         let client = OdosHttpClient::with_config(config).unwrap();
@@ -1123,7 +2626,7 @@ mod tests {
         let client = create_test_client_with_predicate(
             3,
             30000,
-            RetryPredicate::DefaultExcept(|err| {
+            RetryPredicate::default_except(|err| {
                 err.error_code() == Some(&OdosErrorCode::PricingInternal)
             }),
         );
@@ -1155,7 +2658,7 @@ mod tests {
         let client = create_test_client_with_predicate(
             3,
             30000,
-            RetryPredicate::DefaultExcept(|_err| false),
+            RetryPredicate::default_except(|_err| false),
         );
         let response = client
             .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
@@ -1179,51 +2682,196 @@ mod tests {
         // Should fail after retries
         assert!(response.is_err());
         if let Err(e) = response {
-            assert!(matches!(e, OdosError::Http(_)));
+            assert!(matches!(e, OdosError::Http { .. }));
         }
     }
 
-    #[test]
-    fn test_accessor_methods() {
-        let config = ClientConfig {
-            timeout: Duration::from_secs(45),
-            retry_config: RetryConfig {
-                max_retries: 5,
-                ..Default::default()
-            },
-            ..Default::default()
-        };
-        let client = OdosHttpClient::with_config(config.clone()).unwrap();
-
-        // Test config() accessor
-        assert_eq!(client.config().timeout, Duration::from_secs(45));
-        assert_eq!(client.config().retry_config.max_retries, 5);
+    #[tokio::test]
+    async fn test_default_user_agent_sent() {
+        let mock_server = MockServer::start().await;
 
-        // Test inner() accessor - just verify it returns a Client
-        let _inner: &reqwest::Client = client.inner();
-    }
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .and(header("user-agent", DEFAULT_USER_AGENT))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
 
-    #[test]
-    fn test_default_client() {
         let client = OdosHttpClient::default();
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
 
-        // Should use default config
-        assert_eq!(client.config().timeout, Duration::from_secs(30));
-        assert_eq!(client.config().retry_config.max_retries, 3);
+        assert!(response.is_ok());
     }
 
-    #[test]
-    fn test_extract_retry_after_valid_numeric() {
-        let response = reqwest::Response::from(
-            http::Response::builder()
-                .status(429)
-                .header("retry-after", "30")
-                .body("")
-                .unwrap(),
-        );
+    #[tokio::test]
+    async fn test_client_trace_id_sent_and_attached_to_errors() {
+        let mock_server = MockServer::start().await;
 
-        let retry_after = extract_retry_after(&response);
-        assert_eq!(retry_after, Some(Duration::from_secs(30)));
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .set_body_json(serde_json::json!({"detail": "Unavailable", "errorCode": 3000})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = OdosHttpClient::with_config(ClientConfig {
+            retry_config: RetryConfig {
+                max_retries: 2,
+                ..Default::default()
+            },
+            sleeper: Arc::new(RecordingSleeper::new()),
+            send_client_trace_id: true,
+            ..Default::default()
+        })
+        .unwrap();
+        let err = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await
+            .unwrap_err();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let sent: Vec<TraceId> = requests
+            .iter()
+            .map(|request| {
+                request.headers[CLIENT_TRACE_ID_HEADER]
+                    .to_str()
+                    .unwrap()
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+        // Retries reuse the call's ID
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0], sent[1]);
+
+        assert_eq!(err.client_trace_id(), Some(sent[0]));
+        assert_eq!(err.trace_id(), None);
+
+        // Each call gets a new ID
+        let _ = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_ne!(
+            requests[2].headers[CLIENT_TRACE_ID_HEADER],
+            requests[0].headers[CLIENT_TRACE_ID_HEADER]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_trace_id_attached_to_timeout_and_http_errors() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+            .mount(&mock_server)
+            .await;
+
+        let client = OdosHttpClient::with_config(ClientConfig {
+            timeout: Duration::from_millis(100),
+            retry_config: RetryConfig::no_retries(),
+            send_client_trace_id: true,
+            ..Default::default()
+        })
+        .unwrap();
+        let err = client
+            .execute_with_retry(|| client.inner().get(format!("{}/slow", mock_server.uri())))
+            .await
+            .unwrap_err();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let sent: TraceId = requests[0].headers[CLIENT_TRACE_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        // reqwest's own timeout can fire first and surfaces as an HTTP error
+        let is_timeout = matches!(err, OdosError::Timeout { .. })
+            || matches!(err, OdosError::Http { ref source, .. } if source.is_timeout());
+        assert!(is_timeout, "{err:?}");
+        assert_eq!(err.client_trace_id(), Some(sent));
+
+        // Nothing listens on the port once the listener is dropped
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let err = client
+            .execute_with_retry(|| client.inner().get(format!("http://{addr}/closed")))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OdosError::Http { .. }), "{err:?}");
+        assert!(err.client_trace_id().is_some());
+        assert_ne!(err.client_trace_id(), Some(sent));
+    }
+
+    #[tokio::test]
+    async fn test_client_trace_id_not_sent_by_default() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(0, 1000);
+        let err = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await
+            .unwrap_err();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key(CLIENT_TRACE_ID_HEADER));
+        assert_eq!(err.client_trace_id(), None);
+    }
+
+    #[test]
+    fn test_accessor_methods() {
+        let config = ClientConfig {
+            timeout: Duration::from_secs(45),
+            retry_config: RetryConfig {
+                max_retries: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let client = OdosHttpClient::with_config(config.clone()).unwrap();
+
+        // Test config() accessor
+        assert_eq!(client.config().timeout, Duration::from_secs(45));
+        assert_eq!(client.config().retry_config.max_retries, 5);
+
+        // Test inner() accessor - just verify it returns a Client
+        let _inner: &reqwest::Client = client.inner();
+    }
+
+    #[test]
+    fn test_default_client() {
+        let client = OdosHttpClient::default();
+
+        // Should use default config
+        assert_eq!(client.config().timeout, Duration::from_secs(30));
+        assert_eq!(client.config().retry_config.max_retries, 3);
+    }
+
+    #[test]
+    fn test_extract_retry_after_valid_numeric() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(429)
+                .header("retry-after", "30")
+                .body("")
+                .unwrap(),
+        );
+
+        let retry_after = extract_retry_after(&response);
+        assert_eq!(retry_after, Some(Duration::from_secs(30)));
     }
 
     #[test]
@@ -1301,6 +2949,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_retry_after_values() {
+        let now = httpdate::parse_http_date("Tue, 21 Oct 2025 07:27:30 GMT").unwrap();
+
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Tue, 21 Oct 2025 07:28:00 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        // A date already passed means retry now
+        assert_eq!(
+            parse_retry_after("Tue, 21 Oct 2025 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(parse_retry_after("-5", now), None);
+        assert_eq!(parse_retry_after("", now), None);
+    }
+
+    #[test]
+    fn test_extract_retry_after_http_date() {
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(90));
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .status(429)
+                .header("retry-after", date)
+                .body("")
+                .unwrap(),
+        );
+
+        // HTTP-dates have whole-second precision
+        let retry_after = extract_retry_after(&response).unwrap();
+        assert!(
+            retry_after > Duration::from_secs(88) && retry_after <= Duration::from_secs(90),
+            "unexpected delay {retry_after:?}"
+        );
+    }
+
     #[test]
     fn test_extract_retry_after_large_value() {
         let response = reqwest::Response::from(
@@ -1512,11 +3201,91 @@ mod tests {
             }
             Err(e) => {
                 // If it fails, should be wrapped as Http error
-                assert!(matches!(e, OdosError::Http(_)));
+                assert!(matches!(e, OdosError::Http { .. }));
             }
         }
     }
 
+    /// Log output captured by a `tracing_subscriber` fmt layer
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_response_fields_recorded_on_span() {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+            .with_writer(move || writer.clone())
+            .finish();
+        // The current-thread test runtime keeps every await on this thread
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(503).set_body_string(
+                r#"{
+                    "detail": "Algorithm timed out",
+                    "traceId": "a1b2c3d4-e5f6-7890-abcd-ef1234567890",
+                    "errorCode": 2998
+                }"#,
+            ))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(2, 30000);
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+        assert!(response.is_err());
+
+        let logs = logs.contents();
+        let event = logs
+            .lines()
+            .find(|line| line.contains("ERROR") && line.contains("Odos API error response"))
+            .unwrap_or_else(|| panic!("no error event in:\n{logs}"));
+        assert!(event.contains("http.status=503"), "{event}");
+        assert!(event.contains("odos.error_code=2998"), "{event}");
+        assert!(
+            event.contains("odos.trace_id=a1b2c3d4-e5f6-7890-abcd-ef1234567890"),
+            "{event}"
+        );
+
+        // The closed span carries the error fields and the final attempt count
+        let close = logs
+            .lines()
+            .find(|line| line.contains("execute_with_retry_within") && line.contains("close"))
+            .unwrap_or_else(|| panic!("no span close in:\n{logs}"));
+        for field in [
+            "attempts=2",
+            "http.status=503",
+            "odos.error_code=2998",
+            "odos.trace_id=a1b2c3d4-e5f6-7890-abcd-ef1234567890",
+        ] {
+            assert!(close.contains(field), "{field} missing from {close}");
+        }
+    }
+
     #[tokio::test]
     async fn test_rate_limit_with_trace_id() {
         let mock_server = MockServer::start().await;
@@ -1564,4 +3333,818 @@ mod tests {
             panic!("Expected error, got success");
         }
     }
+
+    fn backoff_delays(config: &RetryConfig, seed: u64) -> Vec<Duration> {
+        RetryBackoff::new(config, fastrand::Rng::with_seed(seed)).collect()
+    }
+
+    /// Delays in whole milliseconds; the `f32` growth factor adds
+    /// sub-millisecond error
+    fn backoff_millis(config: &RetryConfig) -> Vec<u128> {
+        backoff_delays(config, 1)
+            .iter()
+            .map(Duration::as_millis)
+            .collect()
+    }
+
+    #[test]
+    fn test_default_backoff_has_no_jitter() {
+        assert_eq!(
+            backoff_millis(&RetryConfig::default()),
+            [100, 200, 400, 800]
+        );
+    }
+
+    #[test]
+    fn test_backoff_multiplier_and_cap() {
+        let config = RetryConfig {
+            max_retries: 4,
+            multiplier: 3.0,
+            max_backoff_ms: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(backoff_millis(&config), [100, 300, 900, 1_000, 1_000]);
+    }
+
+    #[test]
+    fn test_full_jitter_spreads_delays() {
+        let config = RetryConfig {
+            max_retries: 6,
+            multiplier: 1.0,
+            jitter: Jitter::Full,
+            ..Default::default()
+        };
+        let delays = backoff_delays(&config, 42);
+
+        // Without jitter every delay would be exactly 100ms
+        assert!(delays.windows(2).all(|pair| pair[0] != pair[1]));
+        assert!(delays.iter().all(|d| *d <= Duration::from_millis(100)));
+        // The same seed gives the same delays
+        assert_eq!(delays, backoff_delays(&config, 42));
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_stays_within_bounds() {
+        let config = RetryConfig {
+            max_retries: 20,
+            max_backoff_ms: 2_000,
+            jitter: Jitter::Decorrelated,
+            ..Default::default()
+        };
+        let delays = backoff_delays(&config, 7);
+
+        assert!(delays.windows(2).all(|pair| pair[0] != pair[1]));
+        for pair in delays.windows(2) {
+            assert!(pair[1] >= Duration::from_millis(100));
+            assert!(pair[1] <= (pair[0] * 2).min(Duration::from_millis(2_000)));
+        }
+    }
+
+    #[test]
+    fn test_invalid_multiplier_is_rejected() {
+        for multiplier in [0.5, f32::NAN, f32::INFINITY] {
+            let config = ClientConfig {
+                retry_config: RetryConfig {
+                    multiplier,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let err = OdosHttpClient::with_config(config).unwrap_err();
+            assert!(matches!(err, OdosError::Configuration(_)));
+        }
+    }
+
+    fn create_breaker_test_client(
+        failure_threshold: u32,
+        open_duration: Duration,
+    ) -> OdosHttpClient {
+        OdosHttpClient::with_config(ClientConfig {
+            retry_config: RetryConfig::no_retries(),
+            circuit_breaker: Some(CircuitBreakerConfig {
+                failure_threshold,
+                open_duration,
+                half_open_probes: 1,
+            }),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_fails_fast_when_open() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service unavailable"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_breaker_test_client(2, Duration::from_secs(60));
+        let url = format!("{}/test", mock_server.uri());
+
+        for _ in 0..2 {
+            let err = client
+                .execute_with_retry(|| client.inner().get(&url))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, OdosError::Api { .. }));
+        }
+
+        // Clones share the breaker; the server sees no third request
+        let clone = client.clone();
+        let err = clone
+            .execute_with_retry(|| clone.inner().get(&url))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, OdosError::CircuitOpen { endpoint, .. } if endpoint.ends_with("/test"))
+        );
+        assert!(err.suggested_retry_delay().unwrap() > Duration::from_secs(50));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_recovers_after_probe() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(create_retry_mock(503, "Service unavailable".to_string(), 2))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_breaker_test_client(1, Duration::from_secs(60));
+        let url = format!("{}/test", mock_server.uri());
+        let send = || client.execute_with_retry(|| client.inner().get(&url));
+
+        assert!(matches!(send().await, Err(OdosError::Api { .. })));
+        assert!(matches!(send().await, Err(OdosError::CircuitOpen { .. })));
+
+        // Only pause around the jump, so the requests do not auto-advance it
+        tokio::time::pause();
+        tokio::time::advance(Duration::from_secs(61)).await;
+        tokio::time::resume();
+        // The half-open probe succeeds and closes the circuit
+        assert!(send().await.is_ok());
+        assert!(send().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limits_do_not_open_circuit() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(429).set_body_string("Too many requests"))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_breaker_test_client(1, Duration::from_secs(60));
+        let url = format!("{}/test", mock_server.uri());
+
+        for _ in 0..3 {
+            let err = client
+                .execute_with_retry(|| client.inner().get(&url))
+                .await
+                .unwrap_err();
+            assert!(err.is_rate_limit());
+        }
+    }
+
+    #[test]
+    fn test_invalid_circuit_breaker_config_is_rejected() {
+        let config = ClientConfig {
+            circuit_breaker: Some(CircuitBreakerConfig {
+                half_open_probes: 0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let err = OdosHttpClient::with_config(config).unwrap_err();
+        assert!(matches!(err, OdosError::Configuration(_)));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_smooths_concurrent_requests() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("Success"))
+            .expect(20)
+            .mount(&mock_server)
+            .await;
+
+        // Record the limiter's waits instead of sleeping through them
+        let sleeper = RecordingSleeper::new();
+        let client = OdosHttpClient::with_config(ClientConfig {
+            rate_limiter: Some(RateLimiterConfig {
+                requests_per_second: 5.0,
+                burst: 5,
+            }),
+            sleeper: Arc::new(sleeper.clone()),
+            ..Default::default()
+        })
+        .unwrap();
+        let url = format!("{}/test", mock_server.uri());
+
+        let mut requests = tokio::task::JoinSet::new();
+        for _ in 0..20 {
+            // Clones share the bucket
+            let client = client.clone();
+            let url = url.clone();
+            requests
+                .spawn(async move { client.execute_with_retry(|| client.inner().get(&url)).await });
+        }
+        let responses = requests.join_all().await;
+        assert!(responses.iter().all(|response| response.is_ok()));
+
+        // 5 requests from the burst, then 15 more at 200ms intervals; the
+        // limiter's clock keeps running while the responses arrive, so the
+        // waits may be slightly shorter
+        let mut waits = sleeper.durations();
+        waits.sort();
+        assert_eq!(waits.len(), 15, "{waits:?}");
+        for (wait, step) in waits.iter().zip(1..) {
+            let expected = Duration::from_millis(200 * step);
+            assert!(
+                *wait <= expected && expected - *wait < Duration::from_millis(150),
+                "{waits:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_budget_exhausted_by_rate_limiter_returns_token() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("Success"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let sleeper = RecordingSleeper::new();
+        let client = OdosHttpClient::with_config(ClientConfig {
+            rate_limiter: Some(RateLimiterConfig {
+                requests_per_second: 1.0,
+                burst: 1,
+            }),
+            sleeper: Arc::new(sleeper.clone()),
+            ..Default::default()
+        })
+        .unwrap();
+        let url = format!("{}/test", mock_server.uri());
+        let send = |budget| client.execute_with_retry_within(|| client.inner().get(&url), budget);
+
+        send(None).await.unwrap();
+        // The next token is a second away, beyond the budget
+        let err = send(Some(TimeBudget::start(Duration::from_millis(100))))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            OdosError::Timeout {
+                phase: TimeoutPhase::Total,
+                ..
+            }
+        ));
+        assert_eq!(sleeper.count(), 0);
+
+        // Still one second away, not two
+        send(None).await.unwrap();
+        let [wait] = sleeper.durations()[..] else {
+            panic!("expected one wait, got {:?}", sleeper.durations());
+        };
+        assert!(wait <= Duration::from_secs(1), "{wait:?}");
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests_limits_in_flight() {
+        let mock_server = MockServer::start().await;
+
+        const DELAY: Duration = Duration::from_millis(200);
+        let arrivals = Arc::new(Mutex::new(Vec::new()));
+        let recorded = arrivals.clone();
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(move |_req: &Request| {
+                recorded.lock().unwrap().push(Instant::now());
+                ResponseTemplate::new(200).set_delay(DELAY)
+            })
+            .expect(10)
+            .mount(&mock_server)
+            .await;
+
+        let client = OdosHttpClient::with_config(ClientConfig {
+            max_concurrent_requests: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(client.in_flight_requests(), Some(0));
+        let url = format!("{}/test", mock_server.uri());
+
+        let mut requests = tokio::task::JoinSet::new();
+        for _ in 0..10 {
+            // Clones share the slots
+            let client = client.clone();
+            let url = url.clone();
+            requests
+                .spawn(async move { client.execute_with_retry(|| client.inner().get(&url)).await });
+        }
+        // Wait for the first two requests to reach the server rather than
+        // guessing how long that takes
+        while arrivals.lock().unwrap().len() < 2 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(client.in_flight_requests(), Some(2));
+
+        let responses = requests.join_all().await;
+        assert!(responses.iter().all(|response| response.is_ok()));
+        assert_eq!(client.in_flight_requests(), Some(0));
+
+        // A third request can only be sent once one of the two before it has
+        // finished, and no response finishes sooner than DELAY after arriving
+        let mut arrivals = arrivals.lock().unwrap().clone();
+        arrivals.sort();
+        for window in arrivals.windows(3) {
+            let spacing = window[2].duration_since(window[0]);
+            assert!(spacing >= DELAY, "3 requests in flight within {spacing:?}");
+        }
+    }
+
+    #[test]
+    fn test_zero_max_concurrent_requests_is_rejected() {
+        let config = ClientConfig {
+            max_concurrent_requests: Some(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            OdosHttpClient::with_config(config),
+            Err(OdosError::Configuration(_))
+        ));
+        assert_eq!(OdosHttpClient::new().unwrap().in_flight_requests(), None);
+    }
+
+    #[test]
+    fn test_invalid_rate_limiter_config_is_rejected() {
+        let config = ClientConfig {
+            rate_limiter: Some(RateLimiterConfig {
+                requests_per_second: -1.0,
+                burst: 5,
+            }),
+            ..Default::default()
+        };
+
+        let err = OdosHttpClient::with_config(config).unwrap_err();
+        assert!(matches!(err, OdosError::Configuration(_)));
+    }
+
+    #[test]
+    fn test_proxy_config() {
+        let config = ClientConfig {
+            proxy: Some(
+                ProxyConfig::new("http://proxy.internal:3128").with_basic_auth("svc", "hunter2"),
+            ),
+            ..Default::default()
+        };
+        assert!(!format!("{config:?}").contains("hunter2"));
+        assert!(OdosHttpClient::with_config(config).is_ok());
+
+        let config = ClientConfig {
+            proxy: Some(ProxyConfig::new("proxy.internal:3128")),
+            ..Default::default()
+        };
+        let err = OdosHttpClient::with_config(config).unwrap_err();
+        assert!(matches!(err, OdosError::Configuration(_)));
+    }
+
+    #[test]
+    fn test_user_agent_and_default_headers_config() {
+        let mut default_headers = HeaderMap::new();
+        default_headers.insert("x-internal-token", "secret-value".parse().unwrap());
+        let config = ClientConfig {
+            default_headers,
+            ..Default::default()
+        };
+        let debug = format!("{config:?}");
+        assert!(debug.contains("x-internal-token"));
+        assert!(!debug.contains("secret-value"));
+
+        let config = ClientConfig {
+            user_agent: Some("bad\nagent".to_string()),
+            ..Default::default()
+        };
+        let err = OdosHttpClient::with_config(config).unwrap_err();
+        assert!(matches!(err, OdosError::Configuration(_)));
+    }
+
+    #[tokio::test]
+    async fn test_hooks_run_on_every_retry_attempt() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/test"))
+            .and(header("x-body-signature", "len-7"))
+            .respond_with(create_retry_mock(503, "Service unavailable".to_string(), 3))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let hook_calls = Arc::new(Mutex::new(0));
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let (hook_count, seen) = (hook_calls.clone(), observed.clone());
+        let config = ClientConfig {
+            retry_config: RetryConfig {
+                max_retries: 3,
+                ..Default::default()
+            },
+            sleeper: Arc::new(RecordingSleeper::new()),
+            ..Default::default()
+        }
+        .with_request_hook(Arc::new(move |request| {
+            *hook_count.lock().unwrap() += 1;
+            let body = request.body().and_then(|b| b.as_bytes()).unwrap();
+            let signature = format!("len-{}", body.len());
+            request
+                .headers_mut()
+                .insert("x-body-signature", signature.parse().unwrap());
+        }))
+        .with_response_observer(Arc::new(move |meta| {
+            seen.lock().unwrap().push(meta.clone());
+        }));
+        let client = OdosHttpClient::with_config(config).unwrap();
+
+        let url = format!("{}/test?chain=1", mock_server.uri());
+        let response = client
+            .execute_with_retry(|| client.inner().post(&url).body("payload"))
+            .await;
+        assert!(response.is_ok());
+
+        assert_eq!(*hook_calls.lock().unwrap(), 3);
+        let observed = observed.lock().unwrap();
+        let attempts: Vec<_> = observed
+            .iter()
+            .map(|meta| (meta.attempt(), meta.status().map(|s| s.as_u16())))
+            .collect();
+        assert_eq!(attempts, [(1, Some(503)), (2, Some(503)), (3, Some(200))]);
+        // The query string is left out of the endpoint
+        assert_eq!(
+            observed[0].endpoint(),
+            format!("{}/test", mock_server.uri())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_observer_sees_failed_attempts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(200)))
+            .mount(&mock_server)
+            .await;
+
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let seen = observed.clone();
+        let config = ClientConfig {
+            timeout: Duration::from_millis(50),
+            retry_config: RetryConfig::no_retries(),
+            ..Default::default()
+        }
+        .with_response_observer(Arc::new(move |meta| {
+            seen.lock().unwrap().push(meta.clone());
+        }));
+        let client = OdosHttpClient::with_config(config).unwrap();
+
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+        assert!(response.is_err());
+
+        let observed = observed.lock().unwrap();
+        assert_eq!(observed.len(), 1);
+        assert_eq!(observed[0].status(), None);
+        assert!(observed[0].latency() >= Duration::from_millis(40));
+        assert!(format!("{:?}", client.config()).contains("response_observers: 1"));
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_exhausted_fails_fast() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+            .mount(&mock_server)
+            .await;
+
+        let budget = RetryBudget::new(4);
+        let client = OdosHttpClient::with_config(ClientConfig {
+            retry_config: RetryConfig {
+                max_retries: 3,
+                initial_backoff_ms: 10,
+                retry_budget: Some(budget.clone()),
+                ..Default::default()
+            },
+            sleeper: Arc::new(RecordingSleeper::new()),
+            ..Default::default()
+        })
+        .unwrap();
+        let url = format!("{}/test", mock_server.uri());
+
+        let mut requests = tokio::task::JoinSet::new();
+        for _ in 0..4 {
+            let client = client.clone();
+            let url = url.clone();
+            requests
+                .spawn(async move { client.execute_with_retry(|| client.inner().get(&url)).await });
+        }
+        let responses = requests.join_all().await;
+        assert!(responses.iter().all(|response| response.is_err()));
+
+        // One first attempt per request plus the four budgeted retries
+        let sent = mock_server.received_requests().await.unwrap().len();
+        assert_eq!(sent, 8);
+        let denied = budget.denied();
+        assert!(denied >= 2, "{denied}");
+
+        // With the budget spent, later requests are not retried
+        for _ in 0..3 {
+            let result = client.execute_with_retry(|| client.inner().get(&url)).await;
+            assert!(matches!(
+                result,
+                Err(OdosError::Api { status, .. }) if status == StatusCode::SERVICE_UNAVAILABLE
+            ));
+        }
+        let sent = mock_server.received_requests().await.unwrap().len();
+        assert_eq!(sent, 11);
+        assert_eq!(budget.denied(), denied + 3);
+    }
+
+    #[test]
+    fn test_invalid_retry_budget_is_rejected() {
+        let config = ClientConfig {
+            retry_config: RetryConfig {
+                retry_budget: Some(RetryBudget::new(0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(matches!(
+            OdosHttpClient::with_config(config),
+            Err(OdosError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_config_builder_defaults_match_default() {
+        let config = ClientConfig::builder().build().unwrap();
+        assert_eq!(
+            format!("{config:?}"),
+            format!("{:?}", ClientConfig::default())
+        );
+    }
+
+    #[test]
+    fn test_config_builder_accepts_valid_settings() {
+        let config = ClientConfig::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10))
+            .max_connections(1)
+            .endpoint(crate::Endpoint::enterprise_v2())
+            .api_key(ApiKey::new(uuid::Uuid::new_v4()))
+            .user_agent("my-app/1.0")
+            .build()
+            .unwrap();
+        assert_eq!(config.connect_timeout, config.timeout);
+        assert_eq!(config.user_agent.as_deref(), Some("my-app/1.0"));
+    }
+
+    fn config_error(result: Result<ClientConfig>) -> String {
+        match result {
+            Err(OdosError::Configuration(message)) => message,
+            other => panic!("expected a configuration error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_config_builder_rejects_connect_timeout_over_timeout() {
+        let message = config_error(
+            ClientConfig::builder()
+                .timeout(Duration::from_secs(5))
+                .connect_timeout(Duration::from_secs(6))
+                .build(),
+        );
+        assert_eq!(message, "connect_timeout (6s) must not exceed timeout (5s)");
+    }
+
+    #[test]
+    fn test_config_builder_rejects_zero_max_connections() {
+        let message = config_error(ClientConfig::builder().max_connections(0).build());
+        assert_eq!(message, "max_connections must be at least 1");
+    }
+
+    #[test]
+    fn test_config_builder_rejects_enterprise_without_api_key() {
+        let message = config_error(
+            ClientConfig::builder()
+                .endpoint(crate::Endpoint::enterprise_v3())
+                .build(),
+        );
+        assert_eq!(message, "Enterprise endpoints require an api_key");
+    }
+
+    #[test]
+    fn test_config_builder_allows_unauthenticated_enterprise_when_set() {
+        let config = ClientConfig::builder()
+            .endpoint(crate::Endpoint::enterprise_v3())
+            .allow_unauthenticated_enterprise(true)
+            .build()
+            .unwrap();
+        assert!(config.api_key.is_none());
+        assert!(config.allow_unauthenticated_enterprise);
+    }
+
+    #[test]
+    fn test_enterprise_without_api_key_is_rejected_unless_allowed() {
+        let config = ClientConfig {
+            endpoint: crate::Endpoint::enterprise_v2(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            OdosHttpClient::with_config(config.clone()),
+            Err(OdosError::Configuration(_))
+        ));
+
+        assert!(OdosHttpClient::with_config(ClientConfig {
+            allow_unauthenticated_enterprise: true,
+            ..config.clone()
+        })
+        .is_ok());
+        assert!(OdosHttpClient::with_config(ClientConfig {
+            api_key: Some(ApiKey::new(uuid::Uuid::new_v4())),
+            ..config
+        })
+        .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_responses_map_to_unauthorized() {
+        let mock_server = MockServer::start().await;
+        for (route, status) in [("/unauthorized", 401), ("/forbidden", 403)] {
+            Mock::given(method("GET"))
+                .and(path(route))
+                .respond_with(
+                    ResponseTemplate::new(status).set_body_json(serde_json::json!({
+                        "detail": "Invalid API key",
+                        "traceId": "10becdc8-a021-4491-8201-a17b657204e0",
+                        "errorCode": 1000
+                    })),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = create_test_client(3, 1000);
+        for (route, status) in [
+            ("/unauthorized", StatusCode::UNAUTHORIZED),
+            ("/forbidden", StatusCode::FORBIDDEN),
+        ] {
+            let url = format!("{}{route}", mock_server.uri());
+            let err = client
+                .execute_with_retry(|| client.inner().get(&url))
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(&err, OdosError::Unauthorized { status: s, body, .. } if *s == status && body.message == "Invalid API key"),
+                "unexpected error: {err:?}"
+            );
+            assert!(!err.is_retryable());
+            assert!(err.trace_id().is_some());
+            assert_eq!(err.category(), "unauthorized");
+        }
+    }
+
+    #[test]
+    fn test_config_builder_runs_client_checks() {
+        let message = config_error(ClientConfig::builder().max_concurrent_requests(0).build());
+        assert_eq!(message, "max_concurrent_requests must be at least 1");
+    }
+
+    #[tokio::test]
+    async fn test_on_retry_fires_before_backoff() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/test"))
+            .respond_with(create_retry_mock(
+                500,
+                "Internal server error".to_string(),
+                2,
+            ))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let seen = events.clone();
+        let sleeper = RecordingSleeper::new();
+        let config = ClientConfig {
+            retry_config: RetryConfig {
+                on_retry: Some(Arc::new(move |event: RetryEvent<'_>| {
+                    assert!(
+                        matches!(event.error, OdosError::Api { status, .. } if status.as_u16() == 500)
+                    );
+                    seen.lock().unwrap().push((
+                        event.attempt,
+                        event.delay,
+                        event.endpoint.to_string(),
+                    ));
+                })),
+                ..Default::default()
+            },
+            sleeper: Arc::new(sleeper.clone()),
+            ..Default::default()
+        };
+        let client = OdosHttpClient::with_config(config).unwrap();
+
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/test", mock_server.uri())))
+            .await;
+        assert!(response.is_ok());
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (attempt, delay, endpoint) = &events[0];
+        assert_eq!(*attempt, 1);
+        assert!(!delay.is_zero());
+        assert_eq!(sleeper.durations(), vec![*delay]);
+        assert_eq!(*endpoint, format!("{}/test", mock_server.uri()));
+        assert!(format!("{:?}", client.config().retry_config).contains("on_retry: true"));
+    }
+
+    #[tokio::test]
+    async fn test_retry_predicate_closure_captures_allowlist() {
+        let mock_server = MockServer::start().await;
+
+        let error_body =
+            |code: u32| format!(r#"{{ "detail": "Error", "traceId": null, "errorCode": {code} }}"#);
+        // 2998 = AlgoTimeout on /timeout, 3130 = PricingInternal on /pricing
+        Mock::given(method("GET"))
+            .and(path("/timeout"))
+            .respond_with(create_retry_mock(500, error_body(2998), 2))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/pricing"))
+            .respond_with(ResponseTemplate::new(500).set_body_string(error_body(3130)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let allowlist = [OdosErrorCode::AlgoTimeout];
+        let consulted = Arc::new(Mutex::new(0));
+        let count = consulted.clone();
+        let config = ClientConfig {
+            retry_config: RetryConfig {
+                initial_backoff_ms: 10,
+                ..RetryConfig::with_predicate(move |err| {
+                    *count.lock().unwrap() += 1;
+                    err.error_code()
+                        .is_some_and(|code| allowlist.contains(code))
+                })
+            },
+            sleeper: Arc::new(RecordingSleeper::new()),
+            ..Default::default()
+        };
+        // Clones share the captured state
+        let client = OdosHttpClient::with_config(config.clone()).unwrap();
+
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/timeout", mock_server.uri())))
+            .await;
+        assert!(response.is_ok(), "AlgoTimeout should be retried");
+
+        let response = client
+            .execute_with_retry(|| client.inner().get(format!("{}/pricing", mock_server.uri())))
+            .await;
+        assert!(
+            matches!(response, Err(OdosError::Api { body, .. }) if body.code == OdosErrorCode::PricingInternal)
+        );
+
+        assert_eq!(*consulted.lock().unwrap(), 2);
+        assert_eq!(
+            format!("{:?}", config.retry_config.retry_predicate),
+            "Replace(<predicate>)"
+        );
+    }
 }
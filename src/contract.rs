@@ -75,7 +75,7 @@
 use alloy_chains::NamedChain;
 use alloy_primitives::{address, Address};
 
-use crate::OdosChain;
+use crate::{custom_chain::custom_deployment, Chain, OdosChain, RouterAvailability, RouterType};
 
 // =============================================================================
 // V2 Router Addresses (Chain-Specific Deployments)
@@ -285,10 +285,43 @@ pub const ODOS_LO_UNICHAIN_ROUTER: Address = address!("372d96eDA72bEA64dfCa3577d
 /// **Example verification**: <https://snowscan.xyz/address/0x0D05a7D3448512B78fa8A9e46c4872C88C4a0D05>
 pub const ODOS_V3: Address = address!("0D05a7D3448512B78fa8A9e46c4872C88C4a0D05");
 
+/// Token address the Odos API and routers use for a chain's native token
+///
+/// Pass it as the input or output token to swap the native asset (ETH on
+/// Ethereum, POL on Polygon, etc.). A swap from the native token sends its
+/// input amount as the transaction `value`; see
+/// [`SwapBuilder::from_native`](crate::SwapBuilder::from_native) and
+/// [`SwapBuilder::to_native`](crate::SwapBuilder::to_native).
+pub const NATIVE_TOKEN: Address = Address::ZERO;
+
 // =============================================================================
 // Utility Functions (Built on top of the OdosChain trait)
 // =============================================================================
 
+/// Get the `router_type` router address for a specific chain ID
+///
+/// Unlike the [`OdosChain`] getters, this does not fall back to the other
+/// swap router: it returns `None` unless `router_type` itself is deployed.
+///
+/// # Example
+///
+/// ```rust
+/// use odos_sdk::{get_router_by_chain_id, RouterType, ODOS_V3};
+///
+/// assert_eq!(get_router_by_chain_id(8453, RouterType::V3), Some(ODOS_V3));
+/// assert_eq!(get_router_by_chain_id(999999, RouterType::V2), None);
+/// ```
+pub fn get_router_by_chain_id(chain_id: u64, router_type: RouterType) -> Option<Address> {
+    let named_chain = NamedChain::try_from(chain_id).ok()?;
+
+    // Check if the chain specifically supports this router (not just Odos in general)
+    if !deployment(named_chain)?.has(router_type) {
+        return None;
+    }
+
+    named_chain.router_address(router_type).ok()
+}
+
 /// Get the V2 router address for a specific chain ID
 ///
 /// This function leverages the `OdosChain` trait to provide chain ID-based
@@ -315,30 +348,7 @@ pub const ODOS_V3: Address = address!("0D05a7D3448512B78fa8A9e46c4872C88C4a0D05"
 /// assert!(unsupported_chain.is_none());
 /// ```
 pub fn get_v2_router_by_chain_id(chain_id: u64) -> Option<Address> {
-    let named_chain = NamedChain::try_from(chain_id).ok()?;
-
-    // Check if the chain specifically supports V2 (not just Odos in general)
-    if !named_chain.supports_v2() {
-        return None;
-    }
-
-    // Return the V2 router address constant
-    Some(match named_chain {
-        NamedChain::Mainnet => ODOS_V2_ETHEREUM_ROUTER,
-        NamedChain::Arbitrum => ODOS_V2_ARBITRUM_ROUTER,
-        NamedChain::Optimism => ODOS_V2_OP_ROUTER,
-        NamedChain::BinanceSmartChain => ODOS_V2_BSC_ROUTER,
-        NamedChain::Polygon => ODOS_V2_POLYGON_ROUTER,
-        NamedChain::Fraxtal => ODOS_V2_FRAXTAL_ROUTER,
-        NamedChain::ZkSync => ODOS_V2_ZKSYNC_ROUTER,
-        NamedChain::Unichain => ODOS_V2_UNICHAIN_ROUTER,
-        NamedChain::Mantle => ODOS_V2_MANTLE_ROUTER,
-        NamedChain::Base => ODOS_V2_BASE_ROUTER,
-        NamedChain::Avalanche => ODOS_V2_AVALANCHE_ROUTER,
-        NamedChain::Linea => ODOS_V2_LINEA_ROUTER,
-        NamedChain::Sonic => ODOS_V2_SONIC_ROUTER,
-        _ => return None,
-    })
+    get_router_by_chain_id(chain_id, RouterType::V2)
 }
 
 /// Get the Limit Order V2 router address for a specific chain ID
@@ -367,30 +377,7 @@ pub fn get_v2_router_by_chain_id(chain_id: u64) -> Option<Address> {
 /// assert!(unsupported_chain.is_none());
 /// ```
 pub fn get_lo_router_by_chain_id(chain_id: u64) -> Option<Address> {
-    let named_chain = NamedChain::try_from(chain_id).ok()?;
-
-    // Check if the chain specifically supports LO
-    if !named_chain.supports_lo() {
-        return None;
-    }
-
-    // Return the LO router address constant
-    Some(match named_chain {
-        NamedChain::Mainnet => ODOS_LO_ETHEREUM_ROUTER,
-        NamedChain::Optimism => ODOS_LO_OP_ROUTER,
-        NamedChain::BinanceSmartChain => ODOS_LO_BSC_ROUTER,
-        NamedChain::Polygon => ODOS_LO_POLYGON_ROUTER,
-        NamedChain::Arbitrum => ODOS_LO_ARBITRUM_ROUTER,
-        NamedChain::Avalanche => ODOS_LO_AVALANCHE_ROUTER,
-        NamedChain::Base => ODOS_LO_BASE_ROUTER,
-        NamedChain::Fraxtal => ODOS_LO_FRAXTAL_ROUTER,
-        NamedChain::Linea => ODOS_LO_LINEA_ROUTER,
-        NamedChain::Mantle => ODOS_LO_MANTLE_ROUTER,
-        NamedChain::Sonic => ODOS_LO_SONIC_ROUTER,
-        NamedChain::ZkSync => ODOS_LO_ZKSYNC_ROUTER,
-        NamedChain::Unichain => ODOS_LO_UNICHAIN_ROUTER,
-        _ => return None,
-    })
+    get_router_by_chain_id(chain_id, RouterType::LimitOrder)
 }
 
 /// Get the V3 router address for a specific chain ID
@@ -418,191 +405,255 @@ pub fn get_lo_router_by_chain_id(chain_id: u64) -> Option<Address> {
 /// }
 /// ```
 pub fn get_v3_router_by_chain_id(chain_id: u64) -> Option<Address> {
-    let named_chain = NamedChain::try_from(chain_id).ok()?;
+    get_router_by_chain_id(chain_id, RouterType::V3)
+}
 
-    // Check if the chain specifically supports V3
-    if !named_chain.supports_v3() {
-        return None;
+/// Find the kind of Odos router deployed at `address` on any supported chain
+///
+/// Returns `None` for an address that is not a compiled-in router, including
+/// routers of chains registered with [`Chain::register_custom`].
+///
+/// # Example
+///
+/// ```rust
+/// use alloy_primitives::Address;
+/// use odos_sdk::{router_type_for_address, RouterType, ODOS_LO_BASE_ROUTER, ODOS_V3};
+///
+/// assert_eq!(router_type_for_address(ODOS_V3), Some(RouterType::V3));
+/// assert_eq!(router_type_for_address(ODOS_LO_BASE_ROUTER), Some(RouterType::LimitOrder));
+/// assert_eq!(router_type_for_address(Address::ZERO), None);
+/// ```
+pub fn router_type_for_address(address: Address) -> Option<RouterType> {
+    RouterType::all().into_iter().find(|&router_type| {
+        DEPLOYMENTS.iter().any(|&(chain, routers)| {
+            routers.has(router_type) && chain.router_address(router_type).ok() == Some(address)
+        })
+    })
+}
+
+/// Every chain with at least one Odos router deployment known to the SDK,
+/// in ascending chain ID order, with the routers deployed on it.
+///
+/// This is the single source of chain support: the `supports_*` methods of
+/// [`OdosChain`], [`Chain::all`] and the `get_supported_*` helpers all read it.
+/// [`deployment`] adds the chains registered with [`Chain::register_custom`].
+pub(crate) const DEPLOYMENTS: [(NamedChain, RouterAvailability); 13] = {
+    use NamedChain::*;
+
+    const ALL: RouterAvailability = RouterAvailability::all();
+    [
+        (Mainnet, ALL),
+        (Optimism, ALL),
+        (BinanceSmartChain, ALL),
+        (Unichain, ALL),
+        (Polygon, ALL),
+        (Sonic, ALL),
+        (Fraxtal, ALL),
+        (ZkSync, ALL),
+        (Mantle, ALL),
+        (Base, ALL),
+        (Arbitrum, ALL),
+        (Avalanche, ALL),
+        (Linea, ALL),
+    ]
+};
+
+/// Look up the routers deployed on `chain`, or `None` if Odos is not
+/// deployed there.
+pub(crate) fn deployment(chain: NamedChain) -> Option<RouterAvailability> {
+    DEPLOYMENTS
+        .iter()
+        .find(|(deployed, _)| *deployed == chain)
+        .map(|&(_, routers)| routers)
+        .or_else(|| custom_deployment(chain))
+}
+
+/// Ordered set of chains returned by the `get_supported_*` helpers.
+///
+/// Chains are always sorted by ascending chain ID, so the order is stable
+/// across releases: new chains are inserted at the position of their ID
+/// rather than wherever they happen to be declared. Offsets into
+/// [`as_slice`](Self::as_slice) or [`page`](Self::page) therefore only move
+/// when a chain with a lower ID is added or removed.
+///
+/// # Example
+///
+/// ```rust
+/// use odos_sdk::get_supported_chains;
+/// use alloy_chains::NamedChain;
+///
+/// let chains = get_supported_chains();
+/// assert!(chains.contains(1)); // Ethereum
+/// assert_eq!(chains.as_slice()[0], NamedChain::Mainnet); // lowest chain ID first
+///
+/// let ids: Vec<u64> = chains.ids().collect();
+/// assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SupportedChains {
+    chains: Vec<NamedChain>,
+}
+
+impl SupportedChains {
+    /// Collect `chains`, which [`Chain::all`] yields in chain ID order.
+    fn collect(chains: impl Iterator<Item = Chain>) -> Self {
+        Self {
+            chains: chains.map(NamedChain::from).collect(),
+        }
+    }
+
+    /// Iterate over the chains in ascending chain ID order.
+    pub fn iter(&self) -> std::slice::Iter<'_, NamedChain> {
+        self.chains.iter()
+    }
+
+    /// Iterate over the chain IDs in ascending order.
+    pub fn ids(&self) -> impl Iterator<Item = u64> + '_ {
+        self.chains.iter().map(|&chain| u64::from(chain))
     }
 
-    Some(ODOS_V3)
+    /// Check whether the chain with the given ID is in the set.
+    pub fn contains(&self, chain_id: u64) -> bool {
+        self.ids().any(|id| id == chain_id)
+    }
+
+    /// The chains in ascending chain ID order.
+    pub fn as_slice(&self) -> &[NamedChain] {
+        &self.chains
+    }
+
+    /// A page of at most `limit` chains starting at `offset`.
+    ///
+    /// Returns an empty slice when `offset` is past the end.
+    pub fn page(&self, offset: usize, limit: usize) -> &[NamedChain] {
+        let start = offset.min(self.chains.len());
+        let end = start.saturating_add(limit).min(self.chains.len());
+        &self.chains[start..end]
+    }
+
+    /// Number of chains in the set.
+    pub fn len(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+}
+
+impl IntoIterator for SupportedChains {
+    type Item = NamedChain;
+    type IntoIter = std::vec::IntoIter<NamedChain>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chains.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SupportedChains {
+    type Item = &'a NamedChain;
+    type IntoIter = std::slice::Iter<'a, NamedChain>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chains.iter()
+    }
+}
+
+impl From<SupportedChains> for Vec<NamedChain> {
+    fn from(supported: SupportedChains) -> Self {
+        supported.chains
+    }
 }
 
 /// Get all supported chains
 ///
 /// This function queries the trait implementation to determine which
 /// chains are supported. A chain is considered supported if it has at least
-/// one router type (LO, V2, or V3) deployed.
+/// one router type (LO, V2, or V3) deployed. Equivalent to collecting
+/// [`Chain::all`].
 ///
 /// # Returns
 ///
-/// A vector of all supported chains as `NamedChain` values
+/// All supported chains, sorted by ascending chain ID
 ///
 /// # Example
 ///
 /// ```rust
 /// use odos_sdk::get_supported_chains;
-/// use alloy_chains::NamedChain;
 ///
 /// let chains = get_supported_chains();
-/// assert!(chains.contains(&NamedChain::Mainnet)); // Ethereum (has LO, V2, V3)
-/// assert!(chains.contains(&NamedChain::Arbitrum)); // Arbitrum (has LO, V2, V3)
+/// assert!(chains.contains(1)); // Ethereum (has LO, V2, V3)
+/// assert!(chains.contains(42161)); // Arbitrum (has LO, V2, V3)
 ///
 /// // Convert to u64 if needed
-/// let chain_ids: Vec<u64> = chains.iter().map(|&c| c as u64).collect();
+/// let chain_ids: Vec<u64> = chains.ids().collect();
 /// ```
-pub fn get_supported_chains() -> Vec<NamedChain> {
-    use NamedChain::*;
-
-    let all_chains = [
-        Arbitrum,
-        Avalanche,
-        Base,
-        BinanceSmartChain,
-        Fraxtal,
-        Linea,
-        Mainnet,
-        Mantle,
-        Optimism,
-        Polygon,
-        Sonic,
-        Unichain,
-        ZkSync,
-    ];
-
-    all_chains
-        .into_iter()
-        .filter(|&chain| chain.supports_odos())
-        .collect()
+pub fn get_supported_chains() -> SupportedChains {
+    SupportedChains::collect(Chain::all())
 }
 
 /// Get all chains that support Limit Order V2 routers
 ///
 /// # Returns
 ///
-/// A vector of chains that have LO router deployments
+/// Chains that have LO router deployments, sorted by ascending chain ID
 ///
 /// # Example
 ///
 /// ```rust
 /// use odos_sdk::get_supported_lo_chains;
-/// use alloy_chains::NamedChain;
 ///
 /// let lo_chains = get_supported_lo_chains();
-/// assert!(lo_chains.contains(&NamedChain::Mainnet)); // Ethereum
+/// assert!(lo_chains.contains(1)); // Ethereum
 ///
 /// // Convert to u64 if needed
-/// let chain_ids: Vec<u64> = lo_chains.iter().map(|&c| c as u64).collect();
+/// let chain_ids: Vec<u64> = lo_chains.ids().collect();
 /// ```
-pub fn get_supported_lo_chains() -> Vec<NamedChain> {
-    use NamedChain::*;
-
-    let all_chains = [
-        Arbitrum,
-        Avalanche,
-        Base,
-        BinanceSmartChain,
-        Fraxtal,
-        Linea,
-        Mainnet,
-        Mantle,
-        Optimism,
-        Polygon,
-        Sonic,
-        Unichain,
-        ZkSync,
-    ];
-
-    all_chains
-        .into_iter()
-        .filter(|&chain| chain.supports_lo())
-        .collect()
+pub fn get_supported_lo_chains() -> SupportedChains {
+    SupportedChains::collect(Chain::all_supporting(RouterType::LimitOrder))
 }
 
 /// Get all chains that support V2 routers
 ///
 /// # Returns
 ///
-/// A vector of chains that have V2 router deployments
+/// Chains that have V2 router deployments, sorted by ascending chain ID
 ///
 /// # Example
 ///
 /// ```rust
 /// use odos_sdk::get_supported_v2_chains;
-/// use alloy_chains::NamedChain;
 ///
 /// let v2_chains = get_supported_v2_chains();
-/// assert!(v2_chains.contains(&NamedChain::Mainnet)); // Ethereum
+/// assert!(v2_chains.contains(1)); // Ethereum
 ///
 /// // Convert to u64 if needed
-/// let chain_ids: Vec<u64> = v2_chains.iter().map(|&c| c as u64).collect();
+/// let chain_ids: Vec<u64> = v2_chains.ids().collect();
 /// ```
-pub fn get_supported_v2_chains() -> Vec<NamedChain> {
-    use NamedChain::*;
-
-    let all_chains = [
-        Arbitrum,
-        Avalanche,
-        Base,
-        BinanceSmartChain,
-        Fraxtal,
-        Linea,
-        Mainnet,
-        Mantle,
-        Optimism,
-        Polygon,
-        Sonic,
-        Unichain,
-        ZkSync,
-    ];
-
-    all_chains
-        .into_iter()
-        .filter(|&chain| chain.supports_v2())
-        .collect()
+pub fn get_supported_v2_chains() -> SupportedChains {
+    SupportedChains::collect(Chain::all_supporting(RouterType::V2))
 }
 
 /// Get all chains that support V3 routers
 ///
 /// # Returns
 ///
-/// A vector of chains that have V3 router deployments
+/// Chains that have V3 router deployments, sorted by ascending chain ID
 ///
 /// # Example
 ///
 /// ```rust
 /// use odos_sdk::get_supported_v3_chains;
-/// use alloy_chains::NamedChain;
 ///
 /// let v3_chains = get_supported_v3_chains();
-/// assert!(v3_chains.contains(&NamedChain::Mainnet)); // Ethereum
+/// assert!(v3_chains.contains(1)); // Ethereum
 ///
 /// // Convert to u64 if needed
-/// let chain_ids: Vec<u64> = v3_chains.iter().map(|&c| c as u64).collect();
+/// let chain_ids: Vec<u64> = v3_chains.ids().collect();
 /// ```
-pub fn get_supported_v3_chains() -> Vec<NamedChain> {
-    use NamedChain::*;
-
-    let all_chains = [
-        Arbitrum,
-        Avalanche,
-        Base,
-        BinanceSmartChain,
-        Fraxtal,
-        Linea,
-        Mainnet,
-        Mantle,
-        Optimism,
-        Polygon,
-        Sonic,
-        Unichain,
-        ZkSync,
-    ];
-
-    all_chains
-        .into_iter()
-        .filter(|&chain| chain.supports_v3())
-        .collect()
+pub fn get_supported_v3_chains() -> SupportedChains {
+    SupportedChains::collect(Chain::all_supporting(RouterType::V3))
 }
 
 #[cfg(test)]
@@ -644,6 +695,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unified_router_lookup() {
+        for chain in Chain::all() {
+            let chain_id = chain.id();
+            let availability = RouterAvailability::for_chain(chain_id);
+            assert_eq!(availability, chain.router_availability(), "{chain}");
+
+            assert_eq!(
+                get_router_by_chain_id(chain_id, RouterType::LimitOrder),
+                get_lo_router_by_chain_id(chain_id)
+            );
+            assert_eq!(
+                get_router_by_chain_id(chain_id, RouterType::V2),
+                get_v2_router_by_chain_id(chain_id)
+            );
+            assert_eq!(
+                get_router_by_chain_id(chain_id, RouterType::V3),
+                get_v3_router_by_chain_id(chain_id)
+            );
+            for router_type in RouterType::all() {
+                assert_eq!(
+                    get_router_by_chain_id(chain_id, router_type).is_some(),
+                    availability.has(router_type),
+                    "{chain} {router_type}"
+                );
+            }
+        }
+
+        assert_eq!(get_router_by_chain_id(999999, RouterType::V3), None);
+        assert_eq!(
+            RouterAvailability::for_chain(999999),
+            RouterAvailability::none()
+        );
+    }
+
+    #[test]
+    fn test_unified_router_lookup_with_missing_v2() {
+        // Every built-in chain has all three routers, so model a V2 gap with
+        // a custom chain
+        let chain_id = u64::from(NamedChain::OptimismSepolia);
+        let lo = address!("0000000000000000000000000000000000000001");
+        let chain = Chain::register_custom(
+            chain_id,
+            crate::ChainConfig {
+                name: "v2-gap".to_string(),
+                v2_router: None,
+                v3_router: Some(ODOS_V3),
+                lo_router: Some(lo),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            RouterAvailability::for_chain(chain_id),
+            RouterAvailability::lo_v3_only()
+        );
+        assert_eq!(get_router_by_chain_id(chain_id, RouterType::V2), None);
+        assert_eq!(
+            get_router_by_chain_id(chain_id, RouterType::V3),
+            Some(ODOS_V3)
+        );
+        assert_eq!(
+            get_router_by_chain_id(chain_id, RouterType::LimitOrder),
+            Some(lo)
+        );
+        // The trait lookup falls back to V3, like the per-router getters
+        assert_eq!(chain.router_address(RouterType::V2).unwrap(), ODOS_V3);
+        assert_eq!(chain.router_address(RouterType::LimitOrder).unwrap(), lo);
+
+        Chain::unregister_custom(chain_id);
+    }
+
     #[test]
     fn test_chain_id_lookup() {
         assert_eq!(get_v2_router_by_chain_id(1), Some(ODOS_V2_ETHEREUM_ROUTER));
@@ -674,9 +797,9 @@ mod tests {
         let v3_chains = get_supported_v3_chains();
 
         for &chain in &all_chains {
-            let has_lo = lo_chains.contains(&chain);
-            let has_v2 = v2_chains.contains(&chain);
-            let has_v3 = v3_chains.contains(&chain);
+            let has_lo = lo_chains.contains(chain.into());
+            let has_v2 = v2_chains.contains(chain.into());
+            let has_v3 = v3_chains.contains(chain.into());
 
             assert!(
                 has_lo || has_v2 || has_v3,
@@ -685,4 +808,61 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_supported_chains_snapshot() {
+        // Ascending chain ID. Adding or removing a chain must update this list.
+        let expected = [
+            1, 10, 56, 130, 137, 146, 252, 324, 5000, 8453, 42161, 43114, 59144,
+        ];
+
+        assert_eq!(get_supported_chains().ids().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            get_supported_lo_chains().ids().collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            get_supported_v2_chains().ids().collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            get_supported_v3_chains().ids().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_deployments_sorted_and_consistent_with_trait() {
+        assert!(DEPLOYMENTS
+            .windows(2)
+            .all(|pair| u64::from(pair[0].0) < u64::from(pair[1].0)));
+
+        for (chain, routers) in DEPLOYMENTS {
+            assert_eq!(chain.router_availability(), routers);
+            assert_eq!(chain.try_lo_router_address().is_some(), routers.limit_order);
+            assert_eq!(chain.try_v2_router_address().is_some(), routers.v2);
+            assert_eq!(chain.try_v3_router_address().is_some(), routers.v3);
+        }
+        assert_eq!(deployment(NamedChain::Sepolia), None);
+    }
+
+    #[test]
+    fn test_supported_chains_contains_and_page() {
+        let chains = get_supported_chains();
+
+        assert!(chains.contains(1));
+        assert!(!chains.contains(999_999));
+        assert_eq!(chains.len(), chains.as_slice().len());
+        assert!(!chains.is_empty());
+
+        assert_eq!(
+            chains.page(0, 2),
+            &[NamedChain::Mainnet, NamedChain::Optimism]
+        );
+        assert_eq!(chains.page(12, 5), &[NamedChain::Linea]);
+        assert!(chains.page(100, 5).is_empty());
+
+        let collected: Vec<NamedChain> = chains.clone().into_iter().collect();
+        assert_eq!(collected, Vec::from(chains));
+    }
 }
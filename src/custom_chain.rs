@@ -0,0 +1,242 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime registry of chains without a built-in Odos deployment, for local
+//! forks and testnets paired with an Odos-compatible API behind a custom
+//! [`Endpoint`](crate::Endpoint).
+
+use std::{collections::BTreeMap, sync::RwLock};
+
+use alloy_chains::NamedChain;
+use alloy_primitives::Address;
+
+use crate::{
+    contract::DEPLOYMENTS, Chain, OdosChainError, OdosChainResult, RouterAvailability, RouterType,
+};
+
+static REGISTRY: RwLock<BTreeMap<NamedChain, ChainConfig>> = RwLock::new(BTreeMap::new());
+
+/// Router deployments for a chain registered with [`Chain::register_custom`]
+///
+/// A router left as `None` is treated as not deployed, so lookups fall back
+/// to the other swap router exactly as they do for built-in chains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainConfig {
+    /// Name accepted by [`Chain::from_name`], in addition to the chain's
+    /// `alloy_chains` name
+    pub name: String,
+
+    /// V2 router address
+    pub v2_router: Option<Address>,
+
+    /// V3 router address
+    pub v3_router: Option<Address>,
+
+    /// Limit Order V2 router address
+    pub lo_router: Option<Address>,
+}
+
+impl ChainConfig {
+    fn router(&self, router_type: RouterType) -> Option<Address> {
+        match router_type {
+            RouterType::LimitOrder => self.lo_router,
+            RouterType::V2 => self.v2_router,
+            RouterType::V3 => self.v3_router,
+        }
+    }
+
+    fn availability(&self) -> RouterAvailability {
+        RouterAvailability {
+            limit_order: self.lo_router.is_some(),
+            v2: self.v2_router.is_some(),
+            v3: self.v3_router.is_some(),
+        }
+    }
+}
+
+/// Get the routers registered for `chain`, if it is a custom chain
+pub(crate) fn custom_deployment(chain: NamedChain) -> Option<RouterAvailability> {
+    read(|registry| registry.get(&chain).map(ChainConfig::availability))
+}
+
+/// Get the `router_type` router registered for `chain`, if any
+pub(crate) fn custom_router(chain: NamedChain, router_type: RouterType) -> Option<Address> {
+    read(|registry| {
+        registry
+            .get(&chain)
+            .and_then(|config| config.router(router_type))
+    })
+}
+
+/// Find the first custom chain whose config matches `predicate`
+pub(crate) fn find_custom_chain(predicate: impl Fn(&ChainConfig) -> bool) -> Option<NamedChain> {
+    read(|registry| {
+        registry
+            .iter()
+            .find(|(_, config)| predicate(config))
+            .map(|(&chain, _)| chain)
+    })
+}
+
+fn read<T>(f: impl FnOnce(&BTreeMap<NamedChain, ChainConfig>) -> T) -> T {
+    f(&REGISTRY.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+impl Chain {
+    /// Register a chain without a built-in Odos deployment, such as a local
+    /// fork or a testnet
+    ///
+    /// Once registered, the chain behaves like a supported chain:
+    /// [`Chain::from_chain_id`], [`Chain::from_name`] (with `config.name`),
+    /// the [`OdosChain`](crate::OdosChain) router lookups, the
+    /// `get_*_router_by_chain_id` helpers and [`SwapBuilder`](crate::SwapBuilder)
+    /// all accept it. It is not listed by [`Chain::all`] or the
+    /// `get_supported_*` helpers, which describe Odos' own deployments.
+    ///
+    /// The registration is process-wide and replaces any earlier one for
+    /// `chain_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosChainError::InvalidCustomChain`] if `chain_id` has no
+    /// `alloy_chains::NamedChain`, if Odos is already deployed on it, or if
+    /// `config` has no router address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::{Chain, ChainConfig, OdosChain, ODOS_V2_ETHEREUM_ROUTER};
+    ///
+    /// // An Anvil fork of mainnet, which keeps mainnet's router deployment
+    /// let anvil = Chain::register_custom(31337, ChainConfig {
+    ///     name: "mainnet-fork".to_string(),
+    ///     v2_router: Some(ODOS_V2_ETHEREUM_ROUTER),
+    ///     v3_router: None,
+    ///     lo_router: None,
+    /// })?;
+    ///
+    /// assert_eq!(Chain::from_chain_id(31337)?, anvil);
+    /// assert_eq!(Chain::from_name("mainnet-fork")?, anvil);
+    /// assert_eq!(anvil.v2_router_address()?, ODOS_V2_ETHEREUM_ROUTER);
+    /// # Ok::<(), odos_sdk::OdosChainError>(())
+    /// ```
+    pub fn register_custom(chain_id: u64, config: ChainConfig) -> OdosChainResult<Self> {
+        let invalid = |reason: &str| OdosChainError::InvalidCustomChain {
+            chain: format!("Chain ID {chain_id}"),
+            reason: reason.to_string(),
+        };
+
+        let chain = NamedChain::try_from(chain_id)
+            .map_err(|_| invalid("chain ID has no alloy_chains::NamedChain"))?;
+        if DEPLOYMENTS.iter().any(|(deployed, _)| *deployed == chain) {
+            return Err(invalid("Odos is already deployed on this chain"));
+        }
+        if !config.availability().has_any() {
+            return Err(invalid("no router address configured"));
+        }
+
+        REGISTRY
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(chain, config);
+        Ok(Self::from(chain))
+    }
+
+    /// Remove a chain registered with [`register_custom`](Self::register_custom),
+    /// returning its config
+    pub fn unregister_custom(chain_id: u64) -> Option<ChainConfig> {
+        let chain = NamedChain::try_from(chain_id).ok()?;
+        REGISTRY
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&chain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+    use crate::{get_lo_router_by_chain_id, get_v2_router_by_chain_id, OdosChain, ODOS_V3};
+
+    // Each test registers its own chain, as the registry is process-wide
+
+    fn config(name: &str) -> ChainConfig {
+        ChainConfig {
+            name: name.to_string(),
+            v2_router: None,
+            v3_router: Some(address!("0000000000000000000000000000000000000003")),
+            lo_router: None,
+        }
+    }
+
+    #[test]
+    fn test_registered_chain_behaves_like_supported_chain() {
+        let id = u64::from(NamedChain::Holesky);
+        assert!(Chain::from_chain_id(id).is_err());
+
+        let chain = Chain::register_custom(id, config("My Testnet")).unwrap();
+        assert_eq!(Chain::from_chain_id(id).unwrap(), chain);
+        assert_eq!(Chain::from_name("my-testnet").unwrap(), chain);
+        assert!(chain.supports_odos());
+        assert!(chain.supports_v3() && !chain.supports_v2() && !chain.supports_lo());
+
+        let v3 = address!("0000000000000000000000000000000000000003");
+        assert_eq!(chain.v3_router_address().unwrap(), v3);
+        // V2 falls back to V3, as on built-in chains
+        assert_eq!(chain.v2_router_address().unwrap(), v3);
+        assert!(matches!(
+            chain.lo_router_address(),
+            Err(OdosChainError::LimitOrderNotAvailable { .. })
+        ));
+        assert_eq!(get_v2_router_by_chain_id(id), None);
+        assert_eq!(get_lo_router_by_chain_id(id), None);
+        assert!(!Chain::all().any(|supported| supported == chain));
+
+        assert_eq!(Chain::unregister_custom(id), Some(config("My Testnet")));
+        assert!(Chain::from_chain_id(id).is_err());
+        assert!(!chain.supports_odos());
+    }
+
+    #[test]
+    fn test_registration_replaces_earlier_config() {
+        let id = u64::from(NamedChain::Dev);
+        let lo = address!("0000000000000000000000000000000000000001");
+        Chain::register_custom(id, config("dev")).unwrap();
+        let chain = Chain::register_custom(
+            id,
+            ChainConfig {
+                lo_router: Some(lo),
+                ..config("dev")
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chain.lo_router_address().unwrap(), lo);
+        assert_eq!(get_lo_router_by_chain_id(id), Some(lo));
+        Chain::unregister_custom(id);
+    }
+
+    #[test]
+    fn test_invalid_registrations() {
+        let reason = |result: OdosChainResult<Chain>| match result {
+            Err(OdosChainError::InvalidCustomChain { reason, .. }) => reason,
+            other => panic!("unexpected result: {other:?}"),
+        };
+
+        assert!(reason(Chain::register_custom(u64::MAX, config("none"))).contains("NamedChain"));
+        assert!(reason(Chain::register_custom(1, config("mainnet"))).contains("already deployed"));
+        assert_eq!(Chain::ethereum().v3_router_address().unwrap(), ODOS_V3);
+
+        let empty = ChainConfig {
+            v3_router: None,
+            ..config("empty")
+        };
+        assert!(
+            reason(Chain::register_custom(u64::from(NamedChain::Goerli), empty))
+                .contains("no router")
+        );
+    }
+}
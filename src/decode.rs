@@ -0,0 +1,649 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decoding of transactions sent to Odos routers.
+//!
+//! [`decode_router_calldata`] identifies the router from the transaction's
+//! `to` address and decodes its calldata with that router's ABI, so a
+//! pending transaction, from the mempool or a signing queue, can be
+//! inspected without knowing in advance which router or function it uses:
+//!
+//! ```rust
+//! use alloy_primitives::Address;
+//! use odos_sdk::{decode_router_calldata, DecodedOdosCall};
+//!
+//! # fn example(to: Address, data: &[u8]) -> odos_sdk::Result<()> {
+//! match decode_router_calldata(to, data)? {
+//!     DecodedOdosCall::Swap(swap) => println!("Swap of {}", swap.token_address()),
+//!     DecodedOdosCall::SwapMulti(swap) => println!("Swap of {} tokens", swap.inputs().len()),
+//!     DecodedOdosCall::Unknown(selector) => println!("Other router call {selector}"),
+//!     other => println!("{other:?}"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_sol_types::SolInterface;
+
+use crate::{
+    router_type_for_address, MultiSwapInputs, OdosError, OdosRouterV2,
+    OdosV2Router::{swapCall, swapMultiCall, OdosV2RouterCalls},
+    Result, RouterType, SwapInputs,
+};
+
+#[cfg(feature = "v3")]
+use crate::{
+    IOdosRouterV3,
+    OdosV3Router::{self, OdosV3RouterCalls},
+};
+
+#[cfg(feature = "limit-orders")]
+use crate::{
+    limit_order_v2::{OdosLimitOrderRouter, OdosLimitOrderV2::OdosLimitOrderV2Calls},
+    LimitOrderToken,
+};
+
+/// A decoded call to an Odos router
+///
+/// Returned by [`decode_router_calldata`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum DecodedOdosCall {
+    /// A V2 or V3 router `swap`
+    Swap(SwapInputs),
+
+    /// A V2 or V3 router `swapMulti`
+    SwapMulti(MultiSwapInputs),
+
+    /// A V2 or V3 router `swapPermit2`, pulling the input through Permit2
+    SwapPermit2 {
+        /// The Permit2 authorization for the input
+        permit2: Permit2Info,
+        /// The swap
+        swap: SwapInputs,
+    },
+
+    /// A V2 or V3 router `swapMultiPermit2`, pulling the inputs through
+    /// Permit2
+    SwapMultiPermit2 {
+        /// The Permit2 authorization for the inputs
+        permit2: Permit2Info,
+        /// The swap
+        swap: MultiSwapInputs,
+    },
+
+    /// A limit order router fill, of a single or multi-token order
+    #[cfg(feature = "limit-orders")]
+    LimitOrderFill(LimitOrderFill),
+
+    /// A router call this decoder does not interpret, identified by its
+    /// function selector
+    ///
+    /// Covers the routers' admin and fund transfer functions, the compact
+    /// swap encodings, and the V3 hook variants.
+    Unknown(FixedBytes<4>),
+}
+
+/// Permit2 authorization attached to a `swapPermit2`, `swapMultiPermit2` or
+/// Permit2 limit order fill
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Permit2Info {
+    /// Permit2 contract address
+    pub contract_address: Address,
+
+    /// Permit2 nonce
+    pub nonce: U256,
+
+    /// Unix timestamp in seconds after which the permit is invalid
+    pub deadline: U256,
+
+    /// Owner of the permitted tokens, for limit order fills
+    pub order_owner: Option<Address>,
+
+    /// Owner's signature over the permit
+    pub signature: Bytes,
+}
+
+/// A limit order fill decoded from a limit order router call
+///
+/// Both single and multi-token orders decode to this type: a single-token
+/// order has one input, one output and one fill amount.
+#[cfg(feature = "limit-orders")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LimitOrderFill {
+    /// Tokens and amounts the order sells
+    pub inputs: Vec<LimitOrderToken>,
+
+    /// Tokens and minimum amounts the order buys
+    pub outputs: Vec<LimitOrderToken>,
+
+    /// Unix timestamp in seconds after which the order can no longer be
+    /// filled
+    pub expiry: U256,
+
+    /// Whether the order may be filled in several parts
+    pub partially_fillable: bool,
+
+    /// Amount of each input filled by this call
+    pub fill_amounts: Vec<U256>,
+
+    /// Executor running the fill's swap path
+    pub executor: Address,
+
+    /// Permit2 authorization, for the Permit2 fill functions
+    pub permit2: Option<Permit2Info>,
+}
+
+/// Decode calldata sent to the Odos router at `to`
+///
+/// The router type is looked up from `to` among the compiled-in router
+/// addresses, and `data` decoded with that router's ABI.
+///
+/// # Errors
+///
+/// Returns [`OdosError::InvalidInput`] if `to` is not a known Odos router,
+/// if the bindings for its router type are not compiled in (the `v3` and
+/// `limit-orders` features), or if `data` is too short to hold a function
+/// selector or does not decode as the function its selector names.
+pub fn decode_router_calldata(to: Address, data: &[u8]) -> Result<DecodedOdosCall> {
+    let router_type = router_type_for_address(to)
+        .ok_or_else(|| OdosError::invalid_input(format!("{to} is not a known Odos router")))?;
+    let selector = data
+        .get(..4)
+        .map(FixedBytes::<4>::from_slice)
+        .ok_or_else(|| OdosError::invalid_input("Calldata is shorter than a function selector"))?;
+
+    let decode_error = |err: alloy_sol_types::Error| {
+        OdosError::invalid_input(format!(
+            "Failed to decode {router_type} router call {selector}: {err}"
+        ))
+    };
+
+    match router_type {
+        RouterType::V2 => {
+            if !OdosV2RouterCalls::valid_selector(*selector) {
+                return Ok(DecodedOdosCall::Unknown(selector));
+            }
+            decode_v2(OdosV2RouterCalls::abi_decode(data).map_err(decode_error)?)
+        }
+        #[cfg(feature = "v3")]
+        RouterType::V3 => {
+            if !OdosV3RouterCalls::valid_selector(*selector) {
+                return Ok(DecodedOdosCall::Unknown(selector));
+            }
+            decode_v3(OdosV3RouterCalls::abi_decode(data).map_err(decode_error)?)
+        }
+        #[cfg(feature = "limit-orders")]
+        RouterType::LimitOrder => {
+            if !OdosLimitOrderV2Calls::valid_selector(*selector) {
+                return Ok(DecodedOdosCall::Unknown(selector));
+            }
+            Ok(decode_limit_order(
+                OdosLimitOrderV2Calls::abi_decode(data).map_err(decode_error)?,
+                selector,
+            ))
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err(OdosError::invalid_input(format!(
+            "Decoding {router_type} router calls requires the `{}` feature",
+            if router_type == RouterType::V3 {
+                "v3"
+            } else {
+                "limit-orders"
+            }
+        ))),
+    }
+}
+
+fn decode_v2(call: OdosV2RouterCalls) -> Result<DecodedOdosCall> {
+    Ok(match call {
+        OdosV2RouterCalls::swap(_) => DecodedOdosCall::Swap(SwapInputs::try_from(call)?),
+        OdosV2RouterCalls::swapMulti(_) => {
+            DecodedOdosCall::SwapMulti(MultiSwapInputs::try_from(call)?)
+        }
+        OdosV2RouterCalls::swapPermit2(call) => DecodedOdosCall::SwapPermit2 {
+            permit2: v2_permit2(call.permit2),
+            swap: SwapInputs::try_from(OdosV2RouterCalls::swap(swapCall {
+                tokenInfo: call.tokenInfo,
+                pathDefinition: call.pathDefinition,
+                executor: call.executor,
+                referralCode: call.referralCode,
+            }))?,
+        },
+        OdosV2RouterCalls::swapMultiPermit2(call) => DecodedOdosCall::SwapMultiPermit2 {
+            permit2: v2_permit2(call.permit2),
+            swap: MultiSwapInputs::try_from(OdosV2RouterCalls::swapMulti(swapMultiCall {
+                inputs: call.inputs,
+                outputs: call.outputs,
+                valueOutMin: call.valueOutMin,
+                pathDefinition: call.pathDefinition,
+                executor: call.executor,
+                referralCode: call.referralCode,
+            }))?,
+        },
+        other => DecodedOdosCall::Unknown(other.selector().into()),
+    })
+}
+
+fn v2_permit2(permit2: OdosRouterV2::permit2Info) -> Permit2Info {
+    Permit2Info {
+        contract_address: permit2.contractAddress,
+        nonce: permit2.nonce,
+        deadline: permit2.deadline,
+        order_owner: None,
+        signature: permit2.signature,
+    }
+}
+
+#[cfg(feature = "v3")]
+fn decode_v3(call: OdosV3RouterCalls) -> Result<DecodedOdosCall> {
+    Ok(match call {
+        OdosV3RouterCalls::swap(_) => DecodedOdosCall::Swap(SwapInputs::try_from(call)?),
+        OdosV3RouterCalls::swapMulti(_) => {
+            DecodedOdosCall::SwapMulti(MultiSwapInputs::try_from(call)?)
+        }
+        OdosV3RouterCalls::swapPermit2(call) => DecodedOdosCall::SwapPermit2 {
+            permit2: v3_permit2(call.permit2),
+            swap: SwapInputs::try_from(OdosV3RouterCalls::swap(OdosV3Router::swapCall {
+                tokenInfo: call.tokenInfo,
+                pathDefinition: call.pathDefinition,
+                executor: call.executor,
+                referralInfo: call.referralInfo,
+            }))?,
+        },
+        OdosV3RouterCalls::swapMultiPermit2(call) => DecodedOdosCall::SwapMultiPermit2 {
+            permit2: v3_permit2(call.permit2),
+            swap: MultiSwapInputs::try_from(OdosV3RouterCalls::swapMulti(
+                OdosV3Router::swapMultiCall {
+                    inputs: call.inputs,
+                    outputs: call.outputs,
+                    pathDefinition: call.pathDefinition,
+                    executor: call.executor,
+                    referralInfo: call.referralInfo,
+                },
+            ))?,
+        },
+        other => DecodedOdosCall::Unknown(other.selector().into()),
+    })
+}
+
+#[cfg(feature = "v3")]
+fn v3_permit2(permit2: IOdosRouterV3::permit2Info) -> Permit2Info {
+    Permit2Info {
+        contract_address: permit2.contractAddress,
+        nonce: permit2.nonce,
+        deadline: permit2.deadline,
+        order_owner: None,
+        signature: permit2.signature,
+    }
+}
+
+#[cfg(feature = "limit-orders")]
+fn decode_limit_order(call: OdosLimitOrderV2Calls, selector: FixedBytes<4>) -> DecodedOdosCall {
+    let token = |info: OdosLimitOrderRouter::TokenInfo| {
+        LimitOrderToken::from((info.tokenAddress, info.tokenAmount))
+    };
+    let lo_permit2 = |permit2: OdosLimitOrderRouter::Permit2Info| Permit2Info {
+        contract_address: permit2.contractAddress,
+        nonce: permit2.nonce,
+        deadline: permit2.deadline,
+        order_owner: Some(permit2.orderOwner),
+        signature: permit2.signature,
+    };
+    let single = |order: OdosLimitOrderRouter::LimitOrder,
+                  context: OdosLimitOrderRouter::LimitOrderContext,
+                  permit2: Option<Permit2Info>| LimitOrderFill {
+        inputs: vec![token(order.input)],
+        outputs: vec![token(order.output)],
+        expiry: order.expiry,
+        partially_fillable: order.partiallyFillable,
+        fill_amounts: vec![context.currentAmount],
+        executor: context.odosExecutor,
+        permit2,
+    };
+    let multi = |order: OdosLimitOrderRouter::MultiLimitOrder,
+                 context: OdosLimitOrderRouter::MultiLimitOrderContext,
+                 permit2: Option<Permit2Info>| LimitOrderFill {
+        inputs: order.inputs.into_iter().map(token).collect(),
+        outputs: order.outputs.into_iter().map(token).collect(),
+        expiry: order.expiry,
+        partially_fillable: order.partiallyFillable,
+        fill_amounts: context.currentAmounts,
+        executor: context.odosExecutor,
+        permit2,
+    };
+
+    DecodedOdosCall::LimitOrderFill(match call {
+        OdosLimitOrderV2Calls::fillLimitOrder(call) => single(call.order, call.context, None),
+        OdosLimitOrderV2Calls::fillLimitOrderPermit2(call) => {
+            single(call.order, call.context, Some(lo_permit2(call.permit2)))
+        }
+        OdosLimitOrderV2Calls::fillMultiLimitOrder(call) => multi(call.order, call.context, None),
+        OdosLimitOrderV2Calls::fillMultiLimitOrderPermit2(call) => {
+            multi(call.order, call.context, Some(lo_permit2(call.permit2)))
+        }
+        _ => return DecodedOdosCall::Unknown(selector),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+    use alloy_sol_types::SolCall;
+
+    use super::*;
+    use crate::{
+        OdosRouterV2::{inputTokenInfo, outputTokenInfo, swapTokenInfo},
+        OdosV2Router::{swapPermit2Call, transferRouterFundsCall},
+        ODOS_V2_ETHEREUM_ROUTER,
+    };
+
+    // These calls are encoded with the bindings; `test_decode_mainnet_router_calldata`
+    // in tests/fork_tests.rs decodes real mainnet router calldata when
+    // `ODOS_SDK_FORK_URL` is set.
+
+    const USDC: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+    const WETH: Address = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+    const EXECUTOR: Address = address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99");
+    const SIGNER: Address = address!("47E2D28169738039755586743E2dfCF3bd643f86");
+    const PERMIT2: Address = address!("000000000022D473030F116dDEE9F6B43aC78BA3");
+
+    fn token_info() -> swapTokenInfo {
+        swapTokenInfo {
+            inputToken: USDC,
+            inputAmount: U256::from(1_000_000_000u64),
+            inputReceiver: EXECUTOR,
+            outputToken: WETH,
+            outputQuote: U256::from(297_096_823_183_507_284u64),
+            outputMin: U256::from(295_611_339_067_589_747u64),
+            outputReceiver: SIGNER,
+        }
+    }
+
+    fn v2_permit2() -> OdosRouterV2::permit2Info {
+        OdosRouterV2::permit2Info {
+            contractAddress: PERMIT2,
+            nonce: U256::from(3),
+            deadline: U256::from(1_767_225_600u64),
+            signature: Bytes::from_static(&[0xab; 65]),
+        }
+    }
+
+    #[test]
+    fn test_decode_v2_swap() {
+        let call = swapCall {
+            tokenInfo: token_info(),
+            pathDefinition: Bytes::from_static(&[0x01, 0x02]),
+            executor: EXECUTOR,
+            referralCode: 0,
+        };
+
+        let DecodedOdosCall::Swap(swap) =
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &call.abi_encode()).unwrap()
+        else {
+            panic!("expected a swap");
+        };
+        assert_eq!(swap.token_address(), USDC);
+        assert_eq!(swap.amount_in(), U256::from(1_000_000_000u64));
+        assert_eq!(swap.output_token_address(), WETH);
+        assert_eq!(swap.executor(), EXECUTOR);
+    }
+
+    #[test]
+    fn test_decode_v2_swap_permit2() {
+        let call = swapPermit2Call {
+            permit2: v2_permit2(),
+            tokenInfo: token_info(),
+            pathDefinition: Bytes::new(),
+            executor: EXECUTOR,
+            referralCode: 0,
+        };
+
+        let DecodedOdosCall::SwapPermit2 { permit2, swap } =
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &call.abi_encode()).unwrap()
+        else {
+            panic!("expected a Permit2 swap");
+        };
+        assert_eq!(permit2.contract_address, PERMIT2);
+        assert_eq!(permit2.nonce, U256::from(3));
+        assert_eq!(permit2.order_owner, None);
+        assert_eq!(swap.value_out_min(), U256::from(295_611_339_067_589_747u64));
+    }
+
+    #[test]
+    fn test_decode_v2_swap_multi_permit2() {
+        let call = crate::OdosV2Router::swapMultiPermit2Call {
+            permit2: v2_permit2(),
+            inputs: vec![inputTokenInfo {
+                tokenAddress: USDC,
+                amountIn: U256::from(1_000_000_000u64),
+                receiver: EXECUTOR,
+            }],
+            outputs: vec![outputTokenInfo {
+                tokenAddress: WETH,
+                relativeValue: U256::from(1),
+                receiver: SIGNER,
+            }],
+            valueOutMin: U256::from(1),
+            pathDefinition: Bytes::new(),
+            executor: EXECUTOR,
+            referralCode: 0,
+        };
+
+        let DecodedOdosCall::SwapMultiPermit2 { permit2, swap } =
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &call.abi_encode()).unwrap()
+        else {
+            panic!("expected a Permit2 multi swap");
+        };
+        assert_eq!(permit2.signature.len(), 65);
+        assert_eq!(swap.inputs()[0].tokenAddress, USDC);
+        assert_eq!(swap.value_out_min(), Some(U256::from(1)));
+    }
+
+    #[cfg(feature = "v3")]
+    #[test]
+    fn test_decode_v3_swap_multi() {
+        use crate::IOdosRouterV3::{inputTokenInfo, outputTokenInfo, swapReferralInfo};
+
+        let call = OdosV3Router::swapMultiCall {
+            inputs: vec![inputTokenInfo {
+                tokenAddress: USDC,
+                amountIn: U256::from(1_000_000_000u64),
+                receiver: EXECUTOR,
+            }],
+            outputs: vec![outputTokenInfo {
+                tokenAddress: WETH,
+                amountQuote: U256::from(297_096_823_183_507_284u64),
+                amountMin: U256::from(295_611_339_067_589_747u64),
+                receiver: SIGNER,
+            }],
+            pathDefinition: Bytes::new(),
+            executor: EXECUTOR,
+            referralInfo: swapReferralInfo {
+                code: 0,
+                fee: 0,
+                feeRecipient: Address::ZERO,
+            },
+        };
+
+        let DecodedOdosCall::SwapMulti(swap) =
+            decode_router_calldata(crate::ODOS_V3, &call.abi_encode()).unwrap()
+        else {
+            panic!("expected a multi swap");
+        };
+        assert_eq!(swap.outputs()[0].tokenAddress, WETH);
+        assert_eq!(
+            swap.output_amount_mins(),
+            Some(&[U256::from(295_611_339_067_589_747u64)][..])
+        );
+    }
+
+    #[cfg(feature = "v3")]
+    #[test]
+    fn test_decode_v3_swap_permit2() {
+        let call = OdosV3Router::swapPermit2Call {
+            permit2: IOdosRouterV3::permit2Info {
+                contractAddress: PERMIT2,
+                nonce: U256::from(9),
+                deadline: U256::from(1_767_225_600u64),
+                signature: Bytes::from_static(&[0xcd; 65]),
+            },
+            tokenInfo: IOdosRouterV3::swapTokenInfo {
+                inputToken: USDC,
+                inputAmount: U256::from(5_000_000u64),
+                inputReceiver: EXECUTOR,
+                outputToken: WETH,
+                outputQuote: U256::from(2),
+                outputMin: U256::from(1),
+                outputReceiver: SIGNER,
+            },
+            pathDefinition: Bytes::new(),
+            executor: EXECUTOR,
+            referralInfo: IOdosRouterV3::swapReferralInfo {
+                code: 0,
+                fee: 0,
+                feeRecipient: Address::ZERO,
+            },
+        };
+
+        let DecodedOdosCall::SwapPermit2 { permit2, swap } =
+            decode_router_calldata(crate::ODOS_V3, &call.abi_encode()).unwrap()
+        else {
+            panic!("expected a Permit2 swap");
+        };
+        assert_eq!(permit2.nonce, U256::from(9));
+        assert_eq!(swap.amount_in(), U256::from(5_000_000u64));
+    }
+
+    #[cfg(feature = "limit-orders")]
+    #[test]
+    fn test_decode_limit_order_fills() {
+        use crate::limit_order_v2::OdosLimitOrderV2::{
+            fillLimitOrderCall, fillMultiLimitOrderPermit2Call,
+        };
+        use OdosLimitOrderRouter::{
+            LimitOrder, LimitOrderContext, MultiLimitOrder, MultiLimitOrderContext, TokenInfo,
+        };
+
+        let router = crate::ODOS_LO_ETHEREUM_ROUTER;
+        let usdc = TokenInfo::from((USDC, U256::from(2_000_000_000u64)));
+        let weth = TokenInfo::from((WETH, U256::from(10u64).pow(U256::from(18))));
+
+        let fill = fillLimitOrderCall {
+            order: LimitOrder {
+                input: usdc.clone(),
+                output: weth.clone(),
+                expiry: U256::from(1_767_225_600u64),
+                salt: U256::from(42),
+                referralCode: 0,
+                referralFee: 0,
+                referralFeeRecipient: Address::ZERO,
+                partiallyFillable: true,
+            },
+            signature: crate::limit_order_v2::SignatureValidator::Signature {
+                signature: Bytes::from_static(&[0xab; 65]),
+                validationMethod: 0,
+            },
+            context: LimitOrderContext {
+                pathDefinition: Bytes::new(),
+                odosExecutor: EXECUTOR,
+                currentAmount: U256::from(500_000_000u64),
+                inputReceiver: EXECUTOR,
+                minSurplus: U256::ZERO,
+                orderType: U256::ZERO,
+            },
+        };
+        let DecodedOdosCall::LimitOrderFill(decoded) =
+            decode_router_calldata(router, &fill.abi_encode()).unwrap()
+        else {
+            panic!("expected a limit order fill");
+        };
+        assert_eq!(
+            decoded.inputs,
+            vec![(USDC, U256::from(2_000_000_000u64)).into()]
+        );
+        assert!(decoded.partially_fillable);
+        assert_eq!(decoded.fill_amounts, vec![U256::from(500_000_000u64)]);
+        assert_eq!(decoded.permit2, None);
+
+        let multi_fill = fillMultiLimitOrderPermit2Call {
+            order: MultiLimitOrder {
+                inputs: vec![usdc],
+                outputs: vec![weth],
+                expiry: U256::from(1_767_225_600u64),
+                salt: U256::from(43),
+                referralCode: 0,
+                referralFee: 0,
+                referralFeeRecipient: Address::ZERO,
+                partiallyFillable: false,
+            },
+            context: MultiLimitOrderContext {
+                pathDefinition: Bytes::new(),
+                odosExecutor: EXECUTOR,
+                currentAmounts: vec![U256::from(2_000_000_000u64)],
+                inputReceivers: vec![EXECUTOR],
+                minSurplus: vec![U256::ZERO],
+                orderType: U256::ZERO,
+            },
+            permit2: OdosLimitOrderRouter::Permit2Info {
+                contractAddress: PERMIT2,
+                nonce: U256::from(1),
+                deadline: U256::from(1_767_225_600u64),
+                orderOwner: SIGNER,
+                signature: Bytes::from_static(&[0xab; 65]),
+            },
+        };
+        let DecodedOdosCall::LimitOrderFill(decoded) =
+            decode_router_calldata(router, &multi_fill.abi_encode()).unwrap()
+        else {
+            panic!("expected a limit order fill");
+        };
+        assert_eq!(decoded.outputs.len(), 1);
+        assert_eq!(decoded.permit2.unwrap().order_owner, Some(SIGNER));
+    }
+
+    #[test]
+    fn test_decode_unknown_and_invalid_calldata() {
+        let transfer = transferRouterFundsCall {
+            tokens: vec![USDC],
+            amounts: vec![U256::from(1)],
+            dest: SIGNER,
+        };
+        assert!(matches!(
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &transfer.abi_encode()).unwrap(),
+            DecodedOdosCall::Unknown(selector) if selector == transferRouterFundsCall::SELECTOR
+        ));
+        assert!(matches!(
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &[0xde, 0xad, 0xbe, 0xef]).unwrap(),
+            DecodedOdosCall::Unknown(_)
+        ));
+
+        let not_a_router = decode_router_calldata(SIGNER, &transfer.abi_encode()).unwrap_err();
+        assert!(not_a_router.to_string().contains("not a known Odos router"));
+        assert!(decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &[0x01]).is_err());
+
+        // A known selector with truncated arguments
+        let truncated = &swapCall::SELECTOR[..];
+        assert!(matches!(
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, truncated),
+            Err(OdosError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_router_type_for_address() {
+        assert_eq!(
+            router_type_for_address(ODOS_V2_ETHEREUM_ROUTER),
+            Some(RouterType::V2)
+        );
+        assert_eq!(
+            router_type_for_address(crate::ODOS_LO_ARBITRUM_ROUTER),
+            Some(RouterType::LimitOrder)
+        );
+        assert_eq!(router_type_for_address(SIGNER), None);
+    }
+}
@@ -2,17 +2,34 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fmt, time::Duration};
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
 
-use alloy_primitives::hex;
+use alloy_primitives::{hex, Address, B256, U256};
 use reqwest::StatusCode;
+use serde::Serialize;
 use thiserror::Error;
 
 use crate::{
-    error_code::{OdosErrorCode, TraceId},
-    OdosChainError,
+    error_code::{ErrorAction, OdosErrorCode, TraceId},
+    AssemblyDiscrepancy, OdosChainError, PathId, RateLimitInfo, RouterRevert, SimulationError,
 };
 
+/// Which deadline an [`OdosError::Timeout`] refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeoutPhase {
+    /// A single HTTP request exceeded [`ClientConfig::timeout`](crate::ClientConfig::timeout)
+    Request,
+    /// A multi-request operation exhausted its overall budget, e.g.
+    /// [`SwapBuilder::total_budget`](crate::SwapBuilder::total_budget)
+    Total,
+}
+
+/// Bytes of a response body kept in [`OdosError::ResponseDecode`]
+const BODY_SNIPPET_BYTES: usize = 2048;
+
 /// Result type alias for Odos SDK operations
 pub type Result<T> = std::result::Result<T, OdosError>;
 
@@ -21,11 +38,13 @@ pub type Result<T> = std::result::Result<T, OdosError>;
 /// `ApiErrorBody` collects the fields that are common to every error the Odos
 /// service returns over HTTP — the human-readable message, the strongly-typed
 /// [`OdosErrorCode`], and an optional [`TraceId`] for support correspondence.
-/// It is used by both [`OdosError::Api`] (status-bearing failures) and
-/// [`OdosError::RateLimit`] (retry-after-bearing failures) so the orthogonal
+/// It is used by [`OdosError::Api`] and [`OdosError::Unauthorized`]
+/// (status-bearing failures) and [`OdosError::RateLimit`]
+/// (retry-after-bearing failures) so the orthogonal
 /// per-variant fields are the only thing those variants carry directly.
 ///
-/// The [`Display`](fmt::Display) impl renders `"<message>[ [trace: <id>]]"`,
+/// The [`Display`](fmt::Display) impl renders
+/// `"<message>[ [trace: <id>]][ [client trace: <id>]]"`,
 /// which the surrounding variants embed into their own format strings.
 #[derive(Debug, Clone)]
 pub struct ApiErrorBody {
@@ -35,6 +54,10 @@ pub struct ApiErrorBody {
     pub code: OdosErrorCode,
     /// Trace ID for support correspondence, if the API returned one.
     pub trace_id: Option<TraceId>,
+    /// Correlation ID the client sent as `x-trace-id`, when
+    /// [`ClientConfig::send_client_trace_id`](crate::ClientConfig::send_client_trace_id)
+    /// is set.
+    pub client_trace_id: Option<TraceId>,
 }
 
 impl fmt::Display for ApiErrorBody {
@@ -43,10 +66,57 @@ impl fmt::Display for ApiErrorBody {
         if let Some(trace_id) = self.trace_id {
             write!(f, " [trace: {trace_id}]")?;
         }
+        if let Some(client_trace_id) = self.client_trace_id {
+            write!(f, " [client trace: {client_trace_id}]")?;
+        }
         Ok(())
     }
 }
 
+/// Structured summary of an [`OdosError`] for logging pipelines
+///
+/// Built by [`OdosError::to_report`]. Serializes to a flat JSON object with
+/// every field present, `null` when it does not apply, so the shape stays
+/// the same for every error:
+///
+/// ```json
+/// {
+///   "category": "api",
+///   "message": "Odos API error (status: 500 Internal Server Error): Algo failed [trace: 10becdc8-a021-4491-8201-a17b657204e0]",
+///   "http_status": 500,
+///   "error_code": 2999,
+///   "error_code_name": "ALGO_INTERNAL",
+///   "trace_id": "10becdc8-a021-4491-8201-a17b657204e0",
+///   "client_trace_id": null,
+///   "endpoint": "quote",
+///   "retryable": false,
+///   "suggested_retry_delay_ms": 2000
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorReport {
+    /// [`OdosError::category`]
+    pub category: &'static str,
+    /// The error's `Display` output
+    pub message: String,
+    /// [`OdosError::status`], as a number
+    pub http_status: Option<u16>,
+    /// Numeric [`OdosErrorCode`]
+    pub error_code: Option<u16>,
+    /// [`OdosErrorCode::name`]
+    pub error_code_name: Option<&'static str>,
+    /// [`OdosError::trace_id`]
+    pub trace_id: Option<TraceId>,
+    /// [`OdosError::client_trace_id`]
+    pub client_trace_id: Option<TraceId>,
+    /// [`OdosError::endpoint`]
+    pub endpoint: Option<&'static str>,
+    /// [`OdosError::is_retryable`]
+    pub retryable: bool,
+    /// [`OdosError::suggested_retry_delay`], in milliseconds
+    pub suggested_retry_delay_ms: Option<u64>,
+}
+
 /// Comprehensive error types for the Odos SDK
 ///
 /// This enum provides detailed error types for different failure scenarios,
@@ -66,8 +136,10 @@ impl fmt::Display for ApiErrorBody {
 /// - Certain HTTP errors (5xx status codes, connection issues)
 /// - Some API errors (server errors)
 ///
-/// **Note**: Rate limiting errors (429) are NOT retryable. Applications must handle
-/// rate limits globally with proper coordination rather than retrying individual requests.
+/// **Note**: Rate limiting errors (429) are NOT retryable, so the client does not
+/// retry them by default. Applications should handle rate limits globally with
+/// proper coordination, or opt in to waiting for `Retry-After` with
+/// [`RetryConfig::retry_rate_limits`](crate::RetryConfig::retry_rate_limits).
 ///
 /// ## Examples
 ///
@@ -90,23 +162,78 @@ impl fmt::Display for ApiErrorBody {
 /// assert_eq!(timeout_error.category(), "timeout");
 /// assert_eq!(rate_limit_error.category(), "rate_limit");
 /// ```
+///
+/// ## Matching
+///
+/// New variants may be added in minor releases, so matches need a wildcard
+/// arm. [`category`](Self::category), [`is_retryable`](Self::is_retryable)
+/// and the other accessors stay meaningful for variants added later:
+///
+/// ```rust
+/// use odos_sdk::OdosError;
+///
+/// fn log(error: &OdosError) {
+///     match error {
+///         OdosError::RateLimit { retry_after, .. } => {
+///             eprintln!("rate limited, retry after {retry_after:?}");
+///         }
+///         OdosError::Timeout { .. } => eprintln!("timed out"),
+///         other => eprintln!("{} error: {other}", other.category()),
+///     }
+/// }
+/// ```
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum OdosError {
     /// HTTP request errors
-    #[error("HTTP request failed: {0}")]
-    Http(#[from] reqwest::Error),
+    ///
+    /// `client_trace_id` is the ID sent with the request when
+    /// [`ClientConfig::send_client_trace_id`](crate::ClientConfig::send_client_trace_id)
+    /// is set; see [`OdosError::client_trace_id`].
+    #[error("HTTP request failed: {source}")]
+    Http {
+        #[source]
+        source: reqwest::Error,
+        client_trace_id: Option<TraceId>,
+    },
 
     /// API errors returned by the Odos service
+    ///
+    /// `endpoint` names the API operation that failed, such as `"quote"` or
+    /// `"assemble"`; see [`OdosError::endpoint`].
     #[error("Odos API error (status: {status}): {body}")]
     Api {
         status: StatusCode,
         body: ApiErrorBody,
+        endpoint: Option<&'static str>,
+    },
+
+    /// The API rejected the request's credentials with a 401 or 403
+    ///
+    /// Usually a missing or invalid [`ApiKey`](crate::ApiKey), or a key
+    /// that is not enabled for the configured host.
+    #[error("Odos API rejected the request as unauthorized (status: {status}): {body}")]
+    Unauthorized {
+        status: StatusCode,
+        body: ApiErrorBody,
+        endpoint: Option<&'static str>,
     },
 
     /// JSON serialization/deserialization errors
     #[error("JSON processing error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// A successful response's body did not match the expected shape
+    ///
+    /// `body` holds the start of the body, up to 2 KB, so the cause can be
+    /// seen without reproducing the request.
+    #[error("Failed to decode response body: {source}; body: {body}")]
+    ResponseDecode {
+        #[source]
+        source: serde_json::Error,
+        body: String,
+    },
+
     /// Hex decoding errors
     #[error("Hex decoding error: {0}")]
     Hex(#[from] hex::FromHexError),
@@ -135,29 +262,234 @@ pub enum OdosError {
     #[error("Quote request failed: {0}")]
     QuoteRequest(String),
 
+    /// An output leg's implied slippage exceeds its per-token override
+    ///
+    /// Both values are percentages. `actual` is the loss implied by the
+    /// leg's quoted USD output value against its share of the USD input
+    /// value; see [`SwapBuilder::output_slippage`](crate::SwapBuilder::output_slippage).
+    #[error("Quote rejected: implied slippage for output {token} is {actual:.2}%, exceeding limit of {limit}%")]
+    QuoteRejected {
+        token: Address,
+        actual: f64,
+        limit: f64,
+    },
+
+    /// Quoted price impact exceeds the configured limit
+    ///
+    /// Both values are percentages. `actual` keeps the sign reported by the
+    /// API; the limit is compared against its magnitude.
+    #[error("Price impact too high: {actual}% exceeds limit of {limit}%")]
+    PriceImpactTooHigh { actual: f64, limit: f64 },
+
+    /// Quoted output amount is below the configured minimum
+    ///
+    /// Both values are in the output token's base units.
+    #[error("Quoted output {quoted} is below the minimum of {minimum}")]
+    OutputBelowMinimum { quoted: U256, minimum: U256 },
+
+    /// The API simulated the assembled swap and it would fail on-chain
+    ///
+    /// Only returned when simulation is requested, e.g. by
+    /// [`OdosClient::assemble_with_simulation`](crate::OdosClient::assemble_with_simulation).
+    #[error("Assembled swap simulation failed: {}", error.error_message())]
+    SimulationFailed { error: SimulationError },
+
+    /// A local `eth_call` simulation of the assembled swap reverted
+    ///
+    /// Returned by [`simulate_swap`](crate::simulate_swap), and so by
+    /// [`SwapBuilder::execute`](crate::SwapBuilder::execute) with
+    /// [`simulate_before_send`](crate::SwapBuilder::simulate_before_send)
+    /// enabled. `reason` is the decoded revert reason, if recognized, and
+    /// `message` the node's error message.
+    #[error("Swap simulation reverted: {}", reason.as_ref().map_or(message.clone(), ToString::to_string))]
+    SimulationReverted {
+        reason: Option<RouterRevert>,
+        message: String,
+    },
+
+    /// The router address reported by the Odos API differs from the
+    /// expected address
+    ///
+    /// Returned by [`OdosClient::verify_router_address`](crate::OdosClient::verify_router_address)
+    /// when the constant compiled into this SDK is likely stale, and by
+    /// [`OdosClient::assemble`](crate::OdosClient::assemble) when the API
+    /// assembled the swap for a different router than the request named.
+    /// Swaps assembled by the API target the reported router, so approvals
+    /// made against the expected address would not apply.
+    #[error(
+        "Router address mismatch on chain {chain_id}: SDK has {expected}, API reports {reported}"
+    )]
+    RouterAddressMismatch {
+        chain_id: u64,
+        expected: Address,
+        reported: Address,
+    },
+
+    /// The swap was assembled for a different chain than it was quoted on
+    ///
+    /// Returned by [`SwapBuilder::build_transaction`](crate::SwapBuilder::build_transaction)
+    /// when the assembled transaction's chain is not the quote's, as when the
+    /// path ID was quoted on another chain. Signing the transaction for the
+    /// quoted chain would send it to the wrong router.
+    #[error("Chain mismatch: swap quoted on chain {expected}, assembled for chain {reported}")]
+    ChainMismatch { expected: u64, reported: u64 },
+
+    /// A quote was fetched for a different swap than the one being built
+    ///
+    /// Returned by [`SwapBuilder::build_transaction_from_quote`](crate::SwapBuilder::build_transaction_from_quote)
+    /// when the quote's chain, input token, input amount or output tokens
+    /// differ from the builder's. `field` names the first difference, and
+    /// `expected` and `quoted` are the builder's and the quote's values.
+    #[error("Quote does not match the swap: {field} is {quoted}, expected {expected}")]
+    QuoteMismatch {
+        field: &'static str,
+        expected: String,
+        quoted: String,
+    },
+
+    /// The assembled calldata does not match the quote and assembly request
+    ///
+    /// Returned by [`AssemblyVerification::into_result`](crate::AssemblyVerification::into_result),
+    /// and so by [`SwapBuilder::build_transaction`](crate::SwapBuilder::build_transaction)
+    /// with [`verify_assembly`](crate::SwapBuilder::verify_assembly) enabled.
+    /// The transaction must not be signed.
+    #[error(
+        "Assembled calldata does not match the quote: {}",
+        join_discrepancies(discrepancies)
+    )]
+    AssemblyVerificationFailed {
+        discrepancies: Vec<AssemblyDiscrepancy>,
+    },
+
+    /// The quoted path expired before the swap could be assembled
+    ///
+    /// Returned by [`SwapBuilder`](crate::SwapBuilder) when assembly reports
+    /// an expired path ID and no [`auto_requote`](crate::SwapBuilder::auto_requote)
+    /// attempts are left. `requotes` is how many fresh quotes were tried, and
+    /// `source` is the last assemble error.
+    #[error("Quoted path {path_id} expired before assembly after {requotes} re-quotes: {source}")]
+    PathExpired {
+        path_id: PathId,
+        requotes: u32,
+        #[source]
+        source: Box<OdosError>,
+    },
+
+    /// The signer holds less of the input token than the swap spends
+    ///
+    /// `shortfall` is how much more is needed, in the token's base units.
+    #[error("Insufficient balance of {token}: need {required}, short by {shortfall}")]
+    InsufficientBalance {
+        token: Address,
+        required: U256,
+        shortfall: U256,
+    },
+
+    /// The router's allowance for the input token is below the swap amount
+    ///
+    /// `shortfall` is how much more must be approved to `spender`, in the
+    /// token's base units.
+    #[error(
+        "Insufficient allowance of {token} for {spender}: need {required}, short by {shortfall}"
+    )]
+    InsufficientAllowance {
+        token: Address,
+        spender: Address,
+        required: U256,
+        shortfall: U256,
+    },
+
+    /// Estimating gas or fees for a transaction failed
+    ///
+    /// `eth_estimateGas` fails when the node simulates the transaction and it
+    /// reverts, so this usually means the swap would revert if sent.
+    #[error("Gas estimation failed: {0}")]
+    GasEstimation(String),
+
+    /// Sending a transaction, or fetching the nonce for it, failed
+    ///
+    /// Nothing was broadcast, so the transaction can be rebuilt and sent again.
+    #[error("Transaction submission failed: {0}")]
+    TransactionSubmission(String),
+
+    /// Waiting for a submitted transaction's receipt failed
+    ///
+    /// The transaction was broadcast and may still be mined; check `tx_hash`
+    /// before sending the swap again.
+    #[error("Waiting for transaction {tx_hash} failed: {message}")]
+    TransactionConfirmation { tx_hash: B256, message: String },
+
+    /// A transaction was mined but reverted
+    ///
+    /// `reason` is the decoded revert reason, if it could be recovered by
+    /// replaying the transaction; see [`decode_router_revert`](crate::decode_router_revert).
+    #[error("Transaction {tx_hash} reverted after using {gas_used} gas{}", revert_suffix(reason.as_ref()))]
+    TransactionReverted {
+        tx_hash: B256,
+        gas_used: u64,
+        reason: Option<RouterRevert>,
+    },
+
+    /// Signing a message or typed data failed
+    #[error("Signing failed: {0}")]
+    Signing(String),
+
     /// Configuration errors
     #[error("Configuration error: {0}")]
     Configuration(String),
 
     /// Timeout errors
-    #[error("Operation timed out: {0}")]
-    Timeout(String),
+    ///
+    /// `phase` tells whether a single request exceeded the client timeout or
+    /// a multi-request operation exhausted its overall budget.
+    /// `client_trace_id` is set as for [`OdosError::Http`].
+    #[error("Operation timed out: {message}")]
+    Timeout {
+        phase: TimeoutPhase,
+        message: String,
+        client_trace_id: Option<TraceId>,
+    },
 
     /// Rate limit exceeded
     ///
     /// Contains an optional `retry_after` duration from the Retry-After HTTP header,
-    /// alongside the shared [`ApiErrorBody`] (message, error code, and trace ID).
+    /// alongside the shared [`ApiErrorBody`] (message, error code, and trace ID),
+    /// the [`RateLimitInfo`] headers of the rejected response, if any, and
+    /// the API operation that was rejected (see [`OdosError::endpoint`]).
     #[error("Rate limit exceeded: {body}")]
     RateLimit {
         retry_after: Option<Duration>,
         body: ApiErrorBody,
+        rate_limit_info: Option<Box<RateLimitInfo>>,
+        endpoint: Option<&'static str>,
     },
 
+    /// The client's circuit breaker for `endpoint` is open
+    ///
+    /// The request was not sent. The breaker lets a probe through again at
+    /// `retry_at`; see [`CircuitBreakerConfig`](crate::CircuitBreakerConfig).
+    #[error("Circuit breaker open for {endpoint}")]
+    CircuitOpen { endpoint: String, retry_at: Instant },
+
     /// Generic internal error
     #[error("Internal error: {0}")]
     Internal(String),
 }
 
+/// Format the reason of an [`OdosError::TransactionReverted`], if known
+fn revert_suffix(reason: Option<&RouterRevert>) -> String {
+    reason.map_or_else(String::new, |reason| format!(": {reason}"))
+}
+
+/// Format the discrepancies of an [`OdosError::AssemblyVerificationFailed`]
+fn join_discrepancies(discrepancies: &[AssemblyDiscrepancy]) -> String {
+    discrepancies
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 impl OdosError {
     /// Create an API error from response (without error code or trace ID)
     pub fn api_error(status: StatusCode, message: String) -> Self {
@@ -177,7 +509,253 @@ impl OdosError {
                 message,
                 code,
                 trace_id,
+                client_trace_id: None,
+            },
+            endpoint: None,
+        }
+    }
+
+    /// Create an unauthorized error (without error code or trace ID)
+    pub fn unauthorized(status: StatusCode, message: impl Into<String>) -> Self {
+        Self::Unauthorized {
+            status,
+            body: ApiErrorBody {
+                message: message.into(),
+                code: OdosErrorCode::Unknown(0),
+                trace_id: None,
+                client_trace_id: None,
+            },
+            endpoint: None,
+        }
+    }
+
+    /// Create the error for a failed API response: [`OdosError::Unauthorized`]
+    /// for a 401 or 403, [`OdosError::Api`] otherwise
+    pub(crate) fn from_response(status: StatusCode, body: ApiErrorBody) -> Self {
+        if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
+            Self::Unauthorized {
+                status,
+                body,
+                endpoint: None,
+            }
+        } else {
+            Self::Api {
+                status,
+                body,
+                endpoint: None,
+            }
+        }
+    }
+
+    /// Copy the error for another caller waiting on the same request
+    ///
+    /// Variants are kept, except that an [`OdosError::Http`] becomes a
+    /// request [`OdosError::Timeout`] if it timed out and an
+    /// [`OdosError::Internal`] otherwise, since `reqwest` errors cannot be
+    /// copied. JSON errors keep their message but lose their position.
+    pub(crate) fn duplicate(&self) -> Self {
+        use serde::de::Error as _;
+
+        match self {
+            OdosError::Http {
+                source,
+                client_trace_id,
+            } if source.is_timeout() => OdosError::Timeout {
+                phase: TimeoutPhase::Request,
+                message: source.to_string(),
+                client_trace_id: *client_trace_id,
+            },
+            OdosError::Http { source, .. } => {
+                Self::Internal(format!("HTTP request failed: {source}"))
+            }
+            OdosError::Api {
+                status,
+                body,
+                endpoint,
+            } => OdosError::Api {
+                status: *status,
+                body: body.clone(),
+                endpoint: *endpoint,
+            },
+            OdosError::Unauthorized {
+                status,
+                body,
+                endpoint,
+            } => OdosError::Unauthorized {
+                status: *status,
+                body: body.clone(),
+                endpoint: *endpoint,
+            },
+            OdosError::Json(err) => OdosError::Json(serde_json::Error::custom(err)),
+            OdosError::ResponseDecode { source, body } => OdosError::ResponseDecode {
+                source: serde_json::Error::custom(source),
+                body: body.clone(),
+            },
+            OdosError::Hex(err) => OdosError::Hex(*err),
+            OdosError::InvalidInput(message) => OdosError::InvalidInput(message.clone()),
+            OdosError::MissingData(message) => OdosError::MissingData(message.clone()),
+            OdosError::UnsupportedChain { chain_id } => OdosError::UnsupportedChain {
+                chain_id: *chain_id,
+            },
+            OdosError::Contract(message) => OdosError::Contract(message.clone()),
+            OdosError::TransactionAssembly(message) => {
+                OdosError::TransactionAssembly(message.clone())
+            }
+            OdosError::QuoteRequest(message) => OdosError::QuoteRequest(message.clone()),
+            OdosError::QuoteRejected {
+                token,
+                actual,
+                limit,
+            } => OdosError::QuoteRejected {
+                token: *token,
+                actual: *actual,
+                limit: *limit,
+            },
+            OdosError::PriceImpactTooHigh { actual, limit } => OdosError::PriceImpactTooHigh {
+                actual: *actual,
+                limit: *limit,
             },
+            OdosError::OutputBelowMinimum { quoted, minimum } => OdosError::OutputBelowMinimum {
+                quoted: *quoted,
+                minimum: *minimum,
+            },
+            OdosError::SimulationFailed { error } => OdosError::SimulationFailed {
+                error: error.clone(),
+            },
+            OdosError::SimulationReverted { reason, message } => OdosError::SimulationReverted {
+                reason: reason.clone(),
+                message: message.clone(),
+            },
+            OdosError::RouterAddressMismatch {
+                chain_id,
+                expected,
+                reported,
+            } => OdosError::RouterAddressMismatch {
+                chain_id: *chain_id,
+                expected: *expected,
+                reported: *reported,
+            },
+            OdosError::QuoteMismatch {
+                field,
+                expected,
+                quoted,
+            } => OdosError::QuoteMismatch {
+                field,
+                expected: expected.clone(),
+                quoted: quoted.clone(),
+            },
+            OdosError::ChainMismatch { expected, reported } => OdosError::ChainMismatch {
+                expected: *expected,
+                reported: *reported,
+            },
+            OdosError::AssemblyVerificationFailed { discrepancies } => {
+                OdosError::AssemblyVerificationFailed {
+                    discrepancies: discrepancies.clone(),
+                }
+            }
+            OdosError::PathExpired {
+                path_id,
+                requotes,
+                source,
+            } => OdosError::PathExpired {
+                path_id: path_id.clone(),
+                requotes: *requotes,
+                source: Box::new(source.duplicate()),
+            },
+            OdosError::InsufficientBalance {
+                token,
+                required,
+                shortfall,
+            } => OdosError::InsufficientBalance {
+                token: *token,
+                required: *required,
+                shortfall: *shortfall,
+            },
+            OdosError::InsufficientAllowance {
+                token,
+                spender,
+                required,
+                shortfall,
+            } => OdosError::InsufficientAllowance {
+                token: *token,
+                spender: *spender,
+                required: *required,
+                shortfall: *shortfall,
+            },
+            OdosError::GasEstimation(message) => OdosError::GasEstimation(message.clone()),
+            OdosError::TransactionSubmission(message) => {
+                OdosError::TransactionSubmission(message.clone())
+            }
+            OdosError::TransactionConfirmation { tx_hash, message } => {
+                OdosError::TransactionConfirmation {
+                    tx_hash: *tx_hash,
+                    message: message.clone(),
+                }
+            }
+            OdosError::TransactionReverted {
+                tx_hash,
+                gas_used,
+                reason,
+            } => OdosError::TransactionReverted {
+                tx_hash: *tx_hash,
+                gas_used: *gas_used,
+                reason: reason.clone(),
+            },
+            OdosError::Signing(message) => OdosError::Signing(message.clone()),
+            OdosError::Configuration(message) => OdosError::Configuration(message.clone()),
+            OdosError::Timeout {
+                phase,
+                message,
+                client_trace_id,
+            } => OdosError::Timeout {
+                phase: *phase,
+                message: message.clone(),
+                client_trace_id: *client_trace_id,
+            },
+            OdosError::RateLimit {
+                retry_after,
+                body,
+                rate_limit_info,
+                endpoint,
+            } => OdosError::RateLimit {
+                retry_after: *retry_after,
+                body: body.clone(),
+                rate_limit_info: rate_limit_info.clone(),
+                endpoint: *endpoint,
+            },
+            OdosError::CircuitOpen { endpoint, retry_at } => OdosError::CircuitOpen {
+                endpoint: endpoint.clone(),
+                retry_at: *retry_at,
+            },
+            OdosError::Internal(message) => OdosError::Internal(message.clone()),
+        }
+    }
+
+    /// Name the API operation an API-shaped error came from, unless one is
+    /// already set
+    pub(crate) fn with_endpoint(mut self, name: &'static str) -> Self {
+        if let OdosError::Api { endpoint, .. }
+        | OdosError::Unauthorized { endpoint, .. }
+        | OdosError::RateLimit { endpoint, .. } = &mut self
+        {
+            endpoint.get_or_insert(name);
+        }
+        self
+    }
+
+    /// Create a response decode error, keeping the first 2 KB of `body`
+    pub fn response_decode(source: serde_json::Error, body: &str) -> Self {
+        let mut end = body.len().min(BODY_SNIPPET_BYTES);
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut snippet = body[..end].to_string();
+        if end < body.len() {
+            snippet.push('…');
+        }
+        Self::ResponseDecode {
+            source,
+            body: snippet,
         }
     }
 
@@ -211,14 +789,156 @@ impl OdosError {
         Self::QuoteRequest(message.into())
     }
 
+    /// Create a quote rejected error
+    pub fn quote_rejected(token: Address, actual: f64, limit: f64) -> Self {
+        Self::QuoteRejected {
+            token,
+            actual,
+            limit,
+        }
+    }
+
+    /// Create a price impact too high error
+    pub fn price_impact_too_high(actual: f64, limit: f64) -> Self {
+        Self::PriceImpactTooHigh { actual, limit }
+    }
+
+    /// Create an output below minimum error
+    pub fn output_below_minimum(quoted: U256, minimum: U256) -> Self {
+        Self::OutputBelowMinimum { quoted, minimum }
+    }
+
+    /// Create a simulation failure error
+    pub fn simulation_failed(error: SimulationError) -> Self {
+        Self::SimulationFailed { error }
+    }
+
+    /// Create a local simulation revert error
+    pub fn simulation_reverted(reason: Option<RouterRevert>, message: impl Into<String>) -> Self {
+        Self::SimulationReverted {
+            reason,
+            message: message.into(),
+        }
+    }
+
+    /// Create a router address mismatch error
+    pub fn router_address_mismatch(chain_id: u64, expected: Address, reported: Address) -> Self {
+        Self::RouterAddressMismatch {
+            chain_id,
+            expected,
+            reported,
+        }
+    }
+
+    /// Create a chain mismatch error
+    pub fn chain_mismatch(expected: u64, reported: u64) -> Self {
+        Self::ChainMismatch { expected, reported }
+    }
+
+    /// Create a quote mismatch error
+    pub fn quote_mismatch(
+        field: &'static str,
+        expected: impl ToString,
+        quoted: impl ToString,
+    ) -> Self {
+        Self::QuoteMismatch {
+            field,
+            expected: expected.to_string(),
+            quoted: quoted.to_string(),
+        }
+    }
+
+    /// Create an assembly verification error
+    pub fn assembly_verification_failed(discrepancies: Vec<AssemblyDiscrepancy>) -> Self {
+        Self::AssemblyVerificationFailed { discrepancies }
+    }
+
+    /// Create a path expired error wrapping the last assemble error
+    pub fn path_expired(path_id: PathId, requotes: u32, source: OdosError) -> Self {
+        Self::PathExpired {
+            path_id,
+            requotes,
+            source: Box::new(source),
+        }
+    }
+
+    /// Create an insufficient balance error
+    pub fn insufficient_balance(token: Address, required: U256, shortfall: U256) -> Self {
+        Self::InsufficientBalance {
+            token,
+            required,
+            shortfall,
+        }
+    }
+
+    /// Create an insufficient allowance error
+    pub fn insufficient_allowance(
+        token: Address,
+        spender: Address,
+        required: U256,
+        shortfall: U256,
+    ) -> Self {
+        Self::InsufficientAllowance {
+            token,
+            spender,
+            required,
+            shortfall,
+        }
+    }
+
+    /// Create a gas estimation error
+    pub fn gas_estimation_error(message: impl Into<String>) -> Self {
+        Self::GasEstimation(message.into())
+    }
+
+    /// Create a transaction submission error
+    pub fn transaction_submission_error(message: impl Into<String>) -> Self {
+        Self::TransactionSubmission(message.into())
+    }
+
+    /// Create a transaction confirmation error
+    pub fn transaction_confirmation_error(tx_hash: B256, message: impl Into<String>) -> Self {
+        Self::TransactionConfirmation {
+            tx_hash,
+            message: message.into(),
+        }
+    }
+
+    /// Create a transaction reverted error
+    pub fn transaction_reverted(tx_hash: B256, gas_used: u64) -> Self {
+        Self::TransactionReverted {
+            tx_hash,
+            gas_used,
+            reason: None,
+        }
+    }
+
+    /// Create a signing error
+    pub fn signing_error(message: impl Into<String>) -> Self {
+        Self::Signing(message.into())
+    }
+
     /// Create a configuration error
     pub fn configuration_error(message: impl Into<String>) -> Self {
         Self::Configuration(message.into())
     }
 
-    /// Create a timeout error
+    /// Create a timeout error for a single request
     pub fn timeout_error(message: impl Into<String>) -> Self {
-        Self::Timeout(message.into())
+        Self::Timeout {
+            phase: TimeoutPhase::Request,
+            message: message.into(),
+            client_trace_id: None,
+        }
+    }
+
+    /// Create a timeout error for an exhausted total time budget
+    pub fn total_timeout(message: impl Into<String>) -> Self {
+        Self::Timeout {
+            phase: TimeoutPhase::Total,
+            message: message.into(),
+            client_trace_id: None,
+        }
     }
 
     /// Create a rate limit error with optional retry-after duration
@@ -252,7 +972,18 @@ impl OdosError {
                 message: message.into(),
                 code,
                 trace_id,
+                client_trace_id: None,
             },
+            rate_limit_info: None,
+            endpoint: None,
+        }
+    }
+
+    /// Create a circuit open error
+    pub fn circuit_open(endpoint: impl Into<String>, retry_at: Instant) -> Self {
+        Self::CircuitOpen {
+            endpoint: endpoint.into(),
+            retry_at,
         }
     }
 
@@ -281,8 +1012,10 @@ impl OdosError {
     /// [`RetryPredicate::DefaultExcept`]: crate::RetryPredicate::DefaultExcept
     pub fn is_retryable(&self) -> bool {
         match self {
-            OdosError::Http(err) => err.is_timeout() || err.is_connect() || err.is_request(),
-            OdosError::Api { status, body } => {
+            OdosError::Http { source: err, .. } => {
+                err.is_timeout() || err.is_connect() || err.is_request()
+            }
+            OdosError::Api { status, body, .. } => {
                 if matches!(body.code, OdosErrorCode::Unknown(_)) {
                     matches!(
                         *status,
@@ -295,10 +1028,15 @@ impl OdosError {
                     body.code.is_retryable()
                 }
             }
-            OdosError::Timeout(_) => true,
+            // The budget is gone; retrying within it cannot succeed
+            OdosError::Timeout { phase, .. } => *phase == TimeoutPhase::Request,
             // NEVER retry rate limits - application must handle globally
             OdosError::RateLimit { .. } => false,
-            OdosError::Json(_)
+            // Retrying immediately would fail fast again
+            OdosError::CircuitOpen { .. } => false,
+            OdosError::Unauthorized { .. }
+            | OdosError::Json(_)
+            | OdosError::ResponseDecode { .. }
             | OdosError::Hex(_)
             | OdosError::InvalidInput(_)
             | OdosError::MissingData(_)
@@ -306,6 +1044,23 @@ impl OdosError {
             | OdosError::Contract(_)
             | OdosError::TransactionAssembly(_)
             | OdosError::QuoteRequest(_)
+            | OdosError::QuoteRejected { .. }
+            | OdosError::PriceImpactTooHigh { .. }
+            | OdosError::OutputBelowMinimum { .. }
+            | OdosError::SimulationFailed { .. }
+            | OdosError::SimulationReverted { .. }
+            | OdosError::RouterAddressMismatch { .. }
+            | OdosError::ChainMismatch { .. }
+            | OdosError::QuoteMismatch { .. }
+            | OdosError::AssemblyVerificationFailed { .. }
+            | OdosError::PathExpired { .. }
+            | OdosError::InsufficientBalance { .. }
+            | OdosError::InsufficientAllowance { .. }
+            | OdosError::GasEstimation(_)
+            | OdosError::TransactionSubmission(_)
+            | OdosError::TransactionConfirmation { .. }
+            | OdosError::TransactionReverted { .. }
+            | OdosError::Signing(_)
             | OdosError::Configuration(_)
             | OdosError::Internal(_) => false,
         }
@@ -364,6 +1119,106 @@ impl OdosError {
         }
     }
 
+    /// Get the HTTP status of the response that caused the error
+    ///
+    /// Returns the status of API, unauthorized and rate limit (429) errors,
+    /// and of HTTP errors that carry one. `None` means no response was
+    /// involved, e.g. a connection failure, timeout or client-side check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::OdosError;
+    /// use reqwest::StatusCode;
+    ///
+    /// let error = OdosError::api_error(StatusCode::BAD_GATEWAY, "Bad gateway".to_string());
+    /// assert_eq!(error.status(), Some(StatusCode::BAD_GATEWAY));
+    /// assert_eq!(OdosError::timeout_error("Timed out").status(), None);
+    /// ```
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            OdosError::Http { source, .. } => source.status(),
+            OdosError::Api { status, .. } | OdosError::Unauthorized { status, .. } => Some(*status),
+            OdosError::RateLimit { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            _ => None,
+        }
+    }
+
+    /// Get the API operation that returned the error
+    ///
+    /// Set on API, unauthorized and rate limit errors returned by
+    /// [`OdosClient`](crate::OdosClient) methods. The names are stable, so
+    /// they can label metrics together with [`category`](Self::category)
+    /// and [`status`](Self::status):
+    ///
+    /// | Name | Methods |
+    /// | ---- | ------- |
+    /// | `quote` | [`quote`](crate::OdosClient::quote) |
+    /// | `assemble` | [`assemble`](crate::OdosClient::assemble), [`assemble_tx_data`](crate::OdosClient::assemble_tx_data) and the other assemble methods |
+    /// | `router_info` | [`approval_spender`](crate::OdosClient::approval_spender) |
+    /// | `tokens` | [`supported_tokens`](crate::OdosClient::supported_tokens) |
+    /// | `contract_info` | [`contract_info`](crate::OdosClient::contract_info), [`verify_router_address`](crate::OdosClient::verify_router_address) |
+    /// | `liquidity_sources` | [`liquidity_sources`](crate::OdosClient::liquidity_sources) |
+    /// | `chains` | [`supported_chains`](crate::OdosClient::supported_chains), [`verify_chain_constants`](crate::OdosClient::verify_chain_constants) |
+    /// | `health_check` | [`health_check`](crate::OdosClient::health_check) |
+    /// | `limit_order_place`, `limit_orders`, `limit_order_cancel` | the limit order methods |
+    ///
+    /// Returns `None` for other errors, and for errors built by hand or by
+    /// [`OdosHttpClient`](crate::OdosHttpClient) directly.
+    pub fn endpoint(&self) -> Option<&'static str> {
+        match self {
+            OdosError::Api { endpoint, .. }
+            | OdosError::Unauthorized { endpoint, .. }
+            | OdosError::RateLimit { endpoint, .. } => *endpoint,
+            _ => None,
+        }
+    }
+
+    /// Summarize the error for structured logs
+    ///
+    /// Only the message is allocated; see [`ErrorReport`] for the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::OdosError;
+    ///
+    /// # fn log(error: &OdosError) -> serde_json::Result<()> {
+    /// println!("{}", serde_json::to_string(&error.to_report())?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_report(&self) -> ErrorReport {
+        let code = self.error_code();
+        ErrorReport {
+            category: self.category(),
+            message: self.to_string(),
+            http_status: self.status().map(|status| status.as_u16()),
+            error_code: code.map(OdosErrorCode::code),
+            error_code_name: code.map(OdosErrorCode::name),
+            trace_id: self.trace_id(),
+            client_trace_id: self.client_trace_id(),
+            endpoint: self.endpoint(),
+            retryable: self.is_retryable(),
+            suggested_retry_delay_ms: self
+                .suggested_retry_delay()
+                .map(|delay| u64::try_from(delay.as_millis()).unwrap_or(u64::MAX)),
+        }
+    }
+
+    /// Get the server-reported rate limit state for rate limit errors
+    ///
+    /// Returns `None` for other errors, or if the rejected response carried
+    /// no rate limit headers.
+    pub fn rate_limit_info(&self) -> Option<RateLimitInfo> {
+        match self {
+            OdosError::RateLimit {
+                rate_limit_info, ..
+            } => rate_limit_info.as_deref().copied(),
+            _ => None,
+        }
+    }
+
     /// Get the Odos API error code if available
     ///
     /// Returns the strongly-typed error code for API and rate limit errors,
@@ -392,6 +1247,47 @@ impl OdosError {
         self.api_error_body().map(|body| &body.code)
     }
 
+    /// Suggest how to respond to this error
+    ///
+    /// Expired paths ([`is_expired_path`](Self::is_expired_path)) suggest
+    /// [`ErrorAction::Requote`]. Rate limits suggest waiting for
+    /// [`suggested_retry_delay`](Self::suggested_retry_delay). Other errors
+    /// use [`OdosErrorCode::suggested_action`] when the API returned a known
+    /// error code; without one, retryable errors suggest retrying (after
+    /// the suggested delay, if any) and the rest return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::{error_code::ErrorAction, OdosError};
+    ///
+    /// # fn handle_error(error: &OdosError) {
+    /// match error.suggested_action() {
+    ///     Some(ErrorAction::Retry) => println!("Retry now"),
+    ///     Some(ErrorAction::RetryAfterDelay(delay)) => println!("Retry in {delay:?}"),
+    ///     Some(ErrorAction::ContactSupport) => {
+    ///         println!("Contact Odos with trace ID {:?}", error.trace_id())
+    ///     }
+    ///     _ => println!("Giving up: {error}"),
+    /// }
+    /// # }
+    /// ```
+    pub fn suggested_action(&self) -> Option<ErrorAction> {
+        if self.is_expired_path() {
+            return Some(ErrorAction::Requote);
+        }
+        match self.error_code() {
+            Some(code) if !self.is_rate_limit() && !matches!(code, OdosErrorCode::Unknown(_)) => {
+                Some(code.suggested_action())
+            }
+            _ if self.is_rate_limit() || self.is_retryable() => Some(
+                self.suggested_retry_delay()
+                    .map_or(ErrorAction::Retry, ErrorAction::RetryAfterDelay),
+            ),
+            _ => None,
+        }
+    }
+
     /// Get the Odos API trace ID if available
     ///
     /// Returns the trace ID for debugging API errors, or `None` for other error types
@@ -412,12 +1308,67 @@ impl OdosError {
         self.api_error_body().and_then(|body| body.trace_id)
     }
 
-    /// Borrow the shared payload that backs both API-shaped variants
-    /// ([`OdosError::Api`] and [`OdosError::RateLimit`]); returns `None`
-    /// for any other error.
+    /// Get the correlation ID the client sent with the failed request
+    ///
+    /// Set on API, HTTP and timeout errors when
+    /// [`ClientConfig::send_client_trace_id`](crate::ClientConfig::send_client_trace_id)
+    /// is enabled, so the failure can be matched to the request Odos
+    /// received even when the response has no trace ID of its own.
+    pub fn client_trace_id(&self) -> Option<TraceId> {
+        match self {
+            OdosError::Http {
+                client_trace_id, ..
+            }
+            | OdosError::Timeout {
+                client_trace_id, ..
+            } => *client_trace_id,
+            _ => self.api_error_body().and_then(|body| body.client_trace_id),
+        }
+    }
+
+    /// Attach the client trace ID of the failed request to an HTTP or
+    /// timeout error; other errors are returned unchanged
+    pub(crate) fn with_client_trace_id(mut self, trace_id: Option<TraceId>) -> Self {
+        if let OdosError::Http {
+            client_trace_id, ..
+        }
+        | OdosError::Timeout {
+            client_trace_id, ..
+        } = &mut self
+        {
+            *client_trace_id = client_trace_id.or(trace_id);
+        }
+        self
+    }
+
+    /// Check if an assemble request failed because the quoted path ID expired
+    ///
+    /// True for the API error itself and for [`OdosError::PathExpired`].
+    /// The API reports an unknown or expired path as `InvalidAssemblyRequest`
+    /// (4100), which it also uses for other malformed assembly requests, so
+    /// the message must say the path was not found or expired. Re-quoting
+    /// with the same parameters returns a fresh path ID; see
+    /// [`SwapBuilder::auto_requote`](crate::SwapBuilder::auto_requote).
+    pub fn is_expired_path(&self) -> bool {
+        match self {
+            OdosError::Api { body, .. } => {
+                let message = body.message.to_ascii_lowercase();
+                body.code == OdosErrorCode::InvalidAssemblyRequest
+                    && (message.contains("expired") || message.contains("not found"))
+            }
+            OdosError::PathExpired { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Borrow the shared payload that backs the API-shaped variants
+    /// ([`OdosError::Api`], [`OdosError::Unauthorized`] and
+    /// [`OdosError::RateLimit`]); returns `None` for any other error.
     pub fn api_error_body(&self) -> Option<&ApiErrorBody> {
         match self {
-            OdosError::Api { body, .. } | OdosError::RateLimit { body, .. } => Some(body),
+            OdosError::Api { body, .. }
+            | OdosError::Unauthorized { body, .. }
+            | OdosError::RateLimit { body, .. } => Some(body),
             _ => None,
         }
     }
@@ -425,9 +1376,11 @@ impl OdosError {
     /// Get the error category for metrics
     pub fn category(&self) -> &'static str {
         match self {
-            OdosError::Http(_) => "http",
+            OdosError::Http { .. } => "http",
             OdosError::Api { .. } => "api",
+            OdosError::Unauthorized { .. } => "unauthorized",
             OdosError::Json(_) => "json",
+            OdosError::ResponseDecode { .. } => "response_decode",
             OdosError::Hex(_) => "hex",
             OdosError::InvalidInput(_) => "invalid_input",
             OdosError::MissingData(_) => "missing_data",
@@ -435,9 +1388,27 @@ impl OdosError {
             OdosError::Contract(_) => "contract",
             OdosError::TransactionAssembly(_) => "transaction_assembly",
             OdosError::QuoteRequest(_) => "quote_request",
+            OdosError::QuoteRejected { .. } => "quote_rejected",
+            OdosError::PriceImpactTooHigh { .. } => "price_impact_too_high",
+            OdosError::OutputBelowMinimum { .. } => "output_below_minimum",
+            OdosError::SimulationFailed { .. } => "simulation_failed",
+            OdosError::SimulationReverted { .. } => "simulation_reverted",
+            OdosError::RouterAddressMismatch { .. } => "router_address_mismatch",
+            OdosError::ChainMismatch { .. } => "chain_mismatch",
+            OdosError::QuoteMismatch { .. } => "quote_mismatch",
+            OdosError::AssemblyVerificationFailed { .. } => "assembly_verification_failed",
+            OdosError::PathExpired { .. } => "path_expired",
+            OdosError::InsufficientBalance { .. } => "insufficient_balance",
+            OdosError::InsufficientAllowance { .. } => "insufficient_allowance",
+            OdosError::GasEstimation(_) => "gas_estimation",
+            OdosError::TransactionSubmission(_) => "transaction_submission",
+            OdosError::TransactionConfirmation { .. } => "transaction_confirmation",
+            OdosError::TransactionReverted { .. } => "transaction_reverted",
+            OdosError::Signing(_) => "signing",
             OdosError::Configuration(_) => "configuration",
-            OdosError::Timeout(_) => "timeout",
+            OdosError::Timeout { .. } => "timeout",
             OdosError::RateLimit { .. } => "rate_limit",
+            OdosError::CircuitOpen { .. } => "circuit_open",
             OdosError::Internal(_) => "internal",
         }
     }
@@ -448,7 +1419,10 @@ impl OdosError {
     /// - **Rate Limit**: Returns the `retry_after` value from the API if available,
     ///   otherwise suggests 60 seconds. Note: Rate limits should be handled at the
     ///   application level with proper coordination.
-    /// - **Timeout**: Suggests 1 second delay before retry
+    /// - **Timeout**: Suggests 1 second delay before retry, unless a total
+    ///   budget was exhausted
+    /// - **Circuit Open**: Returns the time until the circuit breaker lets a
+    ///   probe through
     /// - **HTTP Server Errors (5xx)**: Suggests 2 seconds with exponential backoff
     /// - **HTTP Connection Errors**: Suggests 500ms before retry
     /// - **Non-retryable Errors**: Returns `None`
@@ -483,13 +1457,20 @@ impl OdosError {
                 Some(retry_after.unwrap_or(Duration::from_secs(60)))
             }
             // Timeout - short delay
-            OdosError::Timeout(_) => Some(Duration::from_secs(1)),
+            OdosError::Timeout {
+                phase: TimeoutPhase::Request,
+                ..
+            } => Some(Duration::from_secs(1)),
+            // Circuit open - wait until the breaker probes again
+            OdosError::CircuitOpen { retry_at, .. } => {
+                Some(retry_at.saturating_duration_since(Instant::now()))
+            }
             // API server errors - moderate delay
             OdosError::Api { status, .. } if status.is_server_error() => {
                 Some(Duration::from_secs(2))
             }
             // HTTP errors - depends on error type
-            OdosError::Http(err) => {
+            OdosError::Http { source: err, .. } => {
                 if err.is_timeout() {
                     Some(Duration::from_secs(1))
                 } else if err.is_connect() || err.is_request() {
@@ -524,7 +1505,11 @@ impl OdosError {
     /// assert!(!error.is_retryable());
     /// ```
     pub fn is_client_error(&self) -> bool {
-        matches!(self, OdosError::Api { status, .. } if status.is_client_error())
+        matches!(
+            self,
+            OdosError::Api { status, .. } | OdosError::Unauthorized { status, .. }
+                if status.is_client_error()
+        )
     }
 
     /// Check if this is a server error (5xx status code)
@@ -551,6 +1536,15 @@ impl OdosError {
     }
 }
 
+impl From<reqwest::Error> for OdosError {
+    fn from(source: reqwest::Error) -> Self {
+        Self::Http {
+            source,
+            client_trace_id: None,
+        }
+    }
+}
+
 // Convert chain errors to appropriate error types
 impl From<OdosChainError> for OdosError {
     fn from(err: OdosChainError) -> Self {
@@ -570,6 +1564,9 @@ impl From<OdosChainError> for OdosError {
             OdosChainError::InvalidAddress { address } => {
                 Self::invalid_input(format!("Invalid address format: {address}"))
             }
+            err @ OdosChainError::InvalidCustomChain { .. } => {
+                Self::configuration_error(err.to_string())
+            }
         }
     }
 }
@@ -585,6 +1582,12 @@ mod tests {
         let timeout_err = OdosError::timeout_error("Request timed out");
         assert!(timeout_err.is_retryable());
 
+        // An exhausted total budget is not retryable
+        let total_err = OdosError::total_timeout("Budget exhausted");
+        assert!(!total_err.is_retryable());
+        assert_eq!(total_err.category(), "timeout");
+        assert_eq!(total_err.suggested_retry_delay(), None);
+
         // API 500 error should be retryable
         let api_err = OdosError::api_error(
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -601,16 +1604,180 @@ mod tests {
         assert!(!rate_limit_err.is_retryable());
     }
 
+    #[test]
+    fn test_duplicate_keeps_variant_and_details() {
+        let rate_limit = OdosError::rate_limit_error_with_retry_after(
+            "Too many requests",
+            Some(Duration::from_secs(7)),
+        )
+        .with_endpoint("quote");
+        let copy = rate_limit.duplicate();
+        assert!(copy.is_rate_limit());
+        assert_eq!(copy.retry_after(), Some(Duration::from_secs(7)));
+        assert_eq!(copy.endpoint(), Some("quote"));
+        assert_eq!(copy.to_string(), rate_limit.to_string());
+
+        let json = OdosError::from(serde_json::from_str::<u64>("nope").unwrap_err());
+        let copy = json.duplicate();
+        assert_eq!(copy.category(), "json");
+        assert!(copy.to_string().contains("expected ident"));
+    }
+
     #[test]
     fn test_error_categories() {
         let api_err = OdosError::api_error(StatusCode::BAD_REQUEST, "Bad request".to_string());
         assert_eq!(api_err.category(), "api");
 
+        let decode_err = OdosError::response_decode(
+            serde_json::from_str::<u64>("\"x\"").unwrap_err(),
+            &"é".repeat(2000),
+        );
+        assert_eq!(decode_err.category(), "response_decode");
+        assert!(!decode_err.is_retryable());
+        match &decode_err {
+            // Truncated on a character boundary
+            OdosError::ResponseDecode { body, .. } => {
+                assert_eq!(body.len(), 2048 + '…'.len_utf8());
+                assert!(body.ends_with('…'));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let unauthorized_err = OdosError::unauthorized(StatusCode::UNAUTHORIZED, "Invalid API key");
+        assert_eq!(unauthorized_err.category(), "unauthorized");
+        assert!(!unauthorized_err.is_retryable());
+
         let timeout_err = OdosError::timeout_error("Timeout");
         assert_eq!(timeout_err.category(), "timeout");
 
         let invalid_err = OdosError::invalid_input("Invalid");
         assert_eq!(invalid_err.category(), "invalid_input");
+
+        let rejected_err = OdosError::quote_rejected(Address::ZERO, 3.0, 1.0);
+        assert_eq!(rejected_err.category(), "quote_rejected");
+        assert!(!rejected_err.is_retryable());
+
+        let impact_err = OdosError::price_impact_too_high(-14.0, 3.0);
+        assert_eq!(impact_err.category(), "price_impact_too_high");
+        assert!(!impact_err.is_retryable());
+
+        let min_err = OdosError::output_below_minimum(U256::from(99), U256::from(100));
+        assert_eq!(min_err.category(), "output_below_minimum");
+        assert!(!min_err.is_retryable());
+
+        let simulation_err = OdosError::simulation_failed(SimulationError::new(
+            "execution_reverted",
+            "Return amount is not enough",
+        ));
+        assert_eq!(simulation_err.category(), "simulation_failed");
+        assert!(!simulation_err.is_retryable());
+        assert!(simulation_err
+            .to_string()
+            .contains("Return amount is not enough"));
+
+        let reverted_simulation_err = OdosError::simulation_reverted(
+            Some(RouterRevert::Reason("Slippage Limit Exceeded".to_string())),
+            "execution reverted",
+        );
+        assert_eq!(reverted_simulation_err.category(), "simulation_reverted");
+        assert!(!reverted_simulation_err.is_retryable());
+        assert_eq!(
+            reverted_simulation_err.to_string(),
+            "Swap simulation reverted: Slippage Limit Exceeded"
+        );
+
+        let mismatch_err =
+            OdosError::router_address_mismatch(1, Address::ZERO, Address::repeat_byte(1));
+        assert_eq!(mismatch_err.category(), "router_address_mismatch");
+        assert!(!mismatch_err.is_retryable());
+
+        let chain_err = OdosError::chain_mismatch(1, 42161);
+        assert_eq!(chain_err.category(), "chain_mismatch");
+        assert!(!chain_err.is_retryable());
+
+        let quote_err = OdosError::quote_mismatch("input amount", 1_000, 2_000);
+        assert_eq!(quote_err.category(), "quote_mismatch");
+        assert!(!quote_err.is_retryable());
+        assert_eq!(
+            quote_err.to_string(),
+            "Quote does not match the swap: input amount is 2000, expected 1000"
+        );
+
+        let verification_err =
+            OdosError::assembly_verification_failed(vec![AssemblyDiscrepancy::OutputReceiver {
+                expected: Address::ZERO,
+                assembled: Address::repeat_byte(1),
+            }]);
+        assert_eq!(verification_err.category(), "assembly_verification_failed");
+        assert!(!verification_err.is_retryable());
+        assert!(verification_err.to_string().contains("output receiver"));
+
+        let expired_err = OdosError::api_error_with_code(
+            StatusCode::BAD_REQUEST,
+            "Path not found".to_string(),
+            OdosErrorCode::InvalidAssemblyRequest,
+            None,
+        );
+        assert!(expired_err.is_expired_path());
+        assert_eq!(expired_err.suggested_action(), Some(ErrorAction::Requote));
+        let malformed_err = OdosError::api_error_with_code(
+            StatusCode::BAD_REQUEST,
+            "Invalid assembly request".to_string(),
+            OdosErrorCode::InvalidAssemblyRequest,
+            None,
+        );
+        assert!(!malformed_err.is_expired_path());
+        assert_eq!(
+            malformed_err.suggested_action(),
+            Some(ErrorAction::FixRequest)
+        );
+        let path_id = PathId::parse("2f6e14ad1cfa589029f413791a0b7f6f").unwrap();
+        let expired_err = OdosError::path_expired(path_id, 2, expired_err);
+        assert_eq!(expired_err.category(), "path_expired");
+        assert!(!expired_err.is_retryable());
+        assert!(expired_err.is_expired_path());
+        assert!(expired_err.to_string().contains("after 2 re-quotes"));
+
+        let balance_err =
+            OdosError::insufficient_balance(Address::ZERO, U256::from(100), U256::from(40));
+        assert_eq!(balance_err.category(), "insufficient_balance");
+        assert!(!balance_err.is_retryable());
+        assert!(balance_err.to_string().contains("short by 40"));
+
+        let allowance_err = OdosError::insufficient_allowance(
+            Address::ZERO,
+            Address::repeat_byte(1),
+            U256::from(100),
+            U256::from(100),
+        );
+        assert_eq!(allowance_err.category(), "insufficient_allowance");
+        assert!(!allowance_err.is_retryable());
+
+        let gas_err = OdosError::gas_estimation_error("execution reverted");
+        assert_eq!(gas_err.category(), "gas_estimation");
+        assert!(!gas_err.is_retryable());
+
+        let submission_err = OdosError::transaction_submission_error("nonce too low");
+        assert_eq!(submission_err.category(), "transaction_submission");
+        assert!(!submission_err.is_retryable());
+
+        let confirmation_err = OdosError::transaction_confirmation_error(B256::ZERO, "timed out");
+        assert_eq!(confirmation_err.category(), "transaction_confirmation");
+        assert!(!confirmation_err.is_retryable());
+
+        let reverted_err = OdosError::transaction_reverted(B256::ZERO, 21_000);
+        assert_eq!(reverted_err.category(), "transaction_reverted");
+
+        let circuit_err =
+            OdosError::circuit_open("https://api.odos.xyz/sor/quote/v2", Instant::now());
+        assert_eq!(circuit_err.category(), "circuit_open");
+        assert!(!circuit_err.is_retryable());
+        assert!(!reverted_err.is_retryable());
+        assert!(reverted_err.to_string().contains("21000 gas"));
+
+        let signing_err = OdosError::signing_error("signer unavailable");
+        assert_eq!(signing_err.category(), "signing");
+        assert!(!signing_err.is_retryable());
     }
 
     #[test]
@@ -677,5 +1844,99 @@ mod tests {
         let other_err = OdosError::invalid_input("Invalid");
         assert!(!other_err.is_client_error());
         assert!(!other_err.is_server_error());
+
+        let unauthorized_err = OdosError::unauthorized(StatusCode::FORBIDDEN, "Forbidden");
+        assert!(unauthorized_err.is_client_error());
+    }
+
+    #[test]
+    fn test_suggested_action() {
+        let too_slippery = OdosError::api_error_with_code(
+            StatusCode::BAD_REQUEST,
+            "Slippage tolerance too high".to_string(),
+            OdosErrorCode::TooSlippery,
+            None,
+        );
+        assert_eq!(
+            too_slippery.suggested_action(),
+            Some(ErrorAction::ReduceSlippage)
+        );
+
+        let rate_limited = OdosError::rate_limit_error_with_retry_after(
+            "Too many requests",
+            Some(Duration::from_secs(7)),
+        );
+        assert_eq!(
+            rate_limited.suggested_action(),
+            Some(ErrorAction::RetryAfterDelay(Duration::from_secs(7)))
+        );
+
+        // Without a known code, retryable errors fall back to the delay
+        let unavailable =
+            OdosError::api_error(StatusCode::SERVICE_UNAVAILABLE, "Unavailable".to_string());
+        assert_eq!(
+            unavailable.suggested_action(),
+            Some(ErrorAction::RetryAfterDelay(Duration::from_secs(2)))
+        );
+
+        assert_eq!(
+            OdosError::invalid_input("Bad amount").suggested_action(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_error_report_json() {
+        let trace_id =
+            TraceId::new(uuid::Uuid::parse_str("10becdc8-a021-4491-8201-a17b657204e0").unwrap());
+        let error = OdosError::api_error_with_code(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Algo failed".to_string(),
+            OdosErrorCode::from(2999),
+            Some(trace_id),
+        )
+        .with_endpoint("quote");
+
+        assert_eq!(
+            serde_json::to_string(&error.to_report()).unwrap(),
+            concat!(
+                r#"{"category":"api","#,
+                r#""message":"Odos API error (status: 500 Internal Server Error): Algo failed [trace: 10becdc8-a021-4491-8201-a17b657204e0]","#,
+                r#""http_status":500,"error_code":2999,"error_code_name":"ALGO_INTERNAL","#,
+                r#""trace_id":"10becdc8-a021-4491-8201-a17b657204e0","client_trace_id":null,"#,
+                r#""endpoint":"quote","#,
+                r#""retryable":false,"suggested_retry_delay_ms":2000}"#
+            )
+        );
+
+        let report = OdosError::invalid_input("Bad amount").to_report();
+        assert_eq!(report.category, "invalid_input");
+        assert_eq!(report.http_status, None);
+        assert_eq!(report.error_code_name, None);
+        assert!(!report.retryable);
+    }
+
+    #[test]
+    fn test_status_and_endpoint() {
+        let api_err = OdosError::api_error(StatusCode::BAD_REQUEST, "Bad request".to_string())
+            .with_endpoint("quote");
+        assert_eq!(api_err.status(), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(api_err.endpoint(), Some("quote"));
+
+        // The innermost label wins
+        let rate_limit_err = OdosError::rate_limit_error("Too many requests")
+            .with_endpoint("assemble")
+            .with_endpoint("quote");
+        assert_eq!(rate_limit_err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(rate_limit_err.endpoint(), Some("assemble"));
+
+        let unauthorized_err = OdosError::unauthorized(StatusCode::UNAUTHORIZED, "No key");
+        assert_eq!(unauthorized_err.status(), Some(StatusCode::UNAUTHORIZED));
+        assert_eq!(unauthorized_err.endpoint(), None);
+
+        // Errors without a response have neither
+        let timeout_err = OdosError::timeout_error("Timed out").with_endpoint("quote");
+        assert_eq!(timeout_err.status(), None);
+        assert_eq!(timeout_err.endpoint(), None);
     }
 }
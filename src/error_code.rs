@@ -16,13 +16,21 @@
 //! - **Internal Errors (5XXX)**: System-level internal errors
 
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, str::FromStr, time::Duration};
 use uuid::Uuid;
 
 /// Strongly-typed trace ID for Odos API error tracking
 ///
 /// Wraps a UUID to prevent confusion with other UUID types in the system.
 /// Each error response from Odos includes a unique trace ID for debugging.
+/// Serializes as the hyphenated UUID string, and parses from one:
+///
+/// ```rust
+/// use odos_sdk::error_code::TraceId;
+///
+/// let trace_id: TraceId = "10becdc8-a021-4491-8201-a17b657204e0".parse().unwrap();
+/// assert_eq!(trace_id.to_string(), "10becdc8-a021-4491-8201-a17b657204e0");
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct TraceId(pub Uuid);
@@ -33,12 +41,27 @@ impl TraceId {
         Self(uuid)
     }
 
+    /// Generate a random (v4) trace ID, for client-side request correlation
+    ///
+    /// See [`ClientConfig::send_client_trace_id`](crate::ClientConfig::send_client_trace_id).
+    pub fn random() -> Self {
+        Self(Uuid::new_v4())
+    }
+
     /// Get the inner UUID
     pub fn as_uuid(&self) -> Uuid {
         self.0
     }
 }
 
+impl FromStr for TraceId {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s.trim()).map(Self)
+    }
+}
+
 impl fmt::Display for TraceId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -68,11 +91,72 @@ pub enum ErrorCategory {
     Unknown,
 }
 
+/// What a caller should do about an error
+///
+/// Returned by [`OdosErrorCode::suggested_action`] and
+/// [`OdosError::suggested_action`](crate::OdosError::suggested_action). New
+/// actions may be added in minor releases.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::error_code::{ErrorAction, OdosErrorCode};
+///
+/// match OdosErrorCode::from(4006).suggested_action() {
+///     ErrorAction::ReduceSlippage => println!("Lower the slippage tolerance"),
+///     ErrorAction::RetryAfterDelay(delay) => println!("Try again in {delay:?}"),
+///     _ => println!("See the error for details"),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorAction {
+    /// Retry the same request; the failure is transient
+    Retry,
+    /// Retry the same request after waiting at least this long
+    RetryAfterDelay(Duration),
+    /// Request a fresh quote, as the quoted path can no longer be assembled
+    Requote,
+    /// Lower the slippage tolerance
+    ReduceSlippage,
+    /// Check that Odos can route the tokens involved
+    CheckTokenSupport,
+    /// Correct the request; sending it again unchanged fails the same way
+    FixRequest,
+    /// Contact Odos support, quoting the trace ID
+    ContactSupport,
+}
+
+/// Wait before retrying when gas data is unavailable (3143)
+const GAS_UNAVAILABLE_DELAY: Duration = Duration::from_secs(5);
+
+/// Wait before retrying an algorithm internal error (2999), which persists
+/// until upstream liquidity changes
+const ALGO_INTERNAL_DELAY: Duration = Duration::from_secs(60);
+
 /// Strongly-typed Odos API error codes
 ///
 /// Each variant represents a specific error condition documented by Odos.
 /// Error codes are grouped by category (1XXX-5XXX ranges).
+///
+/// Variants are added as Odos documents new codes, in minor releases, so
+/// matches need a wildcard arm. Codes the SDK does not know yet arrive as
+/// [`OdosErrorCode::Unknown`]:
+///
+/// ```rust
+/// use odos_sdk::error_code::OdosErrorCode;
+///
+/// fn should_requote(code: OdosErrorCode) -> bool {
+///     match code {
+///         OdosErrorCode::NoViablePath | OdosErrorCode::AlgoTimeout => true,
+///         _ => false,
+///     }
+/// }
+///
+/// assert!(should_requote(OdosErrorCode::from(2000)));
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum OdosErrorCode {
     // General Odos API errors (1XXX)
     /// General API error (1000)
@@ -221,6 +305,66 @@ pub enum OdosErrorCode {
 }
 
 impl OdosErrorCode {
+    /// Get the error code's name as documented by Odos, e.g.
+    /// `"NO_VIABLE_PATH"`, or `"UNKNOWN"` for codes this SDK does not know
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ApiError => "API_ERROR",
+            Self::NoViablePath => "NO_VIABLE_PATH",
+            Self::AlgoValidationError => "ALGO_VALIDATION_ERR",
+            Self::AlgoConnectionError => "ALGO_CONN_ERR",
+            Self::AlgoTimeout => "ALGO_TIMEOUT",
+            Self::AlgoInternal => "ALGO_INTERNAL",
+            Self::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            Self::ConfigInternal => "CONFIG_INTERNAL",
+            Self::ConfigConnectionError => "CONFIG_CONN_ERR",
+            Self::ConfigTimeout => "CONFIG_TIMEOUT",
+            Self::TxnAssemblyInternal => "TXN_ASSEMBLY_INTERNAL",
+            Self::TxnAssemblyConnectionError => "TXN_ASSEMBLY_CONN_ERR",
+            Self::TxnAssemblyTimeout => "TXN_ASSEMBLY_TIMEOUT",
+            Self::ChainDataInternal => "CHAIN_DATA_INTERNAL",
+            Self::ChainDataConnectionError => "CHAIN_DATA_CONN_ERR",
+            Self::ChainDataTimeout => "CHAIN_DATA_TIMEOUT",
+            Self::PricingInternal => "PRICING_INTERNAL",
+            Self::PricingConnectionError => "PRICING_CONN_ERR",
+            Self::PricingTimeout => "PRICING_TIMEOUT",
+            Self::GasInternal => "GAS_INTERNAL",
+            Self::GasConnectionError => "GAS_CONN_ERR",
+            Self::GasTimeout => "GAS_TIMEOUT",
+            Self::GasUnavailable => "GAS_UNAVAILABLE",
+            Self::InvalidRequest => "INVALID_REQUEST",
+            Self::InvalidChainId => "INVALID_CHAIN_ID",
+            Self::InvalidInputTokens => "INVALID_INPUT_TOKENS",
+            Self::InvalidOutputTokens => "INVALID_OUTPUT_TOKENS",
+            Self::InvalidUserAddr => "INVALID_USER_ADDR",
+            Self::BlockedUserAddr => "BLOCKED_USER_ADDR",
+            Self::TooSlippery => "TOO_SLIPPERY",
+            Self::SameInputOutput => "SAME_INPUT_OUTPUT",
+            Self::MultiZapOutput => "MULTI_ZAP_OUTPUT",
+            Self::InvalidTokenCount => "INVALID_TOKEN_COUNT",
+            Self::InvalidTokenAddr => "INVALID_TOKEN_ADDR",
+            Self::NonIntegerTokenAmount => "NON_INTEGER_TOKEN_AMOUNT",
+            Self::NegativeTokenAmount => "NEGATIVE_TOKEN_AMOUNT",
+            Self::SameInputOutputTokens => "SAME_INPUT_OUTPUT_TOKENS",
+            Self::TokenBlacklisted => "TOKEN_BLACKLISTED",
+            Self::InvalidTokenProportions => "INVALID_TOKEN_PROPORTIONS",
+            Self::TokenRoutingUnavailable => "TOKEN_ROUTING_UNAVAILABLE",
+            Self::InvalidReferralCode => "INVALID_REFERRAL_CODE",
+            Self::InvalidTokenAmount => "INVALID_TOKEN_AMOUNT",
+            Self::NonStringTokenAmount => "NON_STRING_TOKEN_AMOUNT",
+            Self::InvalidAssemblyRequest => "INVALID_ASSEMBLY_REQUEST",
+            Self::InvalidAssemblyUserAddr => "INVALID_USER_ADDR",
+            Self::InvalidReceiverAddr => "INVALID_RECEIVER_ADDR",
+            Self::InvalidSwapRequest => "INVALID_SWAP_REQUEST",
+            Self::UserAddrRequired => "USER_ADDR_REQ",
+            Self::InternalError => "INTERNAL_ERROR",
+            Self::SwapUnavailable => "SWAP_UNAVAILABLE",
+            Self::PriceCheckFailure => "PRICE_CHECK_FAILURE",
+            Self::DefaultGasFailure => "DEFAULT_GAS_FAILURE",
+            Self::Unknown(_) => "UNKNOWN",
+        }
+    }
+
     /// Get the numeric error code value
     pub fn code(&self) -> u16 {
         match self {
@@ -393,6 +537,35 @@ impl OdosErrorCode {
             )
     }
 
+    /// Suggest how to respond to this error code
+    ///
+    /// - Timeouts, connection errors and other retryable codes:
+    ///   [`ErrorAction::Retry`], or [`ErrorAction::RetryAfterDelay`] for
+    ///   `GasUnavailable` (3143) and `AlgoInternal` (2999)
+    /// - Unroutable tokens ([`is_unroutable_token`](Self::is_unroutable_token)):
+    ///   [`ErrorAction::CheckTokenSupport`]
+    /// - `TooSlippery` (4006): [`ErrorAction::ReduceSlippage`]
+    /// - Other validation errors, including `InvalidAssemblyRequest` (4100):
+    ///   [`ErrorAction::FixRequest`]. The API also reports an expired path as
+    ///   4100, which the code alone cannot tell apart;
+    ///   [`OdosError::suggested_action`](crate::OdosError::suggested_action)
+    ///   checks the message and suggests [`ErrorAction::Requote`] for it.
+    /// - Blocked addresses, non-retryable internal errors and unknown codes:
+    ///   [`ErrorAction::ContactSupport`]
+    pub fn suggested_action(&self) -> ErrorAction {
+        match self {
+            Self::GasUnavailable => ErrorAction::RetryAfterDelay(GAS_UNAVAILABLE_DELAY),
+            Self::AlgoInternal => ErrorAction::RetryAfterDelay(ALGO_INTERNAL_DELAY),
+            Self::TooSlippery => ErrorAction::ReduceSlippage,
+            Self::BlockedUserAddr => ErrorAction::ContactSupport,
+            code if code.is_retryable() => ErrorAction::Retry,
+            code if code.is_unroutable_token() => ErrorAction::CheckTokenSupport,
+            code if code.is_validation_error() => ErrorAction::FixRequest,
+            Self::AlgoValidationError => ErrorAction::FixRequest,
+            _ => ErrorAction::ContactSupport,
+        }
+    }
+
     /// Check if this error indicates the token cannot be routed
     ///
     /// This is NOT an error condition - it's Odos correctly responding that
@@ -483,61 +656,7 @@ impl From<OdosErrorCode> for u16 {
 
 impl fmt::Display for OdosErrorCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::ApiError => write!(f, "1000 (API_ERROR)"),
-            Self::NoViablePath => write!(f, "2000 (NO_VIABLE_PATH)"),
-            Self::AlgoValidationError => write!(f, "2400 (ALGO_VALIDATION_ERR)"),
-            Self::AlgoConnectionError => write!(f, "2997 (ALGO_CONN_ERR)"),
-            Self::AlgoTimeout => write!(f, "2998 (ALGO_TIMEOUT)"),
-            Self::AlgoInternal => write!(f, "2999 (ALGO_INTERNAL)"),
-            Self::InternalServiceError => write!(f, "3000 (INTERNAL_SERVICE_ERROR)"),
-            Self::ConfigInternal => write!(f, "3100 (CONFIG_INTERNAL)"),
-            Self::ConfigConnectionError => write!(f, "3101 (CONFIG_CONN_ERR)"),
-            Self::ConfigTimeout => write!(f, "3102 (CONFIG_TIMEOUT)"),
-            Self::TxnAssemblyInternal => write!(f, "3110 (TXN_ASSEMBLY_INTERNAL)"),
-            Self::TxnAssemblyConnectionError => write!(f, "3111 (TXN_ASSEMBLY_CONN_ERR)"),
-            Self::TxnAssemblyTimeout => write!(f, "3112 (TXN_ASSEMBLY_TIMEOUT)"),
-            Self::ChainDataInternal => write!(f, "3120 (CHAIN_DATA_INTERNAL)"),
-            Self::ChainDataConnectionError => write!(f, "3121 (CHAIN_DATA_CONN_ERR)"),
-            Self::ChainDataTimeout => write!(f, "3122 (CHAIN_DATA_TIMEOUT)"),
-            Self::PricingInternal => write!(f, "3130 (PRICING_INTERNAL)"),
-            Self::PricingConnectionError => write!(f, "3131 (PRICING_CONN_ERR)"),
-            Self::PricingTimeout => write!(f, "3132 (PRICING_TIMEOUT)"),
-            Self::GasInternal => write!(f, "3140 (GAS_INTERNAL)"),
-            Self::GasConnectionError => write!(f, "3141 (GAS_CONN_ERR)"),
-            Self::GasTimeout => write!(f, "3142 (GAS_TIMEOUT)"),
-            Self::GasUnavailable => write!(f, "3143 (GAS_UNAVAILABLE)"),
-            Self::InvalidRequest => write!(f, "4000 (INVALID_REQUEST)"),
-            Self::InvalidChainId => write!(f, "4001 (INVALID_CHAIN_ID)"),
-            Self::InvalidInputTokens => write!(f, "4002 (INVALID_INPUT_TOKENS)"),
-            Self::InvalidOutputTokens => write!(f, "4003 (INVALID_OUTPUT_TOKENS)"),
-            Self::InvalidUserAddr => write!(f, "4004 (INVALID_USER_ADDR)"),
-            Self::BlockedUserAddr => write!(f, "4005 (BLOCKED_USER_ADDR)"),
-            Self::TooSlippery => write!(f, "4006 (TOO_SLIPPERY)"),
-            Self::SameInputOutput => write!(f, "4007 (SAME_INPUT_OUTPUT)"),
-            Self::MultiZapOutput => write!(f, "4008 (MULTI_ZAP_OUTPUT)"),
-            Self::InvalidTokenCount => write!(f, "4009 (INVALID_TOKEN_COUNT)"),
-            Self::InvalidTokenAddr => write!(f, "4010 (INVALID_TOKEN_ADDR)"),
-            Self::NonIntegerTokenAmount => write!(f, "4011 (NON_INTEGER_TOKEN_AMOUNT)"),
-            Self::NegativeTokenAmount => write!(f, "4012 (NEGATIVE_TOKEN_AMOUNT)"),
-            Self::SameInputOutputTokens => write!(f, "4013 (SAME_INPUT_OUTPUT_TOKENS)"),
-            Self::TokenBlacklisted => write!(f, "4014 (TOKEN_BLACKLISTED)"),
-            Self::InvalidTokenProportions => write!(f, "4015 (INVALID_TOKEN_PROPORTIONS)"),
-            Self::TokenRoutingUnavailable => write!(f, "4016 (TOKEN_ROUTING_UNAVAILABLE)"),
-            Self::InvalidReferralCode => write!(f, "4017 (INVALID_REFERRAL_CODE)"),
-            Self::InvalidTokenAmount => write!(f, "4018 (INVALID_TOKEN_AMOUNT)"),
-            Self::NonStringTokenAmount => write!(f, "4019 (NON_STRING_TOKEN_AMOUNT)"),
-            Self::InvalidAssemblyRequest => write!(f, "4100 (INVALID_ASSEMBLY_REQUEST)"),
-            Self::InvalidAssemblyUserAddr => write!(f, "4101 (INVALID_USER_ADDR)"),
-            Self::InvalidReceiverAddr => write!(f, "4102 (INVALID_RECEIVER_ADDR)"),
-            Self::InvalidSwapRequest => write!(f, "4200 (INVALID_SWAP_REQUEST)"),
-            Self::UserAddrRequired => write!(f, "4201 (USER_ADDR_REQ)"),
-            Self::InternalError => write!(f, "5000 (INTERNAL_ERROR)"),
-            Self::SwapUnavailable => write!(f, "5001 (SWAP_UNAVAILABLE)"),
-            Self::PriceCheckFailure => write!(f, "5002 (PRICE_CHECK_FAILURE)"),
-            Self::DefaultGasFailure => write!(f, "5003 (DEFAULT_GAS_FAILURE)"),
-            Self::Unknown(code) => write!(f, "{code} (UNKNOWN)"),
-        }
+        write!(f, "{} ({})", self.code(), self.name())
     }
 }
 
@@ -553,6 +672,21 @@ mod tests {
         assert_eq!(trace_id.to_string(), "10becdc8-a021-4491-8201-a17b657204e0");
     }
 
+    #[test]
+    fn test_trace_id_parse_and_serde() {
+        let trace_id: TraceId = " 10becdc8-a021-4491-8201-a17b657204e0\n".parse().unwrap();
+        assert_eq!(trace_id.to_string(), "10becdc8-a021-4491-8201-a17b657204e0");
+        assert!("not-a-trace-id".parse::<TraceId>().is_err());
+
+        let json = serde_json::to_string(&trace_id).unwrap();
+        assert_eq!(json, r#""10becdc8-a021-4491-8201-a17b657204e0""#);
+        assert_eq!(serde_json::from_str::<TraceId>(&json).unwrap(), trace_id);
+
+        let random = TraceId::random();
+        assert_ne!(random, TraceId::random());
+        assert_eq!(random.to_string().parse::<TraceId>().unwrap(), random);
+    }
+
     #[test]
     fn test_error_code_from_u16() {
         assert_eq!(OdosErrorCode::from(2999), OdosErrorCode::AlgoInternal);
@@ -637,6 +771,77 @@ mod tests {
         assert!(!OdosErrorCode::NoViablePath.is_retryable());
     }
 
+    #[test]
+    fn test_suggested_actions() {
+        // General
+        assert_eq!(
+            OdosErrorCode::ApiError.suggested_action(),
+            ErrorAction::ContactSupport
+        );
+
+        // Algo
+        assert_eq!(
+            OdosErrorCode::AlgoTimeout.suggested_action(),
+            ErrorAction::Retry
+        );
+        assert_eq!(
+            OdosErrorCode::NoViablePath.suggested_action(),
+            ErrorAction::CheckTokenSupport
+        );
+        assert_eq!(
+            OdosErrorCode::AlgoInternal.suggested_action(),
+            ErrorAction::RetryAfterDelay(Duration::from_secs(60))
+        );
+
+        // Internal service
+        assert_eq!(
+            OdosErrorCode::PricingConnectionError.suggested_action(),
+            ErrorAction::Retry
+        );
+        assert_eq!(
+            OdosErrorCode::from(3143).suggested_action(),
+            ErrorAction::RetryAfterDelay(Duration::from_secs(5))
+        );
+
+        // Validation
+        assert_eq!(
+            OdosErrorCode::from(4006).suggested_action(),
+            ErrorAction::ReduceSlippage
+        );
+        assert_eq!(
+            OdosErrorCode::InvalidChainId.suggested_action(),
+            ErrorAction::FixRequest
+        );
+        assert_eq!(
+            OdosErrorCode::TokenBlacklisted.suggested_action(),
+            ErrorAction::CheckTokenSupport
+        );
+        assert_eq!(
+            OdosErrorCode::InvalidAssemblyRequest.suggested_action(),
+            ErrorAction::FixRequest
+        );
+        assert_eq!(
+            OdosErrorCode::BlockedUserAddr.suggested_action(),
+            ErrorAction::ContactSupport
+        );
+
+        // Internal
+        assert_eq!(
+            OdosErrorCode::InternalError.suggested_action(),
+            ErrorAction::Retry
+        );
+        assert_eq!(
+            OdosErrorCode::PriceCheckFailure.suggested_action(),
+            ErrorAction::ContactSupport
+        );
+
+        // Unknown
+        assert_eq!(
+            OdosErrorCode::Unknown(9999).suggested_action(),
+            ErrorAction::ContactSupport
+        );
+    }
+
     #[test]
     fn test_display_format() {
         assert_eq!(
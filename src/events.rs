@@ -35,9 +35,11 @@
 use alloy_network::Network;
 use alloy_primitives::{Address, B256, I256, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::{BlockNumberOrTag, Filter, Log};
+use alloy_rpc_types::{BlockNumberOrTag, Filter, Log, TransactionReceipt};
 use alloy_sol_types::SolEvent;
 
+use crate::{router_type_for_address, RouterType, SingleQuoteResponse};
+
 #[cfg(feature = "v2")]
 use crate::v2_router::OdosV2Router;
 #[cfg(feature = "v3")]
@@ -68,6 +70,121 @@ pub struct SwapEvent {
     pub log_index: Option<u64>,
 }
 
+impl SwapEvent {
+    /// Get the realized output's deviation from `quote`, in percent
+    ///
+    /// Positive when the swap returned more of the output token than quoted.
+    /// Unlike [`slippage`](Self::slippage), which the router computes against
+    /// the quote embedded in the calldata, this compares against the quote
+    /// the swap was requested with.
+    ///
+    /// Returns `None` if `quote` has no amount for the event's output token,
+    /// or quoted zero of it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use alloy_rpc_types::TransactionReceipt;
+    /// use odos_sdk::{events::parse_swap_events, SingleQuoteResponse};
+    ///
+    /// # fn example(receipt: &TransactionReceipt, quote: &SingleQuoteResponse) {
+    /// for event in parse_swap_events(receipt) {
+    ///     if let Some(slippage) = event.slippage_vs_quote(quote) {
+    ///         println!("Received {} ({slippage:+.3}% vs quote)", event.amount_out);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn slippage_vs_quote(&self, quote: &SingleQuoteResponse) -> Option<f64> {
+        let quoted = quote
+            .out_tokens_iter()
+            .zip(quote.out_amounts_u256())
+            .find(|(token, _)| **token == self.output_token)
+            .and_then(|(_, amount)| amount.ok())
+            .filter(|amount| !amount.is_zero())?;
+
+        let actual = f64::from(self.amount_out);
+        let quoted = f64::from(quoted);
+        Some((actual - quoted) / quoted * 100.0)
+    }
+}
+
+/// Decode the `Swap` events Odos routers emitted in `receipt`
+///
+/// Only logs from known V2 and V3 router addresses are decoded, so events
+/// from other contracts in the same transaction are ignored. Multi-token
+/// swaps emit `SwapMulti` instead; see [`parse_swap_multi_events`].
+///
+/// V3 events are only decoded when the `v3` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use alloy_rpc_types::TransactionReceipt;
+/// use odos_sdk::events::parse_swap_events;
+///
+/// # fn example(receipt: &TransactionReceipt) {
+/// for event in parse_swap_events(receipt) {
+///     println!(
+///         "Swapped {} of {} for {} of {}",
+///         event.input_amount, event.input_token, event.amount_out, event.output_token
+///     );
+/// }
+/// # }
+/// ```
+pub fn parse_swap_events(receipt: &TransactionReceipt) -> Vec<SwapEvent> {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .filter_map(|log| match router_type_for_address(log.address())? {
+            #[cfg(feature = "v2")]
+            RouterType::V2 => SwapEventFilter::decode_v2_swap_log(log),
+            #[cfg(feature = "v3")]
+            RouterType::V3 => SwapEventFilter::decode_v3_swap_log(log),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Decode the `SwapMulti` events Odos routers emitted in `receipt`
+///
+/// The multi-token counterpart of [`parse_swap_events`].
+pub fn parse_swap_multi_events(receipt: &TransactionReceipt) -> Vec<SwapMultiEvent> {
+    receipt
+        .inner
+        .logs()
+        .iter()
+        .filter_map(|log| match router_type_for_address(log.address())? {
+            #[cfg(feature = "v2")]
+            RouterType::V2 => {
+                let event = OdosV2Router::SwapMulti::decode_log(&log.inner).ok()?.data;
+                Some(SwapMultiEvent::new(
+                    log,
+                    event.sender,
+                    event.tokensIn,
+                    event.amountsIn,
+                    event.tokensOut,
+                    event.amountsOut,
+                ))
+            }
+            #[cfg(feature = "v3")]
+            RouterType::V3 => {
+                let event = OdosV3Router::SwapMulti::decode_log(&log.inner).ok()?.data;
+                Some(SwapMultiEvent::new(
+                    log,
+                    event.sender,
+                    event.tokensIn,
+                    event.amountsIn,
+                    event.tokensOut,
+                    event.amountsOut,
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 /// Builder for creating swap event filters.
 ///
 /// This builder provides a fluent API for constructing filters to query
@@ -273,10 +390,195 @@ pub struct SwapMultiEvent {
     pub log_index: Option<u64>,
 }
 
+impl SwapMultiEvent {
+    fn new(
+        log: &Log,
+        sender: Address,
+        tokens_in: Vec<Address>,
+        amounts_in: Vec<U256>,
+        tokens_out: Vec<Address>,
+        amounts_out: Vec<U256>,
+    ) -> Self {
+        Self {
+            sender,
+            tokens_in,
+            amounts_in,
+            tokens_out,
+            amounts_out,
+            block_number: log.block_number,
+            transaction_hash: log.transaction_hash,
+            log_index: log.log_index,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy_primitives::address;
+    use serde_json::json;
+
+    use crate::ODOS_V2_ETHEREUM_ROUTER;
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const SENDER: Address = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+
+    // The logs below are ABI-encoded by hand rather than captured from a
+    // chain; `test_parse_mainnet_swap_receipts` in tests/fork_tests.rs parses
+    // real mainnet router receipts when `ODOS_SDK_FORK_URL` is set.
+
+    /// V2 router `Swap` of 1 USDC for WETH
+    const V2_SWAP_TOPIC: &str =
+        "0x823eaf01002d7353fbcadb2ea3305cc46fa35d799cb0914846d185ac06f8ad05";
+    const V2_SWAP_DATA: &str = concat!(
+        "0x",
+        "00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86",
+        "00000000000000000000000000000000000000000000000000000000000f4240",
+        "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        "00000000000000000000000000000000000000000000000000010e7a6af5da22",
+        "000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+        "000000000000000000000000000000000000000000000000000000452c59c18f",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    );
+
+    /// V3 router `SwapMulti` of 1 USDC for WETH and DAI
+    #[cfg(feature = "v3")]
+    const V3_SWAP_MULTI_TOPIC: &str =
+        "0x2c96555a96d94780f3a97aeb724514e80e331842f3143742d85da5aa68df9d30";
+    #[cfg(feature = "v3")]
+    const V3_SWAP_MULTI_DATA: &str = concat!(
+        "0x",
+        "00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86",
+        "0000000000000000000000000000000000000000000000000000000000000120",
+        "0000000000000000000000000000000000000000000000000000000000000160",
+        "00000000000000000000000000000000000000000000000000000000000001a0",
+        "0000000000000000000000000000000000000000000000000000000000000200",
+        "0000000000000000000000000000000000000000000000000000000000000260",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "00000000000000000000000000000000000000000000000000000000000f4240",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000871a9f4e0c49",
+        "00000000000000000000000000000000000000000000000006de97e09bd18000",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+        "0000000000000000000000006b175474e89094c44da98b954eedeac495271d0f",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    );
+
+    fn log(index: u64, address: Address, topics: &[&str], data: &str) -> serde_json::Value {
+        json!({
+            "address": address,
+            "topics": topics,
+            "data": data,
+            "blockNumber": "0x147c6d9",
+            "blockHash": "0x3e2a4b1c9d8f7e6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a",
+            "transactionHash": "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+            "transactionIndex": "0x2a",
+            "logIndex": format!("{index:#x}"),
+            "removed": false
+        })
+    }
+
+    fn receipt(logs: Vec<serde_json::Value>) -> TransactionReceipt {
+        serde_json::from_value(json!({
+            "type": "0x2",
+            "status": "0x1",
+            "cumulativeGasUsed": "0x5c1a3e",
+            "logs": logs,
+            "logsBloom": format!("0x{}", "0".repeat(512)),
+            "transactionHash": "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+            "transactionIndex": "0x2a",
+            "blockHash": "0x3e2a4b1c9d8f7e6a5b4c3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a",
+            "blockNumber": "0x147c6d9",
+            "gasUsed": "0x2e4b1",
+            "effectiveGasPrice": "0x1985d6e07",
+            "from": SENDER,
+            "to": ODOS_V2_ETHEREUM_ROUTER,
+            "contractAddress": null
+        }))
+        .unwrap()
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_parse_swap_events_from_receipt() {
+        // ERC-20 Transfer of the input, which is not a router event
+        let transfer = log(
+            0,
+            USDC,
+            &[
+                "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                "0x00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86",
+                "0x000000000000000000000000cf5540fffcdc3d510b18bfca6d2b9987b0772559",
+            ],
+            "0x00000000000000000000000000000000000000000000000000000000000f4240",
+        );
+        // The same event shape from a contract that is not an Odos router
+        let impostor = log(1, SENDER, &[V2_SWAP_TOPIC], V2_SWAP_DATA);
+        let swap = log(2, ODOS_V2_ETHEREUM_ROUTER, &[V2_SWAP_TOPIC], V2_SWAP_DATA);
+        let receipt = receipt(vec![transfer, impostor, swap]);
+
+        let events = parse_swap_events(&receipt);
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.sender, SENDER);
+        assert_eq!(event.input_token, USDC);
+        assert_eq!(event.input_amount, U256::from(1_000_000u64));
+        assert_eq!(event.output_token, WETH);
+        assert_eq!(event.amount_out, U256::from(297_393_920_006_690u64));
+        assert_eq!(event.slippage, I256::try_from(297_096_823_183i64).unwrap());
+        assert_eq!(event.referral_code, 0);
+        assert_eq!(event.block_number, Some(0x147c6d9));
+        assert_eq!(event.log_index, Some(2));
+        assert!(parse_swap_multi_events(&receipt).is_empty());
+
+        let quote: SingleQuoteResponse =
+            serde_json::from_value(crate::sor::tests::quote_json()).unwrap();
+        let slippage = event.slippage_vs_quote(&quote).unwrap();
+        assert!((slippage - 0.1).abs() < 1e-9, "{slippage}");
+
+        let other_output = SwapEvent {
+            output_token: USDC,
+            ..event.clone()
+        };
+        assert_eq!(other_output.slippage_vs_quote(&quote), None);
+    }
+
+    #[cfg(feature = "v3")]
+    #[test]
+    fn test_parse_swap_multi_events_from_receipt() {
+        let receipt = receipt(vec![log(
+            0,
+            crate::ODOS_V3,
+            &[V3_SWAP_MULTI_TOPIC],
+            V3_SWAP_MULTI_DATA,
+        )]);
+
+        assert!(parse_swap_events(&receipt).is_empty());
+        let events = parse_swap_multi_events(&receipt);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].sender, SENDER);
+        assert_eq!(events[0].tokens_in, vec![USDC]);
+        assert_eq!(
+            events[0].tokens_out,
+            vec![WETH, address!("6b175474e89094c44da98b954eedeac495271d0f")]
+        );
+        assert_eq!(
+            events[0].amounts_out,
+            vec![
+                U256::from(148_548_411_591_753u64),
+                U256::from(495_000_000_000_000_000u64)
+            ]
+        );
+    }
 
     #[test]
     fn test_swap_event_filter_builder() {
@@ -0,0 +1,480 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use alloy_primitives::Address;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    AssembleRequest, AssemblyResponse, ClientConfig, OdosClient, OdosError, PathId, QuoteRequest,
+    Result, RetryConfig, SingleQuoteResponse,
+};
+
+/// Chain and token pair a fixture answers for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct FixtureKey {
+    chain_id: u64,
+    input: Address,
+    output: Address,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixtureKind {
+    Quote,
+    Assemble,
+}
+
+impl FixtureKind {
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Quote => "quote",
+            Self::Assemble => "assemble",
+        }
+    }
+}
+
+/// Canned quote and assemble responses loaded from a fixture directory
+#[derive(Debug)]
+pub(crate) struct Fixtures {
+    dir: PathBuf,
+    quotes: HashMap<FixtureKey, SingleQuoteResponse>,
+    assemblies: HashMap<FixtureKey, AssemblyResponse>,
+    /// Fixture each quoted path ID came from
+    path_ids: HashMap<PathId, FixtureKey>,
+}
+
+impl Fixtures {
+    /// Load and decode every fixture under `dir`
+    pub(crate) fn load(dir: &Path) -> Result<Self> {
+        let mut fixtures = Self {
+            dir: dir.to_owned(),
+            quotes: HashMap::new(),
+            assemblies: HashMap::new(),
+            path_ids: HashMap::new(),
+        };
+
+        for chain_dir in sorted_entries(dir)? {
+            let chain_id = chain_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse().ok())
+                .filter(|_| chain_dir.is_dir())
+                .ok_or_else(|| {
+                    OdosError::configuration_error(format!(
+                        "Unexpected fixture entry {}; expected one directory per chain ID",
+                        chain_dir.display()
+                    ))
+                })?;
+            for file in sorted_entries(&chain_dir)? {
+                let (key, kind) = parse_file_name(chain_id, &file)?;
+                match kind {
+                    FixtureKind::Quote => {
+                        let quote: SingleQuoteResponse = decode(&file)?;
+                        check_quote_tokens(&file, key, &quote)?;
+                        fixtures.quotes.insert(key, quote);
+                    }
+                    FixtureKind::Assemble => {
+                        fixtures.assemblies.insert(key, decode(&file)?);
+                    }
+                }
+            }
+        }
+
+        if fixtures.quotes.is_empty() {
+            return Err(OdosError::configuration_error(format!(
+                "No quote fixtures found in {}",
+                dir.display()
+            )));
+        }
+        for key in fixtures.assemblies.keys() {
+            if !fixtures.quotes.contains_key(key) {
+                return Err(OdosError::configuration_error(format!(
+                    "Assemble fixture {} has no quote fixture {} to take its path ID from",
+                    fixtures.path(*key, FixtureKind::Assemble).display(),
+                    fixtures.path(*key, FixtureKind::Quote).display()
+                )));
+            }
+        }
+        for (key, quote) in &fixtures.quotes {
+            if let Some(other) = fixtures.path_ids.insert(quote.path_id().clone(), *key) {
+                return Err(OdosError::configuration_error(format!(
+                    "Quote fixtures {} and {} share path ID {}",
+                    fixtures.path(other, FixtureKind::Quote).display(),
+                    fixtures.path(*key, FixtureKind::Quote).display(),
+                    quote.path_id()
+                )));
+            }
+        }
+        Ok(fixtures)
+    }
+
+    /// Answer a quote request with the fixture for its chain and token pair
+    pub(crate) fn quote(&self, request: &QuoteRequest) -> Result<SingleQuoteResponse> {
+        let chain_id = request.chain().id();
+        let keys: Vec<FixtureKey> = request
+            .input_tokens()
+            .iter()
+            .flat_map(|input| {
+                request
+                    .output_tokens()
+                    .iter()
+                    .map(move |output| FixtureKey {
+                        chain_id,
+                        input: input.token_address(),
+                        output: output.token_address(),
+                    })
+            })
+            .collect();
+
+        match keys.as_slice() {
+            [key] => self.quotes.get(key).cloned().ok_or_else(|| {
+                OdosError::missing_data(format!(
+                    "Missing quote fixture {}",
+                    self.path(*key, FixtureKind::Quote).display()
+                ))
+            }),
+            _ => Err(OdosError::missing_data(format!(
+                "Quote fixtures cover one input and one output token; the request on chain \
+                 {chain_id} has {} inputs and {} outputs",
+                request.input_tokens().len(),
+                request.output_tokens().len()
+            ))),
+        }
+    }
+
+    /// Answer an assemble request for a path ID quoted from the fixtures
+    ///
+    /// The fixture's transaction is returned as recorded, so a request from
+    /// any other signer than the recorded `from` is rejected rather than
+    /// answered with a transaction it could not send.
+    pub(crate) fn assembly(&self, request: &AssembleRequest) -> Result<AssemblyResponse> {
+        let path_id = &request.path_id;
+        let key = *self.path_ids.get(path_id).ok_or_else(|| {
+            OdosError::missing_data(format!(
+                "Path ID {path_id} is not from a quote fixture in {}",
+                self.dir.display()
+            ))
+        })?;
+        let assembly = self.assemblies.get(&key).ok_or_else(|| {
+            OdosError::missing_data(format!(
+                "Missing assemble fixture {}",
+                self.path(key, FixtureKind::Assemble).display()
+            ))
+        })?;
+        if assembly.transaction.from != request.user_addr {
+            return Err(OdosError::invalid_input(format!(
+                "Assemble fixture {} was recorded for signer {}, not {}",
+                self.path(key, FixtureKind::Assemble).display(),
+                assembly.transaction.from,
+                request.user_addr
+            )));
+        }
+        Ok(assembly.clone())
+    }
+
+    /// Where the fixture of `kind` for `key` lives
+    fn path(&self, key: FixtureKey, kind: FixtureKind) -> PathBuf {
+        self.dir.join(key.chain_id.to_string()).join(format!(
+            "{:#x}-{:#x}.{}.json",
+            key.input,
+            key.output,
+            kind.suffix()
+        ))
+    }
+}
+
+impl OdosClient {
+    /// Create a client that answers quotes and assembly from fixture files
+    ///
+    /// Responses are read from `dir`, laid out as
+    /// `<chain ID>/<input token>-<output token>.quote.json` with an optional
+    /// `.assemble.json` sibling, tokens written as lowercase `0x` hex. Quotes
+    /// are matched on the chain and token pair of single-input,
+    /// single-output requests; assembly is matched on the path ID of a quote
+    /// fixture, and the returned transaction is the recorded one, so the
+    /// assemble request must come from the signer the fixture was recorded
+    /// for. Requests without a fixture fail with [`OdosError::MissingData`]
+    /// naming the file to add, and every other endpoint fails the same way,
+    /// so the client never touches the network.
+    ///
+    /// Fixtures are the response bodies of `/sor/quote/v2` and
+    /// `/sor/assemble`, saved as returned by the API.
+    ///
+    /// The client otherwise uses the default configuration, without retries.
+    /// Requires the `test-utils` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::Configuration`] if the directory cannot be read,
+    /// holds no quote fixtures, or has a file that is misnamed or does not
+    /// deserialize.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{Chain, OdosClient, Slippage};
+    /// use alloy_primitives::{address, U256};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::from_fixtures("tests/fixtures/odos")?;
+    ///
+    /// let tx = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000_000))
+    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///     .slippage(Slippage::standard())
+    ///     // The signer the assemble fixture was recorded for
+    ///     .signer(address!("47e2d28169738039755586743e2dfcf3bd643f86"))
+    ///     .build_transaction()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_fixtures(dir: impl AsRef<Path>) -> Result<Self> {
+        let fixtures = Fixtures::load(dir.as_ref())?;
+        let client = Self::with_config(ClientConfig {
+            retry_config: RetryConfig::no_retries(),
+            ..Default::default()
+        })?;
+        Ok(client.with_fixtures(fixtures))
+    }
+}
+
+/// Entries of `dir`, sorted so load errors are deterministic
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>> {
+    let read_error = |err: std::io::Error| {
+        OdosError::configuration_error(format!(
+            "Failed to read fixture directory {}: {err}",
+            dir.display()
+        ))
+    };
+    let mut entries = fs::read_dir(dir)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(read_error)?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Key and kind of a fixture named `<input>-<output>.<kind>.json`
+fn parse_file_name(chain_id: u64, file: &Path) -> Result<(FixtureKey, FixtureKind)> {
+    let parsed = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(".json"))
+        .and_then(|stem| stem.rsplit_once('.'))
+        .and_then(|(pair, kind)| {
+            let kind = match kind {
+                "quote" => FixtureKind::Quote,
+                "assemble" => FixtureKind::Assemble,
+                _ => return None,
+            };
+            let (input, output) = pair.split_once('-')?;
+            let key = FixtureKey {
+                chain_id,
+                input: input.parse().ok()?,
+                output: output.parse().ok()?,
+            };
+            Some((key, kind))
+        });
+    parsed.ok_or_else(|| {
+        OdosError::configuration_error(format!(
+            "Unexpected fixture file {}; expected <input token>-<output token>.quote.json \
+             or .assemble.json",
+            file.display()
+        ))
+    })
+}
+
+fn decode<T: DeserializeOwned>(file: &Path) -> Result<T> {
+    let body = fs::read_to_string(file).map_err(|err| {
+        OdosError::configuration_error(format!("Failed to read fixture {}: {err}", file.display()))
+    })?;
+    serde_json::from_str(&body).map_err(|err| {
+        OdosError::configuration_error(format!("Invalid fixture {}: {err}", file.display()))
+    })
+}
+
+/// Reject a quote fixture filed under a token pair it does not quote
+fn check_quote_tokens(file: &Path, key: FixtureKey, quote: &SingleQuoteResponse) -> Result<()> {
+    let input = quote.in_tokens_iter().next();
+    let output = quote.out_tokens_iter().next();
+    if input == Some(&key.input) && output == Some(&key.output) {
+        return Ok(());
+    }
+    Err(OdosError::configuration_error(format!(
+        "Quote fixture {} quotes {input:?} to {output:?}, not the token pair in its name",
+        file.display()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, U256};
+
+    use super::*;
+    use crate::{Chain, Slippage, ODOS_V2_BASE_ROUTER, ODOS_V2_ETHEREUM_ROUTER};
+
+    const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/odos");
+    const MAINNET_USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const MAINNET_WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const BASE_USDC: Address = address!("833589fcd6edb6e08f4c7c32d4f71b54bda02913");
+    const SIGNER: Address = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+
+    /// Fresh directory holding `files`, relative paths to contents
+    fn fixture_dir(files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("odos-fixtures-{}", uuid::Uuid::new_v4()));
+        for (name, body) in files {
+            let path = dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, body).unwrap();
+        }
+        dir
+    }
+
+    fn load_error(files: &[(&str, &str)]) -> String {
+        let dir = fixture_dir(files);
+        let err = OdosClient::from_fixtures(&dir).unwrap_err();
+        // Nothing is written for an empty file list
+        let _ = fs::remove_dir_all(dir);
+        assert!(matches!(err, OdosError::Configuration(_)));
+        err.to_string()
+    }
+
+    fn mainnet_quote_fixture() -> String {
+        fs::read_to_string(format!(
+            "{FIXTURES}/1/{MAINNET_USDC:#x}-{MAINNET_WETH:#x}.quote.json"
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_swap_is_quoted_and_assembled_offline() {
+        let client = OdosClient::from_fixtures(FIXTURES).unwrap();
+
+        let swap = || {
+            client
+                .swap()
+                .chain(Chain::ethereum())
+                .from_token(MAINNET_USDC, U256::from(1_000_000_000u64))
+                .to_token(MAINNET_WETH)
+                .slippage(Slippage::standard())
+                .signer(SIGNER)
+        };
+        let quote = swap().quote().await.unwrap();
+        assert_eq!(quote.out_amount().unwrap(), "297096823183507284");
+
+        let tx = swap().build_transaction().await.unwrap();
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(ODOS_V2_ETHEREUM_ROUTER)
+        );
+        assert_eq!(tx.from, Some(SIGNER));
+        assert_eq!(tx.chain_id, Some(1));
+
+        let tx = client
+            .swap()
+            .chain(Chain::base())
+            .from_token(crate::NATIVE_TOKEN, U256::from(100_000_000_000_000_000u64))
+            .to_token(BASE_USDC)
+            .slippage(Slippage::standard())
+            .signer(SIGNER)
+            .build_transaction()
+            .await
+            .unwrap();
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(ODOS_V2_BASE_ROUTER)
+        );
+        assert_eq!(tx.value, Some(U256::from(100_000_000_000_000_000u64)));
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_requests_name_missing_fixtures() {
+        let dir = fixture_dir(&[(
+            &format!("1/{MAINNET_USDC:#x}-{MAINNET_WETH:#x}.quote.json"),
+            &mainnet_quote_fixture(),
+        )]);
+        let client = OdosClient::from_fixtures(&dir).unwrap();
+        let swap = client
+            .swap()
+            .chain(Chain::ethereum())
+            .from_token(MAINNET_WETH, U256::from(1_000_000u64))
+            .to_token(MAINNET_USDC)
+            .slippage(Slippage::standard())
+            .signer(SIGNER);
+
+        let err = swap.quote().await.unwrap_err();
+        assert!(matches!(err, OdosError::MissingData(_)));
+        assert!(err
+            .to_string()
+            .contains(&format!("1/{MAINNET_WETH:#x}-{MAINNET_USDC:#x}.quote.json")));
+
+        let err = client
+            .assemble_tx_data(SIGNER, SIGNER, "2f6e14ad1cfa589029f413791a0b7f6f")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains(&format!(
+            "1/{MAINNET_USDC:#x}-{MAINNET_WETH:#x}.assemble.json"
+        )));
+
+        let err = client.supported_chains().await.unwrap_err();
+        assert!(matches!(err, OdosError::MissingData(_)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_assembly_rejects_other_signer() {
+        let client = OdosClient::from_fixtures(FIXTURES).unwrap();
+        let other = address!("742d35cc6634c0532925a3b8d35f3e7a5edd29c0");
+
+        let err = client
+            .swap()
+            .chain(Chain::ethereum())
+            .from_token(MAINNET_USDC, U256::from(1_000_000_000u64))
+            .to_token(MAINNET_WETH)
+            .slippage(Slippage::standard())
+            .signer(other)
+            .build_transaction()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+        let message = err.to_string();
+        assert!(message.contains(&SIGNER.to_string()) && message.contains(&other.to_string()));
+    }
+
+    #[test]
+    fn test_invalid_fixtures_are_rejected_at_load() {
+        let quote_name = format!("1/{MAINNET_USDC:#x}-{MAINNET_WETH:#x}.quote.json");
+
+        let err = load_error(&[(&quote_name, r#"{ "pathId": "not a quote" }"#)]);
+        assert!(err.contains("Invalid fixture") && err.contains(&quote_name));
+
+        let swapped = format!("1/{MAINNET_WETH:#x}-{MAINNET_USDC:#x}.quote.json");
+        let err = load_error(&[(&swapped, &mainnet_quote_fixture())]);
+        assert!(err.contains("not the token pair in its name"));
+
+        let err = load_error(&[
+            (&quote_name, &mainnet_quote_fixture()),
+            ("1/usdc-weth.quote.json", "{}"),
+        ]);
+        assert!(err.contains("Unexpected fixture file"));
+
+        let err = load_error(&[(
+            &format!("1/{MAINNET_USDC:#x}-{MAINNET_WETH:#x}.assemble.json"),
+            "{}",
+        )]);
+        assert!(err.contains("Invalid fixture"));
+
+        let err = load_error(&[]);
+        assert!(err.contains("Failed to read fixture directory"));
+    }
+}
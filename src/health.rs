@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use crate::ApiHost;
+
+/// Result of [`OdosClient::health_check`](crate::OdosClient::health_check)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// Time from sending the request to receiving the response headers
+    pub latency: Duration,
+
+    /// API host tier the client is configured for
+    pub host: ApiHost,
+
+    /// Whether the API accepted the configured API key
+    pub api_key: ApiKeyStatus,
+}
+
+impl HealthStatus {
+    /// Check that the API answered and did not reject the API key
+    pub fn is_healthy(&self) -> bool {
+        self.api_key != ApiKeyStatus::Invalid
+    }
+}
+
+/// How the API treated the client's API key in a health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiKeyStatus {
+    /// The request was sent with the API key and succeeded
+    Accepted,
+
+    /// No API key is configured and the request succeeded without one
+    NotConfigured,
+
+    /// The API answered 401 or 403: the key is invalid, or the endpoint
+    /// requires a key and none is configured
+    Invalid,
+}
@@ -0,0 +1,309 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::LiquiditySource;
+
+/// Token supported by the Odos API on a chain
+///
+/// Returned by [`OdosClient::supported_tokens`](crate::OdosClient::supported_tokens).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    /// Token contract address
+    #[serde(default)]
+    pub address: Address,
+    /// Token symbol
+    pub symbol: String,
+    /// Token name
+    pub name: String,
+    /// Token decimals
+    pub decimals: u8,
+    /// Odos identifier of the underlying asset, shared by bridged variants
+    #[serde(default)]
+    pub asset_id: Option<String>,
+    /// Odos asset category (e.g. `"eth"`, `"usd"`)
+    #[serde(default)]
+    pub asset_type: Option<String>,
+    /// Protocol issuing the token, for protocol receipt tokens
+    #[serde(default)]
+    pub protocol_id: Option<String>,
+    /// Whether the token's balances rebase; swap amounts for rebasing
+    /// tokens can drift between quote and execution
+    #[serde(default)]
+    pub is_rebasing: bool,
+}
+
+/// Response from the Odos supported tokens endpoint
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TokensResponse {
+    token_map: BTreeMap<Address, TokenInfo>,
+}
+
+impl TokensResponse {
+    /// Flatten the token map into a list sorted by address
+    pub(crate) fn into_tokens(self) -> Vec<TokenInfo> {
+        self.token_map
+            .into_iter()
+            .map(|(address, token)| TokenInfo { address, ..token })
+            .collect()
+    }
+}
+
+/// Liquidity source available for routing on a chain
+///
+/// Returned by [`OdosClient::liquidity_sources`](crate::OdosClient::liquidity_sources).
+/// The `id` is the name to pass to
+/// [`SwapBuilder::allow_sources`](crate::SwapBuilder::allow_sources) or
+/// [`SwapBuilder::block_sources`](crate::SwapBuilder::block_sources).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquiditySourceInfo {
+    /// Source name as accepted by the quote API
+    pub id: String,
+    /// URL of the source's logo, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_uri: Option<String>,
+    /// Any other fields the API reports for the source
+    #[serde(flatten)]
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+}
+
+impl LiquiditySourceInfo {
+    /// Get the source as a [`LiquiditySource`]
+    pub fn source(&self) -> LiquiditySource {
+        LiquiditySource::from(self.id.as_str())
+    }
+}
+
+/// Odos contract deployment details for a chain
+///
+/// Returned by [`OdosClient::contract_info`](crate::OdosClient::contract_info).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractInfo {
+    /// Chain the contracts are deployed on
+    pub chain_id: u64,
+    /// Router contract address for the configured API version
+    pub router_address: Address,
+    /// Executor contract address used by the router
+    pub executor_address: Address,
+    /// Router ABI, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub router_abi: Option<serde_json::Value>,
+    /// ERC-20 ABI, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub erc20_abi: Option<serde_json::Value>,
+}
+
+/// Response from the Odos supported chains endpoint
+#[derive(Deserialize)]
+pub(crate) struct ChainsResponse {
+    pub(crate) chains: Vec<u64>,
+}
+
+/// Differences between the chains the Odos API supports and the chains
+/// compiled into this SDK
+///
+/// Returned by [`OdosClient::verify_chain_constants`](crate::OdosClient::verify_chain_constants).
+/// Serializes to JSON for structured logs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChainReconciliation {
+    /// Chains the API supports that this SDK has no router constants for,
+    /// sorted by chain ID
+    pub api_only: Vec<u64>,
+    /// Chains this SDK has router constants for that the API no longer
+    /// reports, sorted by chain ID
+    pub sdk_only: Vec<u64>,
+}
+
+impl ChainReconciliation {
+    /// Compare the chain IDs reported by the API with the SDK's chain IDs
+    pub(crate) fn between(api: &[u64], sdk: &[u64]) -> Self {
+        let mut api_only: Vec<u64> = api.iter().copied().filter(|id| !sdk.contains(id)).collect();
+        let mut sdk_only: Vec<u64> = sdk.iter().copied().filter(|id| !api.contains(id)).collect();
+        api_only.sort_unstable();
+        api_only.dedup();
+        sdk_only.sort_unstable();
+        sdk_only.dedup();
+        Self { api_only, sdk_only }
+    }
+
+    /// Whether the API and the SDK agree on the supported chains
+    pub fn is_consistent(&self) -> bool {
+        self.api_only.is_empty() && self.sdk_only.is_empty()
+    }
+}
+
+/// Support for one chain as reported by the Odos API, next to the SDK's own
+///
+/// Returned by [`OdosClient::runtime_chain_support`](crate::OdosClient::runtime_chain_support).
+/// Serializes to JSON for structured logs.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeChainSupport {
+    /// Chain the support was checked for
+    pub chain_id: u64,
+    /// Whether the API lists the chain as supported
+    pub api_supported: bool,
+    /// Whether this SDK has router addresses for the chain, built in or
+    /// registered with [`Chain::register_custom`](crate::Chain::register_custom)
+    pub sdk_supported: bool,
+    /// Router the API reports for the configured API version, or `None` if
+    /// the API does not list the chain
+    pub router_address: Option<Address>,
+}
+
+impl RuntimeChainSupport {
+    /// Whether the API serves the chain but this SDK does not know it yet
+    pub fn is_unlisted(&self) -> bool {
+        self.api_supported && !self.sdk_supported
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloy_primitives::address;
+
+    use super::*;
+
+    /// Trimmed `GET /info/tokens/1` response
+    pub(crate) const TOKENS_FIXTURE: &str = r#"{
+        "tokenMap": {
+            "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2": {
+                "name": "Wrapped Ether",
+                "symbol": "WETH",
+                "decimals": 18,
+                "assetId": "weth",
+                "assetType": "eth",
+                "protocolId": null,
+                "isRebasing": false
+            },
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48": {
+                "name": "USD Coin",
+                "symbol": "USDC",
+                "decimals": 6,
+                "assetId": "usdc",
+                "assetType": "usd",
+                "protocolId": null,
+                "isRebasing": false
+            },
+            "0xae7ab96520DE3A18E5e111B5EaAb095312D7fE84": {
+                "name": "Liquid staked Ether 2.0",
+                "symbol": "stETH",
+                "decimals": 18,
+                "assetId": "steth",
+                "assetType": "eth",
+                "protocolId": "lido",
+                "isRebasing": true
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_tokens_response_deserializes_fixture() {
+        let response: TokensResponse = serde_json::from_str(TOKENS_FIXTURE).unwrap();
+        let tokens = response.into_tokens();
+
+        assert_eq!(tokens.len(), 3);
+        // Sorted by address
+        assert_eq!(
+            tokens[0].address,
+            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+        );
+        assert_eq!(tokens[0].symbol, "USDC");
+        assert_eq!(tokens[0].decimals, 6);
+        assert_eq!(tokens[0].asset_type.as_deref(), Some("usd"));
+        assert_eq!(tokens[0].protocol_id, None);
+
+        let steth = &tokens[1];
+        assert_eq!(steth.symbol, "stETH");
+        assert_eq!(steth.protocol_id.as_deref(), Some("lido"));
+        assert!(steth.is_rebasing);
+    }
+
+    #[test]
+    fn test_token_info_tolerates_missing_optional_fields() {
+        let json = r#"{ "tokenMap": { "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2":
+            { "name": "Wrapped Ether", "symbol": "WETH", "decimals": 18, "newField": 1 } } }"#;
+
+        let tokens = serde_json::from_str::<TokensResponse>(json)
+            .unwrap()
+            .into_tokens();
+        assert_eq!(tokens[0].asset_id, None);
+        assert!(!tokens[0].is_rebasing);
+    }
+
+    #[test]
+    fn test_chain_reconciliation() {
+        let report = ChainReconciliation::between(&[1, 10, 99999, 10], &[1, 10, 56]);
+        assert_eq!(report.api_only, [99999]);
+        assert_eq!(report.sdk_only, [56]);
+        assert!(!report.is_consistent());
+
+        assert!(ChainReconciliation::between(&[10, 1], &[1, 10]).is_consistent());
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "api_only": [99999], "sdk_only": [56] })
+        );
+    }
+
+    /// Trimmed `GET /info/liquidity-sources/1` response
+    pub(crate) const LIQUIDITY_SOURCES_FIXTURE: &str = r#"[
+        { "id": "Uniswap V3", "logoUri": "https://assets.odos.xyz/dexs/uniswap.png" },
+        { "id": "Curve Stable", "logoUri": "https://assets.odos.xyz/dexs/curve.png" },
+        { "id": "Fluid DEX", "logoUri": null, "category": "amm" }
+    ]"#;
+
+    #[test]
+    fn test_liquidity_sources_deserialize_fixture() {
+        let sources: Vec<LiquiditySourceInfo> =
+            serde_json::from_str(LIQUIDITY_SOURCES_FIXTURE).unwrap();
+
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].source(), LiquiditySource::UniswapV3);
+        assert_eq!(
+            sources[0].logo_uri.as_deref(),
+            Some("https://assets.odos.xyz/dexs/uniswap.png")
+        );
+        assert_eq!(sources[1].source(), LiquiditySource::CurveStable);
+
+        // Unknown sources and fields are preserved
+        assert_eq!(
+            sources[2].source(),
+            LiquiditySource::Other("Fluid DEX".to_string())
+        );
+        assert_eq!(sources[2].logo_uri, None);
+        assert_eq!(sources[2].metadata["category"], "amm");
+    }
+
+    /// Trimmed `GET /info/contract-info/v2/1` response
+    pub(crate) const CONTRACT_INFO_FIXTURE: &str = r#"{
+        "chainId": 1,
+        "routerAddress": "0xCf5540fFFCdC3d510B18bFcA6d2b9987b0772559",
+        "executorAddress": "0x76edF8C155A1e0D9B2aD11B04d9671CBC25fEE99",
+        "routerAbi": { "abi": [] },
+        "erc20Abi": { "abi": [] }
+    }"#;
+
+    #[test]
+    fn test_contract_info_deserializes_fixture() {
+        let info: ContractInfo = serde_json::from_str(CONTRACT_INFO_FIXTURE).unwrap();
+
+        assert_eq!(info.chain_id, 1);
+        assert_eq!(info.router_address, crate::ODOS_V2_ETHEREUM_ROUTER);
+        assert_eq!(
+            info.executor_address,
+            address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99")
+        );
+        assert!(info.router_abi.is_some());
+    }
+}
@@ -83,10 +83,10 @@ mod tests {
         use alloy_primitives::{Address, U256};
 
         let input_token = InputToken::new(Address::ZERO, U256::from(1000));
-        let output_token = OutputToken::new(Address::ZERO, 1);
+        let output_token = OutputToken::new(Address::ZERO, 1.0);
 
         let quote_request = QuoteRequest::builder()
-            .chain_id(1)
+            .chain(Chain::ethereum())
             .input_tokens(vec![input_token])
             .output_tokens(vec![output_token])
             .slippage_limit_percent(1.0)
@@ -64,9 +64,9 @@
 //!
 //! // Step 1: Get a quote
 //! let quote_request = QuoteRequest::builder()
-//!     .chain_id(1)
+//!     .chain(Chain::ethereum())
 //!     .input_tokens(vec![(usdc, U256::from(1_000_000)).into()])
-//!     .output_tokens(vec![(weth, 1).into()])
+//!     .output_tokens(vec![(weth, 1.0).into()])
 //!     .slippage_limit_percent(0.5)
 //!     .user_addr(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
 //!     .compact(false)
@@ -86,7 +86,7 @@
 //!     .output_recipient(Address::from_str("0x742d35Cc6634C0532925a3b8D35f3e7a5edD29c0")?)
 //!     .token_address(usdc)
 //!     .token_amount(U256::from(1_000_000))
-//!     .path_id(quote.path_id().to_string())
+//!     .path_id(quote.path_id())
 //!     .build();
 //!
 //! let transaction = client.assemble(&assembly_request).await?;
@@ -110,8 +110,14 @@
 //!     retry_config: RetryConfig {
 //!         max_retries: 3,
 //!         initial_backoff_ms: 100,
+//!         max_backoff_ms: 30_000,
+//!         multiplier: 2.0,
+//!         jitter: Jitter::None,
 //!         retry_server_errors: true,
 //!         retry_predicate: RetryPredicate::Default,
+//!         on_retry: None,
+//!         retry_budget: None,
+//!         retry_rate_limits: None,
 //!     },
 //!     max_connections: 20,
 //!     pool_idle_timeout: Duration::from_secs(90),
@@ -136,7 +142,7 @@
 //!
 //! # async fn example() {
 //! # let client = OdosClient::new().unwrap();
-//! # let quote_request = QuoteRequest::builder().chain_id(1).input_tokens(vec![]).output_tokens(vec![]).slippage_limit_percent(1.0).user_addr(Address::ZERO).compact(false).simple(false).referral_code(0).disable_rfqs(false).build();
+//! # let quote_request = QuoteRequest::builder().chain(Chain::ethereum()).input_tokens(vec![]).output_tokens(vec![]).slippage_limit_percent(1.0).user_addr(Address::ZERO).compact(false).simple(false).referral_code(0).disable_rfqs(false).build();
 //! match client.quote(&quote_request).await {
 //!     Ok(quote) => {
 //!         // Handle successful quote
@@ -161,13 +167,16 @@
 //!
 //!         // Handle by error type
 //!         match err {
-//!             OdosError::Api { status, body } => {
+//!             OdosError::Api { status, body, .. } => {
 //!                 eprintln!("API error {}: {}", status, body.message);
 //!             }
-//!             OdosError::Timeout(msg) => {
-//!                 eprintln!("Request timed out: {}", msg);
+//!             OdosError::Unauthorized { status, .. } => {
+//!                 eprintln!("API key rejected ({}) - check ODOS_API_KEY", status);
+//!             }
+//!             OdosError::Timeout { message, .. } => {
+//!                 eprintln!("Request timed out: {}", message);
 //!             }
-//!             OdosError::RateLimit { retry_after, body } => {
+//!             OdosError::RateLimit { retry_after, body, .. } => {
 //!                 if let Some(duration) = retry_after {
 //!                     eprintln!("Rate limited: {}. Retry after {} seconds", body.message, duration.as_secs());
 //!                 } else {
@@ -214,14 +223,16 @@
 //! The Odos API enforces rate limits to ensure fair usage. The SDK handles rate limits intelligently:
 //!
 //! - **HTTP 429 responses** are detected and classified as [`OdosError::RateLimit`]
-//! - Rate limit errors are **NOT retried** (return immediately with `Retry-After` header)
-//! - The SDK **captures `Retry-After` headers** for application-level handling
+//! - Rate limit errors are **not retried by default** (return immediately with `Retry-After` header);
+//!   opt in with [`RetryConfig::retry_rate_limits`] to wait for `Retry-After` and retry
+//! - The SDK **captures `Retry-After` headers**, in delay-seconds or HTTP-date form, for application-level handling
 //! - Applications should handle rate limits globally with proper backoff coordination
 //!
 //! ### Best Practices for Avoiding Rate Limits
 //!
 //! 1. **Share a single client** across your application instead of creating new clients per request
-//! 2. **Implement application-level rate limiting** if making many concurrent requests
+//! 2. **Set [`ClientConfig::rate_limiter`]** if making many concurrent requests, so the
+//!    client spreads them out instead of triggering 429s
 //! 3. **Handle rate limit errors gracefully** and back off at the application level if needed
 //!
 //! ### Example: Handling Rate Limits
@@ -234,7 +245,7 @@
 //! # async fn example() -> Result<()> {
 //! # let client = OdosClient::new()?;
 //! # let quote_request = QuoteRequest::builder()
-//! #     .chain_id(1)
+//! #     .chain(Chain::ethereum())
 //! #     .input_tokens(vec![])
 //! #     .output_tokens(vec![])
 //! #     .slippage_limit_percent(1.0)
@@ -282,15 +293,18 @@
 //!     max_retries: 5,
 //!     initial_backoff_ms: 200,
 //!     retry_server_errors: false,  // Don't retry 5xx errors
-//!     retry_predicate: RetryPredicate::Default,
+//!     jitter: Jitter::Full,        // Spread out retries across workers
+//!     ..Default::default()
 //! };
 //! let client = OdosClient::with_retry_config(retry_config)?;
 //! # Ok(())
 //! # }
 //! ```
 //!
-//! **Note:** Rate limit errors (429) are never retried regardless of configuration.
-//! This prevents retry cascades that make rate limiting worse.
+//! **Note:** Rate limit errors (429) are not retried by default, whatever the
+//! other settings. This prevents retry cascades that make rate limiting worse.
+//! Low-volume jobs can opt in with [`RetryConfig::retry_rate_limits`], which
+//! waits for `Retry-After` up to a cap before retrying.
 //!
 //! ## Provider Construction (Alloy Best Practices)
 //!
@@ -419,24 +433,67 @@
 
 mod api;
 mod api_key;
+pub mod approvals;
 mod assemble;
+#[cfg(feature = "v2")]
+mod assembled_swap;
+mod assembly_verification;
+mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod build_info;
 mod chain;
+mod circuit_breaker;
 mod client;
 mod contract;
+mod custom_chain;
+#[cfg(feature = "v2")]
+mod decode;
 mod error;
 pub mod error_code;
 #[cfg(any(feature = "v2", feature = "v3"))]
 pub mod events;
+#[cfg(any(test, feature = "test-utils"))]
+mod fixtures;
+mod health;
+mod info;
 #[cfg(test)]
 mod integration_tests;
 #[cfg(feature = "limit-orders")]
+mod limit_order;
+#[cfg(feature = "limit-orders")]
 mod limit_order_v2;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+mod middleware;
 #[cfg(feature = "multicall")]
 pub mod multicall;
+mod odos_api;
+mod path_viz;
+#[cfg(feature = "v2")]
+mod permit2;
+mod proxy;
+mod quote_cache;
+mod quote_coalescer;
+mod quote_watch;
+mod rate_limit_info;
+mod rate_limiter;
+mod retry_budget;
+mod revert;
 mod router_type;
+#[cfg(feature = "provider")]
+mod simulation;
+mod sleep;
 mod sor;
+mod support_manifest;
 mod swap;
 mod swap_builder;
+mod swap_events;
+#[cfg(feature = "provider")]
+mod swap_execution;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+mod tokens;
 pub mod tooling;
 mod transfer;
 mod types;
@@ -455,45 +512,92 @@ pub use api::{
     SingleQuoteResponse,
 };
 
-// SwapInputs is only available with v2 feature (contains V2 router types)
+// Swap input decoding is only available with v2 feature (contains V2 router types)
 #[cfg(feature = "v2")]
-pub use api::SwapInputs;
+pub use api::{DecodedSwap, MultiSwapInputs, SwapInputs};
 
 // API key management
 pub use api_key::ApiKey;
 
 // Transaction assembly
 pub use assemble::{
-    parse_value, AssembleRequest, AssemblyResponse, Simulation, SimulationError, TransactionData,
+    parse_value, AssembleRequest, AssembledToken, AssemblyResponse, Simulation, SimulationError,
+    TransactionData,
 };
 
+// Assembled calldata verification
+#[cfg(feature = "v2")]
+pub use assembly_verification::verify_assembly;
+pub use assembly_verification::{AssemblyDiscrepancy, AssemblyVerification};
+
+// Assembled swap wrapper (decodes router calldata)
+#[cfg(feature = "v2")]
+pub use assembled_swap::AssembledSwap;
+
+// Batch and streaming quotes
+pub use batch::BatchOptions;
+pub use quote_watch::QuoteWatch;
+
+// Build introspection
+pub use build_info::{build_info, BuildInfo};
+
 // Chain support
 pub use chain::{OdosChain, OdosChainError, OdosChainResult, OdosRouterSelection};
+pub use custom_chain::ChainConfig;
 
 // HTTP client configuration
-pub use client::{ClientConfig, OdosHttpClient, RetryConfig, RetryPredicate};
+pub use circuit_breaker::CircuitBreakerConfig;
+pub use client::{
+    ClientConfig, Jitter, OdosHttpClient, RateLimitRetryPolicy, RetryCallback, RetryConfig,
+    RetryEvent, RetryPredicate, RetryPredicateFn,
+};
+pub use middleware::{RequestHook, ResponseMeta, ResponseObserver};
+pub use proxy::ProxyConfig;
+pub use quote_cache::QuoteCacheConfig;
+pub use rate_limit_info::RateLimitInfo;
+pub use rate_limiter::RateLimiterConfig;
+pub use retry_budget::RetryBudget;
 
 // Contract addresses and chain helpers
 pub use contract::{
-    get_lo_router_by_chain_id, get_supported_chains, get_supported_lo_chains,
-    get_supported_v2_chains, get_supported_v3_chains, get_v2_router_by_chain_id,
-    get_v3_router_by_chain_id, ODOS_LO_ARBITRUM_ROUTER, ODOS_LO_AVALANCHE_ROUTER,
-    ODOS_LO_BASE_ROUTER, ODOS_LO_BSC_ROUTER, ODOS_LO_ETHEREUM_ROUTER, ODOS_LO_FRAXTAL_ROUTER,
-    ODOS_LO_LINEA_ROUTER, ODOS_LO_MANTLE_ROUTER, ODOS_LO_OP_ROUTER, ODOS_LO_POLYGON_ROUTER,
-    ODOS_LO_SONIC_ROUTER, ODOS_LO_UNICHAIN_ROUTER, ODOS_LO_ZKSYNC_ROUTER, ODOS_V2_ARBITRUM_ROUTER,
+    get_lo_router_by_chain_id, get_router_by_chain_id, get_supported_chains,
+    get_supported_lo_chains, get_supported_v2_chains, get_supported_v3_chains,
+    get_v2_router_by_chain_id, get_v3_router_by_chain_id, router_type_for_address, SupportedChains,
+    NATIVE_TOKEN, ODOS_LO_ARBITRUM_ROUTER, ODOS_LO_AVALANCHE_ROUTER, ODOS_LO_BASE_ROUTER,
+    ODOS_LO_BSC_ROUTER, ODOS_LO_ETHEREUM_ROUTER, ODOS_LO_FRAXTAL_ROUTER, ODOS_LO_LINEA_ROUTER,
+    ODOS_LO_MANTLE_ROUTER, ODOS_LO_OP_ROUTER, ODOS_LO_POLYGON_ROUTER, ODOS_LO_SONIC_ROUTER,
+    ODOS_LO_UNICHAIN_ROUTER, ODOS_LO_ZKSYNC_ROUTER, ODOS_V2_ARBITRUM_ROUTER,
     ODOS_V2_AVALANCHE_ROUTER, ODOS_V2_BASE_ROUTER, ODOS_V2_BSC_ROUTER, ODOS_V2_ETHEREUM_ROUTER,
     ODOS_V2_FRAXTAL_ROUTER, ODOS_V2_LINEA_ROUTER, ODOS_V2_MANTLE_ROUTER, ODOS_V2_OP_ROUTER,
     ODOS_V2_POLYGON_ROUTER, ODOS_V2_SONIC_ROUTER, ODOS_V2_UNICHAIN_ROUTER, ODOS_V2_ZKSYNC_ROUTER,
     ODOS_V3,
 };
 
+// Router calldata decoding
+#[cfg(feature = "limit-orders")]
+pub use decode::LimitOrderFill;
+#[cfg(feature = "v2")]
+pub use decode::{decode_router_calldata, DecodedOdosCall, Permit2Info};
+
 // Error handling
-pub use error::{ApiErrorBody, OdosError, Result};
+pub use error::{ApiErrorBody, ErrorReport, OdosError, Result, TimeoutPhase};
+
+// Limit order API
+#[cfg(feature = "limit-orders")]
+pub use limit_order::{
+    cancel_limit_order_transaction, LimitOrder, LimitOrderRequest, LimitOrderResponse,
+    LimitOrderState, LimitOrderStatus, LimitOrderToken, LIMIT_ORDER_EIP712_NAME,
+    LIMIT_ORDER_EIP712_VERSION,
+};
 
 // Limit order contract bindings
 #[cfg(feature = "limit-orders")]
 pub use limit_order_v2::LimitOrderV2;
 
+// Limit order router custom errors
+#[cfg(feature = "limit-orders")]
+pub use limit_order_v2::OdosLimitOrderV2Errors;
+
 // Limit order event types (different from V2/V3 Swap events)
 #[cfg(feature = "limit-orders")]
 pub use limit_order_v2::{
@@ -502,12 +606,46 @@ pub use limit_order_v2::{
     SwapRouterFunds,
 };
 
+// Info endpoint types
+pub use info::{
+    ChainReconciliation, ContractInfo, LiquiditySourceInfo, RuntimeChainSupport, TokenInfo,
+};
+
+// Quote routing graph
+pub use path_viz::{PathViz, PathVizLink, PathVizNode};
+
+// Permit2 signature transfers
+#[cfg(feature = "v2")]
+pub use permit2::{
+    swap_permit2_calldata, PermitTransferFrom, TokenPermissions, PERMIT2_ADDRESS,
+    PERMIT2_EIP712_NAME,
+};
+
+// Router revert decoding
+pub use revert::{decode_router_revert, RouterRevert};
+
 // Router type selection
 pub use router_type::{RouterAvailability, RouterType};
 
+// Sleep strategy used for retry backoff
+pub use sleep::{SleepFuture, Sleeper, TokioSleeper};
+
+// API health checks
+pub use health::{ApiKeyStatus, HealthStatus};
+
+// Local swap simulation
+#[cfg(feature = "provider")]
+pub use simulation::{
+    allowance_override, simulate_swap, simulate_swap_with_overrides, SwapSimulation,
+};
+
 // Smart Order Router client
+pub use odos_api::{ApiFuture, OdosApi};
 #[allow(deprecated)]
-pub use sor::{OdosClient, OdosSor};
+pub use sor::{OdosClient, OdosSor, DEFAULT_GAS_BUFFER_PERCENT, HEALTH_CHECK_TIMEOUT};
+
+// ERC-20 approvals for the router
+pub use approvals::ApprovalAmount;
 
 // Swap execution context
 #[allow(deprecated)]
@@ -516,11 +654,28 @@ pub use swap::{AssemblyRequest, SwapContext};
 // High-level swap builder
 pub use swap_builder::SwapBuilder;
 
+// Executed swap outcome
+#[cfg(feature = "provider")]
+pub use swap_execution::SwapExecution;
+
+// Swap lifecycle audit events
+pub use swap_events::{SwapEventKind, SwapEventRecord};
+
+// Supported chains and routers manifest
+pub use support_manifest::{
+    support_manifest, ChainSupport, SupportManifest, SUPPORT_MANIFEST_JSON,
+};
+
 // Transfer types
 pub use transfer::TransferRouterFunds;
+#[cfg(feature = "v2")]
+pub use transfer::TransferRouterFundsRequest;
 
 // Type-safe domain types
-pub use types::{Chain, ReferralCode, Slippage};
+pub use types::{
+    Chain, ChainMetadata, LiquiditySource, PathId, ReferralCode, Slippage, TokenAmount,
+    MAX_REFERRAL_FEE_BPS,
+};
 
 // V2 router contract bindings
 #[cfg(feature = "v2")]
@@ -0,0 +1,966 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use alloy_network::TransactionBuilder;
+use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_rpc_types::TransactionRequest;
+use alloy_sol_types::{Eip712Domain, SolCall, SolStruct};
+use bon::Builder;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    get_lo_router_by_chain_id, limit_order_v2::OdosLimitOrderV2::cancelLimitOrderCall, OdosClient,
+    OdosError, Result,
+};
+
+/// EIP-712 domain name of the Odos limit order router
+pub const LIMIT_ORDER_EIP712_NAME: &str = "OdosLimitOrderRouter";
+
+/// EIP-712 domain version of the Odos limit order router
+pub const LIMIT_ORDER_EIP712_VERSION: &str = "1";
+
+/// EIP-712 types of the limit order router, in the router's field order
+mod eip712 {
+    alloy_sol_types::sol! {
+        struct TokenInfo {
+            address tokenAddress;
+            uint256 tokenAmount;
+        }
+
+        struct LimitOrder {
+            TokenInfo input;
+            TokenInfo output;
+            uint256 expiry;
+            uint256 salt;
+            uint64 referralCode;
+            uint64 referralFee;
+            address referralFeeRecipient;
+            bool partiallyFillable;
+        }
+    }
+}
+
+/// Token and amount on one side of a [`LimitOrder`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrderToken {
+    /// Token contract address
+    pub token_address: Address,
+    /// Exact input amount, or minimum output amount, in base units
+    #[serde(with = "crate::api::decimal_u256")]
+    pub token_amount: U256,
+}
+
+impl From<(Address, U256)> for LimitOrderToken {
+    fn from((token_address, token_amount): (Address, U256)) -> Self {
+        Self {
+            token_address,
+            token_amount,
+        }
+    }
+}
+
+/// A limit order as signed by its owner
+///
+/// Mirrors the `LimitOrder` struct of the Odos limit order router: the owner
+/// sells exactly `input.token_amount` of the input token for at least
+/// `output.token_amount` of the output token, any time before `expiry`.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::{address, U256};
+/// use odos_sdk::LimitOrder;
+///
+/// let order = LimitOrder::builder()
+///     .input((address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(2_000_000_000u64)))
+///     .output((address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"), U256::from(10u64).pow(U256::from(18))))
+///     .expiry(1_767_225_600)
+///     .build();
+///
+/// assert!(!order.partially_fillable());
+/// ```
+#[derive(Builder, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrder {
+    /// Token and exact amount to sell
+    #[builder(into)]
+    input: LimitOrderToken,
+    /// Token and minimum amount to receive
+    #[builder(into)]
+    output: LimitOrderToken,
+    /// Unix timestamp in seconds after which the order can no longer be filled
+    expiry: u64,
+    /// Value making the order hash unique; random unless set
+    #[builder(default = random_salt())]
+    #[serde(with = "crate::api::decimal_u256")]
+    salt: U256,
+    /// Odos referral code credited for fills
+    #[builder(default)]
+    referral_code: u64,
+    /// Referral fee taken from the output
+    #[builder(default)]
+    referral_fee: u64,
+    /// Recipient of the referral fee
+    #[builder(default)]
+    referral_fee_recipient: Address,
+    /// Whether the order may be filled in several parts
+    #[builder(default)]
+    partially_fillable: bool,
+}
+
+impl LimitOrder {
+    pub fn input(&self) -> LimitOrderToken {
+        self.input
+    }
+
+    pub fn output(&self) -> LimitOrderToken {
+        self.output
+    }
+
+    pub fn expiry(&self) -> u64 {
+        self.expiry
+    }
+
+    pub fn salt(&self) -> U256 {
+        self.salt
+    }
+
+    pub fn referral_code(&self) -> u64 {
+        self.referral_code
+    }
+
+    pub fn referral_fee(&self) -> u64 {
+        self.referral_fee
+    }
+
+    pub fn referral_fee_recipient(&self) -> Address {
+        self.referral_fee_recipient
+    }
+
+    pub fn partially_fillable(&self) -> bool {
+        self.partially_fillable
+    }
+
+    /// Get the EIP-712 domain of the limit order router on `chain_id`
+    ///
+    /// `verifying_contract` defaults to the limit order router compiled into
+    /// this crate for the chain (see [`get_lo_router_by_chain_id`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::UnsupportedChain`] if no verifying contract is
+    /// given and the chain has no limit order router.
+    pub fn eip712_domain(
+        chain_id: u64,
+        verifying_contract: Option<Address>,
+    ) -> Result<Eip712Domain> {
+        let verifying_contract = verifying_contract
+            .or_else(|| get_lo_router_by_chain_id(chain_id))
+            .ok_or_else(|| OdosError::unsupported_chain(chain_id))?;
+
+        Ok(Eip712Domain::new(
+            Some(LIMIT_ORDER_EIP712_NAME.into()),
+            Some(LIMIT_ORDER_EIP712_VERSION.into()),
+            Some(U256::from(chain_id)),
+            Some(verifying_contract),
+            None,
+        ))
+    }
+
+    /// Get the EIP-712 struct hash of the order
+    ///
+    /// Matches `getLimitOrderStructHash` on the limit order router.
+    pub fn eip712_struct_hash(&self) -> B256 {
+        self.to_sol().eip712_hash_struct()
+    }
+
+    /// Get the EIP-712 hash the owner signs to authorize the order
+    ///
+    /// `verifying_contract` defaults to the chain's limit order router, as in
+    /// [`eip712_domain`](Self::eip712_domain).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::UnsupportedChain`] if no verifying contract is
+    /// given and the chain has no limit order router.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::{address, U256};
+    /// use odos_sdk::LimitOrder;
+    ///
+    /// let order = LimitOrder::builder()
+    ///     .input((address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(2_000_000_000u64)))
+    ///     .output((address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"), U256::from(10u64).pow(U256::from(18))))
+    ///     .expiry(1_767_225_600)
+    ///     .build();
+    ///
+    /// // Signed against the Ethereum limit order router
+    /// let hash = order.eip712_signing_hash(1, None)?;
+    /// # Ok::<(), odos_sdk::OdosError>(())
+    /// ```
+    pub fn eip712_signing_hash(
+        &self,
+        chain_id: u64,
+        verifying_contract: Option<Address>,
+    ) -> Result<B256> {
+        let domain = Self::eip712_domain(chain_id, verifying_contract)?;
+        Ok(self.to_sol().eip712_signing_hash(&domain))
+    }
+
+    /// Sign the order's EIP-712 hash with `signer`
+    ///
+    /// The 65-byte signature can be passed to
+    /// [`LimitOrderRequest::builder`] as `Bytes::from(signature.as_bytes())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::UnsupportedChain`] as for
+    /// [`eip712_signing_hash`](Self::eip712_signing_hash), or
+    /// [`OdosError::Signing`] if the signer fails.
+    #[cfg(feature = "signer")]
+    pub async fn sign_with<S>(
+        &self,
+        signer: &S,
+        chain_id: u64,
+        verifying_contract: Option<Address>,
+    ) -> Result<alloy_primitives::Signature>
+    where
+        S: alloy_signer::Signer + ?Sized,
+    {
+        let hash = self.eip712_signing_hash(chain_id, verifying_contract)?;
+        signer
+            .sign_hash(&hash)
+            .await
+            .map_err(|err| OdosError::signing_error(err.to_string()))
+    }
+
+    fn to_sol(&self) -> eip712::LimitOrder {
+        eip712::LimitOrder {
+            input: self.input.into(),
+            output: self.output.into(),
+            expiry: U256::from(self.expiry),
+            salt: self.salt,
+            referralCode: self.referral_code,
+            referralFee: self.referral_fee,
+            referralFeeRecipient: self.referral_fee_recipient,
+            partiallyFillable: self.partially_fillable,
+        }
+    }
+}
+
+impl From<LimitOrderToken> for eip712::TokenInfo {
+    fn from(token: LimitOrderToken) -> Self {
+        Self {
+            tokenAddress: token.token_address,
+            tokenAmount: token.token_amount,
+        }
+    }
+}
+
+fn random_salt() -> U256 {
+    U256::from(Uuid::new_v4().as_u128())
+}
+
+/// Request to place a signed [`LimitOrder`] with the Odos limit order API
+///
+/// The signature is the owner's EIP-712 signature over the order, computed
+/// by the caller.
+#[derive(Builder, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrderRequest {
+    /// Chain the order is placed on
+    chain_id: u64,
+    /// Address that owns the input tokens and signed the order
+    owner: Address,
+    /// The signed order
+    order: LimitOrder,
+    /// EIP-712 signature over `order` by `owner`
+    #[builder(into)]
+    signature: Bytes,
+}
+
+impl LimitOrderRequest {
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    pub fn owner(&self) -> Address {
+        self.owner
+    }
+
+    pub fn order(&self) -> &LimitOrder {
+        &self.order
+    }
+
+    pub fn signature(&self) -> &Bytes {
+        &self.signature
+    }
+
+    /// Check the request for values the API would reject
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`] naming the offending field if an
+    /// amount is zero, both sides use the same token, the order has already
+    /// expired or the signature is empty.
+    pub fn validate(&self) -> Result<()> {
+        let order = &self.order;
+        if order.input.token_amount.is_zero() {
+            return Err(OdosError::invalid_input(
+                "order.input.token_amount: must be greater than zero",
+            ));
+        }
+        if order.output.token_amount.is_zero() {
+            return Err(OdosError::invalid_input(
+                "order.output.token_amount: must be greater than zero",
+            ));
+        }
+        if order.input.token_address == order.output.token_address {
+            return Err(OdosError::invalid_input(
+                "order.output.token_address: must differ from the input token",
+            ));
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        if order.expiry <= now {
+            return Err(OdosError::invalid_input(format!(
+                "order.expiry: must be in the future, got {}",
+                order.expiry
+            )));
+        }
+
+        if self.signature.is_empty() {
+            return Err(OdosError::invalid_input("signature: must not be empty"));
+        }
+        Ok(())
+    }
+}
+
+/// Response from the Odos limit order placement endpoint
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrderResponse {
+    /// Hash identifying the order on the limit order router
+    pub order_hash: B256,
+}
+
+/// Lifecycle state of a limit order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitOrderState {
+    /// Waiting to be filled, possibly after partial fills
+    Open,
+    /// Completely filled
+    Filled,
+    /// Cancelled by the owner
+    Cancelled,
+    /// Expired before it was completely filled
+    Expired,
+    /// A state this SDK version does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a limit order as tracked by the Odos limit order API
+///
+/// Returned by [`OdosClient::get_limit_orders`] and
+/// [`OdosClient::cancel_limit_order`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrderStatus {
+    /// Hash identifying the order on the limit order router
+    pub order_hash: B256,
+    /// Address that owns the order
+    pub owner: Address,
+    /// Current state of the order
+    pub status: LimitOrderState,
+    /// Input amount filled so far, in base units
+    #[serde(with = "crate::api::decimal_u256")]
+    pub filled_input_amount: U256,
+    /// Output amount received so far, in base units
+    #[serde(with = "crate::api::decimal_u256")]
+    pub filled_output_amount: U256,
+    /// Unix timestamp in seconds after which the order can no longer be filled
+    pub expiry: u64,
+    /// The order as placed, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<LimitOrder>,
+}
+
+/// Response from the Odos limit order listing endpoint
+#[derive(Deserialize)]
+struct LimitOrdersResponse {
+    orders: Vec<LimitOrderStatus>,
+}
+
+/// Request body of the Odos limit order cancellation endpoint
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelLimitOrderRequest {
+    chain_id: u64,
+    order_hash: B256,
+}
+
+/// Build a transaction cancelling a limit order on-chain
+///
+/// Calls `cancelLimitOrder` on the chain's limit order router from `owner`.
+/// Unlike [`OdosClient::cancel_limit_order`], which withdraws the order from
+/// the Odos API, this revokes the signed order itself, so it can no longer
+/// be filled by anyone.
+///
+/// # Errors
+///
+/// Returns [`OdosError::UnsupportedChain`] if the chain has no limit order
+/// router.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::{address, B256};
+/// use odos_sdk::{cancel_limit_order_transaction, ODOS_LO_ETHEREUM_ROUTER};
+///
+/// let owner = address!("00000000000000000000000000000000000000aa");
+/// let tx = cancel_limit_order_transaction(1, owner, B256::repeat_byte(0x11))?;
+///
+/// assert_eq!(tx.to, Some(ODOS_LO_ETHEREUM_ROUTER.into()));
+/// assert_eq!(tx.from, Some(owner));
+/// # Ok::<(), odos_sdk::OdosError>(())
+/// ```
+pub fn cancel_limit_order_transaction(
+    chain_id: u64,
+    owner: Address,
+    order_hash: B256,
+) -> Result<TransactionRequest> {
+    let router = get_lo_router_by_chain_id(chain_id)
+        .ok_or_else(|| OdosError::unsupported_chain(chain_id))?;
+
+    Ok(TransactionRequest::default()
+        .with_chain_id(chain_id)
+        .with_from(owner)
+        .with_to(router)
+        .with_input(
+            cancelLimitOrderCall {
+                orderHash: order_hash,
+            }
+            .abi_encode(),
+        ))
+}
+
+impl OdosClient {
+    /// Place a signed limit order with the Odos limit order API
+    ///
+    /// The request is validated with [`LimitOrderRequest::validate`] before
+    /// it is sent.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::InvalidInput`] - The request failed validation
+    /// - [`OdosError::Api`] - The API rejected the order, e.g. for an invalid
+    ///   signature or insufficient allowance
+    /// - [`OdosError::Http`] - Network error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{LimitOrder, LimitOrderRequest, OdosClient};
+    /// use alloy_primitives::{Address, Bytes};
+    ///
+    /// # async fn example(order: LimitOrder, owner: Address, signature: Bytes) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let request = LimitOrderRequest::builder()
+    ///     .chain_id(1)
+    ///     .owner(owner)
+    ///     .order(order)
+    ///     .signature(signature)
+    ///     .build();
+    ///
+    /// let response = client.create_limit_order(&request).await?;
+    /// println!("Placed order {}", response.order_hash);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self, request), fields(chain_id = request.chain_id), level = "debug")]
+    pub async fn create_limit_order(
+        &self,
+        request: &LimitOrderRequest,
+    ) -> Result<LimitOrderResponse> {
+        let url = self.config().endpoint.limit_order_place_url();
+        self.create_limit_order_at(url, request).await
+    }
+
+    async fn create_limit_order_at(
+        &self,
+        url: Url,
+        request: &LimitOrderRequest,
+    ) -> Result<LimitOrderResponse> {
+        request.validate()?;
+        self.post_json(url, "limit_order_place", request).await
+    }
+
+    /// List the limit orders `owner` has placed on `chain_id`
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::Api`] - The API rejected the request
+    /// - [`OdosError::Http`] - Network error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{LimitOrderState, OdosClient};
+    /// use alloy_primitives::address;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let owner = address!("00000000000000000000000000000000000000aa");
+    ///
+    /// for order in client.get_limit_orders(owner, 1).await? {
+    ///     if order.status == LimitOrderState::Open {
+    ///         println!("{} filled {} so far", order.order_hash, order.filled_input_amount);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_limit_orders(
+        &self,
+        owner: Address,
+        chain_id: u64,
+    ) -> Result<Vec<LimitOrderStatus>> {
+        let url = self.config().endpoint.limit_orders_url(chain_id, owner);
+        self.get_limit_orders_at(url).await
+    }
+
+    async fn get_limit_orders_at(&self, url: Url) -> Result<Vec<LimitOrderStatus>> {
+        let response: LimitOrdersResponse = self.get_json(url, "limit_orders").await?;
+        Ok(response.orders)
+    }
+
+    /// Withdraw a limit order from the Odos limit order API
+    ///
+    /// The API stops offering the order to fillers, but the owner's
+    /// signature stays valid on the router until the order expires. To
+    /// revoke the order itself, send the transaction built by
+    /// [`cancel_limit_order_transaction`].
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::Api`] - The order is unknown or can no longer be
+    ///   cancelled
+    /// - [`OdosError::Http`] - Network error
+    #[instrument(skip(self), level = "debug")]
+    pub async fn cancel_limit_order(
+        &self,
+        chain_id: u64,
+        order_hash: B256,
+    ) -> Result<LimitOrderStatus> {
+        let url = self.config().endpoint.limit_order_cancel_url();
+        self.cancel_limit_order_at(url, chain_id, order_hash).await
+    }
+
+    async fn cancel_limit_order_at(
+        &self,
+        url: Url,
+        chain_id: u64,
+        order_hash: B256,
+    ) -> Result<LimitOrderStatus> {
+        self.post_json(
+            url,
+            "limit_order_cancel",
+            &CancelLimitOrderRequest {
+                chain_id,
+                order_hash,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_primitives::{address, b256};
+    use wiremock::{
+        matchers::{body_partial_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{test_utils::NoopSleeper, ApiKey, ClientConfig, RetryConfig};
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const OWNER: Address = address!("00000000000000000000000000000000000000aa");
+    /// EIP-712 hashes of `order(1_767_225_600)`, on Ethereum for the signing hash
+    const STRUCT_HASH: B256 =
+        b256!("2dc1679d35c5809aa6d497a7aa4acde8f3996be6739c6f50667cf465c9bf0939");
+    const SIGNING_HASH: B256 =
+        b256!("eef07c692ea1e6e3153ebfcb448a6f7754e5deb29ecf54b67cdd2767c3125057");
+    const ORDER_HASH: B256 =
+        b256!("1111111111111111111111111111111111111111111111111111111111111111");
+
+    fn order(expiry: u64) -> LimitOrder {
+        LimitOrder::builder()
+            .input((USDC, U256::from(2_000_000_000u64)))
+            .output((WETH, U256::from(1_000_000_000_000_000_000u64)))
+            .expiry(expiry)
+            .salt(U256::from(42))
+            .build()
+    }
+
+    fn request(expiry: u64, signature: &'static [u8]) -> LimitOrderRequest {
+        LimitOrderRequest::builder()
+            .chain_id(1)
+            .owner(OWNER)
+            .order(order(expiry))
+            .signature(Bytes::from_static(signature))
+            .build()
+    }
+
+    fn test_client(api_key: Option<ApiKey>) -> OdosClient {
+        OdosClient::with_config(ClientConfig {
+            api_key,
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn place_url(server: &MockServer) -> Url {
+        Url::parse(&format!("{}/lo/order/place", server.uri())).unwrap()
+    }
+
+    /// Trimmed `GET /lo/orders/1/{owner}` response
+    const LIMIT_ORDERS_FIXTURE: &str = r#"{
+        "orders": [
+            {
+                "orderHash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "owner": "0x00000000000000000000000000000000000000aa",
+                "status": "open",
+                "filledInputAmount": "500000000",
+                "filledOutputAmount": "250000000000000000",
+                "expiry": 1767225600,
+                "order": {
+                    "input": {
+                        "tokenAddress": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                        "tokenAmount": "2000000000"
+                    },
+                    "output": {
+                        "tokenAddress": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                        "tokenAmount": "1000000000000000000"
+                    },
+                    "expiry": 1767225600,
+                    "salt": "42",
+                    "referralCode": 0,
+                    "referralFee": 0,
+                    "referralFeeRecipient": "0x0000000000000000000000000000000000000000",
+                    "partiallyFillable": true
+                }
+            },
+            {
+                "orderHash": "0x2222222222222222222222222222222222222222222222222222222222222222",
+                "owner": "0x00000000000000000000000000000000000000aa",
+                "status": "filled",
+                "filledInputAmount": "1000000",
+                "filledOutputAmount": "400000000000000",
+                "expiry": 1767225600
+            },
+            {
+                "orderHash": "0x3333333333333333333333333333333333333333333333333333333333333333",
+                "owner": "0x00000000000000000000000000000000000000aa",
+                "status": "pending_review",
+                "filledInputAmount": "0",
+                "filledOutputAmount": "0",
+                "expiry": 1767225600
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_limit_order_wire_format() {
+        let json = serde_json::to_value(request(1_767_225_600, &[0xab, 0xcd])).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "chainId": 1,
+                "owner": "0x00000000000000000000000000000000000000aa",
+                "order": {
+                    "input": {
+                        "tokenAddress": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                        "tokenAmount": "2000000000"
+                    },
+                    "output": {
+                        "tokenAddress": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+                        "tokenAmount": "1000000000000000000"
+                    },
+                    "expiry": 1_767_225_600,
+                    "salt": "42",
+                    "referralCode": 0,
+                    "referralFee": 0,
+                    "referralFeeRecipient": "0x0000000000000000000000000000000000000000",
+                    "partiallyFillable": false
+                },
+                "signature": "0xabcd"
+            })
+        );
+    }
+
+    #[test]
+    fn test_eip712_type_matches_router_layout() {
+        assert_eq!(
+            eip712::LimitOrder::eip712_encode_type(),
+            "LimitOrder(TokenInfo input,TokenInfo output,uint256 expiry,uint256 salt,\
+             uint64 referralCode,uint64 referralFee,address referralFeeRecipient,\
+             bool partiallyFillable)TokenInfo(address tokenAddress,uint256 tokenAmount)"
+        );
+    }
+
+    #[test]
+    fn test_eip712_hashes_fixed_vector() {
+        let order = order(1_767_225_600);
+
+        assert_eq!(order.eip712_struct_hash(), STRUCT_HASH);
+        assert_eq!(order.eip712_signing_hash(1, None).unwrap(), SIGNING_HASH);
+
+        // The verifying contract defaults to the chain's limit order router
+        assert_eq!(
+            order
+                .eip712_signing_hash(1, Some(crate::ODOS_LO_ETHEREUM_ROUTER))
+                .unwrap(),
+            SIGNING_HASH
+        );
+        assert_ne!(
+            order
+                .eip712_signing_hash(1, Some(Address::repeat_byte(0x01)))
+                .unwrap(),
+            SIGNING_HASH
+        );
+        assert_ne!(order.eip712_signing_hash(10, None).unwrap(), SIGNING_HASH);
+    }
+
+    #[test]
+    fn test_eip712_requires_router_or_verifying_contract() {
+        let order = order(1_767_225_600);
+
+        let err = order.eip712_signing_hash(999_999, None).unwrap_err();
+        assert!(matches!(
+            err,
+            OdosError::UnsupportedChain { chain_id: 999_999 }
+        ));
+        assert!(order
+            .eip712_signing_hash(999_999, Some(Address::repeat_byte(0x01)))
+            .is_ok());
+    }
+
+    #[cfg(feature = "signer")]
+    #[tokio::test]
+    async fn test_sign_with_recovers_owner() {
+        use alloy_signer_local::PrivateKeySigner;
+
+        let signer = PrivateKeySigner::random();
+        let order = order(1_767_225_600);
+
+        let signature = order.sign_with(&signer, 1, None).await.unwrap();
+        let recovered = signature
+            .recover_address_from_prehash(&order.eip712_signing_hash(1, None).unwrap())
+            .unwrap();
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn test_salt_defaults_to_random() {
+        let build = || {
+            LimitOrder::builder()
+                .input((USDC, U256::from(1)))
+                .output((WETH, U256::from(1)))
+                .expiry(1)
+                .build()
+        };
+        assert_ne!(build().salt(), build().salt());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_orders() {
+        let far_future = u64::MAX;
+        assert!(request(far_future, &[0x01]).validate().is_ok());
+
+        let err = request(1, &[0x01]).validate().unwrap_err();
+        assert!(err.to_string().contains("order.expiry"));
+
+        let err = request(far_future, &[]).validate().unwrap_err();
+        assert!(err.to_string().contains("signature"));
+
+        let same_token = LimitOrderRequest::builder()
+            .chain_id(1)
+            .owner(OWNER)
+            .order(
+                LimitOrder::builder()
+                    .input((USDC, U256::from(1)))
+                    .output((USDC, U256::from(1)))
+                    .expiry(far_future)
+                    .build(),
+            )
+            .signature(Bytes::from_static(&[0x01]))
+            .build();
+        let err = same_token.validate().unwrap_err();
+        assert!(err.to_string().contains("order.output.token_address"));
+    }
+
+    #[tokio::test]
+    async fn test_create_limit_order_posts_signed_order() {
+        let server = MockServer::start().await;
+        let api_key: ApiKey = "11111111-1a11-1111-a11a-aaa11a111a1a".parse().unwrap();
+        Mock::given(method("POST"))
+            .and(path("/lo/order/place"))
+            .and(header("X-API-Key", api_key.as_str().as_str()))
+            .and(body_partial_json(serde_json::json!({
+                "owner": "0x00000000000000000000000000000000000000aa",
+                "signature": "0xabcd"
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "orderHash": ORDER_HASH })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let response = test_client(Some(api_key))
+            .create_limit_order_at(place_url(&server), &request(u64::MAX, &[0xab, 0xcd]))
+            .await
+            .unwrap();
+        assert_eq!(response.order_hash, ORDER_HASH);
+    }
+
+    #[tokio::test]
+    async fn test_create_limit_order_surfaces_api_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/lo/order/place"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "detail": "Invalid signature",
+                "traceId": "10becdc8-a021-4491-8201-a17b657204e0"
+            })))
+            .mount(&server)
+            .await;
+
+        let err = test_client(None)
+            .create_limit_order_at(place_url(&server), &request(u64::MAX, &[0x01]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OdosError::Api { .. }));
+        assert!(err.to_string().contains("Invalid signature"));
+    }
+
+    #[tokio::test]
+    async fn test_get_limit_orders_parses_statuses() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/lo/orders/1/{OWNER:#x}")))
+            .respond_with(ResponseTemplate::new(200).set_body_string(LIMIT_ORDERS_FIXTURE))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let url = Url::parse(&format!("{}/lo/orders/1/{OWNER:#x}", server.uri())).unwrap();
+        let orders = test_client(None).get_limit_orders_at(url).await.unwrap();
+
+        assert_eq!(orders.len(), 3);
+        assert_eq!(orders[0].order_hash, ORDER_HASH);
+        assert_eq!(orders[0].status, LimitOrderState::Open);
+        assert_eq!(orders[0].filled_input_amount, U256::from(500_000_000u64));
+        assert_eq!(orders[0].expiry, 1_767_225_600);
+        let order = orders[0].order.as_ref().unwrap();
+        assert_eq!(order.input(), (USDC, U256::from(2_000_000_000u64)).into());
+        assert!(order.partially_fillable());
+
+        assert_eq!(orders[1].status, LimitOrderState::Filled);
+        assert_eq!(orders[1].order, None);
+        assert_eq!(orders[2].status, LimitOrderState::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_limit_order_posts_order_hash() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/lo/order/cancel"))
+            .and(body_partial_json(serde_json::json!({
+                "chainId": 1,
+                "orderHash": ORDER_HASH
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "orderHash": ORDER_HASH,
+                "owner": OWNER,
+                "status": "cancelled",
+                "filledInputAmount": "0",
+                "filledOutputAmount": "0",
+                "expiry": 1_767_225_600
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let url = Url::parse(&format!("{}/lo/order/cancel", server.uri())).unwrap();
+        let status = test_client(None)
+            .cancel_limit_order_at(url, 1, ORDER_HASH)
+            .await
+            .unwrap();
+        assert_eq!(status.status, LimitOrderState::Cancelled);
+    }
+
+    #[test]
+    fn test_cancel_limit_order_transaction_encodes_call() {
+        let tx = cancel_limit_order_transaction(1, OWNER, ORDER_HASH).unwrap();
+
+        assert_eq!(tx.to, Some(crate::ODOS_LO_ETHEREUM_ROUTER.into()));
+        assert_eq!(tx.from, Some(OWNER));
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(
+            tx.input.input().unwrap(),
+            &Bytes::from(
+                [
+                    cancelLimitOrderCall::SELECTOR.as_slice(),
+                    ORDER_HASH.as_slice()
+                ]
+                .concat()
+            )
+        );
+
+        assert!(matches!(
+            cancel_limit_order_transaction(999_999, OWNER, ORDER_HASH),
+            Err(OdosError::UnsupportedChain { chain_id: 999_999 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_limit_order_validates_before_sending() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let err = test_client(None)
+            .create_limit_order_at(place_url(&server), &request(1, &[0x01]))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+    }
+}
@@ -123,6 +123,9 @@ sol!(
     "abis/odos_limit_order_v2.json"
 );
 
+// Re-export the custom errors, decoded by `decode_router_revert`
+pub use OdosLimitOrderV2::OdosLimitOrderV2Errors;
+
 // Re-export event types for consumers who need to decode limit order events.
 // These events are fundamentally different from the Swap/SwapMulti events
 // emitted by V2/V3 routers.
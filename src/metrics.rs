@@ -0,0 +1,194 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Request metrics, recorded through the `metrics` crate facade.
+//!
+//! With the `metrics` feature enabled, every request made by
+//! [`OdosHttpClient`](crate::OdosHttpClient) is recorded to the globally
+//! installed `metrics` recorder. Install an exporter, such as
+//! `metrics-exporter-prometheus`, to collect them; without one, recording
+//! is a no-op.
+//!
+//! | Metric | Type | Labels |
+//! | ------ | ---- | ------ |
+//! | [`REQUESTS_TOTAL`] | counter | `endpoint`, `status` |
+//! | [`REQUEST_DURATION_SECONDS`] | histogram | `endpoint` |
+//! | [`RETRIES_TOTAL`] | counter | `endpoint` |
+//! | [`ERRORS_TOTAL`] | counter | `endpoint`, `category` |
+//!
+//! `endpoint` is the scheme, host and path requested, without the query.
+
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::OdosError;
+
+/// Counter of request attempts, retries included
+///
+/// `status` is the response status code, or `none` when no response
+/// arrived because of a connection error or timeout.
+pub const REQUESTS_TOTAL: &str = "odos_sdk_requests_total";
+
+/// Histogram of request attempt latency in seconds, from sending the
+/// request to receiving the response headers or the failure
+pub const REQUEST_DURATION_SECONDS: &str = "odos_sdk_request_duration_seconds";
+
+/// Counter of retries, recorded before each backoff pause
+pub const RETRIES_TOTAL: &str = "odos_sdk_retries_total";
+
+/// Counter of requests that failed after any retries
+///
+/// `category` is the error's [`OdosError::category`].
+pub const ERRORS_TOTAL: &str = "odos_sdk_errors_total";
+
+/// Record one request attempt
+pub(crate) fn record_attempt(endpoint: &str, status: Option<StatusCode>, latency: Duration) {
+    let status = status.map_or_else(|| "none".to_string(), |status| status.as_u16().to_string());
+    ::metrics::counter!(REQUESTS_TOTAL, "endpoint" => endpoint.to_string(), "status" => status)
+        .increment(1);
+    ::metrics::histogram!(REQUEST_DURATION_SECONDS, "endpoint" => endpoint.to_string())
+        .record(latency);
+}
+
+/// Record a retry of a failed attempt
+pub(crate) fn record_retry(endpoint: &str) {
+    ::metrics::counter!(RETRIES_TOTAL, "endpoint" => endpoint.to_string()).increment(1);
+}
+
+/// Record a request that failed with `error`
+pub(crate) fn record_error(endpoint: &str, error: &OdosError) {
+    ::metrics::counter!(
+        ERRORS_TOTAL,
+        "endpoint" => endpoint.to_string(),
+        "category" => error.category(),
+    )
+    .increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use metrics_util::{
+        debugging::{DebugValue, DebuggingRecorder},
+        CompositeKey,
+    };
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{test_utils::NoopSleeper, ClientConfig, OdosHttpClient, RetryConfig};
+
+    type Metric = (CompositeKey, DebugValue);
+
+    /// Sum the counters named `name` whose labels include all of `labels`
+    fn counter(metrics: &[Metric], name: &str, labels: &[(&str, &str)]) -> u64 {
+        metrics
+            .iter()
+            .filter(|(key, _)| {
+                let key = key.key();
+                key.name() == name
+                    && labels.iter().all(|(label, value)| {
+                        key.labels()
+                            .any(|l| l.key() == *label && l.value() == *value)
+                    })
+            })
+            .map(|(_, value)| match value {
+                DebugValue::Counter(count) => *count,
+                other => panic!("{name} is not a counter: {other:?}"),
+            })
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn test_requests_errors_and_retries_are_counted() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        // The current-thread test runtime keeps every await on this thread
+        let _guard = ::metrics::set_default_local_recorder(&recorder);
+
+        let mock_server = MockServer::start().await;
+        for (route, status) in [("/ok", 200), ("/bad", 400), ("/down", 503)] {
+            Mock::given(method("GET"))
+                .and(path(route))
+                .respond_with(ResponseTemplate::new(status))
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = OdosHttpClient::with_config(ClientConfig {
+            retry_config: RetryConfig {
+                max_retries: 2,
+                ..Default::default()
+            },
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap();
+        let ok = format!("{}/ok", mock_server.uri());
+        let bad = format!("{}/bad", mock_server.uri());
+        let down = format!("{}/down", mock_server.uri());
+
+        assert!(client
+            .execute_with_retry(|| client.inner().get(&ok))
+            .await
+            .is_ok());
+        assert!(client
+            .execute_with_retry(|| client.inner().get(&bad))
+            .await
+            .is_err());
+        assert!(client
+            .execute_with_retry(|| client.inner().get(&down))
+            .await
+            .is_err());
+
+        let metrics: Vec<Metric> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| (key, value))
+            .collect();
+
+        // Success
+        let requests = |endpoint: &str, status: &str| {
+            counter(
+                &metrics,
+                REQUESTS_TOTAL,
+                &[("endpoint", endpoint), ("status", status)],
+            )
+        };
+        assert_eq!(requests(&ok, "200"), 1);
+        assert_eq!(counter(&metrics, ERRORS_TOTAL, &[("endpoint", &ok)]), 0);
+
+        // API error, not retried
+        assert_eq!(requests(&bad, "400"), 1);
+        assert_eq!(
+            counter(
+                &metrics,
+                ERRORS_TOTAL,
+                &[("endpoint", &bad), ("category", "api")]
+            ),
+            1
+        );
+        assert_eq!(counter(&metrics, RETRIES_TOTAL, &[("endpoint", &bad)]), 0);
+
+        // Server error, retried once before giving up
+        assert_eq!(requests(&down, "503"), 2);
+        assert_eq!(counter(&metrics, RETRIES_TOTAL, &[("endpoint", &down)]), 1);
+        assert_eq!(counter(&metrics, ERRORS_TOTAL, &[("endpoint", &down)]), 1);
+
+        let latencies: usize = metrics
+            .iter()
+            .filter(|(key, _)| key.key().name() == REQUEST_DURATION_SECONDS)
+            .map(|(_, value)| match value {
+                DebugValue::Histogram(samples) => samples.len(),
+                other => panic!("latency is not a histogram: {other:?}"),
+            })
+            .sum();
+        assert_eq!(latencies, 4);
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{Request, StatusCode};
+
+/// Hook run on every outgoing request attempt, retries included
+///
+/// Runs on the fully built request just before it is sent, so it can read
+/// the final URL and body, e.g. to add a signature header computed over the
+/// body. Register with [`ClientConfig::with_request_hook`](crate::ClientConfig::with_request_hook).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use odos_sdk::ClientConfig;
+/// use reqwest::header::HeaderValue;
+///
+/// let config = ClientConfig::default().with_request_hook(Arc::new(|request| {
+///     let body_len = request.body().and_then(|b| b.as_bytes()).map_or(0, <[u8]>::len);
+///     request
+///         .headers_mut()
+///         .insert("x-body-length", HeaderValue::from(body_len));
+/// }));
+/// ```
+pub type RequestHook = Arc<dyn Fn(&mut Request) + Send + Sync>;
+
+/// Observer called after every request attempt, retries included
+///
+/// Register with [`ClientConfig::with_response_observer`](crate::ClientConfig::with_response_observer).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use odos_sdk::ClientConfig;
+///
+/// let config = ClientConfig::default().with_response_observer(Arc::new(|meta| {
+///     println!(
+///         "{} attempt {} -> {:?} in {:?}",
+///         meta.endpoint(),
+///         meta.attempt(),
+///         meta.status(),
+///         meta.latency()
+///     );
+/// }));
+/// ```
+pub type ResponseObserver = Arc<dyn Fn(&ResponseMeta) + Send + Sync>;
+
+/// Outcome of one request attempt, passed to a [`ResponseObserver`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    pub(crate) endpoint: String,
+    pub(crate) attempt: u32,
+    pub(crate) status: Option<StatusCode>,
+    pub(crate) latency: Duration,
+}
+
+impl ResponseMeta {
+    /// Get the endpoint requested: scheme, host and path, without the query
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Get the attempt number, starting at 1
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Get the response status, or `None` if no response arrived because of
+    /// a connection error or timeout
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// Get the time from sending the request to receiving the response
+    /// headers, or to the failure
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+}
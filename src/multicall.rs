@@ -24,7 +24,9 @@
 //! ## Multicall3 (Recommended for 10+ calls)
 //!
 //! Batches all calls into a single RPC request. More efficient at scale,
-//! atomic state reads, but requires Multicall3 contract.
+//! atomic state reads, but requires Multicall3 contract. Batches of more than
+//! [`DEFAULT_CHUNK_SIZE`] calls are split into several requests, each read
+//! atomically.
 //!
 //! ```rust,ignore
 //! use odos_sdk::multicall::{multicall_check_balances, multicall_check_allowances};
@@ -34,6 +36,9 @@
 //! let balances = multicall_check_balances(&provider, owner, &tokens).await?;
 //! ```
 //!
+//! [`multicall_token_metadata`] batches `symbol`, `name` and `decimals` the
+//! same way, for rendering arbitrary tokens.
+//!
 //! ## When to Use Which
 //!
 //! | Calls | Recommendation | Reason |
@@ -41,12 +46,33 @@
 //! | 1-5   | Parallel RPC  | Simpler, no contract dependency |
 //! | 5-10  | Either        | Similar performance |
 //! | 10+   | Multicall3    | Single RPC, lower latency, less rate limiting |
+//!
+//! ## Chains without Multicall3
+//!
+//! The batch functions call [`MULTICALL3_ADDRESS`] unless their
+//! `*_with_options` variant is given another address in
+//! [`MulticallOptions`]. [`MulticallOptions::for_chain`] looks the address up
+//! with [`multicall3_address`], and on chains without a deployment sends the
+//! same calls as parallel RPC requests, with the same results:
+//!
+//! ```rust,ignore
+//! use odos_sdk::multicall::{multicall_check_balances_with_options, MulticallOptions};
+//!
+//! let options = MulticallOptions::for_chain(chain);
+//! let balances = multicall_check_balances_with_options(&provider, owner, &tokens, &options).await?;
+//! ```
+
+use std::fmt;
 
-use alloy_network::{Ethereum, Network};
-use alloy_primitives::{Address, U256};
+use alloy_chains::NamedChain;
+use alloy_network::{Ethereum, Network, TransactionBuilder};
+use alloy_primitives::{Address, Bytes, B256, U256};
 use alloy_provider::Provider;
-use alloy_rpc_types::TransactionRequest;
+use alloy_rpc_types::{BlockId, BlockNumHash, TransactionRequest};
 use alloy_sol_types::{sol, SolCall};
+use futures_util::future::join_all;
+
+use crate::{contract::DEPLOYMENTS, Chain};
 
 // =============================================================================
 // Simple Parallel RPC Functions (no contract dependency)
@@ -162,6 +188,15 @@ sol! {
 
     #[allow(missing_docs)]
     function allowance(address owner, address spender) external view returns (uint256);
+
+    #[allow(missing_docs)]
+    function symbol() external view returns (string);
+
+    #[allow(missing_docs)]
+    function name() external view returns (string);
+
+    #[allow(missing_docs)]
+    function decimals() external view returns (uint8);
 }
 
 sol! {
@@ -180,10 +215,501 @@ sol! {
         }
 
         function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+
+        struct Call {
+            address target;
+            bytes callData;
+        }
+
+        function tryBlockAndAggregate(bool requireSuccess, Call[] calldata calls) external payable returns (uint256 blockNumber, bytes32 blockHash, Result[] memory returnData);
+
+        function getEthBalance(address addr) external view returns (uint256 balance);
+    }
+}
+
+use IMulticall3::{getEthBalanceCall, Call, Call3, IMulticall3Instance, Result as MulticallResult};
+
+/// Default maximum number of inner calls sent in one `aggregate3` request.
+///
+/// Larger batches are split into several requests so they stay within node
+/// calldata, gas and response size limits.
+pub const DEFAULT_CHUNK_SIZE: usize = 500;
+
+/// Multicall3 deployment on zkSync Era, whose bytecode-hash based
+/// addressing puts it at a different address than on other chains.
+pub const ZKSYNC_MULTICALL3_ADDRESS: Address =
+    alloy_primitives::address!("F9cda624FBC7e059355ce98a31693d299FACd963");
+
+/// Get the Multicall3 deployment on `chain`, or `None` if the SDK knows of
+/// none.
+///
+/// Every chain with a built-in Odos deployment has Multicall3, at
+/// [`MULTICALL3_ADDRESS`] except for zkSync Era. Chains registered with
+/// [`Chain::register_custom`] and other unknown chains return `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use odos_sdk::{
+///     multicall::{multicall3_address, MULTICALL3_ADDRESS, ZKSYNC_MULTICALL3_ADDRESS},
+///     Chain,
+/// };
+///
+/// assert_eq!(multicall3_address(Chain::ethereum()), Some(MULTICALL3_ADDRESS));
+/// assert_eq!(multicall3_address(Chain::zksync()), Some(ZKSYNC_MULTICALL3_ADDRESS));
+/// ```
+pub fn multicall3_address(chain: Chain) -> Option<Address> {
+    match chain.inner() {
+        NamedChain::ZkSync => Some(ZKSYNC_MULTICALL3_ADDRESS),
+        named if DEPLOYMENTS.iter().any(|(deployed, _)| *deployed == named) => {
+            Some(MULTICALL3_ADDRESS)
+        }
+        _ => None,
+    }
+}
+
+/// Options for the `*_with_options` batch functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MulticallOptions {
+    /// Multicall3 contract to batch through, or `None` to send each call as
+    /// its own `eth_call`, [`chunk_size`](Self::chunk_size) at a time in
+    /// parallel, on chains without a deployment.
+    ///
+    /// Defaults to [`MULTICALL3_ADDRESS`]; use [`for_chain`](Self::for_chain)
+    /// to pick the chain's deployment.
+    pub multicall3: Option<Address>,
+    /// Maximum number of inner calls per `aggregate3` request; values below 1
+    /// are treated as 1.
+    pub chunk_size: usize,
+    /// Also read each owner's native balance with Multicall3's
+    /// `getEthBalance` (or `eth_getBalance` without Multicall3), e.g. to
+    /// check that it can pay for gas. Used by
+    /// [`multicall_preflight_checks_with_options`].
+    pub include_native_balance: bool,
+}
+
+impl MulticallOptions {
+    /// Default options with the Multicall3 deployment of `chain`, falling
+    /// back to parallel calls if it has none (see [`multicall3_address`]).
+    pub fn for_chain(chain: Chain) -> Self {
+        Self {
+            multicall3: multicall3_address(chain),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for MulticallOptions {
+    fn default() -> Self {
+        Self {
+            multicall3: Some(MULTICALL3_ADDRESS),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            include_native_balance: false,
+        }
+    }
+}
+
+/// Build the call reading `owner`'s native balance through the Multicall3
+/// of `options`.
+///
+/// Without a Multicall3 the call still targets [`MULTICALL3_ADDRESS`], for
+/// [`call_directly`] to recognize and answer with `eth_getBalance`.
+fn native_balance_call(owner: Address, options: &MulticallOptions) -> Call3 {
+    Call3 {
+        target: options.multicall3.unwrap_or(MULTICALL3_ADDRESS),
+        allowFailure: true,
+        callData: getEthBalanceCall { addr: owner }.abi_encode().into(),
+    }
+}
+
+/// Run `calls` as `options` configure, returning the results in call order.
+async fn execute_calls<N, P>(
+    provider: &P,
+    calls: Vec<Call3>,
+    options: &MulticallOptions,
+) -> Result<Vec<MulticallResult>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let chunk_size = options.chunk_size.max(1);
+    let mut results = Vec::with_capacity(calls.len());
+
+    let Some(multicall3) = options.multicall3 else {
+        for chunk in calls.chunks(chunk_size) {
+            let outcomes =
+                join_all(chunk.iter().map(|call| call_directly(provider, call, None))).await;
+            for outcome in outcomes {
+                results.push(outcome?);
+            }
+        }
+        return Ok(results);
+    };
+
+    let multicall = IMulticall3Instance::new(multicall3, provider);
+    for chunk in calls.chunks(chunk_size) {
+        results.extend(multicall.aggregate3(chunk.to_vec()).call().await?);
+    }
+    Ok(results)
+}
+
+/// Run `calls` as `options` configure, all at the same block, returning the
+/// block number and hash and the results in call order.
+///
+/// With Multicall3, each chunk goes through `tryBlockAndAggregate` and every
+/// chunk after the first is pinned by hash to the block the first was read
+/// at. The parallel fallback resolves `block` to a number and hash first.
+async fn execute_calls_at_block<N, P>(
+    provider: &P,
+    calls: Vec<Call3>,
+    options: &MulticallOptions,
+    block: Option<BlockId>,
+) -> Result<(BlockNumHash, Vec<MulticallResult>), alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let chunk_size = options.chunk_size.max(1);
+    let mut results = Vec::with_capacity(calls.len());
+
+    let Some(multicall3) = options.multicall3 else {
+        let read_at = block_num_hash(provider, block.unwrap_or_default()).await?;
+        let pinned = Some(BlockId::hash(read_at.hash));
+
+        for chunk in calls.chunks(chunk_size) {
+            let outcomes = join_all(
+                chunk
+                    .iter()
+                    .map(|call| call_directly(provider, call, pinned)),
+            )
+            .await;
+            for outcome in outcomes {
+                results.push(outcome?);
+            }
+        }
+        return Ok((read_at, results));
+    };
+
+    let multicall = IMulticall3Instance::new(multicall3, provider);
+    let mut pinned = block;
+    let mut read_at = None;
+    for chunk in calls.chunks(chunk_size) {
+        let chunk = chunk
+            .iter()
+            .map(|call| Call {
+                target: call.target,
+                callData: call.callData.clone(),
+            })
+            .collect();
+        let mut request = multicall.tryBlockAndAggregate(false, chunk);
+        if let Some(pinned) = pinned {
+            request = request.block(pinned);
+        }
+        let read = request.call().await?;
+
+        if read_at.is_none() {
+            let number = u64::try_from(read.blockNumber).unwrap_or(u64::MAX);
+            // `blockhash` of the executing block is zero inside an `eth_call`,
+            // so most nodes leave the hash to be looked up by number
+            let hash = match read.blockHash {
+                hash if !hash.is_zero() => hash,
+                _ => {
+                    block_num_hash(provider, BlockId::number(number))
+                        .await?
+                        .hash
+                }
+            };
+            read_at = Some(BlockNumHash::new(number, hash));
+            pinned = Some(BlockId::hash(hash));
+        }
+        results.extend(read.returnData);
+    }
+    Ok((read_at.unwrap_or_default(), results))
+}
+
+/// Number and hash of the block `block_id` refers to
+async fn block_num_hash<N, P>(
+    provider: &P,
+    block_id: BlockId,
+) -> Result<BlockNumHash, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    use alloy_consensus::BlockHeader;
+    use alloy_network::{primitives::HeaderResponse, BlockResponse};
+
+    let block = provider.get_block(block_id).await?.ok_or_else(|| {
+        alloy_transport::TransportErrorKind::custom_str(&format!("Block {block_id} not found"))
+    })?;
+    Ok(BlockNumHash::new(
+        block.header().number(),
+        block.header().hash(),
+    ))
+}
+
+/// Send `call` as its own `eth_call` at `block`, reporting a revert as a
+/// failed result as `aggregate3` would.
+async fn call_directly<N, P>(
+    provider: &P,
+    call: &Call3,
+    block: Option<BlockId>,
+) -> Result<MulticallResult, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let block = block.unwrap_or_default();
+    if call.target == MULTICALL3_ADDRESS {
+        if let Ok(native) = getEthBalanceCall::abi_decode(&call.callData) {
+            let balance = provider.get_balance(native.addr).block_id(block).await?;
+            return Ok(MulticallResult {
+                success: true,
+                returnData: balance.to_be_bytes::<32>().to_vec().into(),
+            });
+        }
+    }
+
+    let tx = N::TransactionRequest::default()
+        .with_to(call.target)
+        .with_input(call.callData.clone());
+    match provider.call(tx).block(block).await {
+        Ok(return_data) => Ok(MulticallResult {
+            success: true,
+            returnData: return_data,
+        }),
+        Err(err) => match err.as_error_resp() {
+            Some(payload) if payload.as_revert_data().is_some() => Ok(MulticallResult {
+                success: false,
+                returnData: payload.as_revert_data().unwrap_or_default(),
+            }),
+            Some(payload) if payload.message.contains("revert") => Ok(MulticallResult {
+                success: false,
+                returnData: Bytes::new(),
+            }),
+            _ => Err(err.into()),
+        },
+    }
+}
+
+/// Outcome of a single inner call within a Multicall3 batch.
+///
+/// A failure of the whole batch (RPC error, Multicall3 missing) is reported
+/// as the top-level `Err` of the batch function. `CallOutcome` distinguishes
+/// the per-call result inside a successful batch, so a token contract that
+/// reverts is no longer indistinguishable from a genuine zero value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallOutcome<T> {
+    /// The call succeeded and its return data decoded to `T`.
+    Success(T),
+    /// The call reverted or returned data that could not be decoded.
+    Failed {
+        /// Raw return data (revert payload or the malformed return value).
+        return_data: Bytes,
+    },
+}
+
+impl<T> CallOutcome<T> {
+    /// Returns true if the call succeeded.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success(_))
+    }
+
+    /// Returns the decoded value, if the call succeeded.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Self::Success(value) => Some(value),
+            Self::Failed { .. } => None,
+        }
+    }
+
+    /// Converts into the decoded value, discarding failure details.
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Self::Success(value) => Some(value),
+            Self::Failed { .. } => None,
+        }
+    }
+
+    /// Returns the decoded value, or `default` if the call failed.
+    pub fn unwrap_or(self, default: T) -> T {
+        self.ok().unwrap_or(default)
+    }
+}
+
+/// Decode a `uint256`-returning inner call result.
+fn decode_u256(result: &MulticallResult) -> CallOutcome<U256> {
+    if result.success && result.returnData.len() >= 32 {
+        CallOutcome::Success(U256::from_be_slice(&result.returnData[..32]))
+    } else {
+        CallOutcome::Failed {
+            return_data: result.returnData.clone(),
+        }
+    }
+}
+
+/// Inner call of a batch, as reported by a [`MulticallCallError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MulticallCall {
+    /// ERC20 `balanceOf`.
+    BalanceOf,
+    /// ERC20 `allowance`.
+    Allowance,
+    /// Multicall3 `getEthBalance`, or `eth_getBalance` without Multicall3.
+    NativeBalance,
+}
+
+impl fmt::Display for MulticallCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::BalanceOf => "balanceOf",
+            Self::Allowance => "allowance",
+            Self::NativeBalance => "getEthBalance",
+        })
+    }
+}
+
+/// A failed inner call of a Multicall3 batch.
+///
+/// Returned per call by the `*_detailed` batch functions and collected in
+/// [`PreflightResult::errors`], so a wrong token address or a token that
+/// reverts is reported rather than read as a zero value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticallCallError {
+    /// The call that failed.
+    pub call: MulticallCall,
+    /// Contract called, or the account whose balance was read for
+    /// [`MulticallCall::NativeBalance`].
+    pub target: Address,
+    /// Whether the call reverted, rather than returning data that does not
+    /// decode (as a call to an address without code does).
+    pub reverted: bool,
+    /// Raw return data: the revert payload or the malformed return value.
+    pub return_data: Bytes,
+}
+
+impl MulticallCallError {
+    fn new(call: MulticallCall, target: Address, result: &MulticallResult) -> Self {
+        Self {
+            call,
+            target,
+            reverted: !result.success,
+            return_data: result.returnData.clone(),
+        }
+    }
+
+    /// Decode the revert reason, for a call that reverted with an
+    /// `Error(string)` or `Panic(uint256)` payload.
+    pub fn revert_reason(&self) -> Option<crate::RouterRevert> {
+        self.reverted
+            .then(|| crate::decode_router_revert(&self.return_data))
+            .flatten()
+    }
+}
+
+impl fmt::Display for MulticallCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.reverted {
+            return write!(
+                f,
+                "{} call to {} returned {} bytes, which do not decode as a uint256",
+                self.call,
+                self.target,
+                self.return_data.len()
+            );
+        }
+        write!(f, "{} call to {} reverted", self.call, self.target)?;
+        match self.revert_reason() {
+            Some(reason) => write!(f, ": {reason}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for MulticallCallError {}
+
+/// Decode a `uint256`-returning inner call result of `call` to `target`,
+/// keeping the failure details.
+fn decode_u256_detailed(
+    call: MulticallCall,
+    target: Address,
+    result: &MulticallResult,
+) -> Result<U256, MulticallCallError> {
+    decode_u256(result)
+        .ok()
+        .ok_or_else(|| MulticallCallError::new(call, target, result))
+}
+
+impl<T> From<Result<T, MulticallCallError>> for CallOutcome<T> {
+    fn from(result: Result<T, MulticallCallError>) -> Self {
+        match result {
+            Ok(value) => Self::Success(value),
+            Err(err) => Self::Failed {
+                return_data: err.return_data,
+            },
+        }
+    }
+}
+
+/// Decode a `string`-returning inner call result.
+///
+/// Tokens that predate the ERC20 standard (e.g. MKR) return `bytes32`
+/// instead; those are read up to the first zero byte. Anything else, or an
+/// empty or non-UTF-8 value, decodes to `None`.
+fn decode_string(result: &MulticallResult) -> Option<String> {
+    use alloy_sol_types::{sol_data, SolType};
+
+    if !result.success {
+        return None;
     }
+    let data = &result.returnData;
+    let value = match sol_data::String::abi_decode_validate(data) {
+        Ok(value) => value,
+        Err(_) if data.len() == 32 => {
+            let end = data.iter().position(|&b| b == 0).unwrap_or(32);
+            String::from_utf8(data[..end].to_vec()).ok()?
+        }
+        Err(_) => return None,
+    };
+    (!value.is_empty()).then_some(value)
+}
+
+/// Decode a `decimals` inner call result, accepting any `uint` return that
+/// fits in a `u8`.
+fn decode_decimals(result: &MulticallResult) -> Option<u8> {
+    decode_u256(result)
+        .ok()
+        .and_then(|decimals| u8::try_from(decimals).ok())
 }
 
-use IMulticall3::{Call3, IMulticall3Instance, Result as MulticallResult};
+/// ERC20 metadata of a token, read with [`multicall_token_metadata`].
+///
+/// Each field is `None` if the token does not implement the call, reverts,
+/// or returns data that does not decode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMetadata {
+    /// The token queried.
+    pub token: Address,
+    /// Token symbol, e.g. `USDC`.
+    pub symbol: Option<String>,
+    /// Token name, e.g. `USD Coin`.
+    pub name: Option<String>,
+    /// Number of decimals of the token's base unit.
+    pub decimals: Option<u8>,
+}
+
+impl TokenMetadata {
+    /// Build the metadata from the `symbol`, `name` and `decimals` results.
+    fn from_results(token: Address, [symbol, name, decimals]: &[MulticallResult; 3]) -> Self {
+        Self {
+            token,
+            symbol: decode_string(symbol),
+            name: decode_string(name),
+            decimals: decode_decimals(decimals),
+        }
+    }
+}
 
 /// A pre-flight check for swap prerequisites.
 #[derive(Debug, Clone)]
@@ -203,14 +729,26 @@ pub struct SwapPreflightCheck {
 pub struct PreflightResult {
     /// The token checked.
     pub token: Address,
-    /// Current balance of the owner.
-    pub balance: U256,
-    /// Current allowance for the spender.
-    pub allowance: U256,
-    /// Whether the balance is sufficient.
+    /// Current balance of the owner, or `None` if the `balanceOf` call failed.
+    pub balance: Option<U256>,
+    /// Current allowance for the spender, or `None` if the `allowance` call failed.
+    pub allowance: Option<U256>,
+    /// Whether the balance is known and sufficient.
     pub sufficient_balance: bool,
-    /// Whether the allowance is sufficient.
+    /// Whether the allowance is known and sufficient.
     pub sufficient_allowance: bool,
+    /// Native balance of the owner, if requested with
+    /// [`MulticallOptions::include_native_balance`]; `None` if not requested
+    /// or if the `getEthBalance` call failed.
+    pub native_balance: Option<U256>,
+    /// Block the values were read at, for results of
+    /// [`multicall_preflight_checks_at_block`]; `None` for unpinned results.
+    pub block_number: Option<u64>,
+    /// Hash of the block the values were read at, alongside `block_number`.
+    pub block_hash: Option<B256>,
+    /// Details of each balance, allowance or native balance call that
+    /// failed, explaining the `None` fields.
+    pub errors: Vec<MulticallCallError>,
 }
 
 impl PreflightResult {
@@ -220,11 +758,37 @@ impl PreflightResult {
     }
 
     /// Returns the amount of additional approval needed, or 0 if sufficient.
+    ///
+    /// If the allowance could not be read, the full `required` amount is
+    /// returned.
     pub fn approval_needed(&self, required: U256) -> U256 {
-        if self.allowance >= required {
+        let allowance = self.allowance.unwrap_or(U256::ZERO);
+        if allowance >= required {
             U256::ZERO
         } else {
-            required.saturating_sub(self.allowance)
+            required.saturating_sub(allowance)
+        }
+    }
+
+    /// Build a result from the balance and allowance call outcomes.
+    pub(crate) fn from_outcomes(
+        check: &SwapPreflightCheck,
+        balance: CallOutcome<U256>,
+        allowance: CallOutcome<U256>,
+    ) -> Self {
+        let balance = balance.ok();
+        let allowance = allowance.ok();
+
+        Self {
+            token: check.token,
+            balance,
+            allowance,
+            sufficient_balance: balance.is_some_and(|b| b >= check.required_amount),
+            sufficient_allowance: allowance.is_some_and(|a| a >= check.required_amount),
+            native_balance: None,
+            block_number: None,
+            block_hash: None,
+            errors: Vec::new(),
         }
     }
 }
@@ -232,6 +796,8 @@ impl PreflightResult {
 /// Batch check ERC20 balances for multiple tokens using Multicall3.
 ///
 /// Fetches all balances in a single RPC call. Recommended for 10+ tokens.
+/// Batches of more than [`DEFAULT_CHUNK_SIZE`] tokens are split into
+/// several requests.
 ///
 /// # Arguments
 ///
@@ -241,20 +807,80 @@ impl PreflightResult {
 ///
 /// # Returns
 ///
-/// A vector of balances corresponding to each token in the input list.
-/// Failed calls return `U256::ZERO`.
+/// A vector of [`CallOutcome`]s corresponding to each token in the input list.
+/// A token whose `balanceOf` reverts is reported as [`CallOutcome::Failed`];
+/// an error for the batch as a whole is returned as `Err`.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// let tokens = vec![usdc, weth, dai, link, uni];
 /// let balances = multicall_check_balances(&provider, my_address, &tokens).await?;
+///
+/// for (token, balance) in tokens.iter().zip(balances) {
+///     match balance {
+///         CallOutcome::Success(amount) => println!("{token}: {amount}"),
+///         CallOutcome::Failed { .. } => println!("{token}: balanceOf reverted"),
+///     }
+/// }
 /// ```
 pub async fn multicall_check_balances<N, P>(
     provider: &P,
     owner: Address,
     tokens: &[Address],
-) -> Result<Vec<U256>, alloy_contract::Error>
+) -> Result<Vec<CallOutcome<U256>>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    multicall_check_balances_with_options(provider, owner, tokens, &MulticallOptions::default())
+        .await
+}
+
+/// Batch check ERC20 balances for multiple tokens using Multicall3, with
+/// `options`.
+///
+/// As [`multicall_check_balances`], but sends the calls as [`MulticallOptions`] configure.
+pub async fn multicall_check_balances_with_options<N, P>(
+    provider: &P,
+    owner: Address,
+    tokens: &[Address],
+    options: &MulticallOptions,
+) -> Result<Vec<CallOutcome<U256>>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let balances = multicall_check_balances_detailed(provider, owner, tokens, options).await?;
+    Ok(balances.into_iter().map(CallOutcome::from).collect())
+}
+
+/// Batch check ERC20 balances for multiple tokens using Multicall3, with
+/// the details of each failed call.
+///
+/// As [`multicall_check_balances_with_options`], but a token whose
+/// `balanceOf` fails is reported as a [`MulticallCallError`] naming the
+/// token, whether it reverted, and its raw return data.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let balances =
+///     multicall_check_balances_detailed(&provider, owner, &tokens, &MulticallOptions::default())
+///         .await?;
+/// for balance in balances {
+///     match balance {
+///         Ok(amount) => println!("{amount}"),
+///         Err(err) => println!("{err}"),
+///     }
+/// }
+/// ```
+pub async fn multicall_check_balances_detailed<N, P>(
+    provider: &P,
+    owner: Address,
+    tokens: &[Address],
+    options: &MulticallOptions,
+) -> Result<Vec<Result<U256, MulticallCallError>>, alloy_contract::Error>
 where
     N: Network,
     P: Provider<N>,
@@ -263,8 +889,6 @@ where
         return Ok(vec![]);
     }
 
-    let multicall = IMulticall3Instance::new(MULTICALL3_ADDRESS, provider);
-
     let calls: Vec<Call3> = tokens
         .iter()
         .map(|&token| {
@@ -277,23 +901,20 @@ where
         })
         .collect();
 
-    let results: Vec<MulticallResult> = multicall.aggregate3(calls).call().await?;
+    let results = execute_calls(provider, calls, options).await?;
 
-    Ok(results
-        .into_iter()
-        .map(|result| {
-            if result.success && result.returnData.len() >= 32 {
-                U256::from_be_slice(&result.returnData[..32])
-            } else {
-                U256::ZERO
-            }
-        })
+    Ok(tokens
+        .iter()
+        .zip(&results)
+        .map(|(&token, result)| decode_u256_detailed(MulticallCall::BalanceOf, token, result))
         .collect())
 }
 
 /// Batch check ERC20 allowances for multiple tokens using Multicall3.
 ///
 /// Fetches all allowances in a single RPC call. Recommended for 10+ tokens.
+/// Batches of more than [`DEFAULT_CHUNK_SIZE`] tokens are split into
+/// several requests.
 ///
 /// # Arguments
 ///
@@ -304,14 +925,62 @@ where
 ///
 /// # Returns
 ///
-/// A vector of allowances corresponding to each token in the input list.
-/// Failed calls return `U256::ZERO`.
+/// A vector of [`CallOutcome`]s corresponding to each token in the input list.
+/// A token whose `allowance` reverts is reported as [`CallOutcome::Failed`];
+/// an error for the batch as a whole is returned as `Err`.
 pub async fn multicall_check_allowances<N, P>(
     provider: &P,
     owner: Address,
     spender: Address,
     tokens: &[Address],
-) -> Result<Vec<U256>, alloy_contract::Error>
+) -> Result<Vec<CallOutcome<U256>>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    multicall_check_allowances_with_options(
+        provider,
+        owner,
+        spender,
+        tokens,
+        &MulticallOptions::default(),
+    )
+    .await
+}
+
+/// Batch check ERC20 allowances for multiple tokens using Multicall3, with
+/// `options`.
+///
+/// As [`multicall_check_allowances`], but sends the calls as [`MulticallOptions`] configure.
+pub async fn multicall_check_allowances_with_options<N, P>(
+    provider: &P,
+    owner: Address,
+    spender: Address,
+    tokens: &[Address],
+    options: &MulticallOptions,
+) -> Result<Vec<CallOutcome<U256>>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let allowances =
+        multicall_check_allowances_detailed(provider, owner, spender, tokens, options).await?;
+    Ok(allowances.into_iter().map(CallOutcome::from).collect())
+}
+
+/// Batch check ERC20 allowances for multiple tokens using Multicall3, with
+/// the details of each failed call.
+///
+/// As [`multicall_check_allowances_with_options`], but a token whose
+/// `allowance` fails is reported as a [`MulticallCallError`] naming the
+/// token, whether it reverted, and its raw return data.
+pub async fn multicall_check_allowances_detailed<N, P>(
+    provider: &P,
+    owner: Address,
+    spender: Address,
+    tokens: &[Address],
+    options: &MulticallOptions,
+) -> Result<Vec<Result<U256, MulticallCallError>>, alloy_contract::Error>
 where
     N: Network,
     P: Provider<N>,
@@ -320,8 +989,6 @@ where
         return Ok(vec![]);
     }
 
-    let multicall = IMulticall3Instance::new(MULTICALL3_ADDRESS, provider);
-
     let calls: Vec<Call3> = tokens
         .iter()
         .map(|&token| {
@@ -334,17 +1001,12 @@ where
         })
         .collect();
 
-    let results: Vec<MulticallResult> = multicall.aggregate3(calls).call().await?;
+    let results = execute_calls(provider, calls, options).await?;
 
-    Ok(results
-        .into_iter()
-        .map(|result| {
-            if result.success && result.returnData.len() >= 32 {
-                U256::from_be_slice(&result.returnData[..32])
-            } else {
-                U256::ZERO
-            }
-        })
+    Ok(tokens
+        .iter()
+        .zip(&results)
+        .map(|(&token, result)| decode_u256_detailed(MulticallCall::Allowance, token, result))
         .collect())
 }
 
@@ -353,6 +1015,10 @@ where
 /// Efficiently checks both balances and allowances in a single batched RPC call,
 /// then returns detailed results for each token. Recommended for 10+ checks.
 ///
+/// Uses the default [`MulticallOptions`]; see
+/// [`multicall_preflight_checks_with_options`] to read native balances too or
+/// change the chunk size.
+///
 /// # Arguments
 ///
 /// * `provider` - The Alloy provider
@@ -361,10 +1027,47 @@ where
 /// # Returns
 ///
 /// A vector of results indicating whether each swap is ready to execute.
+/// A balance or allowance call that reverts leaves the corresponding field
+/// `None` and the matching `sufficient_*` flag `false`.
 pub async fn multicall_preflight_checks<N, P>(
     provider: &P,
     checks: &[SwapPreflightCheck],
 ) -> Result<Vec<PreflightResult>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    multicall_preflight_checks_with_options(provider, checks, &MulticallOptions::default()).await
+}
+
+/// Perform pre-flight checks for multiple swaps using Multicall3, with
+/// `options`.
+///
+/// As [`multicall_preflight_checks`], but with
+/// [`include_native_balance`](MulticallOptions::include_native_balance) each
+/// result's `native_balance` holds the owner's native balance, and the calls
+/// are sent in requests of at most
+/// [`chunk_size`](MulticallOptions::chunk_size) calls. Results are returned
+/// in the order of `checks` however the calls are split.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let options = MulticallOptions {
+///     include_native_balance: true,
+///     ..MulticallOptions::default()
+/// };
+/// for result in multicall_preflight_checks_with_options(&provider, &checks, &options).await? {
+///     if result.native_balance.unwrap_or_default() < gas_cost {
+///         println!("{}: not enough native balance for gas", result.token);
+///     }
+/// }
+/// ```
+pub async fn multicall_preflight_checks_with_options<N, P>(
+    provider: &P,
+    checks: &[SwapPreflightCheck],
+    options: &MulticallOptions,
+) -> Result<Vec<PreflightResult>, alloy_contract::Error>
 where
     N: Network,
     P: Provider<N>,
@@ -373,10 +1076,68 @@ where
         return Ok(vec![]);
     }
 
-    let multicall = IMulticall3Instance::new(MULTICALL3_ADDRESS, provider);
+    let results = execute_calls(provider, preflight_calls(checks, options), options).await?;
+    Ok(preflight_results(checks, &results, options, None))
+}
+
+/// Perform pre-flight checks for multiple swaps using Multicall3, all read
+/// at one block.
+///
+/// Reads through Multicall3's `tryBlockAndAggregate`, which reports the
+/// block the batch executed at, and returns its number and hash with the
+/// results; each result's `block_number` and `block_hash` carry them too.
+/// With `block` set, the reads are pinned to that block instead of the
+/// latest. Batches split into several requests by
+/// [`chunk_size`](MulticallOptions::chunk_size) are pinned by hash to the
+/// block of the first request, so balances and allowances never straddle a
+/// newly mined block or a reorg; after a reorg, later requests fail instead
+/// of reading the replacement block.
+///
+/// Multicall3 reports a zero hash when its node cannot see the hash of the
+/// executing block, in which case the hash is looked up by number.
+///
+/// Without a Multicall3 in `options`, `block` is resolved to a number and
+/// hash and the parallel calls are all made at that hash.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (block, results) =
+///     multicall_preflight_checks_at_block(&provider, &checks, None, &MulticallOptions::default())
+///         .await?;
+/// println!("Read at block {} ({})", block.number, block.hash);
+/// ```
+pub async fn multicall_preflight_checks_at_block<N, P>(
+    provider: &P,
+    checks: &[SwapPreflightCheck],
+    block: Option<BlockId>,
+    options: &MulticallOptions,
+) -> Result<(BlockNumHash, Vec<PreflightResult>), alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    let (read_at, results) =
+        execute_calls_at_block(provider, preflight_calls(checks, options), options, block).await?;
+    Ok((
+        read_at,
+        preflight_results(checks, &results, options, Some(read_at)),
+    ))
+}
+
+/// Number of calls [`preflight_calls`] makes per check.
+fn calls_per_check(options: &MulticallOptions) -> usize {
+    if options.include_native_balance {
+        3
+    } else {
+        2
+    }
+}
 
-    // Build calls for both balances and allowances
-    let mut calls: Vec<Call3> = Vec::with_capacity(checks.len() * 2);
+/// Build the balance, allowance and optional native balance calls of each
+/// check.
+fn preflight_calls(checks: &[SwapPreflightCheck], options: &MulticallOptions) -> Vec<Call3> {
+    let mut calls: Vec<Call3> = Vec::with_capacity(checks.len() * calls_per_check(options));
 
     for check in checks {
         // Balance check
@@ -398,38 +1159,138 @@ where
             allowFailure: true,
             callData: allowance_calldata.into(),
         });
+
+        // Native balance check, answered by Multicall3 itself
+        if options.include_native_balance {
+            calls.push(native_balance_call(check.owner, options));
+        }
     }
 
-    let results: Vec<MulticallResult> = multicall.aggregate3(calls).call().await?;
+    calls
+}
 
-    // Parse results in pairs (balance, allowance)
-    Ok(checks
+/// Match the results of [`preflight_calls`] back to their checks.
+fn preflight_results(
+    checks: &[SwapPreflightCheck],
+    results: &[MulticallResult],
+    options: &MulticallOptions,
+    read_at: Option<BlockNumHash>,
+) -> Vec<PreflightResult> {
+    // Parse results in groups of (balance, allowance[, native balance])
+    checks
         .iter()
-        .enumerate()
-        .map(|(i, check)| {
-            let balance_result = &results[i * 2];
-            let allowance_result = &results[i * 2 + 1];
-
-            let balance = if balance_result.success && balance_result.returnData.len() >= 32 {
-                U256::from_be_slice(&balance_result.returnData[..32])
-            } else {
-                U256::ZERO
-            };
-
-            let allowance = if allowance_result.success && allowance_result.returnData.len() >= 32 {
-                U256::from_be_slice(&allowance_result.returnData[..32])
-            } else {
-                U256::ZERO
-            };
+        .zip(results.chunks_exact(calls_per_check(options)))
+        .map(|(check, group)| {
+            let balance = decode_u256_detailed(MulticallCall::BalanceOf, check.token, &group[0]);
+            let allowance = decode_u256_detailed(MulticallCall::Allowance, check.token, &group[1]);
+            let native_balance = group.get(2).map(|native| {
+                decode_u256_detailed(MulticallCall::NativeBalance, check.owner, native)
+            });
+            let errors = [Some(&balance), Some(&allowance), native_balance.as_ref()]
+                .into_iter()
+                .flatten()
+                .filter_map(|result| result.as_ref().err().cloned())
+                .collect();
 
             PreflightResult {
-                token: check.token,
-                balance,
-                allowance,
-                sufficient_balance: balance >= check.required_amount,
-                sufficient_allowance: allowance >= check.required_amount,
+                native_balance: native_balance.and_then(Result::ok),
+                block_number: read_at.map(|block| block.number),
+                block_hash: read_at.map(|block| block.hash),
+                errors,
+                ..PreflightResult::from_outcomes(check, balance.into(), allowance.into())
             }
         })
+        .collect()
+}
+
+/// Batch read ERC20 `symbol`, `name` and `decimals` using Multicall3.
+///
+/// Fetches the metadata of all tokens in a single RPC call, three inner
+/// calls per token, split into several requests above
+/// [`DEFAULT_CHUNK_SIZE`] calls.
+///
+/// # Arguments
+///
+/// * `provider` - The Alloy provider
+/// * `tokens` - List of token addresses to read
+///
+/// # Returns
+///
+/// A vector of [`TokenMetadata`] corresponding to each token in the input
+/// list. Symbols and names returned as `bytes32` are decoded too. A call a
+/// token does not implement, or that reverts, leaves its field `None`
+/// rather than failing the batch; an error for the batch as a whole is
+/// returned as `Err`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let tokens = vec![usdc, weth, mkr];
+/// for metadata in multicall_token_metadata(&provider, &tokens).await? {
+///     println!(
+///         "{}: {} ({:?} decimals)",
+///         metadata.token,
+///         metadata.symbol.as_deref().unwrap_or("?"),
+///         metadata.decimals,
+///     );
+/// }
+/// ```
+pub async fn multicall_token_metadata<N, P>(
+    provider: &P,
+    tokens: &[Address],
+) -> Result<Vec<TokenMetadata>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    multicall_token_metadata_with_options(provider, tokens, &MulticallOptions::default()).await
+}
+
+/// Batch read ERC20 `symbol`, `name` and `decimals` using Multicall3, with
+/// `options`.
+///
+/// As [`multicall_token_metadata`], but sends the calls as [`MulticallOptions`] configure.
+pub async fn multicall_token_metadata_with_options<N, P>(
+    provider: &P,
+    tokens: &[Address],
+    options: &MulticallOptions,
+) -> Result<Vec<TokenMetadata>, alloy_contract::Error>
+where
+    N: Network,
+    P: Provider<N>,
+{
+    if tokens.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let calls: Vec<Call3> = tokens
+        .iter()
+        .flat_map(|&token| {
+            [
+                symbolCall {}.abi_encode(),
+                nameCall {}.abi_encode(),
+                decimalsCall {}.abi_encode(),
+            ]
+            .map(|calldata| Call3 {
+                target: token,
+                allowFailure: true,
+                callData: calldata.into(),
+            })
+        })
+        .collect();
+
+    let results = execute_calls(provider, calls, options).await?;
+
+    // Parse results in triples (symbol, name, decimals)
+    Ok(tokens
+        .iter()
+        .zip(results.chunks_exact(3))
+        .map(|(&token, triple)| {
+            TokenMetadata::from_results(
+                token,
+                triple.try_into().expect("chunks_exact yields triples"),
+            )
+        })
         .collect())
 }
 
@@ -451,19 +1312,27 @@ mod tests {
     fn test_preflight_result_is_ready() {
         let result = PreflightResult {
             token: Address::ZERO,
-            balance: U256::from(1000),
-            allowance: U256::from(1000),
+            balance: Some(U256::from(1000)),
+            allowance: Some(U256::from(1000)),
             sufficient_balance: true,
             sufficient_allowance: true,
+            native_balance: None,
+            block_number: None,
+            block_hash: None,
+            errors: Vec::new(),
         };
         assert!(result.is_ready());
 
         let result_insufficient = PreflightResult {
             token: Address::ZERO,
-            balance: U256::from(1000),
-            allowance: U256::from(100),
+            balance: Some(U256::from(1000)),
+            allowance: Some(U256::from(100)),
             sufficient_balance: true,
             sufficient_allowance: false,
+            native_balance: None,
+            block_number: None,
+            block_hash: None,
+            errors: Vec::new(),
         };
         assert!(!result_insufficient.is_ready());
     }
@@ -472,15 +1341,89 @@ mod tests {
     fn test_approval_needed() {
         let result = PreflightResult {
             token: Address::ZERO,
-            balance: U256::from(1000),
-            allowance: U256::from(500),
+            balance: Some(U256::from(1000)),
+            allowance: Some(U256::from(500)),
             sufficient_balance: true,
             sufficient_allowance: false,
+            native_balance: None,
+            block_number: None,
+            block_hash: None,
+            errors: Vec::new(),
         };
 
         assert_eq!(result.approval_needed(U256::from(800)), U256::from(300));
         assert_eq!(result.approval_needed(U256::from(500)), U256::ZERO);
         assert_eq!(result.approval_needed(U256::from(300)), U256::ZERO);
+
+        let unknown = PreflightResult {
+            allowance: None,
+            ..result
+        };
+        assert_eq!(unknown.approval_needed(U256::from(800)), U256::from(800));
+    }
+
+    fn success(value: u64) -> MulticallResult {
+        MulticallResult {
+            success: true,
+            returnData: U256::from(value).to_be_bytes::<32>().to_vec().into(),
+        }
+    }
+
+    fn revert(data: &'static [u8]) -> MulticallResult {
+        MulticallResult {
+            success: false,
+            returnData: Bytes::from_static(data),
+        }
+    }
+
+    #[test]
+    fn test_decode_mixed_outcomes() {
+        let results = [
+            success(1_000),
+            revert(b"\x08\xc3\x79\xa0"),
+            success(0),
+            // Succeeded but returned too little data to be a uint256
+            MulticallResult {
+                success: true,
+                returnData: Bytes::from_static(&[0x01]),
+            },
+        ];
+
+        let outcomes: Vec<CallOutcome<U256>> = results.iter().map(decode_u256).collect();
+
+        assert_eq!(outcomes[0], CallOutcome::Success(U256::from(1_000)));
+        assert_eq!(
+            outcomes[1],
+            CallOutcome::Failed {
+                return_data: Bytes::from_static(b"\x08\xc3\x79\xa0")
+            }
+        );
+        // A genuine zero balance is a success, not a failure
+        assert_eq!(outcomes[2], CallOutcome::Success(U256::ZERO));
+        assert!(!outcomes[3].is_success());
+        assert_eq!(outcomes[3].value(), None);
+    }
+
+    #[test]
+    fn test_preflight_result_from_failed_balance() {
+        let check = SwapPreflightCheck {
+            token: Address::ZERO,
+            owner: Address::ZERO,
+            spender: Address::ZERO,
+            required_amount: U256::from(100),
+        };
+
+        let result = PreflightResult::from_outcomes(
+            &check,
+            decode_u256(&revert(b"")),
+            decode_u256(&success(500)),
+        );
+
+        assert_eq!(result.balance, None);
+        assert_eq!(result.allowance, Some(U256::from(500)));
+        assert!(!result.sufficient_balance);
+        assert!(result.sufficient_allowance);
+        assert!(!result.is_ready());
     }
 
     #[test]
@@ -501,4 +1444,595 @@ mod tests {
         // allowance selector is 0xdd62ed3e
         assert_eq!(&calldata[0..4], &[0xdd, 0x62, 0xed, 0x3e]);
     }
+
+    fn returned(data: Vec<u8>) -> MulticallResult {
+        MulticallResult {
+            success: true,
+            returnData: data.into(),
+        }
+    }
+
+    #[test]
+    fn test_decode_string_symbol() {
+        use alloy_sol_types::SolValue;
+
+        let symbol = returned("USDC".to_string().abi_encode());
+        assert_eq!(decode_string(&symbol), Some("USDC".to_string()));
+
+        let name = returned("USD Coin".to_string().abi_encode());
+        assert_eq!(decode_string(&name), Some("USD Coin".to_string()));
+    }
+
+    #[test]
+    fn test_decode_bytes32_symbol() {
+        // MKR's symbol(), returned as bytes32 "MKR" padded with zeros
+        let mut data = [0u8; 32];
+        data[..3].copy_from_slice(b"MKR");
+        assert_eq!(
+            decode_string(&returned(data.to_vec())),
+            Some("MKR".to_string())
+        );
+
+        // A full-width bytes32 has no terminating zero
+        assert_eq!(
+            decode_string(&returned([b'A'; 32].to_vec())),
+            Some("A".repeat(32))
+        );
+    }
+
+    #[test]
+    fn test_decode_missing_metadata() {
+        // Not implemented: the call reverts, or succeeds on a contract
+        // without code and returns nothing
+        assert_eq!(decode_string(&revert(b"")), None);
+        assert_eq!(decode_string(&returned(vec![])), None);
+        assert_eq!(decode_string(&returned([0u8; 32].to_vec())), None);
+        assert_eq!(decode_string(&returned(vec![0xff; 32])), None);
+        assert_eq!(decode_decimals(&revert(b"")), None);
+
+        let metadata = TokenMetadata::from_results(
+            Address::ZERO,
+            &[revert(b""), returned(vec![]), success(6)],
+        );
+        assert_eq!(
+            metadata,
+            TokenMetadata {
+                token: Address::ZERO,
+                symbol: None,
+                name: None,
+                decimals: Some(6),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_decimals_out_of_range() {
+        assert_eq!(decode_decimals(&success(18)), Some(18));
+        assert_eq!(decode_decimals(&success(256)), None);
+    }
+
+    /// Token that `mount_multicall` answers with an `Error(string)` revert
+    const REVERTING_TOKEN: Address = address!("00000000000000000000000000000000000dead1");
+
+    /// Token that `mount_multicall` answers with empty return data, as an
+    /// address without code does
+    const CODELESS_TOKEN: Address = address!("00000000000000000000000000000000000dead2");
+
+    /// Mount a Multicall3 that answers `balanceOf` with the token address,
+    /// `allowance` with 1 and `getEthBalance` with 7, counting the
+    /// `aggregate3` requests
+    async fn mount_multicall(server: &wiremock::MockServer, requests: u64) {
+        use wiremock::{matchers::body_partial_json, Mock, ResponseTemplate};
+
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_call" }),
+        ))
+        .respond_with(|request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let input: Bytes = body["params"][0]["input"]
+                .as_str()
+                .unwrap()
+                .parse()
+                .unwrap();
+            let calls = IMulticall3::aggregate3Call::abi_decode(&input)
+                .unwrap()
+                .calls;
+            assert!(calls.len() <= DEFAULT_CHUNK_SIZE);
+
+            let results: Vec<MulticallResult> = calls
+                .iter()
+                .map(|call| {
+                    if call.target == REVERTING_TOKEN {
+                        return MulticallResult {
+                            success: false,
+                            returnData: alloy_sol_types::SolError::abi_encode(
+                                &alloy_sol_types::Revert::from("Pausable: paused"),
+                            )
+                            .into(),
+                        };
+                    }
+                    if call.target == CODELESS_TOKEN {
+                        return MulticallResult {
+                            success: true,
+                            returnData: Bytes::new(),
+                        };
+                    }
+                    let value = match &call.callData[..4] {
+                        _ if call.target == MULTICALL3_ADDRESS => U256::from(7),
+                        selector if selector == balanceOfCall::SELECTOR => {
+                            U256::from_be_slice(call.target.as_slice())
+                        }
+                        _ => U256::from(1),
+                    };
+                    MulticallResult {
+                        success: true,
+                        returnData: value.to_be_bytes::<32>().to_vec().into(),
+                    }
+                })
+                .collect();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "result": Bytes::from(IMulticall3::aggregate3Call::abi_encode_returns(&results)),
+            }))
+        })
+        .expect(requests)
+        .mount(server)
+        .await;
+    }
+
+    fn rpc_provider(server: &wiremock::MockServer) -> impl Provider {
+        alloy_provider::ProviderBuilder::new().connect_http(server.uri().parse().unwrap())
+    }
+
+    fn token(index: u64) -> Address {
+        Address::left_padding_from(&(index + 1).to_be_bytes())
+    }
+
+    #[tokio::test]
+    async fn test_detailed_balances_report_failed_calls() {
+        let server = wiremock::MockServer::start().await;
+        mount_multicall(&server, 2).await;
+        let provider = rpc_provider(&server);
+        let tokens = [token(0), REVERTING_TOKEN, CODELESS_TOKEN, token(3)];
+
+        let balances = multicall_check_balances_detailed(
+            &provider,
+            Address::ZERO,
+            &tokens,
+            &MulticallOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(balances[0], Ok(U256::from(1)));
+        assert_eq!(balances[3], Ok(U256::from(4)));
+
+        let reverted = balances[1].as_ref().unwrap_err();
+        assert_eq!(reverted.call, MulticallCall::BalanceOf);
+        assert_eq!(reverted.target, REVERTING_TOKEN);
+        assert!(reverted.reverted);
+        assert_eq!(
+            reverted.revert_reason(),
+            Some(crate::RouterRevert::Reason("Pausable: paused".to_string()))
+        );
+        assert_eq!(
+            reverted.to_string(),
+            format!("balanceOf call to {REVERTING_TOKEN} reverted: Pausable: paused")
+        );
+
+        let codeless = balances[2].as_ref().unwrap_err();
+        assert_eq!(codeless.target, CODELESS_TOKEN);
+        assert!(!codeless.reverted);
+        assert!(codeless.return_data.is_empty());
+        assert_eq!(codeless.revert_reason(), None);
+        assert!(codeless.to_string().contains("returned 0 bytes"));
+
+        // The lossy variant keeps the raw return data of the same failures
+        let outcomes = multicall_check_balances(&provider, Address::ZERO, &tokens)
+            .await
+            .unwrap();
+        assert_eq!(outcomes[0], CallOutcome::Success(U256::from(1)));
+        assert_eq!(
+            outcomes[1],
+            CallOutcome::Failed {
+                return_data: reverted.return_data.clone()
+            }
+        );
+        assert_eq!(
+            outcomes[2],
+            CallOutcome::Failed {
+                return_data: Bytes::new()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detailed_allowances_report_failed_calls() {
+        let server = wiremock::MockServer::start().await;
+        mount_multicall(&server, 1).await;
+
+        let allowances = multicall_check_allowances_detailed(
+            &rpc_provider(&server),
+            Address::ZERO,
+            Address::ZERO,
+            &[REVERTING_TOKEN, token(1)],
+            &MulticallOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        let reverted = allowances[0].as_ref().unwrap_err();
+        assert_eq!(reverted.call, MulticallCall::Allowance);
+        assert_eq!(reverted.target, REVERTING_TOKEN);
+        assert_eq!(allowances[1], Ok(U256::from(1)));
+    }
+
+    #[tokio::test]
+    async fn test_preflight_errors_name_failed_calls() {
+        let server = wiremock::MockServer::start().await;
+        mount_multicall(&server, 1).await;
+        let owner = token(9);
+        let check = |token| SwapPreflightCheck {
+            token,
+            owner,
+            spender: Address::ZERO,
+            required_amount: U256::from(1),
+        };
+
+        let results = multicall_preflight_checks(
+            &rpc_provider(&server),
+            &[check(token(0)), check(REVERTING_TOKEN)],
+        )
+        .await
+        .unwrap();
+
+        assert!(results[0].errors.is_empty());
+        assert!(results[0].is_ready());
+
+        let failed = &results[1];
+        assert_eq!(failed.balance, None);
+        assert_eq!(failed.allowance, None);
+        assert!(!failed.is_ready());
+        let calls: Vec<_> = failed.errors.iter().map(|err| err.call).collect();
+        assert_eq!(calls, [MulticallCall::BalanceOf, MulticallCall::Allowance]);
+        assert!(failed
+            .errors
+            .iter()
+            .all(|err| err.target == REVERTING_TOKEN && err.reverted));
+    }
+
+    #[tokio::test]
+    async fn test_large_batch_is_chunked_in_order() {
+        let server = wiremock::MockServer::start().await;
+        // 1,200 calls in chunks of 500, 500 and 200
+        mount_multicall(&server, 3).await;
+
+        let tokens: Vec<Address> = (0..1_200).map(token).collect();
+        let balances = multicall_check_balances(&rpc_provider(&server), Address::ZERO, &tokens)
+            .await
+            .unwrap();
+
+        assert_eq!(balances.len(), 1_200);
+        for (index, balance) in balances.into_iter().enumerate() {
+            assert_eq!(balance, CallOutcome::Success(U256::from(index + 1)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preflight_checks_with_native_balance_across_chunks() {
+        let server = wiremock::MockServer::start().await;
+        // 400 checks of 3 calls each, in chunks of 250 that split the groups
+        mount_multicall(&server, 5).await;
+
+        let checks: Vec<SwapPreflightCheck> = (0..400)
+            .map(|index| SwapPreflightCheck {
+                token: token(index),
+                owner: Address::repeat_byte(0xaa),
+                spender: Address::repeat_byte(0xbb),
+                required_amount: U256::from(1),
+            })
+            .collect();
+        let options = MulticallOptions {
+            chunk_size: 250,
+            include_native_balance: true,
+            ..MulticallOptions::default()
+        };
+        let results =
+            multicall_preflight_checks_with_options(&rpc_provider(&server), &checks, &options)
+                .await
+                .unwrap();
+
+        assert_eq!(results.len(), 400);
+        for (index, result) in results.iter().enumerate() {
+            assert_eq!(result.token, token(index as u64));
+            assert_eq!(result.balance, Some(U256::from(index + 1)));
+            assert_eq!(result.allowance, Some(U256::from(1)));
+            assert_eq!(result.native_balance, Some(U256::from(7)));
+            assert!(result.is_ready());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preflight_checks_default_skip_native_balance() {
+        let server = wiremock::MockServer::start().await;
+        mount_multicall(&server, 1).await;
+
+        let check = SwapPreflightCheck {
+            token: token(0),
+            owner: Address::repeat_byte(0xaa),
+            spender: Address::repeat_byte(0xbb),
+            required_amount: U256::from(1),
+        };
+        let results = multicall_preflight_checks(&rpc_provider(&server), &[check])
+            .await
+            .unwrap();
+        assert_eq!(results[0].native_balance, None);
+        assert!(results[0].is_ready());
+    }
+
+    #[test]
+    fn test_multicall3_address_table() {
+        assert_eq!(
+            multicall3_address(Chain::ethereum()),
+            Some(MULTICALL3_ADDRESS)
+        );
+        assert_eq!(multicall3_address(Chain::base()), Some(MULTICALL3_ADDRESS));
+        assert_eq!(
+            multicall3_address(Chain::zksync()),
+            Some(ZKSYNC_MULTICALL3_ADDRESS)
+        );
+        assert_eq!(multicall3_address(Chain::from(NamedChain::Sepolia)), None);
+        assert!(Chain::all().all(|chain| multicall3_address(chain).is_some()));
+
+        assert_eq!(
+            MulticallOptions::for_chain(Chain::zksync()).multicall3,
+            Some(ZKSYNC_MULTICALL3_ADDRESS)
+        );
+        assert_eq!(
+            MulticallOptions::for_chain(Chain::from(NamedChain::Sepolia)).multicall3,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_override_multicall3_address() {
+        let server = wiremock::MockServer::start().await;
+        mount_multicall(&server, 1).await;
+
+        let options = MulticallOptions::for_chain(Chain::zksync());
+        let balances = multicall_check_balances_with_options(
+            &rpc_provider(&server),
+            Address::ZERO,
+            &[token(0), token(1)],
+            &options,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            balances,
+            [
+                CallOutcome::Success(U256::from(1)),
+                CallOutcome::Success(U256::from(2))
+            ]
+        );
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let to: Address = body["params"][0]["to"].as_str().unwrap().parse().unwrap();
+        assert_eq!(to, ZKSYNC_MULTICALL3_ADDRESS);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_to_parallel_calls_without_multicall3() {
+        use wiremock::{matchers::body_partial_json, Mock, ResponseTemplate};
+
+        let reverting = token(1);
+        let server = wiremock::MockServer::start().await;
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_call" }),
+        ))
+        .respond_with(move |request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let to: Address = body["params"][0]["to"].as_str().unwrap().parse().unwrap();
+            let input: Bytes = body["params"][0]["input"]
+                .as_str()
+                .unwrap()
+                .parse()
+                .unwrap();
+            let reply = if to == reverting {
+                serde_json::json!({
+                    "code": 3,
+                    "message": "execution reverted",
+                    "data": "0x08c379a0",
+                })
+            } else {
+                let value = if input[..4] == balanceOfCall::SELECTOR {
+                    U256::from_be_slice(to.as_slice())
+                } else {
+                    U256::from(1)
+                };
+                return ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "result": alloy_primitives::B256::from(value),
+                }));
+            };
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "error": reply,
+            }))
+        })
+        // Two calls per check, one request each
+        .expect(6)
+        .mount(&server)
+        .await;
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_getBalance" }),
+        ))
+        .respond_with(|request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "result": "0x7",
+            }))
+        })
+        .expect(3)
+        .mount(&server)
+        .await;
+
+        let checks: Vec<SwapPreflightCheck> = (0..3)
+            .map(|index| SwapPreflightCheck {
+                token: token(index),
+                owner: Address::repeat_byte(0xaa),
+                spender: Address::repeat_byte(0xbb),
+                required_amount: U256::from(1),
+            })
+            .collect();
+        let options = MulticallOptions {
+            multicall3: None,
+            chunk_size: 4,
+            include_native_balance: true,
+        };
+        let results =
+            multicall_preflight_checks_with_options(&rpc_provider(&server), &checks, &options)
+                .await
+                .unwrap();
+
+        assert_eq!(results[0].balance, Some(U256::from(1)));
+        assert_eq!(results[0].allowance, Some(U256::from(1)));
+        assert_eq!(results[0].native_balance, Some(U256::from(7)));
+        // The reverting token fails its calls without failing the batch
+        assert_eq!(results[1].balance, None);
+        assert_eq!(results[1].allowance, None);
+        assert_eq!(results[1].native_balance, Some(U256::from(7)));
+        assert_eq!(results[2].balance, Some(U256::from(3)));
+        assert!(results[2].is_ready());
+    }
+
+    /// Mount a Multicall3 answering `tryBlockAndAggregate` at the requested
+    /// block number, or at block 19,000,000 for `latest` and [`BLOCK_HASH`],
+    /// with every balance and allowance 1
+    async fn mount_block_multicall(server: &wiremock::MockServer, requests: u64) {
+        use wiremock::{matchers::body_partial_json, Mock, ResponseTemplate};
+
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_call" }),
+        ))
+        .respond_with(|request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let input: Bytes = body["params"][0]["input"]
+                .as_str()
+                .unwrap()
+                .parse()
+                .unwrap();
+            let call = IMulticall3::tryBlockAndAggregateCall::abi_decode(&input).unwrap();
+            assert!(!call.requireSuccess);
+
+            let block = match requested_block(&body).as_str() {
+                "latest" => 19_000_000,
+                hash if hash == BLOCK_HASH.to_string() => 19_000_000,
+                number => u64::from_str_radix(number.trim_start_matches("0x"), 16).unwrap(),
+            };
+            let returns = IMulticall3::tryBlockAndAggregateReturn {
+                blockNumber: U256::from(block),
+                blockHash: BLOCK_HASH,
+                returnData: call.calls.iter().map(|_| success(1)).collect(),
+            };
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "result": Bytes::from(
+                    IMulticall3::tryBlockAndAggregateCall::abi_encode_returns(&returns)
+                ),
+            }))
+        })
+        .expect(requests)
+        .mount(server)
+        .await;
+    }
+
+    const BLOCK_HASH: B256 = B256::repeat_byte(0x11);
+
+    /// Block tag, number or hash an `eth_call` body was made at
+    fn requested_block(body: &serde_json::Value) -> String {
+        let block = &body["params"][1];
+        block
+            .as_str()
+            .or_else(|| block["blockHash"].as_str())
+            .unwrap()
+            .to_string()
+    }
+
+    fn requested_blocks(requests: &[wiremock::Request]) -> Vec<String> {
+        requests
+            .iter()
+            .map(|request| requested_block(&serde_json::from_slice(&request.body).unwrap()))
+            .collect()
+    }
+
+    fn checks(count: u64) -> Vec<SwapPreflightCheck> {
+        (0..count)
+            .map(|index| SwapPreflightCheck {
+                token: token(index),
+                owner: Address::repeat_byte(0xaa),
+                spender: Address::repeat_byte(0xbb),
+                required_amount: U256::from(1),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_preflight_checks_at_block_reports_block_number() {
+        let server = wiremock::MockServer::start().await;
+        // 6 calls in chunks of 4 and 2
+        mount_block_multicall(&server, 2).await;
+
+        let options = MulticallOptions {
+            chunk_size: 4,
+            ..MulticallOptions::default()
+        };
+        let (block, results) =
+            multicall_preflight_checks_at_block(&rpc_provider(&server), &checks(3), None, &options)
+                .await
+                .unwrap();
+
+        assert_eq!(block, BlockNumHash::new(19_000_000, BLOCK_HASH));
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| {
+            result.block_number == Some(19_000_000)
+                && result.block_hash == Some(BLOCK_HASH)
+                && result.is_ready()
+        }));
+        // Later chunks are pinned by hash to the block of the first
+        assert_eq!(
+            requested_blocks(&server.received_requests().await.unwrap()),
+            ["latest".to_string(), BLOCK_HASH.to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preflight_checks_at_pinned_block() {
+        let server = wiremock::MockServer::start().await;
+        mount_block_multicall(&server, 1).await;
+
+        let (block, results) = multicall_preflight_checks_at_block(
+            &rpc_provider(&server),
+            &checks(2),
+            Some(BlockId::number(18_500_000)),
+            &MulticallOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(block, BlockNumHash::new(18_500_000, BLOCK_HASH));
+        assert_eq!(results[1].block_number, Some(18_500_000));
+        assert_eq!(results[1].block_hash, Some(BLOCK_HASH));
+        assert_eq!(
+            requested_blocks(&server.received_requests().await.unwrap()),
+            ["0x11a49a0"]
+        );
+    }
 }
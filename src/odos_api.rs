@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt, future::Future, pin::Pin};
+
+use alloy_primitives::Address;
+use alloy_rpc_types::TransactionRequest;
+
+use crate::{
+    ApiVersion, AssemblyRequest, ContractInfo, LiquiditySourceInfo, OdosClient, QuoteRequest,
+    Result, SingleQuoteResponse, TokenInfo, TransactionData,
+};
+
+/// Boxed future returned by [`OdosApi`] methods
+pub type ApiFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// Odos API operations, implemented by [`OdosClient`]
+///
+/// Code that takes `&dyn OdosApi` or `impl OdosApi` instead of an
+/// [`OdosClient`] can be unit tested without a network, e.g. with
+/// `MockOdosApi` under the `test-utils` feature.
+/// [`SwapBuilder::with_api`](crate::SwapBuilder::with_api) builds swaps on
+/// top of any implementation.
+///
+/// Methods return boxed futures so the trait can be used as a trait object.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::{OdosApi, QuoteRequest, Result};
+///
+/// /// Output amount of the best route, as a string
+/// async fn best_output(api: &dyn OdosApi, request: &QuoteRequest) -> Result<String> {
+///     let quote = api.quote(request).await?;
+///     Ok(quote.out_amount().cloned().unwrap_or_default())
+/// }
+/// ```
+pub trait OdosApi: fmt::Debug + Send + Sync {
+    /// API version served, which decides the router swaps are assembled for
+    fn api_version(&self) -> ApiVersion;
+
+    /// Get a swap quote; see [`OdosClient::quote`]
+    fn quote<'a>(&'a self, request: &'a QuoteRequest) -> ApiFuture<'a, SingleQuoteResponse>;
+
+    /// Assemble a quoted path into transaction data; see
+    /// [`OdosClient::assemble_tx_data`]
+    fn assemble_tx_data<'a>(
+        &'a self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &'a str,
+    ) -> ApiFuture<'a, TransactionData>;
+
+    /// Assemble a quoted path into a transaction request; see
+    /// [`OdosClient::assemble`]
+    fn assemble<'a>(&'a self, request: &'a AssemblyRequest) -> ApiFuture<'a, TransactionRequest>;
+
+    /// Get the tokens supported on a chain; see [`OdosClient::supported_tokens`]
+    fn supported_tokens(&self, chain_id: u64) -> ApiFuture<'_, Vec<TokenInfo>>;
+
+    /// Get the router contract details for a chain; see
+    /// [`OdosClient::contract_info`]
+    fn contract_info(&self, chain_id: u64) -> ApiFuture<'_, ContractInfo>;
+
+    /// Get the liquidity sources of a chain; see
+    /// [`OdosClient::liquidity_sources`]
+    fn liquidity_sources(&self, chain_id: u64) -> ApiFuture<'_, Vec<LiquiditySourceInfo>>;
+
+    /// Get the IDs of the chains the API supports; see
+    /// [`OdosClient::supported_chains`]
+    fn supported_chains(&self) -> ApiFuture<'_, Vec<u64>>;
+}
+
+impl OdosApi for OdosClient {
+    fn api_version(&self) -> ApiVersion {
+        self.config().endpoint.version()
+    }
+
+    fn quote<'a>(&'a self, request: &'a QuoteRequest) -> ApiFuture<'a, SingleQuoteResponse> {
+        Box::pin(OdosClient::quote(self, request))
+    }
+
+    fn assemble_tx_data<'a>(
+        &'a self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &'a str,
+    ) -> ApiFuture<'a, TransactionData> {
+        Box::pin(OdosClient::assemble_tx_data(
+            self,
+            signer_address,
+            output_recipient,
+            path_id,
+        ))
+    }
+
+    fn assemble<'a>(&'a self, request: &'a AssemblyRequest) -> ApiFuture<'a, TransactionRequest> {
+        Box::pin(OdosClient::assemble(self, request))
+    }
+
+    fn supported_tokens(&self, chain_id: u64) -> ApiFuture<'_, Vec<TokenInfo>> {
+        Box::pin(OdosClient::supported_tokens(self, chain_id))
+    }
+
+    fn contract_info(&self, chain_id: u64) -> ApiFuture<'_, ContractInfo> {
+        Box::pin(OdosClient::contract_info(self, chain_id))
+    }
+
+    fn liquidity_sources(&self, chain_id: u64) -> ApiFuture<'_, Vec<LiquiditySourceInfo>> {
+        Box::pin(OdosClient::liquidity_sources(self, chain_id))
+    }
+
+    fn supported_chains(&self) -> ApiFuture<'_, Vec<u64>> {
+        Box::pin(OdosClient::supported_chains(self))
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+/// Routing graph returned by the Odos quote API when `pathViz` is requested
+///
+/// Nodes are the tokens visited by the route; links are the hops between
+/// them, each naming the liquidity source and the share of value it carries.
+/// Fields the SDK does not model are ignored here; use
+/// [`SingleQuoteResponse::path_viz_raw`](crate::SingleQuoteResponse::path_viz_raw)
+/// to access the complete payload.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct PathViz {
+    /// Tokens in the route, referenced by index from [`PathVizLink`]
+    pub nodes: Vec<PathVizNode>,
+    /// Hops between tokens
+    pub links: Vec<PathVizLink>,
+}
+
+impl PathViz {
+    /// Get the source and target nodes of a link, if its indices are in range
+    pub fn link_nodes(&self, link: &PathVizLink) -> Option<(&PathVizNode, &PathVizNode)> {
+        Some((self.nodes.get(link.source)?, self.nodes.get(link.target)?))
+    }
+}
+
+/// A token in a [`PathViz`] routing graph
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct PathVizNode {
+    /// Token name
+    pub name: String,
+    /// Token symbol
+    pub symbol: String,
+    /// Token decimals, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
+    /// Token contract address, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+/// A hop between two tokens in a [`PathViz`] routing graph
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathVizLink {
+    /// Index of the source token in [`PathViz::nodes`]
+    pub source: usize,
+    /// Index of the target token in [`PathViz::nodes`]
+    pub target: usize,
+    /// Liquidity source (protocol) used for this hop
+    #[serde(rename = "label")]
+    pub protocol: String,
+    /// Percentage of the input value routed through this hop
+    #[serde(rename = "value")]
+    pub percentage: f64,
+    /// USD value entering this hop, if reported
+    #[serde(default, rename = "in_value", skip_serializing_if = "Option::is_none")]
+    pub in_value: Option<f64>,
+    /// USD value leaving this hop, if reported
+    #[serde(default, rename = "out_value", skip_serializing_if = "Option::is_none")]
+    pub out_value: Option<f64>,
+    /// Source token details, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_token: Option<PathVizNode>,
+    /// Target token details, if reported
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_token: Option<PathVizNode>,
+}
@@ -0,0 +1,375 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Permit2 signature transfers, which let a swap pull its input token
+//! without a prior ERC-20 approval of the router.
+//!
+//! The owner approves the canonical [`PERMIT2_ADDRESS`] contract once per
+//! token, then signs a [`PermitTransferFrom`] for each swap. The router's
+//! `swapPermit2` entry point redeems the signature through Permit2 in the
+//! same transaction as the swap.
+
+use alloy_primitives::{address, Address, Bytes, B256, U256};
+use alloy_sol_types::{Eip712Domain, SolCall, SolStruct};
+use bon::Builder;
+use uuid::Uuid;
+
+use crate::{
+    OdosError,
+    OdosRouterV2::permit2Info,
+    OdosV2Router::{swapCall, swapPermit2Call},
+    Result,
+};
+
+/// Address of the canonical Permit2 contract, the same on every chain
+pub const PERMIT2_ADDRESS: Address = address!("000000000022D473030F116dDEE9F6B43aC78BA3");
+
+/// EIP-712 domain name of the Permit2 contract
+pub const PERMIT2_EIP712_NAME: &str = "Permit2";
+
+/// EIP-712 types of Permit2's `SignatureTransfer`, in the contract's field order
+mod eip712 {
+    alloy_sol_types::sol! {
+        struct TokenPermissions {
+            address token;
+            uint256 amount;
+        }
+
+        struct PermitTransferFrom {
+            TokenPermissions permitted;
+            address spender;
+            uint256 nonce;
+            uint256 deadline;
+        }
+    }
+}
+
+/// Token and maximum amount a [`PermitTransferFrom`] lets the spender pull
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TokenPermissions {
+    /// Token contract address
+    pub token: Address,
+    /// Maximum amount the spender may transfer, in base units
+    pub amount: U256,
+}
+
+impl From<(Address, U256)> for TokenPermissions {
+    fn from((token, amount): (Address, U256)) -> Self {
+        Self { token, amount }
+    }
+}
+
+/// A Permit2 signature transfer authorization, as signed by the token owner
+///
+/// Mirrors Permit2's `PermitTransferFrom` struct. For an Odos swap, the
+/// permitted token and amount are the swap's input, and the spender is the
+/// router the swap is sent to, which redeems the permit in `swapPermit2`.
+///
+/// Available only when the `v2` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::{address, U256};
+/// use odos_sdk::{PermitTransferFrom, ODOS_V2_ETHEREUM_ROUTER};
+///
+/// let permit = PermitTransferFrom::builder()
+///     .permitted((address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000)))
+///     .spender(ODOS_V2_ETHEREUM_ROUTER)
+///     .deadline(1_767_225_600)
+///     .build();
+///
+/// // Signed on Ethereum
+/// let hash = permit.eip712_signing_hash(1);
+/// ```
+#[derive(Builder, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PermitTransferFrom {
+    /// Token and maximum amount to transfer
+    #[builder(into)]
+    permitted: TokenPermissions,
+    /// Contract allowed to redeem the permit
+    spender: Address,
+    /// Permit2 signature nonce; random unless set
+    ///
+    /// Permit2 nonces are unordered: any value not used before by the owner
+    /// is valid.
+    #[builder(default = random_nonce())]
+    nonce: U256,
+    /// Unix timestamp in seconds after which the permit is invalid
+    deadline: u64,
+}
+
+impl PermitTransferFrom {
+    /// Get the token and maximum amount to transfer
+    pub fn permitted(&self) -> TokenPermissions {
+        self.permitted
+    }
+
+    /// Get the contract allowed to redeem the permit
+    pub fn spender(&self) -> Address {
+        self.spender
+    }
+
+    /// Get the Permit2 signature nonce
+    pub fn nonce(&self) -> U256 {
+        self.nonce
+    }
+
+    /// Get the Unix timestamp in seconds after which the permit is invalid
+    pub fn deadline(&self) -> u64 {
+        self.deadline
+    }
+
+    /// Get the EIP-712 domain of the Permit2 contract on `chain_id`
+    pub fn eip712_domain(chain_id: u64) -> Eip712Domain {
+        Eip712Domain::new(
+            Some(PERMIT2_EIP712_NAME.into()),
+            None,
+            Some(U256::from(chain_id)),
+            Some(PERMIT2_ADDRESS),
+            None,
+        )
+    }
+
+    /// Get the EIP-712 struct hash of the permit
+    pub fn eip712_struct_hash(&self) -> B256 {
+        self.to_sol().eip712_hash_struct()
+    }
+
+    /// Get the EIP-712 hash the owner signs to authorize the transfer on
+    /// `chain_id`
+    pub fn eip712_signing_hash(&self, chain_id: u64) -> B256 {
+        self.to_sol()
+            .eip712_signing_hash(&Self::eip712_domain(chain_id))
+    }
+
+    /// Sign the permit's EIP-712 hash with `signer`
+    ///
+    /// The 65-byte signature can be passed to
+    /// [`SwapBuilder::with_permit2`](crate::SwapBuilder::with_permit2) as
+    /// `Bytes::from(signature.as_bytes())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::Signing`] if the signer fails.
+    #[cfg(feature = "signer")]
+    pub async fn sign_with<S>(
+        &self,
+        signer: &S,
+        chain_id: u64,
+    ) -> Result<alloy_primitives::Signature>
+    where
+        S: alloy_signer::Signer + ?Sized,
+    {
+        signer
+            .sign_hash(&self.eip712_signing_hash(chain_id))
+            .await
+            .map_err(|err| OdosError::signing_error(err.to_string()))
+    }
+
+    fn to_sol(self) -> eip712::PermitTransferFrom {
+        eip712::PermitTransferFrom {
+            permitted: eip712::TokenPermissions {
+                token: self.permitted.token,
+                amount: self.permitted.amount,
+            },
+            spender: self.spender,
+            nonce: self.nonce,
+            deadline: U256::from(self.deadline),
+        }
+    }
+}
+
+fn random_nonce() -> U256 {
+    U256::from(Uuid::new_v4().as_u128())
+}
+
+/// Re-encode V2 router `swap` calldata as `swapPermit2`, redeeming `permit`
+/// with the owner's `signature`
+///
+/// The swap's token info, path, executor and referral code are kept, so the
+/// result spends and buys exactly what the original call did.
+///
+/// Available only when the `v2` feature is enabled.
+///
+/// # Errors
+///
+/// Returns [`OdosError::InvalidInput`] if `calldata` is not a V2 router
+/// `swap`, or if `permit` is not for exactly the swap's input token and
+/// amount: the router rebuilds the signed permit from the swap's input.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use alloy_primitives::Bytes;
+/// use odos_sdk::{swap_permit2_calldata, PermitTransferFrom};
+///
+/// # fn example(swap: &[u8], permit: &PermitTransferFrom, signature: Bytes) -> odos_sdk::Result<()> {
+/// let calldata = swap_permit2_calldata(swap, permit, signature)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn swap_permit2_calldata(
+    calldata: &[u8],
+    permit: &PermitTransferFrom,
+    signature: Bytes,
+) -> Result<Bytes> {
+    let swap = swapCall::abi_decode(calldata).map_err(|err| {
+        OdosError::invalid_input(format!(
+            "Permit2 assembly requires a V2 router swap call: {err}"
+        ))
+    })?;
+
+    let permitted = permit.permitted();
+    let input = (swap.tokenInfo.inputToken, swap.tokenInfo.inputAmount);
+    if permitted.token != input.0 || permitted.amount != input.1 {
+        return Err(OdosError::invalid_input(format!(
+            "Permit2 permit for {} of {} does not match the swap input of {} of {}",
+            permitted.amount, permitted.token, input.1, input.0
+        )));
+    }
+
+    Ok(swapPermit2Call {
+        permit2: permit2Info {
+            contractAddress: PERMIT2_ADDRESS,
+            nonce: permit.nonce(),
+            deadline: U256::from(permit.deadline()),
+            signature,
+        },
+        tokenInfo: swap.tokenInfo,
+        pathDefinition: swap.pathDefinition,
+        executor: swap.executor,
+        referralCode: swap.referralCode,
+    }
+    .abi_encode()
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{b256, keccak256};
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+    use crate::{
+        decode_router_calldata, DecodedOdosCall, OdosRouterV2::swapTokenInfo,
+        ODOS_V2_ETHEREUM_ROUTER,
+    };
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const EXECUTOR: Address = address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99");
+    const SIGNER: Address = address!("47E2D28169738039755586743E2dfCF3bd643f86");
+
+    fn permit(amount: u64) -> PermitTransferFrom {
+        PermitTransferFrom::builder()
+            .permitted((USDC, U256::from(amount)))
+            .spender(ODOS_V2_ETHEREUM_ROUTER)
+            .nonce(U256::from(7))
+            .deadline(1_767_225_600)
+            .build()
+    }
+
+    fn swap() -> swapCall {
+        swapCall {
+            tokenInfo: swapTokenInfo {
+                inputToken: USDC,
+                inputAmount: U256::from(1_000_000_000u64),
+                inputReceiver: EXECUTOR,
+                outputToken: WETH,
+                outputQuote: U256::from(297_096_823_183_507_284u64),
+                outputMin: U256::from(295_611_339_067_589_747u64),
+                outputReceiver: SIGNER,
+            },
+            pathDefinition: Bytes::from_static(&[0x01, 0x02]),
+            executor: EXECUTOR,
+            referralCode: 7,
+        }
+    }
+
+    #[test]
+    fn test_eip712_hashes_match_permit2() {
+        // Type hashes and domain separator as defined by the Permit2 contract
+        let token_permissions_typehash =
+            keccak256("TokenPermissions(address token,uint256 amount)");
+        assert_eq!(
+            token_permissions_typehash,
+            b256!("618358ac3db8dc274f0cd8829da7e234bd48cd73c4a740aede1adec9846d06a1")
+        );
+        let permit_typehash = keccak256(
+            "PermitTransferFrom(TokenPermissions permitted,address spender,uint256 nonce,uint256 deadline)TokenPermissions(address token,uint256 amount)",
+        );
+        assert_eq!(
+            permit_typehash,
+            b256!("939c21a48a8dbe3a9a2404a1d46691e4d39f6583d6ec6b35714604c986d80106")
+        );
+        assert_eq!(
+            PermitTransferFrom::eip712_domain(1).separator(),
+            b256!("866a5aba21966af95d6c7ab78eb2b2fc913915c28be3b9aa07cc04ff903e3f28")
+        );
+
+        let permit = permit(1_000_000_000);
+        let permitted = keccak256(
+            (
+                token_permissions_typehash,
+                USDC,
+                U256::from(1_000_000_000u64),
+            )
+                .abi_encode(),
+        );
+        let struct_hash = keccak256(
+            (
+                permit_typehash,
+                permitted,
+                ODOS_V2_ETHEREUM_ROUTER,
+                U256::from(7),
+                U256::from(1_767_225_600u64),
+            )
+                .abi_encode(),
+        );
+        assert_eq!(permit.eip712_struct_hash(), struct_hash);
+        assert_ne!(
+            permit.eip712_signing_hash(1),
+            permit.eip712_signing_hash(8453)
+        );
+    }
+
+    #[test]
+    fn test_swap_permit2_calldata_keeps_swap() {
+        let signature = Bytes::from_static(&[0xab; 65]);
+        let calldata = swap_permit2_calldata(
+            &swap().abi_encode(),
+            &permit(1_000_000_000),
+            signature.clone(),
+        )
+        .unwrap();
+        assert_eq!(calldata[..4], swapPermit2Call::SELECTOR);
+
+        let DecodedOdosCall::SwapPermit2 { permit2, swap } =
+            decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, &calldata).unwrap()
+        else {
+            panic!("expected a Permit2 swap");
+        };
+        assert_eq!(permit2.contract_address, PERMIT2_ADDRESS);
+        assert_eq!(permit2.nonce, U256::from(7));
+        assert_eq!(permit2.deadline, U256::from(1_767_225_600u64));
+        assert_eq!(permit2.signature, signature);
+        assert_eq!(swap.token_address(), USDC);
+        assert_eq!(swap.amount_in(), U256::from(1_000_000_000u64));
+        assert_eq!(swap.output_token_address(), WETH);
+        assert_eq!(swap.executor(), EXECUTOR);
+    }
+
+    #[test]
+    fn test_swap_permit2_calldata_rejects_mismatches() {
+        let signature = Bytes::from_static(&[0xab; 65]);
+        let err =
+            swap_permit2_calldata(&swap().abi_encode(), &permit(1), signature.clone()).unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+
+        let err =
+            swap_permit2_calldata(&[0xde, 0xad, 0xbe, 0xef], &permit(1), signature).unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+    }
+}
@@ -44,19 +44,26 @@ pub use crate::OdosClient;
 pub use crate::{AssemblyRequest, AssemblyResponse, QuoteRequest, SingleQuoteResponse};
 
 // High-level builder API
-pub use crate::SwapBuilder;
+#[cfg(feature = "provider")]
+pub use crate::SwapExecution;
+pub use crate::{ApprovalAmount, SwapBuilder, NATIVE_TOKEN};
 
 // Tool/runtime-friendly DTOs
 pub use crate::tooling;
 
 // Type-safe domain types
-pub use crate::{Chain, ReferralCode, Slippage};
+pub use crate::{
+    Chain, ChainMetadata, LiquiditySource, PathId, ReferralCode, Slippage, TokenAmount,
+};
 
 // Error types
-pub use crate::{ApiErrorBody, OdosError, Result};
+pub use crate::{ApiErrorBody, ErrorReport, OdosError, Result};
 
 // Configuration
-pub use crate::{ClientConfig, Endpoint, RetryConfig, RetryPredicate};
+pub use crate::{
+    CircuitBreakerConfig, ClientConfig, Endpoint, Jitter, ProxyConfig, RateLimitRetryPolicy,
+    RateLimiterConfig, RetryBudget, RetryConfig, RetryPredicate,
+};
 
 // Chain support trait
 pub use crate::OdosChain;
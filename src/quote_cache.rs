@@ -0,0 +1,315 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{OdosError, QuoteRequest, Result, SingleQuoteResponse};
+
+/// Quote cache settings for [`ClientConfig::quote_cache`](crate::ClientConfig::quote_cache)
+///
+/// Successful [`OdosClient::quote`](crate::OdosClient::quote) responses are
+/// kept for `ttl` and served again for identical requests, up to `capacity`
+/// entries; when full, expired entries are dropped first and then the least
+/// recently used one. Cached responses report
+/// [`is_cached`](SingleQuoteResponse::is_cached), since their path ID is
+/// older than the call. Errors, assembly and the quotes made by
+/// [`SwapBuilder`](crate::SwapBuilder) are never cached.
+///
+/// Requests are keyed on every field, input amounts and addresses included.
+/// With `key_on_slippage` disabled, requests differing only in their slippage
+/// limit share an entry, which suits display prices but not quotes that will
+/// be assembled.
+///
+/// The cache is shared by every clone of the client.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use odos_sdk::{ClientConfig, QuoteCacheConfig};
+///
+/// let config = ClientConfig {
+///     quote_cache: Some(QuoteCacheConfig {
+///         ttl: Duration::from_secs(5),
+///         key_on_slippage: false,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteCacheConfig {
+    /// How long a response is served from the cache
+    ///
+    /// Default: 3 seconds
+    pub ttl: Duration,
+
+    /// Maximum number of cached responses
+    ///
+    /// Default: 256
+    pub capacity: usize,
+
+    /// Whether requests with different slippage limits are cached apart
+    ///
+    /// Default: true
+    pub key_on_slippage: bool,
+}
+
+impl Default for QuoteCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(3),
+            capacity: 256,
+            key_on_slippage: true,
+        }
+    }
+}
+
+impl QuoteCacheConfig {
+    /// Check that the TTL and capacity are usable
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.ttl.is_zero() {
+            return Err(OdosError::configuration_error(
+                "Quote cache ttl must be positive",
+            ));
+        }
+        if self.capacity == 0 {
+            return Err(OdosError::configuration_error(
+                "Quote cache capacity must be at least 1",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    /// Normalized request, compared on lookup so hash collisions miss
+    request: QuoteRequest,
+    response: SingleQuoteResponse,
+    received_at: Instant,
+    /// Value of [`Entries::clock`] when the entry was last read or written
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    by_key: HashMap<u64, Entry>,
+    clock: u64,
+}
+
+impl Entries {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+/// LRU cache of quote responses shared by clones of a client
+#[derive(Debug, Clone)]
+pub(crate) struct QuoteCache {
+    config: QuoteCacheConfig,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl QuoteCache {
+    pub(crate) fn new(config: QuoteCacheConfig) -> Self {
+        Self {
+            config,
+            entries: Arc::default(),
+        }
+    }
+
+    /// Get the cached response to `request`, marked as cached
+    pub(crate) fn get(&self, request: &QuoteRequest) -> Option<SingleQuoteResponse> {
+        self.get_at(request, now())
+    }
+
+    /// Cache the response to `request`
+    pub(crate) fn insert(&self, request: &QuoteRequest, response: &SingleQuoteResponse) {
+        self.insert_at(request, response, now());
+    }
+
+    fn get_at(&self, request: &QuoteRequest, now: Instant) -> Option<SingleQuoteResponse> {
+        let (key, request) = self.key(request);
+        let mut entries = self.lock();
+        let entry = entries.by_key.get(&key)?;
+        if entry.request != request {
+            return None;
+        }
+        if self.is_expired(entry, now) {
+            entries.by_key.remove(&key);
+            return None;
+        }
+
+        let tick = entries.tick();
+        let entry = entries.by_key.get_mut(&key)?;
+        entry.last_used = tick;
+        Some(entry.response.clone().served_from_cache(entry.received_at))
+    }
+
+    fn insert_at(&self, request: &QuoteRequest, response: &SingleQuoteResponse, now: Instant) {
+        let (key, request) = self.key(request);
+        let mut entries = self.lock();
+        if !entries.by_key.contains_key(&key) && entries.by_key.len() >= self.config.capacity {
+            entries
+                .by_key
+                .retain(|_, entry| !self.is_expired(entry, now));
+        }
+        if !entries.by_key.contains_key(&key) && entries.by_key.len() >= self.config.capacity {
+            let oldest = entries
+                .by_key
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(&key, _)| key);
+            if let Some(oldest) = oldest {
+                entries.by_key.remove(&oldest);
+            }
+        }
+
+        let last_used = entries.tick();
+        entries.by_key.insert(
+            key,
+            Entry {
+                request,
+                response: response.clone(),
+                received_at: now,
+                last_used,
+            },
+        );
+    }
+
+    /// Normalize `request` and derive its key
+    fn key(&self, request: &QuoteRequest) -> (u64, QuoteRequest) {
+        let request = if self.config.key_on_slippage {
+            request.clone()
+        } else {
+            request.without_slippage()
+        };
+        (request_key(&request), request)
+    }
+
+    fn is_expired(&self, entry: &Entry, now: Instant) -> bool {
+        now.saturating_duration_since(entry.received_at) >= self.config.ttl
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Current time from tokio's clock, so tests can pause and advance it
+fn now() -> Instant {
+    tokio::time::Instant::now().into_std()
+}
+
+/// Hash of every field of `request`
+pub(crate) fn request_key(request: &QuoteRequest) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    request.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sor::tests::{quote_json, quote_request};
+
+    fn cache(config: QuoteCacheConfig) -> QuoteCache {
+        QuoteCache::new(config)
+    }
+
+    fn response() -> SingleQuoteResponse {
+        serde_json::from_value(quote_json()).unwrap()
+    }
+
+    fn with_slippage(percent: f64) -> QuoteRequest {
+        let mut json = serde_json::to_value(quote_request(None)).unwrap();
+        json["slippageLimitPercent"] = percent.into();
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_hit_until_ttl_expires() {
+        let cache = cache(QuoteCacheConfig::default());
+        let request = quote_request(None);
+        let now = Instant::now();
+        assert!(cache.get_at(&request, now).is_none());
+
+        cache.insert_at(&request, &response(), now);
+        let hit = cache
+            .get_at(&request, now + Duration::from_millis(2999))
+            .unwrap();
+        assert!(hit.is_cached());
+        assert_eq!(hit.cached_at(), Some(now));
+        assert_eq!(hit.path_id(), response().path_id());
+
+        assert!(cache
+            .get_at(&request, now + Duration::from_secs(3))
+            .is_none());
+        assert!(cache.lock().by_key.is_empty());
+    }
+
+    #[test]
+    fn test_key_covers_amounts_and_optionally_slippage() {
+        let now = Instant::now();
+        let keyed = cache(QuoteCacheConfig::default());
+        keyed.insert_at(&with_slippage(0.5), &response(), now);
+        assert!(keyed.get_at(&with_slippage(0.5), now).is_some());
+        assert!(keyed.get_at(&with_slippage(1.0), now).is_none());
+        assert!(keyed.get_at(&quote_request(Some(25.0)), now).is_none());
+
+        let unkeyed = cache(QuoteCacheConfig {
+            key_on_slippage: false,
+            ..Default::default()
+        });
+        unkeyed.insert_at(&with_slippage(0.5), &response(), now);
+        assert!(unkeyed.get_at(&with_slippage(1.0), now).is_some());
+        assert!(unkeyed.get_at(&quote_request(Some(25.0)), now).is_none());
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = cache(QuoteCacheConfig {
+            capacity: 2,
+            ..Default::default()
+        });
+        let now = Instant::now();
+        let [first, second, third] = [0.5, 1.0, 2.0].map(with_slippage);
+
+        cache.insert_at(&first, &response(), now);
+        cache.insert_at(&second, &response(), now);
+        assert!(cache.get_at(&first, now).is_some());
+        cache.insert_at(&third, &response(), now);
+
+        assert!(cache.get_at(&first, now).is_some());
+        assert!(cache.get_at(&second, now).is_none());
+        assert!(cache.get_at(&third, now).is_some());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(QuoteCacheConfig::default().validate().is_ok());
+        for invalid in [
+            QuoteCacheConfig {
+                ttl: Duration::ZERO,
+                ..Default::default()
+            },
+            QuoteCacheConfig {
+                capacity: 0,
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                invalid.validate(),
+                Err(OdosError::Configuration(_))
+            ));
+        }
+    }
+}
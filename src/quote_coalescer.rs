@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::watch;
+
+use crate::{quote_cache::request_key, QuoteRequest, Result, SingleQuoteResponse};
+
+/// Result of a finished flight, as handed to its waiters
+type Outcome = Option<Result<SingleQuoteResponse>>;
+
+/// A quote request in flight, awaited by identical requests
+#[derive(Debug)]
+struct Flight {
+    request: QuoteRequest,
+    outcome: watch::Receiver<Outcome>,
+}
+
+/// How a caller takes part in a flight
+enum Role {
+    /// Send the request and share its result
+    Leader(watch::Sender<Outcome>),
+    /// Wait for the leader's result
+    Follower(watch::Receiver<Outcome>),
+    /// An unrelated request with the same key is in flight; send alone
+    Alone,
+}
+
+/// Coalesces identical quote requests in flight, shared by clones of a
+/// client
+///
+/// The first caller for a request sends it; identical requests made before
+/// it completes wait for and receive a copy of its result, errors included.
+/// Finished flights are forgotten, so a failure does not affect later calls.
+/// If the sending caller is dropped before the response arrives, one waiter
+/// takes over and sends the request itself.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct QuoteCoalescer {
+    flights: Arc<Mutex<HashMap<u64, Flight>>>,
+}
+
+impl QuoteCoalescer {
+    /// Run `fetch` for `request`, unless an identical request is in flight
+    pub(crate) async fn run<F, Fut>(
+        &self,
+        request: &QuoteRequest,
+        fetch: F,
+    ) -> Result<SingleQuoteResponse>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<SingleQuoteResponse>>,
+    {
+        let key = request_key(request);
+        let sender = loop {
+            let mut receiver = match self.join(key, request) {
+                Role::Leader(sender) => break Some(sender),
+                Role::Alone => break None,
+                Role::Follower(receiver) => receiver,
+            };
+            // An error means the leader was dropped without a result
+            let finished = receiver
+                .wait_for(Option::is_some)
+                .await
+                .ok()
+                .and_then(|outcome| outcome.as_ref().map(duplicate));
+            if let Some(result) = finished {
+                return result;
+            }
+        };
+
+        let Some(sender) = sender else {
+            return fetch().await;
+        };
+        let landing = Landing { flights: self, key };
+        let result = fetch().await;
+        drop(landing);
+        sender.send_replace(Some(duplicate(&result)));
+        result
+    }
+
+    fn join(&self, key: u64, request: &QuoteRequest) -> Role {
+        let mut flights = self.flights.lock().unwrap_or_else(|e| e.into_inner());
+        match flights.get(&key) {
+            Some(flight) if flight.request == *request => Role::Follower(flight.outcome.clone()),
+            Some(_) => Role::Alone,
+            None => {
+                let (sender, outcome) = watch::channel(None);
+                flights.insert(
+                    key,
+                    Flight {
+                        request: request.clone(),
+                        outcome,
+                    },
+                );
+                Role::Leader(sender)
+            }
+        }
+    }
+}
+
+/// Removes the leader's flight when it finishes or is dropped
+struct Landing<'a> {
+    flights: &'a QuoteCoalescer,
+    key: u64,
+}
+
+impl Drop for Landing<'_> {
+    fn drop(&mut self) {
+        self.flights
+            .flights
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.key);
+    }
+}
+
+fn duplicate(result: &Result<SingleQuoteResponse>) -> Result<SingleQuoteResponse> {
+    match result {
+        Ok(quote) => Ok(quote.clone()),
+        Err(err) => Err(err.duplicate()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        sor::tests::{quote_json, quote_request},
+        OdosError,
+    };
+
+    fn quote() -> SingleQuoteResponse {
+        serde_json::from_value(quote_json()).unwrap()
+    }
+
+    // With the clock paused, the sleeps below only wait for the spawned tasks
+    // to go idle
+    #[tokio::test(start_paused = true)]
+    async fn test_waiter_takes_over_from_dropped_leader() {
+        let coalescer = QuoteCoalescer::default();
+        let leader = tokio::spawn({
+            let coalescer = coalescer.clone();
+            async move {
+                coalescer
+                    .run(&quote_request(None), std::future::pending)
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let follower = tokio::spawn({
+            let coalescer = coalescer.clone();
+            async move {
+                coalescer
+                    .run(&quote_request(None), || async { Ok(quote()) })
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!follower.is_finished());
+
+        leader.abort();
+        assert_eq!(follower.await.unwrap().unwrap(), quote());
+        assert!(coalescer.flights.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_finished_flights_are_forgotten() {
+        let coalescer = QuoteCoalescer::default();
+        let err = coalescer
+            .run(&quote_request(None), || async {
+                Err(OdosError::invalid_input("no route"))
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+        assert!(coalescer.flights.lock().unwrap().is_empty());
+
+        let quote = coalescer
+            .run(&quote_request(None), || async { Ok(quote()) })
+            .await
+            .unwrap();
+        assert_eq!(quote, self::quote());
+    }
+}
@@ -0,0 +1,309 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::U256;
+use tokio_stream::Stream;
+
+use crate::{OdosClient, QuoteRequest, Result, SingleQuoteResponse};
+
+type QuoteFuture = Pin<Box<dyn Future<Output = Result<SingleQuoteResponse>> + Send>>;
+
+/// Stream of quotes for one request, re-quoted on an interval
+///
+/// Created by [`OdosClient::watch_quote`]. Quotes are requested one at a
+/// time: a request that outlasts the interval skips the ticks it overran,
+/// and the next request starts on the following tick. After a rate limit
+/// error, the next request waits at least the error's `Retry-After`, or one
+/// more interval if it has none.
+///
+/// Every quote and error is yielded unless [`min_change_bps`](Self::min_change_bps)
+/// is set. The stream never ends on its own; dropping it cancels any request
+/// in flight.
+pub struct QuoteWatch {
+    client: OdosClient,
+    request: Arc<QuoteRequest>,
+    interval: Duration,
+    min_change_bps: Option<u32>,
+    /// First output amount of the last quote yielded
+    last_amount: Option<U256>,
+    /// When the next request may start
+    next_start: Instant,
+    /// When the request in flight started, or was scheduled to
+    started: Instant,
+    pending: Option<QuoteFuture>,
+}
+
+impl fmt::Debug for QuoteWatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuoteWatch")
+            .field("request", &self.request)
+            .field("interval", &self.interval)
+            .field("min_change_bps", &self.min_change_bps)
+            .field("last_amount", &self.last_amount)
+            .field("in_flight", &self.pending.is_some())
+            .finish()
+    }
+}
+
+impl QuoteWatch {
+    /// Only yield quotes whose first output amount differs from the last
+    /// yielded quote by more than `bps` basis points
+    ///
+    /// The first quote and every error are always yielded.
+    pub fn min_change_bps(mut self, bps: u32) -> Self {
+        self.min_change_bps = Some(bps);
+        self
+    }
+
+    fn start_request(&mut self) -> QuoteFuture {
+        let now = Instant::now();
+        let wait = self.next_start.saturating_duration_since(now);
+        self.started = now + wait;
+
+        let client = self.client.clone();
+        let request = Arc::clone(&self.request);
+        Box::pin(async move {
+            if !wait.is_zero() {
+                client.config().sleeper.sleep(wait).await;
+            }
+            client.quote(&request).await
+        })
+    }
+
+    /// Schedule the next request on the first tick after `result` arrived
+    fn schedule(&mut self, result: &Result<SingleQuoteResponse>) {
+        let now = Instant::now();
+        let elapsed = now.saturating_duration_since(self.started);
+        let ticks = (elapsed.as_nanos() / self.interval.as_nanos().max(1) + 1)
+            .try_into()
+            .unwrap_or(u32::MAX);
+        self.next_start = self.started + self.interval.saturating_mul(ticks);
+
+        if let Err(err) = result {
+            if err.is_rate_limit() {
+                let backoff = err.retry_after().unwrap_or(self.interval);
+                self.next_start = self.next_start.max(now + backoff);
+            }
+        }
+    }
+
+    /// Whether `quote` should be yielded, recording its amount if so
+    fn passes_threshold(&mut self, quote: &SingleQuoteResponse) -> bool {
+        let Some(bps) = self.min_change_bps else {
+            return true;
+        };
+        let Ok(amount) = quote.out_amount_u256() else {
+            return true;
+        };
+        let changed = match self.last_amount {
+            None => true,
+            Some(last) => {
+                amount.abs_diff(last).saturating_mul(U256::from(10_000))
+                    > last.saturating_mul(U256::from(bps))
+            }
+        };
+        if changed {
+            self.last_amount = Some(amount);
+        }
+        changed
+    }
+}
+
+impl Stream for QuoteWatch {
+    type Item = Result<SingleQuoteResponse>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let mut pending = match self.pending.take() {
+                Some(pending) => pending,
+                None => self.start_request(),
+            };
+            let Poll::Ready(result) = pending.as_mut().poll(cx) else {
+                self.pending = Some(pending);
+                return Poll::Pending;
+            };
+
+            self.schedule(&result);
+            match &result {
+                Ok(quote) if !self.passes_threshold(quote) => continue,
+                _ => return Poll::Ready(Some(result)),
+            }
+        }
+    }
+}
+
+impl OdosClient {
+    /// Stream quotes for `request`, re-quoted every `interval`
+    ///
+    /// The first quote is requested when the stream is first polled. See
+    /// [`QuoteWatch`] for how overruns and rate limits stretch the interval,
+    /// and [`QuoteWatch::min_change_bps`] to only yield meaningful price
+    /// moves. Waits go through
+    /// [`ClientConfig::sleeper`](crate::ClientConfig::sleeper).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use odos_sdk::{OdosClient, QuoteRequest};
+    /// use tokio_stream::StreamExt;
+    ///
+    /// # async fn example(request: QuoteRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let mut quotes = client
+    ///     .watch_quote(request, Duration::from_secs(2))
+    ///     .min_change_bps(5);
+    /// while let Some(quote) = quotes.next().await {
+    ///     println!("{:?}", quote?.out_amount());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_quote(&self, request: QuoteRequest, interval: Duration) -> QuoteWatch {
+        let now = Instant::now();
+        QuoteWatch {
+            client: self.clone(),
+            request: Arc::new(request),
+            interval,
+            min_change_bps: None,
+            last_amount: None,
+            next_start: now,
+            started: now,
+            pending: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    use tokio_stream::StreamExt;
+    use url::Url;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, Request, Respond, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{
+        sor::tests::{quote_json, quote_request},
+        test_utils::RecordingSleeper,
+        ApiVersion, ClientConfig, Endpoint, RetryConfig,
+    };
+
+    /// Answers with each output amount in turn, or a rate limit for `None`
+    struct AmountSequence {
+        amounts: Mutex<std::vec::IntoIter<Option<u64>>>,
+        hits: Arc<AtomicUsize>,
+    }
+
+    impl Respond for AmountSequence {
+        fn respond(&self, _request: &Request) -> ResponseTemplate {
+            self.hits.fetch_add(1, Ordering::SeqCst);
+            match self.amounts.lock().unwrap().next().flatten() {
+                Some(amount) => {
+                    let mut quote = quote_json();
+                    quote["outAmounts"] = serde_json::json!([amount.to_string()]);
+                    ResponseTemplate::new(200).set_body_json(quote)
+                }
+                None => ResponseTemplate::new(429)
+                    .insert_header("retry-after", "5")
+                    .set_body_string("Slow down"),
+            }
+        }
+    }
+
+    async fn client(
+        amounts: Vec<Option<u64>>,
+        sleeper: &RecordingSleeper,
+    ) -> (OdosClient, MockServer, Arc<AtomicUsize>) {
+        let server = MockServer::start().await;
+        let hits = Arc::new(AtomicUsize::new(0));
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(AmountSequence {
+                amounts: Mutex::new(amounts.into_iter()),
+                hits: Arc::clone(&hits),
+            })
+            .mount(&server)
+            .await;
+        let client = OdosClient::with_config(ClientConfig {
+            endpoint: Endpoint::custom(Url::parse(&server.uri()).unwrap(), ApiVersion::V2),
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(sleeper.clone()),
+            ..Default::default()
+        })
+        .unwrap();
+        (client, server, hits)
+    }
+
+    fn out_amount(quote: Result<SingleQuoteResponse>) -> u64 {
+        quote.unwrap().out_amount().unwrap().parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_watch_yields_every_quote_on_interval() {
+        let sleeper = RecordingSleeper::new();
+        let (client, _server, hits) =
+            client(vec![Some(1000), Some(1000), Some(1001)], &sleeper).await;
+
+        let interval = Duration::from_millis(500);
+        let quotes: Vec<u64> = client
+            .watch_quote(quote_request(None), interval)
+            .take(3)
+            .map(out_amount)
+            .collect()
+            .await;
+
+        assert_eq!(quotes, [1000, 1000, 1001]);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        // The first quote is immediate, later ones wait for their tick
+        assert_eq!(sleeper.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_watch_skips_small_changes() {
+        let sleeper = RecordingSleeper::new();
+        let amounts = [1000, 1000, 1005, 1200, 1201, 1000].map(Some).to_vec();
+        let (client, _server, hits) = client(amounts, &sleeper).await;
+
+        let quotes: Vec<u64> = client
+            .watch_quote(quote_request(None), Duration::from_millis(10))
+            .min_change_bps(100)
+            .take(3)
+            .map(out_amount)
+            .collect()
+            .await;
+
+        assert_eq!(quotes, [1000, 1200, 1000]);
+        assert_eq!(hits.load(Ordering::SeqCst), 6);
+    }
+
+    #[tokio::test]
+    async fn test_watch_backs_off_after_rate_limit() {
+        let sleeper = RecordingSleeper::new();
+        let (client, _server, _hits) = client(vec![Some(1000), None, Some(1100)], &sleeper).await;
+
+        let mut quotes = client.watch_quote(quote_request(None), Duration::from_millis(100));
+        assert_eq!(out_amount(quotes.next().await.unwrap()), 1000);
+        assert!(quotes.next().await.unwrap().unwrap_err().is_rate_limit());
+        assert_eq!(out_amount(quotes.next().await.unwrap()), 1100);
+
+        let waits = sleeper.durations();
+        assert!(waits[1] > Duration::from_millis(4900) && waits[1] <= Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+/// Server-reported rate limit state, parsed from response headers
+///
+/// Read from the `x-ratelimit-limit`, `x-ratelimit-remaining` and
+/// `x-ratelimit-reset` headers, or their unprefixed `ratelimit-*` forms.
+/// Each field is `None` when its header is missing or malformed.
+///
+/// The latest snapshot is available from
+/// [`OdosClient::last_rate_limit_info`](crate::OdosClient::last_rate_limit_info),
+/// so callers can slow down before the API starts answering with 429s, and
+/// [`OdosError::RateLimit`](crate::OdosError::RateLimit) carries the snapshot
+/// from the rejected response.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::OdosClient;
+///
+/// # fn example(client: &OdosClient) {
+/// if let Some(info) = client.last_rate_limit_info() {
+///     if info.remaining == Some(0) {
+///         println!("Out of requests, window resets in {:?}", info.reset);
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window
+    pub limit: Option<u64>,
+
+    /// Requests left in the current window
+    pub remaining: Option<u64>,
+
+    /// Time until the window resets, from when the response was received
+    pub reset: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parse the rate limit headers, returning `None` if none are usable
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let info = Self {
+            limit: header_u64(headers, "limit"),
+            remaining: header_u64(headers, "remaining"),
+            reset: header_u64(headers, "reset").map(Duration::from_secs),
+        };
+        (info.limit.is_some() || info.remaining.is_some() || info.reset.is_some()).then_some(info)
+    }
+}
+
+/// Read `x-ratelimit-<name>`, falling back to `ratelimit-<name>`
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    [format!("x-ratelimit-{name}"), format!("ratelimit-{name}")]
+        .iter()
+        .find_map(|header| headers.get(header.as_str()))
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.parse().unwrap(), HeaderValue::from_static(value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_prefixed_and_unprefixed_headers() {
+        let info = RateLimitInfo::from_headers(&headers(&[
+            ("x-ratelimit-limit", "600"),
+            ("x-ratelimit-remaining", "598"),
+            ("x-ratelimit-reset", "42"),
+        ]));
+        assert_eq!(
+            info,
+            Some(RateLimitInfo {
+                limit: Some(600),
+                remaining: Some(598),
+                reset: Some(Duration::from_secs(42)),
+            })
+        );
+
+        let info = RateLimitInfo::from_headers(&headers(&[("ratelimit-remaining", "3")]));
+        assert_eq!(
+            info,
+            Some(RateLimitInfo {
+                limit: None,
+                remaining: Some(3),
+                reset: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_or_malformed_headers() {
+        assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+        assert_eq!(
+            RateLimitInfo::from_headers(&headers(&[
+                ("x-ratelimit-limit", "many"),
+                ("x-ratelimit-remaining", "-1"),
+            ])),
+            None
+        );
+    }
+}
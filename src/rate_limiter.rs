@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{OdosError, Result};
+
+/// Client-side rate limit for [`ClientConfig::rate_limiter`](crate::ClientConfig::rate_limiter)
+///
+/// A token bucket holding up to `burst` tokens, refilled at
+/// `requests_per_second`. Every request attempt, including retries, takes a
+/// token; when the bucket is empty the request waits for the next token
+/// instead of being rejected, so bursts of concurrent quote and assemble
+/// calls are spread out to the configured rate. Waiting requests are served
+/// in the order they asked.
+///
+/// The bucket is shared by every clone of the client, so share one client
+/// across the application for the limit to hold globally.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::{ClientConfig, RateLimiterConfig};
+///
+/// let config = ClientConfig {
+///     rate_limiter: Some(RateLimiterConfig {
+///         requests_per_second: 5.0,
+///         burst: 10,
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimiterConfig {
+    /// Sustained request rate; may be fractional, e.g. `0.5` for one request
+    /// every two seconds
+    pub requests_per_second: f64,
+
+    /// Requests that may be sent back to back before the rate applies
+    pub burst: u32,
+}
+
+impl RateLimiterConfig {
+    /// Check that the rate and burst are usable
+    pub(crate) fn validate(&self) -> Result<()> {
+        let rate = self.requests_per_second;
+        if !rate.is_finite() || rate <= 0.0 {
+            return Err(OdosError::configuration_error(format!(
+                "Rate limiter requests_per_second must be positive and finite, got {rate}"
+            )));
+        }
+        if self.burst == 0 {
+            return Err(OdosError::configuration_error(
+                "Rate limiter burst must be at least 1",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative when waiting requests have reserved
+    /// tokens that have not been refilled yet
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token bucket shared by clones of a client
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    config: RateLimiterConfig,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimiterConfig) -> Self {
+        Self {
+            config,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(config.burst),
+                refilled_at: now(),
+            }),
+        }
+    }
+
+    /// Take a token, returning how long to wait before sending
+    pub(crate) fn reserve(&self) -> Duration {
+        self.reserve_at(now())
+    }
+
+    /// Return a token taken by [`reserve`](Self::reserve) for a request
+    /// that was not sent
+    pub(crate) fn refund(&self) {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        bucket.tokens = (bucket.tokens + 1.0).min(f64::from(self.config.burst));
+    }
+
+    fn reserve_at(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        let rate = self.config.requests_per_second;
+
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * rate).min(f64::from(self.config.burst));
+        bucket.refilled_at = bucket.refilled_at.max(now);
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / rate)
+        }
+    }
+}
+
+/// Current time from tokio's clock, so tests can pause and advance it
+fn now() -> Instant {
+    tokio::time::Instant::now().into_std()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_second: f64, burst: u32) -> RateLimiter {
+        RateLimiter::new(RateLimiterConfig {
+            requests_per_second,
+            burst,
+        })
+    }
+
+    fn millis(delay: Duration) -> u128 {
+        delay.as_micros().div_ceil(1000)
+    }
+
+    #[test]
+    fn test_burst_then_steady_rate() {
+        let limiter = limiter(5.0, 2);
+        let now = Instant::now();
+
+        let delays: Vec<u128> = (0..5).map(|_| millis(limiter.reserve_at(now))).collect();
+        assert_eq!(delays, [0, 0, 200, 400, 600]);
+    }
+
+    #[test]
+    fn test_bucket_refills_up_to_burst() {
+        let limiter = limiter(2.0, 3);
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve_at(now), Duration::ZERO);
+        }
+
+        // Half a second refills one token
+        let later = now + Duration::from_millis(500);
+        assert_eq!(limiter.reserve_at(later), Duration::ZERO);
+        assert_eq!(millis(limiter.reserve_at(later)), 500);
+
+        // A long idle period refills no more than the burst
+        let idle = later + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve_at(idle), Duration::ZERO);
+        }
+        assert!(limiter.reserve_at(idle) > Duration::ZERO);
+    }
+
+    // The clock is paused, so the waits pass without waiting
+    #[tokio::test(start_paused = true)]
+    async fn test_concurrent_reservations_are_spread_to_rate() {
+        let limiter = std::sync::Arc::new(limiter(5.0, 5));
+        let start = tokio::time::Instant::now();
+
+        let mut requests = tokio::task::JoinSet::new();
+        for _ in 0..20 {
+            let limiter = limiter.clone();
+            requests.spawn(async move {
+                tokio::time::sleep(limiter.reserve()).await;
+                start.elapsed()
+            });
+        }
+        let mut sent = requests.join_all().await;
+        sent.sort();
+
+        // 5 requests from the burst, then 15 more at 200ms intervals
+        let expected: Vec<u128> = [0; 5]
+            .into_iter()
+            .chain((1..=15).map(|n| n * 200))
+            .collect();
+        assert_eq!(sent.into_iter().map(millis).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_refund_returns_token() {
+        let limiter = limiter(5.0, 1);
+        let now = Instant::now();
+        assert_eq!(limiter.reserve_at(now), Duration::ZERO);
+        assert_eq!(millis(limiter.reserve_at(now)), 200);
+
+        // The refunded reservation no longer delays the next one
+        limiter.refund();
+        assert_eq!(millis(limiter.reserve_at(now)), 200);
+
+        // Refunds never fill the bucket past the burst
+        for _ in 0..3 {
+            limiter.refund();
+        }
+        assert_eq!(limiter.reserve_at(now), Duration::ZERO);
+        assert_eq!(millis(limiter.reserve_at(now)), 200);
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let valid = RateLimiterConfig {
+            requests_per_second: 0.5,
+            burst: 1,
+        };
+        assert!(valid.validate().is_ok());
+
+        for invalid in [
+            RateLimiterConfig {
+                requests_per_second: 0.0,
+                ..valid
+            },
+            RateLimiterConfig {
+                requests_per_second: f64::NAN,
+                ..valid
+            },
+            RateLimiterConfig { burst: 0, ..valid },
+        ] {
+            assert!(matches!(
+                invalid.validate(),
+                Err(OdosError::Configuration(_))
+            ));
+        }
+    }
+}
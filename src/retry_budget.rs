@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{OdosError, Result};
+
+/// Cap on retries across requests for [`RetryConfig::retry_budget`](crate::RetryConfig::retry_budget)
+///
+/// A token bucket holding up to `retries_per_minute` retries and refilled
+/// at that rate. Every retry takes a token; once the bucket is empty, failed
+/// attempts return their error immediately instead of being retried, so an
+/// outage does not multiply request volume by `max_retries`. First attempts
+/// are never limited.
+///
+/// The bucket is shared by clones of the budget, so every clone of a client
+/// (and any other client configured with a clone of the same budget) draws
+/// from it.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::{ClientConfig, RetryBudget, RetryConfig};
+///
+/// let budget = RetryBudget::new(60);
+/// let config = ClientConfig {
+///     retry_config: RetryConfig {
+///         retry_budget: Some(budget.clone()),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+///
+/// // Later, for observability
+/// println!("retries denied: {}", budget.denied());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    retries_per_minute: u32,
+    bucket: Mutex<Bucket>,
+    denied: AtomicU64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    /// Create a budget of `retries_per_minute`, starting full
+    pub fn new(retries_per_minute: u32) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                retries_per_minute,
+                bucket: Mutex::new(Bucket {
+                    tokens: f64::from(retries_per_minute),
+                    refilled_at: Instant::now(),
+                }),
+                denied: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Get the configured number of retries per minute
+    pub fn retries_per_minute(&self) -> u32 {
+        self.inner.retries_per_minute
+    }
+
+    /// Get the number of retries denied because the budget was exhausted
+    pub fn denied(&self) -> u64 {
+        self.inner.denied.load(Ordering::Relaxed)
+    }
+
+    /// Check that the budget allows any retries
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.inner.retries_per_minute == 0 {
+            return Err(OdosError::configuration_error(
+                "Retry budget retries_per_minute must be at least 1; use RetryConfig::no_retries to disable retries",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Take a retry token, returning `false` and counting the denial when
+    /// the budget is exhausted
+    pub(crate) fn try_acquire(&self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> bool {
+        let capacity = f64::from(self.inner.retries_per_minute);
+        let mut bucket = self.inner.bucket.lock().unwrap_or_else(|e| e.into_inner());
+
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        let per_second = capacity / Duration::from_secs(60).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * per_second).min(capacity);
+        bucket.refilled_at = bucket.refilled_at.max(now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            self.inner.denied.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_exhausts_and_refills() {
+        let budget = RetryBudget::new(2);
+        let now = Instant::now();
+
+        assert!(budget.try_acquire_at(now));
+        assert!(budget.try_acquire_at(now));
+        assert!(!budget.try_acquire_at(now));
+        assert_eq!(budget.denied(), 1);
+
+        // Two per minute refills one token every 30 seconds
+        let later = now + Duration::from_secs(30);
+        assert!(budget.try_acquire_at(later));
+        assert!(!budget.try_acquire_at(later));
+        assert_eq!(budget.denied(), 2);
+
+        // A long idle period refills no more than the capacity
+        let idle = later + Duration::from_secs(600);
+        assert!(budget.try_acquire_at(idle));
+        assert!(budget.try_acquire_at(idle));
+        assert!(!budget.try_acquire_at(idle));
+    }
+
+    #[test]
+    fn test_clones_share_budget() {
+        let budget = RetryBudget::new(1);
+        let clone = budget.clone();
+
+        assert!(clone.try_acquire());
+        assert!(!budget.try_acquire());
+        assert_eq!(clone.denied(), 1);
+    }
+
+    #[test]
+    fn test_budget_validation() {
+        assert!(RetryBudget::new(1).validate().is_ok());
+        assert!(matches!(
+            RetryBudget::new(0).validate(),
+            Err(OdosError::Configuration(_))
+        ));
+    }
+}
@@ -0,0 +1,364 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decoding of router revert data into readable reasons.
+//!
+//! Nodes report a reverted `eth_call` or `eth_estimateGas` with the raw
+//! revert data. [`decode_router_revert`] turns it into a [`RouterRevert`]:
+//!
+//! ```rust
+//! use alloy_primitives::hex;
+//! use odos_sdk::{decode_router_revert, RouterRevert};
+//!
+//! // The V2 router's `require(..., "Slippage Limit Exceeded")`
+//! let data = hex!(
+//!     "08c379a0"
+//!     "0000000000000000000000000000000000000000000000000000000000000020"
+//!     "0000000000000000000000000000000000000000000000000000000000000017"
+//!     "536c697070616765204c696d6974204578636565646564000000000000000000"
+//! );
+//! let revert = decode_router_revert(&data).unwrap();
+//! assert_eq!(revert, RouterRevert::Reason("Slippage Limit Exceeded".to_string()));
+//! assert!(revert.is_slippage_exceeded());
+//! ```
+
+use std::fmt;
+
+use alloy_primitives::U256;
+#[cfg(any(feature = "v3", feature = "limit-orders"))]
+use alloy_sol_types::SolInterface;
+use alloy_sol_types::{Panic, PanicKind, Revert, SolError};
+
+#[cfg(feature = "limit-orders")]
+use crate::OdosLimitOrderV2Errors;
+#[cfg(feature = "v3")]
+use crate::OdosV3Router::OdosV3RouterErrors;
+
+/// The V2 router's revert reason when an output falls below its minimum
+const SLIPPAGE_LIMIT_EXCEEDED: &str = "Slippage Limit Exceeded";
+
+/// Why an Odos router call reverted
+///
+/// Returned by [`decode_router_revert`]. The V2 router reverts with
+/// `require` strings, the V3 and limit order routers also with custom
+/// errors. Each variant's [`Display`](fmt::Display) is a human-readable
+/// reason.
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum RouterRevert {
+    /// `Error(string)`, from a failed `require` or `revert("...")`
+    Reason(String),
+
+    /// `Panic(uint256)`, from a failed `assert`, an arithmetic overflow or
+    /// another Solidity runtime check
+    Panic(U256),
+
+    /// A custom error of the V3 router
+    #[cfg(feature = "v3")]
+    V3(OdosV3RouterErrors),
+
+    /// A custom error of the limit order router, boxed as some carry
+    /// signatures
+    #[cfg(feature = "limit-orders")]
+    LimitOrder(Box<OdosLimitOrderV2Errors>),
+}
+
+// The generated error bindings implement neither `Debug` nor `PartialEq`,
+// so custom errors are shown by their reason and compared by encoding
+impl fmt::Debug for RouterRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reason(reason) => f.debug_tuple("Reason").field(reason).finish(),
+            Self::Panic(code) => f.debug_tuple("Panic").field(code).finish(),
+            #[cfg(feature = "v3")]
+            Self::V3(_) => f.debug_tuple("V3").field(&self.to_string()).finish(),
+            #[cfg(feature = "limit-orders")]
+            Self::LimitOrder(_) => f
+                .debug_tuple("LimitOrder")
+                .field(&self.to_string())
+                .finish(),
+        }
+    }
+}
+
+impl PartialEq for RouterRevert {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Reason(a), Self::Reason(b)) => a == b,
+            (Self::Panic(a), Self::Panic(b)) => a == b,
+            #[cfg(feature = "v3")]
+            (Self::V3(a), Self::V3(b)) => a.abi_encode() == b.abi_encode(),
+            #[cfg(feature = "limit-orders")]
+            (Self::LimitOrder(a), Self::LimitOrder(b)) => a.abi_encode() == b.abi_encode(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for RouterRevert {}
+
+impl RouterRevert {
+    /// Get the kind of a [`Panic`](Self::Panic), if its code is a known one
+    pub fn panic_kind(&self) -> Option<PanicKind> {
+        match self {
+            Self::Panic(code) => u32::try_from(code).ok().and_then(PanicKind::from_number),
+            _ => None,
+        }
+    }
+
+    /// Whether the call reverted because an output fell below its minimum
+    ///
+    /// A swap that fails this way may succeed if re-quoted, or with more
+    /// slippage.
+    pub fn is_slippage_exceeded(&self) -> bool {
+        match self {
+            Self::Reason(reason) => reason == SLIPPAGE_LIMIT_EXCEEDED,
+            #[cfg(feature = "limit-orders")]
+            Self::LimitOrder(error) => {
+                matches!(**error, OdosLimitOrderV2Errors::SlippageLimitExceeded(_))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for RouterRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reason(reason) => f.write_str(reason),
+            Self::Panic(code) => match self.panic_kind() {
+                Some(kind) => write!(f, "panic: {kind}"),
+                None => write!(f, "panic code {code:#x}"),
+            },
+            #[cfg(feature = "v3")]
+            Self::V3(error) => fmt_v3_error(error, f),
+            #[cfg(feature = "limit-orders")]
+            Self::LimitOrder(error) => fmt_limit_order_error(error, f),
+        }
+    }
+}
+
+#[cfg(feature = "v3")]
+fn fmt_v3_error(error: &OdosV3RouterErrors, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match error {
+        OdosV3RouterErrors::OwnableInvalidOwner(e) => {
+            write!(f, "invalid router owner {}", e.owner)
+        }
+        OdosV3RouterErrors::OwnableUnauthorizedAccount(e) => {
+            write!(f, "{} is not the router owner", e.account)
+        }
+        OdosV3RouterErrors::SafeERC20FailedOperation(e) => {
+            write!(f, "token {} transfer or approval failed", e.token)
+        }
+    }
+}
+
+#[cfg(feature = "limit-orders")]
+fn fmt_limit_order_error(
+    error: &OdosLimitOrderV2Errors,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    use OdosLimitOrderV2Errors::*;
+
+    match error {
+        AddressNotAllowed(e) => write!(f, "{} is not an allowed filler", e.account),
+        ArbitrageNotAllowed(e) => write!(f, "arbitrage of token {} is not allowed", e.tokenAddress),
+        CurrentAmountMismatch(e) => write!(
+            f,
+            "token {} order amount {} with {} filled does not match the current amount {}",
+            e.tokenAddress, e.orderAmount, e.filledAmount, e.currentAmount
+        ),
+        ERC1271Revert(e) => write!(f, "ERC-1271 signature check reverted: {}", e.error),
+        ERC6492DeployFailed(e) => write!(f, "ERC-6492 signer deployment failed: {}", e.error),
+        FunctionIsDisabled(_) => f.write_str("function is disabled"),
+        InvalidAddress(e) => write!(f, "invalid address {}", e._address),
+        InvalidArguments(_) => f.write_str("invalid arguments"),
+        InvalidEip1271Signature(e) => write!(
+            f,
+            "invalid ERC-1271 signature from {} for order {}",
+            e.account, e.orderHash
+        ),
+        InvalidPresignLength(e) => write!(
+            f,
+            "presign length is {}, expected {}",
+            e.actualLength, e.expectedLength
+        ),
+        InvalidReferralFee(e) => write!(f, "invalid referral fee {}", e.referralFee),
+        InvalidShortString(_) => f.write_str("invalid short string"),
+        InvalidSignatureLength(_) => f.write_str("invalid signature length"),
+        InvalidSignatureVValue(_) => f.write_str("invalid signature v value"),
+        MinSurplusCheckFailed(e) => write!(
+            f,
+            "token {} surplus is {}, expected at least {}",
+            e.tokenAddress, e.actualValue, e.expectedValue
+        ),
+        OrderCancelled(e) => write!(f, "order {} is cancelled", e.orderHash),
+        OrderExpired(e) => write!(
+            f,
+            "order expired at {}, current time is {}",
+            e.orderExpiry, e.currentTimestamp
+        ),
+        OrderNotPresigned(e) => {
+            write!(f, "order {} is not presigned by {}", e.orderHash, e.account)
+        }
+        SlippageLimitExceeded(e) => write!(
+            f,
+            "slippage limit exceeded: token {} amount is {}, expected {}",
+            e.tokenAddress, e.actualAmount, e.expectedAmount
+        ),
+        StringTooLong(e) => write!(f, "string too long: {}", e.str),
+        TransferFailed(e) => write!(f, "transfer of {} to {} failed", e.amount, e.destination),
+    }
+}
+
+/// Decode the revert data of a failed router call
+///
+/// Recognizes `Error(string)`, `Panic(uint256)`, and the custom errors of
+/// the V3 router (with the `v3` feature) and the limit order router (with
+/// the `limit-orders` feature). Returns `None` for empty or unrecognized
+/// data, such as a revert bubbled up from a token or liquidity source with
+/// its own custom errors.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::hex;
+/// use alloy_sol_types::PanicKind;
+/// use odos_sdk::decode_router_revert;
+///
+/// let data = hex!("4e487b710000000000000000000000000000000000000000000000000000000000000011");
+/// let revert = decode_router_revert(&data).unwrap();
+/// assert_eq!(revert.panic_kind(), Some(PanicKind::UnderOverflow));
+/// assert_eq!(revert.to_string(), "panic: arithmetic underflow or overflow");
+/// ```
+pub fn decode_router_revert(data: &[u8]) -> Option<RouterRevert> {
+    if let Ok(revert) = Revert::abi_decode(data) {
+        return Some(RouterRevert::Reason(revert.reason));
+    }
+    if let Ok(panic) = Panic::abi_decode(data) {
+        return Some(RouterRevert::Panic(panic.code));
+    }
+
+    #[cfg(feature = "v3")]
+    if let Ok(error) = OdosV3RouterErrors::abi_decode(data) {
+        return Some(RouterRevert::V3(error));
+    }
+
+    #[cfg(feature = "limit-orders")]
+    if let Ok(error) = OdosLimitOrderV2Errors::abi_decode(data) {
+        return Some(RouterRevert::LimitOrder(Box::new(error)));
+    }
+
+    None
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloy_primitives::hex;
+
+    use super::*;
+
+    /// `Error("Slippage Limit Exceeded")`, as the V2 router reverts with it
+    pub(crate) const SLIPPAGE_REVERT: [u8; 100] = hex!(
+        "08c379a0"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "0000000000000000000000000000000000000000000000000000000000000017"
+        "536c697070616765204c696d6974204578636565646564000000000000000000"
+    );
+
+    #[test]
+    fn test_decode_error_string() {
+        let revert = decode_router_revert(&SLIPPAGE_REVERT).unwrap();
+        assert_eq!(
+            revert,
+            RouterRevert::Reason(SLIPPAGE_LIMIT_EXCEEDED.to_string())
+        );
+        assert!(revert.is_slippage_exceeded());
+        assert_eq!(revert.to_string(), "Slippage Limit Exceeded");
+        assert_eq!(revert.panic_kind(), None);
+    }
+
+    #[test]
+    fn test_decode_panic() {
+        let revert = decode_router_revert(&hex!(
+            "4e487b710000000000000000000000000000000000000000000000000000000000000012"
+        ))
+        .unwrap();
+        assert_eq!(revert, RouterRevert::Panic(U256::from(0x12)));
+        assert_eq!(revert.panic_kind(), Some(PanicKind::DivisionByZero));
+        assert!(!revert.is_slippage_exceeded());
+
+        let unknown = decode_router_revert(&hex!(
+            "4e487b7100000000000000000000000000000000000000000000000000000000000000ff"
+        ))
+        .unwrap();
+        assert_eq!(unknown.panic_kind(), None);
+        assert_eq!(unknown.to_string(), "panic code 0xff");
+    }
+
+    #[test]
+    fn test_unrecognized_data() {
+        assert_eq!(decode_router_revert(&[]), None);
+        assert_eq!(decode_router_revert(&hex!("deadbeef")), None);
+        // A truncated `Error(string)`
+        assert_eq!(decode_router_revert(&SLIPPAGE_REVERT[..40]), None);
+    }
+
+    #[cfg(feature = "v3")]
+    #[test]
+    fn test_decode_v3_custom_errors() {
+        let revert = decode_router_revert(&hex!(
+            "118cdaa7"
+            "00000000000000000000000047e2d28169738039755586743e2dfcf3bd643f86"
+        ))
+        .unwrap();
+        assert!(matches!(
+            revert,
+            RouterRevert::V3(OdosV3RouterErrors::OwnableUnauthorizedAccount(_))
+        ));
+        assert_eq!(
+            revert.to_string(),
+            "0x47E2D28169738039755586743E2dfCF3bd643f86 is not the router owner"
+        );
+
+        let revert = decode_router_revert(&hex!(
+            "5274afe7"
+            "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        ))
+        .unwrap();
+        assert_eq!(
+            revert.to_string(),
+            "token 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48 transfer or approval failed"
+        );
+    }
+
+    #[cfg(feature = "limit-orders")]
+    #[test]
+    fn test_decode_limit_order_custom_errors() {
+        use alloy_primitives::address;
+        use alloy_sol_types::SolError;
+
+        use crate::limit_order_v2::OdosLimitOrderV2::{OrderExpired, SlippageLimitExceeded};
+
+        let data = SlippageLimitExceeded {
+            tokenAddress: address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+            expectedAmount: U256::from(100),
+            actualAmount: U256::from(99),
+        }
+        .abi_encode();
+        let revert = decode_router_revert(&data).unwrap();
+        assert!(revert.is_slippage_exceeded());
+        assert!(revert.to_string().contains("amount is 99, expected 100"));
+
+        let data = OrderExpired {
+            orderExpiry: U256::from(1_700_000_000),
+            currentTimestamp: U256::from(1_700_000_060),
+        }
+        .abi_encode();
+        assert_eq!(
+            decode_router_revert(&data).unwrap().to_string(),
+            "order expired at 1700000000, current time is 1700000060"
+        );
+    }
+}
@@ -6,7 +6,12 @@
 //! This module provides enums and types to represent the different router types
 //! available across Odos-supported chains.
 
-use std::fmt;
+use std::{fmt, str::FromStr};
+
+use alloy_chains::NamedChain;
+use serde::{Deserialize, Serialize};
+
+use crate::{contract::deployment, OdosError, Result};
 
 /// Represents the different types of Odos routers.
 ///
@@ -27,6 +32,21 @@ use std::fmt;
 /// to get only routers that emit `Swap`/`SwapMulti` events, or [`order_routers()`](RouterType::order_routers)
 /// for limit order routers.
 ///
+/// # Parsing and serialization
+///
+/// Displays and serializes as [`as_str`](RouterType::as_str) (`"LO"`, `"V2"`,
+/// `"V3"`), and parses case-insensitively from those names or
+/// `"limit_order"`, so it can appear in configuration files:
+///
+/// ```rust
+/// use odos_sdk::RouterType;
+///
+/// assert_eq!("v3".parse::<RouterType>()?, RouterType::V3);
+/// assert_eq!("limit-order".parse::<RouterType>()?, RouterType::LimitOrder);
+/// assert_eq!(serde_json::to_string(&RouterType::V2)?, r#""V2""#);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
 /// # Example
 ///
 /// ```rust
@@ -43,7 +63,8 @@ use std::fmt;
 ///     assert!(!router_type.emits_swap_events());
 /// }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "&'static str")]
 pub enum RouterType {
     /// Limit Order V2 router for limit order functionality.
     ///
@@ -172,6 +193,40 @@ impl fmt::Display for RouterType {
     }
 }
 
+impl FromStr for RouterType {
+    type Err = OdosError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s
+            .trim()
+            .to_ascii_lowercase()
+            .replace(['-', ' '], "_")
+            .as_str()
+        {
+            "lo" | "limit_order" | "limitorder" => Ok(RouterType::LimitOrder),
+            "v2" => Ok(RouterType::V2),
+            "v3" => Ok(RouterType::V3),
+            _ => Err(OdosError::invalid_input(format!(
+                "Unknown router type '{s}'; expected LO, V2 or V3"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<String> for RouterType {
+    type Error = OdosError;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<RouterType> for &'static str {
+    fn from(router_type: RouterType) -> Self {
+        router_type.as_str()
+    }
+}
+
 /// Represents which routers are available on a specific chain
 ///
 /// This provides a type-safe way to query router availability without
@@ -187,6 +242,25 @@ pub struct RouterAvailability {
 }
 
 impl RouterAvailability {
+    /// Get the routers deployed on the chain with `chain_id`
+    ///
+    /// Returns [`none()`](Self::none) for chains without an Odos deployment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use odos_sdk::{RouterAvailability, RouterType};
+    ///
+    /// assert!(RouterAvailability::for_chain(1).has(RouterType::V3));
+    /// assert_eq!(RouterAvailability::for_chain(999999), RouterAvailability::none());
+    /// ```
+    pub fn for_chain(chain_id: u64) -> Self {
+        NamedChain::try_from(chain_id)
+            .ok()
+            .and_then(deployment)
+            .unwrap_or(Self::none())
+    }
+
     /// Creates a new `RouterAvailability` with all routers available
     pub const fn all() -> Self {
         Self {
@@ -307,6 +381,41 @@ mod tests {
         assert_eq!(RouterType::V3.to_string(), "V3");
     }
 
+    #[test]
+    fn test_router_type_parse_and_serde() {
+        for router_type in RouterType::all() {
+            assert_eq!(
+                router_type.to_string().parse::<RouterType>().unwrap(),
+                router_type
+            );
+            assert_eq!(
+                router_type
+                    .as_str()
+                    .to_lowercase()
+                    .parse::<RouterType>()
+                    .unwrap(),
+                router_type
+            );
+
+            let json = serde_json::to_string(&router_type).unwrap();
+            assert_eq!(json, format!("\"{router_type}\""));
+            assert_eq!(
+                serde_json::from_str::<RouterType>(&json).unwrap(),
+                router_type
+            );
+        }
+
+        assert_eq!(
+            " Limit_Order ".parse::<RouterType>().unwrap(),
+            RouterType::LimitOrder
+        );
+        assert!(matches!(
+            "v4".parse::<RouterType>(),
+            Err(OdosError::InvalidInput(_))
+        ));
+        assert!(serde_json::from_str::<RouterType>(r#""v4""#).is_err());
+    }
+
     #[test]
     fn test_router_availability_all() {
         let avail = RouterAvailability::all();
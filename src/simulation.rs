@@ -0,0 +1,369 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_network::Ethereum;
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::{
+    state::{StateOverride, StateOverridesBuilder},
+    TransactionRequest,
+};
+use alloy_sol_types::SolValue;
+
+use crate::{decode_router_calldata, decode_router_revert, DecodedOdosCall, OdosError, Result};
+
+/// Outcome of an assembled swap simulated with [`simulate_swap`]
+///
+/// The amounts are the router's return values from an `eth_call` against
+/// the provider's latest state, so they are what the swap would deliver if
+/// mined now.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapSimulation {
+    /// `(token, amount)` the swap would deliver per output token, in base
+    /// units
+    pub outputs: Vec<(Address, U256)>,
+}
+
+impl SwapSimulation {
+    /// Get the amount of `token` the swap would deliver, in base units
+    pub fn output_amount(&self, token: Address) -> Option<U256> {
+        self.outputs
+            .iter()
+            .find_map(|(output, amount)| (*output == token).then_some(*amount))
+    }
+}
+
+/// Simulate an assembled swap with `eth_call` against the provider's state
+///
+/// Unlike the Odos API's simulation, requested with
+/// [`OdosClient::assemble_with_simulation`](crate::OdosClient::assemble_with_simulation),
+/// this runs against your own RPC node, so it checks the swap against the state
+/// you are about to send into. `tx` must carry its sender, as for
+/// [`OdosClient::assemble`](crate::OdosClient::assemble) output.
+///
+/// Available only when the `provider` feature is enabled.
+///
+/// # Errors
+///
+/// - [`OdosError::InvalidInput`] if `tx` is not a V2 or V3 router `swap`,
+///   `swapMulti` or their Permit2 variants
+/// - [`OdosError::SimulationReverted`] if the swap reverts, with the reason
+///   decoded by [`decode_router_revert`] when possible
+/// - [`OdosError::Contract`] if the call fails for another reason or the
+///   return data does not decode
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use alloy_provider::ProviderBuilder;
+/// use alloy_rpc_types::TransactionRequest;
+/// use odos_sdk::simulate_swap;
+///
+/// # async fn example(tx: TransactionRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let provider = ProviderBuilder::new().connect_http("https://eth.llamarpc.com".parse()?);
+/// let simulation = simulate_swap(&provider, &tx).await?;
+/// println!("Would receive {:?}", simulation.outputs);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn simulate_swap<P>(provider: &P, tx: &TransactionRequest) -> Result<SwapSimulation>
+where
+    P: Provider<Ethereum>,
+{
+    simulate(provider, tx, None).await
+}
+
+/// Simulate an assembled swap with `eth_call`, applying `overrides` to the
+/// state first
+///
+/// Useful to simulate before the input token is approved: see
+/// [`allowance_override`]. Otherwise the same as [`simulate_swap`].
+///
+/// Available only when the `provider` feature is enabled.
+///
+/// # Errors
+///
+/// As for [`simulate_swap`].
+pub async fn simulate_swap_with_overrides<P>(
+    provider: &P,
+    tx: &TransactionRequest,
+    overrides: StateOverride,
+) -> Result<SwapSimulation>
+where
+    P: Provider<Ethereum>,
+{
+    simulate(provider, tx, Some(overrides)).await
+}
+
+/// Build a state override granting `spender` an unlimited allowance of
+/// `token` from `owner`
+///
+/// `mapping_slot` is the storage slot of the token contract's
+/// `mapping(address => mapping(address => uint256))` allowance mapping
+/// (for example `10` for mainnet USDC's implementation or `4` for WETH). It
+/// varies by token and can be read from the verified source or found with
+/// `cast storage`.
+///
+/// Available only when the `provider` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::address;
+/// use odos_sdk::{allowance_override, ODOS_V2_ETHEREUM_ROUTER};
+///
+/// let weth = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+/// let owner = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+/// let overrides = allowance_override(weth, owner, ODOS_V2_ETHEREUM_ROUTER, 4);
+/// assert!(overrides.contains_key(&weth));
+/// ```
+pub fn allowance_override(
+    token: Address,
+    owner: Address,
+    spender: Address,
+    mapping_slot: u64,
+) -> StateOverride {
+    let owner_slot = keccak256((owner, U256::from(mapping_slot)).abi_encode());
+    let slot = keccak256((spender, owner_slot).abi_encode());
+    StateOverridesBuilder::default()
+        .with_state_diff(token, [(slot, B256::from(U256::MAX))])
+        .build()
+}
+
+async fn simulate<P>(
+    provider: &P,
+    tx: &TransactionRequest,
+    overrides: Option<StateOverride>,
+) -> Result<SwapSimulation>
+where
+    P: Provider<Ethereum>,
+{
+    let router = tx
+        .to
+        .and_then(|kind| kind.to().copied())
+        .ok_or_else(|| OdosError::invalid_input("Swap transaction has no router address"))?;
+    let calldata = tx
+        .input
+        .input()
+        .ok_or_else(|| OdosError::invalid_input("Swap transaction has no calldata"))?;
+
+    let (output_tokens, multi) = match decode_router_calldata(router, calldata)? {
+        DecodedOdosCall::Swap(swap) | DecodedOdosCall::SwapPermit2 { swap, .. } => {
+            (vec![swap.output_token_address()], false)
+        }
+        DecodedOdosCall::SwapMulti(swap) | DecodedOdosCall::SwapMultiPermit2 { swap, .. } => (
+            swap.outputs()
+                .iter()
+                .map(|output| output.tokenAddress)
+                .collect(),
+            true,
+        ),
+        other => {
+            return Err(OdosError::invalid_input(format!(
+                "Cannot simulate {other:?}, which is not a router swap"
+            )))
+        }
+    };
+
+    let result = provider
+        .call(tx.clone())
+        .overrides_opt(overrides)
+        .await
+        .map_err(|err| match err.as_error_resp() {
+            Some(payload) if payload.message.contains("revert") => {
+                let reason = payload
+                    .as_revert_data()
+                    .and_then(|data| decode_router_revert(&data));
+                OdosError::simulation_reverted(reason, payload.message.to_string())
+            }
+            _ => OdosError::contract_error(format!("eth_call failed: {err}")),
+        })?;
+
+    let decoded = if multi {
+        Vec::<U256>::abi_decode(&result)
+    } else {
+        U256::abi_decode(&result).map(|amount| vec![amount])
+    };
+    let amounts = decoded
+        .map_err(|err| OdosError::contract_error(format!("Undecodable swap return data: {err}")))?;
+
+    Ok(SwapSimulation {
+        outputs: output_tokens.into_iter().zip(amounts).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::{address, Bytes};
+    use alloy_provider::ProviderBuilder;
+    use alloy_sol_types::SolCall;
+    use serde_json::{json, Value};
+    use wiremock::{matchers::body_partial_json, Mock, MockServer, Request, ResponseTemplate};
+
+    use super::*;
+    use crate::{
+        OdosRouterV2::{inputTokenInfo, outputTokenInfo, swapTokenInfo},
+        OdosV2Router::{swapCall, swapMultiCall},
+        ODOS_V2_ETHEREUM_ROUTER,
+    };
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const SIGNER: Address = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+
+    fn swap_tx(call: impl SolCall) -> TransactionRequest {
+        TransactionRequest::default()
+            .with_from(SIGNER)
+            .with_to(ODOS_V2_ETHEREUM_ROUTER)
+            .with_input(call.abi_encode())
+    }
+
+    fn swap_call() -> swapCall {
+        swapCall {
+            tokenInfo: swapTokenInfo {
+                inputToken: USDC,
+                inputAmount: U256::from(1_000_000),
+                inputReceiver: Address::repeat_byte(0xee),
+                outputToken: WETH,
+                outputQuote: U256::from(400),
+                outputMin: U256::from(398),
+                outputReceiver: SIGNER,
+            },
+            pathDefinition: Bytes::new(),
+            executor: Address::repeat_byte(0xee),
+            referralCode: 0,
+        }
+    }
+
+    /// Answer `eth_call` with the JSON-RPC `response` member, `"result"` or
+    /// `"error"`
+    async fn mock_eth_call(response: Value) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(body_partial_json(json!({ "method": "eth_call" })))
+            .respond_with(move |request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                let mut reply = response.clone();
+                reply["jsonrpc"] = json!("2.0");
+                reply["id"] = body["id"].clone();
+                ResponseTemplate::new(200).set_body_json(reply)
+            })
+            .expect(1)
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_simulate_swap_decodes_output_amount() {
+        let server = mock_eth_call(json!({
+            "result": Bytes::from(U256::from(399).abi_encode())
+        }))
+        .await;
+        let provider = ProviderBuilder::new().connect_http(server.uri().parse().unwrap());
+
+        let simulation = simulate_swap(&provider, &swap_tx(swap_call()))
+            .await
+            .unwrap();
+        assert_eq!(simulation.outputs, [(WETH, U256::from(399))]);
+        assert_eq!(simulation.output_amount(WETH), Some(U256::from(399)));
+        assert_eq!(simulation.output_amount(USDC), None);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_swap_multi_decodes_output_amounts() {
+        let call = swapMultiCall {
+            inputs: vec![inputTokenInfo {
+                tokenAddress: USDC,
+                amountIn: U256::from(1_000_000),
+                receiver: Address::repeat_byte(0xee),
+            }],
+            outputs: [WETH, crate::NATIVE_TOKEN]
+                .map(|token| outputTokenInfo {
+                    tokenAddress: token,
+                    relativeValue: U256::from(1),
+                    receiver: SIGNER,
+                })
+                .to_vec(),
+            valueOutMin: U256::ZERO,
+            pathDefinition: Bytes::new(),
+            executor: Address::repeat_byte(0xee),
+            referralCode: 0,
+        };
+        let server = mock_eth_call(json!({
+            "result": Bytes::from(vec![U256::from(5), U256::from(7)].abi_encode())
+        }))
+        .await;
+        let provider = ProviderBuilder::new().connect_http(server.uri().parse().unwrap());
+
+        let simulation = simulate_swap(&provider, &swap_tx(call)).await.unwrap();
+        assert_eq!(
+            simulation.outputs,
+            [(WETH, U256::from(5)), (crate::NATIVE_TOKEN, U256::from(7))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_swap_decodes_revert() {
+        let server = mock_eth_call(json!({
+            "error": {
+                "code": 3,
+                "message": "execution reverted",
+                "data": concat!(
+                    "0x08c379a0",
+                    "0000000000000000000000000000000000000000000000000000000000000020",
+                    "0000000000000000000000000000000000000000000000000000000000000017",
+                    "536c697070616765204c696d6974204578636565646564000000000000000000",
+                )
+            }
+        }))
+        .await;
+        let provider = ProviderBuilder::new().connect_http(server.uri().parse().unwrap());
+
+        let err = simulate_swap(&provider, &swap_tx(swap_call()))
+            .await
+            .unwrap_err();
+        let OdosError::SimulationReverted {
+            reason: Some(reason),
+            ..
+        } = &err
+        else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert!(reason.is_slippage_exceeded());
+        assert!(!err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_simulate_swap_rejects_non_swap_calls() {
+        let server = MockServer::start().await;
+        let provider = ProviderBuilder::new().connect_http(server.uri().parse().unwrap());
+
+        let tx = TransactionRequest::default()
+            .with_to(ODOS_V2_ETHEREUM_ROUTER)
+            .with_input(Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]));
+        assert!(matches!(
+            simulate_swap(&provider, &tx).await,
+            Err(OdosError::InvalidInput(_))
+        ));
+
+        let tx = swap_tx(swap_call()).with_to(Address::repeat_byte(1));
+        assert!(matches!(
+            simulate_swap(&provider, &tx).await,
+            Err(OdosError::InvalidInput(_))
+        ));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_allowance_override_slot() {
+        // WETH9 keeps `allowance` at slot 4
+        let overrides = allowance_override(WETH, SIGNER, ODOS_V2_ETHEREUM_ROUTER, 4);
+        let diff = overrides[&WETH].state_diff.as_ref().unwrap();
+
+        let inner = keccak256((SIGNER, U256::from(4)).abi_encode());
+        let slot = keccak256([ODOS_V2_ETHEREUM_ROUTER.into_word(), inner].concat());
+        assert_eq!(diff.get(&slot), Some(&B256::from(U256::MAX)));
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt, future::Future, pin::Pin, time::Duration};
+
+/// Boxed future returned by [`Sleeper::sleep`].
+pub type SleepFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// Strategy used by the SDK whenever it needs to wait.
+///
+/// The HTTP client routes every internal pause (retry backoff and any other
+/// SDK-driven delay) through the configured [`Sleeper`] instead of calling
+/// `tokio::time::sleep` directly. This decouples the retry loop from tokio's
+/// timer, which is useful for:
+///
+/// - Deterministic tests that want to assert on requested delays without
+///   actually waiting (see `RecordingSleeper` under the `test-utils` feature).
+/// - Alternative runtimes or custom timer implementations.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+/// use odos_sdk::{ClientConfig, SleepFuture, Sleeper};
+///
+/// #[derive(Debug)]
+/// struct SkipSleep;
+///
+/// impl Sleeper for SkipSleep {
+///     fn sleep(&self, _duration: Duration) -> SleepFuture<'_> {
+///         Box::pin(async {})
+///     }
+/// }
+///
+/// let config = ClientConfig {
+///     sleeper: Arc::new(SkipSleep),
+///     ..Default::default()
+/// };
+/// ```
+pub trait Sleeper: fmt::Debug + Send + Sync {
+    /// Wait for `duration` before resolving.
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_>;
+}
+
+/// Default [`Sleeper`] backed by `tokio::time::sleep`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tokio_sleeper_waits() {
+        let start = std::time::Instant::now();
+        TokioSleeper.sleep(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+}
@@ -2,16 +2,32 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
 use alloy_network::TransactionBuilder;
 use alloy_primitives::{hex, Address};
 use alloy_rpc_types::TransactionRequest;
-use reqwest::Response;
-use serde_json::Value;
-use tracing::instrument;
+use reqwest::{Response, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use tokio::sync::mpsc;
+use tracing::{instrument, warn};
+use url::Url;
 
 use crate::{
-    client::parse_error_response, parse_value, AssembleRequest, AssemblyRequest, AssemblyResponse,
-    ClientConfig, OdosError, OdosHttpClient, Result, RetryConfig, SwapBuilder,
+    client::{parse_error_response, TimeBudget},
+    info::{ChainsResponse, TokensResponse},
+    parse_value,
+    quote_cache::QuoteCache,
+    quote_coalescer::QuoteCoalescer,
+    swap_events::SwapEventSink,
+    ApiKeyStatus, ApiVersion, AssembleRequest, AssemblyRequest, AssemblyResponse, Chain,
+    ChainReconciliation, ClientConfig, ContractInfo, Endpoint, HealthStatus, LiquiditySourceInfo,
+    OdosChain, OdosChainResult, OdosError, OdosHttpClient, PathId, RateLimitInfo, Result,
+    RetryConfig, RuntimeChainSupport, Simulation, SwapBuilder, SwapEventRecord, TokenInfo,
 };
 
 use super::TransactionData;
@@ -88,6 +104,32 @@ use crate::{QuoteRequest, SingleQuoteResponse};
 #[derive(Debug, Clone)]
 pub struct OdosClient {
     client: OdosHttpClient,
+    spender_cache: SpenderCache,
+    quote_cache: Option<QuoteCache>,
+    quote_coalescer: Option<QuoteCoalescer>,
+    swap_events: SwapEventSink,
+    #[cfg(any(test, feature = "test-utils"))]
+    fixtures: Option<Arc<crate::fixtures::Fixtures>>,
+}
+
+/// Longest wait for a [`health_check`](OdosClient::health_check) response
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long an approval spender returned by the API is reused before
+/// being looked up again.
+const APPROVAL_SPENDER_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Approval spenders by API origin, chain ID and API version, shared
+/// between clones of a client.
+type SpenderCache = Arc<Mutex<HashMap<SpenderKey, (Address, Instant)>>>;
+
+/// Origin of the router info URL, chain ID and API version
+type SpenderKey = (String, u64, ApiVersion);
+
+/// Response body of the router info endpoint.
+#[derive(Debug, Deserialize)]
+struct RouterInfoResponse {
+    address: Address,
 }
 
 impl OdosClient {
@@ -120,6 +162,12 @@ impl OdosClient {
     pub fn new() -> Result<Self> {
         Ok(Self {
             client: OdosHttpClient::new()?,
+            spender_cache: SpenderCache::default(),
+            quote_cache: None,
+            quote_coalescer: None,
+            swap_events: SwapEventSink::default(),
+            #[cfg(any(test, feature = "test-utils"))]
+            fixtures: None,
         })
     }
 
@@ -137,16 +185,19 @@ impl OdosClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if the underlying HTTP client cannot be initialized.
+    /// Returns [`OdosError::Configuration`] if the configuration is invalid,
+    /// e.g. an Enterprise endpoint without an API key, or an error if the
+    /// underlying HTTP client cannot be initialized.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use odos_sdk::{OdosClient, ClientConfig, Endpoint};
+    /// use odos_sdk::{ApiKey, OdosClient, ClientConfig, Endpoint};
     ///
     /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = ClientConfig {
     ///     endpoint: Endpoint::enterprise_v3(),
+    ///     api_key: Some(ApiKey::new(uuid::Uuid::new_v4())),
     ///     ..Default::default()
     /// };
     /// let client = OdosClient::with_config(config)?;
@@ -154,8 +205,16 @@ impl OdosClient {
     /// # }
     /// ```
     pub fn with_config(config: ClientConfig) -> Result<Self> {
+        let quote_cache = config.quote_cache.map(QuoteCache::new);
+        let quote_coalescer = config.coalesce_quotes.then(QuoteCoalescer::default);
         Ok(Self {
             client: OdosHttpClient::with_config(config)?,
+            spender_cache: SpenderCache::default(),
+            quote_cache,
+            quote_coalescer,
+            swap_events: SwapEventSink::default(),
+            #[cfg(any(test, feature = "test-utils"))]
+            fixtures: None,
         })
     }
 
@@ -196,6 +255,68 @@ impl OdosClient {
         self.client.config()
     }
 
+    /// Get the number of API calls currently in flight, or `None` unless
+    /// [`ClientConfig::max_concurrent_requests`] is set
+    ///
+    /// See [`OdosHttpClient::in_flight_requests`](crate::OdosHttpClient::in_flight_requests).
+    pub fn in_flight_requests(&self) -> Option<usize> {
+        self.client.in_flight_requests()
+    }
+
+    /// Get the rate limit headers of the most recent API response that had
+    /// any
+    ///
+    /// See [`OdosHttpClient::last_rate_limit_info`](crate::OdosHttpClient::last_rate_limit_info).
+    pub fn last_rate_limit_info(&self) -> Option<RateLimitInfo> {
+        self.client.last_rate_limit_info()
+    }
+
+    /// Send swap lifecycle events from every [`SwapBuilder`] of this client
+    ///
+    /// Events are sent without blocking; see the
+    /// [ordering and backpressure rules](crate::SwapEventRecord) and
+    /// [`dropped_swap_events`](Self::dropped_swap_events). Clones made after
+    /// this call share the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::OdosClient;
+    /// use tokio::sync::mpsc;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (events_tx, mut events_rx) = mpsc::channel(256);
+    /// let client = OdosClient::new()?.with_swap_events(events_tx);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_swap_events(mut self, sender: mpsc::Sender<SwapEventRecord>) -> Self {
+        self.swap_events.set_sender(sender);
+        self
+    }
+
+    /// Number of swap event records dropped because their channel was full
+    /// or closed
+    ///
+    /// Counts drops from the client's channel and from channels attached with
+    /// [`SwapBuilder::events`]; shared between clones.
+    pub fn dropped_swap_events(&self) -> u64 {
+        self.swap_events.dropped()
+    }
+
+    pub(crate) fn swap_event_sink(&self) -> &SwapEventSink {
+        &self.swap_events
+    }
+
+    /// Answer quotes and assembly from `fixtures` and refuse every other
+    /// request
+    #[cfg(any(test, feature = "test-utils"))]
+    pub(crate) fn with_fixtures(mut self, fixtures: crate::fixtures::Fixtures) -> Self {
+        self.fixtures = Some(Arc::new(fixtures));
+        self.client = self.client.offline();
+        self
+    }
+
     /// Create a high-level swap builder
     ///
     /// This is the recommended way to build swaps for most use cases.
@@ -248,6 +369,8 @@ impl OdosClient {
     /// # Errors
     ///
     /// This method can fail with various errors:
+    /// - [`OdosError::InvalidInput`] - The request failed client-side validation
+    ///   (see [`QuoteRequest::validate`])
     /// - [`OdosError::Api`] - API returned an error (invalid input, unsupported chain, etc.)
     /// - [`OdosError::RateLimit`] - Rate limit exceeded
     /// - [`OdosError::Http`] - Network error
@@ -255,24 +378,32 @@ impl OdosClient {
     ///
     /// Server errors (5xx) are automatically retried based on the retry configuration.
     ///
+    /// # Caching and coalescing
+    ///
+    /// With [`ClientConfig::quote_cache`] set, a response to an identical
+    /// request received within the cache TTL is returned without a request
+    /// and reports [`SingleQuoteResponse::is_cached`]. With
+    /// [`ClientConfig::coalesce_quotes`] set, a call identical to one already
+    /// in flight shares its result instead of sending another request.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use odos_sdk::{OdosClient, QuoteRequest, InputToken, OutputToken};
+    /// use odos_sdk::{Chain, OdosClient, QuoteRequest, InputToken, OutputToken};
     /// use alloy_primitives::{address, U256};
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = OdosClient::new()?;
     ///
     /// let quote_request = QuoteRequest::builder()
-    ///     .chain_id(1) // Ethereum mainnet
+    ///     .chain(Chain::ethereum())
     ///     .input_tokens(vec![InputToken::new(
     ///         address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), // USDC
     ///         U256::from(1000000) // 1 USDC (6 decimals)
     ///     )])
     ///     .output_tokens(vec![OutputToken::new(
     ///         address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"), // WETH
-    ///         1 // 100% to WETH
+    ///         1.0 // 100% to WETH
     ///     )])
     ///     .slippage_limit_percent(0.5)
     ///     .user_addr(address!("0000000000000000000000000000000000000000"))
@@ -289,257 +420,2276 @@ impl OdosClient {
     /// ```
     #[instrument(skip(self), level = "debug")]
     pub async fn quote(&self, quote_request: &QuoteRequest) -> Result<SingleQuoteResponse> {
-        let response = self
-            .client
-            .execute_with_retry(|| {
-                let mut builder = self
-                    .client
-                    .inner()
-                    .post(self.client.config().endpoint.quote_url())
-                    .header("accept", "application/json")
-                    .json(quote_request);
-
-                // Add API key header if available
-                if let Some(ref api_key) = self.client.config().api_key {
-                    builder = builder.header("X-API-Key", api_key.as_str());
-                }
+        self.quote_within(quote_request, None).await
+    }
 
-                builder
-            })
-            .await?;
+    /// [`quote`](Self::quote) bounded by an optional total time budget
+    pub(crate) async fn quote_within(
+        &self,
+        quote_request: &QuoteRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<SingleQuoteResponse> {
+        let url = self.client.config().endpoint.quote_url();
+        if let Some(quote) = self
+            .quote_cache
+            .as_ref()
+            .and_then(|cache| cache.get(quote_request))
+        {
+            return Ok(quote);
+        }
 
-        if response.status().is_success() {
-            let single_quote_response = response.json().await?;
-            Ok(single_quote_response)
-        } else {
-            let status = response.status();
-            let parsed = parse_error_response(response).await;
-            Err(OdosError::api_error_with_code(
-                status,
-                parsed.message,
-                parsed.code,
-                parsed.trace_id,
-            ))
+        let fetch = || async {
+            let quote = self.quote_at(url, quote_request, budget).await?;
+            if let Some(cache) = &self.quote_cache {
+                cache.insert(quote_request, &quote);
+            }
+            Ok(quote)
+        };
+        match &self.quote_coalescer {
+            Some(coalescer) => coalescer.run(quote_request, fetch).await,
+            None => fetch().await,
         }
     }
 
-    /// Deprecated: Use [`quote`](Self::quote) instead
-    #[deprecated(since = "0.25.0", note = "Use `quote` instead")]
-    pub async fn get_swap_quote(
+    pub(crate) async fn quote_at(
         &self,
+        url: Url,
         quote_request: &QuoteRequest,
+        budget: Option<TimeBudget>,
     ) -> Result<SingleQuoteResponse> {
-        self.quote(quote_request).await
-    }
+        if self.client.config().validate_quote_requests {
+            quote_request.validate()?;
+        }
+        let chain_id = quote_request.chain().id();
+        #[cfg(any(test, feature = "test-utils"))]
+        if let Some(fixtures) = &self.fixtures {
+            return fixtures
+                .quote(quote_request)
+                .map(|quote| quote.quoted_on(chain_id));
+        }
 
-    #[instrument(skip(self), level = "debug")]
-    pub async fn get_assemble_response(
-        &self,
-        assemble_request: AssembleRequest,
-    ) -> Result<Response> {
-        self.client
-            .execute_with_retry(|| {
-                let mut builder = self
-                    .client
-                    .inner()
-                    .post(self.client.config().endpoint.assemble_url())
-                    .header("Content-Type", "application/json")
-                    .json(&assemble_request);
+        let response = self
+            .client
+            .execute_with_retry_within(
+                || {
+                    let mut builder = self
+                        .client
+                        .inner()
+                        .post(url.clone())
+                        .header("accept", "application/json")
+                        .json(quote_request);
 
-                // Add API key header if available
-                if let Some(ref api_key) = self.client.config().api_key {
-                    builder = builder.header("X-API-Key", api_key.as_str());
-                }
+                    // Add API key header if available
+                    if let Some(ref api_key) = self.client.config().api_key {
+                        builder = builder.header("X-API-Key", api_key.as_str());
+                    }
 
-                builder
-            })
+                    builder
+                },
+                budget,
+            )
+            .await
+            .map_err(|e| e.with_endpoint("quote"))?;
+
+        read_json(response)
             .await
+            .map(|quote: SingleQuoteResponse| quote.quoted_on(chain_id))
+            .map_err(|e| e.with_endpoint("quote"))
     }
 
-    /// Assemble transaction data from a quote
-    ///
-    /// Takes a path ID from a quote response and assembles the complete
-    /// transaction data needed to execute the swap on-chain.
-    ///
-    /// # Arguments
-    ///
-    /// * `signer_address` - Address that will sign and send the transaction
-    /// * `output_recipient` - Address that will receive the output tokens
-    /// * `path_id` - Path ID from a previous quote response
+    /// Get the address to approve as spender for swaps on `chain`
     ///
-    /// # Returns
+    /// Approvals should target the address the API will pull tokens from,
+    /// which Odos may rotate independently of SDK releases. This queries the
+    /// router info endpoint for the configured API version and caches the
+    /// result per host and chain for one hour; clones of the client share
+    /// the cache.
     ///
-    /// Returns [`TransactionData`] containing:
-    /// - Transaction calldata (`data`)
-    /// - ETH value to send (`value`)
-    /// - Target contract address (`to`)
-    /// - Gas estimates
+    /// If the host does not offer router info for the chain, i.e. it answers
+    /// 404 or reports the router as not available, a warning is logged and
+    /// the router address compiled into this crate for the configured API
+    /// version is returned and cached instead.
     ///
     /// # Errors
     ///
-    /// - [`OdosError::Api`] - Invalid path ID, expired quote, or other API error
-    /// - [`OdosError::RateLimit`] - Rate limit exceeded
-    /// - [`OdosError::Http`] - Network error
-    /// - [`OdosError::Timeout`] - Request timeout
+    /// Returns the lookup error for any other failure, e.g. an unauthorized
+    /// request, a server error or an undecodable response, and when the
+    /// host has no router info and no compiled-in router exists for
+    /// `chain`.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use odos_sdk::OdosClient;
-    /// use alloy_primitives::address;
+    /// use odos_sdk::{Chain, OdosClient};
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = OdosClient::new()?;
-    /// let path_id = "path_id_from_quote_response";
-    ///
-    /// let tx_data = client.assemble_tx_data(
-    ///     address!("0000000000000000000000000000000000000001"),
-    ///     address!("0000000000000000000000000000000000000001"),
-    ///     path_id
-    /// ).await?;
+    /// let spender = client.approval_spender(Chain::base()).await?;
+    /// println!("Approve {spender}");
     /// # Ok(())
     /// # }
     /// ```
     #[instrument(skip(self), level = "debug")]
-    pub async fn assemble_tx_data(
-        &self,
-        signer_address: Address,
-        output_recipient: Address,
-        path_id: &str,
-    ) -> Result<TransactionData> {
-        let assemble_request = AssembleRequest {
-            user_addr: signer_address,
-            path_id: path_id.to_string(),
-            simulate: false,
-            receiver: Some(output_recipient),
-        };
+    pub async fn approval_spender(&self, chain: Chain) -> Result<Address> {
+        let endpoint = self.client.config().endpoint.clone();
+        self.approval_spender_at(chain, &endpoint).await
+    }
 
-        let response = self.get_assemble_response(assemble_request).await?;
+    /// [`approval_spender`](Self::approval_spender) for `endpoint`'s API
+    /// version instead of the configured one
+    pub(crate) async fn approval_spender_at(
+        &self,
+        chain: Chain,
+        endpoint: &Endpoint,
+    ) -> Result<Address> {
+        let url = endpoint.router_info_url(chain.id());
+        self.resolve_approval_spender(chain, endpoint.version(), url)
+            .await
+    }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let parsed = parse_error_response(response).await;
-            return Err(OdosError::api_error_with_code(
-                status,
-                parsed.message,
-                parsed.code,
-                parsed.trace_id,
-            ));
+    /// Cache lookup, fetch from `url`, and compiled-in fallback for
+    /// [`approval_spender`](Self::approval_spender).
+    async fn resolve_approval_spender(
+        &self,
+        chain: Chain,
+        version: ApiVersion,
+        url: Url,
+    ) -> Result<Address> {
+        let key = (url.origin().ascii_serialization(), chain.id(), version);
+        if let Some(&(spender, fetched_at)) = self.spender_cache().get(&key) {
+            if fetched_at.elapsed() < APPROVAL_SPENDER_TTL {
+                return Ok(spender);
+            }
         }
 
-        let value: Value = response.json().await?;
+        let spender = match self.fetch_approval_spender(url).await {
+            Ok(spender) => spender,
+            Err(err) if router_info_unavailable(&err) => {
+                let Ok(fallback) = compiled_router_address(chain, version) else {
+                    return Err(err);
+                };
 
-        let AssemblyResponse { transaction, .. } = serde_json::from_value(value)?;
+                warn!(
+                    chain_id = chain.id(),
+                    fallback = %fallback,
+                    error = %err,
+                    "Router info not available; falling back to compiled-in router address"
+                );
+                fallback
+            }
+            Err(err) => return Err(err),
+        };
+        self.spender_cache().insert(key, (spender, Instant::now()));
+        Ok(spender)
+    }
 
-        Ok(transaction)
+    async fn fetch_approval_spender(&self, url: Url) -> Result<Address> {
+        let RouterInfoResponse { address } = self.get_json(url, "router_info").await?;
+        Ok(address)
     }
 
-    /// Assemble a transaction from an assembly request
+    /// Get the tokens the Odos API supports on a chain
     ///
-    /// Assembles transaction data and constructs a [`TransactionRequest`] ready
-    /// for gas parameter configuration and signing. This is a convenience method
-    /// that combines [`assemble_tx_data`](Self::assemble_tx_data) with transaction
-    /// request construction.
+    /// Checking a token against this list up front avoids discovering an
+    /// unsupported token through a failed quote. The list is fetched on every
+    /// call; cache it if you query it often.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `request` - The assembly request containing addresses and path ID
+    /// Returns an error if the request fails after retries or the response
+    /// cannot be parsed.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// Returns a [`TransactionRequest`] with:
-    /// - `to`: Router contract address
-    /// - `from`: Signer address
-    /// - `data`: Encoded swap calldata
-    /// - `value`: ETH amount to send
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
     ///
-    /// Gas parameters (gas limit, gas price) are NOT set and must be configured
-    /// by the caller before signing.
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let tokens = client.supported_tokens(1).await?;
+    /// let usdc = tokens.iter().find(|token| token.symbol == "USDC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn supported_tokens(&self, chain_id: u64) -> Result<Vec<TokenInfo>> {
+        let url = self.client.config().endpoint.tokens_url(chain_id);
+        self.fetch_supported_tokens(url).await
+    }
+
+    async fn fetch_supported_tokens(&self, url: Url) -> Result<Vec<TokenInfo>> {
+        let response: TokensResponse = self.get_json(url, "tokens").await?;
+        Ok(response.into_tokens())
+    }
+
+    /// Get the Odos contract addresses for a chain
+    ///
+    /// Reports the router and executor for the configured API version.
     ///
     /// # Errors
     ///
-    /// - [`OdosError::Api`] - Invalid path ID or API error
-    /// - [`OdosError::RateLimit`] - Rate limit exceeded
-    /// - [`OdosError::Http`] - Network error
-    /// - [`OdosError::Timeout`] - Request timeout
-    /// - [`OdosError::Hex`] - Failed to decode transaction data
+    /// Returns an error if the request fails after retries or the response
+    /// cannot be parsed.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use odos_sdk::{OdosClient, AssemblyRequest};
-    /// use alloy_primitives::{address, U256};
-    /// use alloy_chains::NamedChain;
+    /// use odos_sdk::OdosClient;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = OdosClient::new()?;
+    /// let info = client.contract_info(1).await?;
+    /// println!("Router: {}", info.router_address);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn contract_info(&self, chain_id: u64) -> Result<ContractInfo> {
+        let url = self.client.config().endpoint.contract_info_url(chain_id);
+        self.get_json(url, "contract_info").await
+    }
+
+    /// Check that the router the Odos API reports for `chain` matches the
+    /// compiled-in router constant for the configured API version
     ///
-    /// let request = AssemblyRequest::builder()
-    ///     .chain(NamedChain::Mainnet)
-    ///     .signer_address(address!("0000000000000000000000000000000000000001"))
-    ///     .output_recipient(address!("0000000000000000000000000000000000000001"))
-    ///     .router_address(address!("0000000000000000000000000000000000000002"))
-    ///     .token_address(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"))
-    ///     .token_amount(U256::from(1000000))
-    ///     .path_id("path_id_from_quote".to_string())
-    ///     .build();
+    /// A safety net against stale constants such as
+    /// [`ODOS_V3`](crate::ODOS_V3); run it at service startup.
     ///
-    /// let mut tx_request = client.assemble(&request).await?;
+    /// # Errors
     ///
-    /// // Configure gas parameters before signing
-    /// // tx_request = tx_request.with_gas_limit(300000);
-    /// // tx_request = tx_request.with_max_fee_per_gas(...);
+    /// Returns [`OdosError::RouterAddressMismatch`] if the addresses differ,
+    /// a contract error if the SDK has no router for `chain` in the
+    /// configured API version, or the request error if the contract info
+    /// cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{Chain, OdosClient};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// client.verify_router_address(Chain::ethereum()).await?;
     /// # Ok(())
     /// # }
     /// ```
     #[instrument(skip(self), level = "debug")]
-    pub async fn assemble(&self, request: &AssemblyRequest) -> Result<TransactionRequest> {
-        let TransactionData { data, value, .. } = self
-            .assemble_tx_data(
-                request.signer_address(),
-                request.output_recipient(),
-                request.path_id(),
-            )
-            .await?;
-
-        Ok(TransactionRequest::default()
-            .with_input(hex::decode(&data)?)
-            .with_value(parse_value(&value)?)
-            .with_to(request.router_address())
-            .with_from(request.signer_address()))
+    pub async fn verify_router_address(&self, chain: Chain) -> Result<()> {
+        let url = self.client.config().endpoint.contract_info_url(chain.id());
+        self.check_router_address(chain, url).await
     }
 
-    /// Deprecated: Use [`assemble`](Self::assemble) instead
-    #[deprecated(since = "0.25.0", note = "Use `assemble` instead")]
-    pub async fn build_base_transaction(
-        &self,
-        swap: &AssemblyRequest,
-    ) -> Result<TransactionRequest> {
-        self.assemble(swap).await
+    async fn check_router_address(&self, chain: Chain, url: Url) -> Result<()> {
+        let expected = self.compiled_router_address(chain)?;
+
+        let ContractInfo { router_address, .. } = self.get_json(url, "contract_info").await?;
+        if router_address != expected {
+            return Err(OdosError::router_address_mismatch(
+                chain.id(),
+                expected,
+                router_address,
+            ));
+        }
+        Ok(())
     }
-}
 
-impl Default for OdosClient {
-    /// Creates a default Odos client with standard configuration.
+    /// Get the liquidity sources the Odos API can route through on a chain
     ///
-    /// # Panics
+    /// Use the returned IDs to build source allow or block lists.
     ///
-    /// Panics if the underlying HTTP client cannot be initialized.
-    /// This should only fail in extremely rare cases such as:
-    /// - TLS initialization failure
-    /// - System resource exhaustion
-    /// - Invalid system configuration
+    /// # Errors
     ///
-    /// In practice, this almost never fails and is safe for most use cases.
-    /// See [`OdosHttpClient::default`] for more details.
-    fn default() -> Self {
-        Self::new().expect("Failed to create default OdosClient")
+    /// Returns an error if the request fails after retries or the response
+    /// cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// for source in client.liquidity_sources(1).await? {
+    ///     println!("{}", source.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn liquidity_sources(&self, chain_id: u64) -> Result<Vec<LiquiditySourceInfo>> {
+        let url = self
+            .client
+            .config()
+            .endpoint
+            .liquidity_sources_url(chain_id);
+        self.get_json(url, "liquidity_sources").await
     }
-}
 
-/// Deprecated alias for [`OdosClient`]
-///
-/// This type alias is provided for backward compatibility.
-/// Use [`OdosClient`] instead in new code.
-#[deprecated(since = "0.25.0", note = "Use `OdosClient` instead")]
-pub type OdosSor = OdosClient;
+    /// Get the chain IDs the Odos API supports
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after retries or the response
+    /// cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let chains = client.supported_chains().await?;
+    /// assert!(chains.contains(&1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn supported_chains(&self) -> Result<Vec<u64>> {
+        let url = self.client.config().endpoint.chains_url();
+        self.fetch_supported_chains(url).await
+    }
+
+    async fn fetch_supported_chains(&self, url: Url) -> Result<Vec<u64>> {
+        let ChainsResponse { chains } = self.get_json(url, "chains").await?;
+        Ok(chains)
+    }
+
+    /// Check that the Odos API is reachable and accepts the API key
+    ///
+    /// Sends one request to the chains info endpoint, without retries and
+    /// with a timeout of at most [`HEALTH_CHECK_TIMEOUT`] (or the configured
+    /// timeout, if shorter). A 401 or 403 response is reported as
+    /// [`ApiKeyStatus::Invalid`] rather than an error, so startup code can
+    /// tell a bad key apart from an outage.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::Timeout`] - The API did not answer in time
+    /// - [`OdosError::Http`] - The API could not be reached
+    /// - [`OdosError::Api`] - Any other error response
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{ApiKeyStatus, OdosClient};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let health = client.health_check().await?;
+    /// if health.api_key == ApiKeyStatus::Invalid {
+    ///     return Err("Odos rejected the API key".into());
+    /// }
+    /// println!("Odos {:?} reachable in {:?}", health.host, health.latency);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn health_check(&self) -> Result<HealthStatus> {
+        let url = self.client.config().endpoint.chains_url();
+        self.health_check_at(url).await
+    }
+
+    async fn health_check_at(&self, url: Url) -> Result<HealthStatus> {
+        let config = self.client.config();
+        let mut builder = self
+            .client
+            .inner()
+            .get(url)
+            .header("accept", "application/json");
+        if let Some(ref api_key) = config.api_key {
+            builder = builder.header("X-API-Key", api_key.as_str());
+        }
+
+        let sent_at = Instant::now();
+        let response = self
+            .client
+            .execute_once(builder, config.timeout.min(HEALTH_CHECK_TIMEOUT))
+            .await?;
+        let latency = sent_at.elapsed();
+
+        let status = response.status();
+        let api_key = if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
+            ApiKeyStatus::Invalid
+        } else if !status.is_success() {
+            let parsed = parse_error_response(response).await;
+            return Err(OdosError::from_response(status, parsed).with_endpoint("health_check"));
+        } else if config.api_key.is_some() {
+            ApiKeyStatus::Accepted
+        } else {
+            ApiKeyStatus::NotConfigured
+        };
+
+        Ok(HealthStatus {
+            latency,
+            host: config.endpoint.host(),
+            api_key,
+        })
+    }
+
+    /// Compare the chains the Odos API supports with
+    /// [`get_supported_chains()`](crate::get_supported_chains)
+    ///
+    /// Catches drift when Odos launches or retires a chain before this crate
+    /// is updated. Run it at service startup or in CI and log the report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the supported chains cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let report = client.verify_chain_constants().await?;
+    /// if !report.is_consistent() {
+    ///     eprintln!("Chain support drift: {}", serde_json::to_string(&report)?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn verify_chain_constants(&self) -> Result<ChainReconciliation> {
+        let url = self.client.config().endpoint.chains_url();
+        self.reconcile_chains(url).await
+    }
+
+    async fn reconcile_chains(&self, url: Url) -> Result<ChainReconciliation> {
+        let api = self.fetch_supported_chains(url).await?;
+        let sdk: Vec<u64> = crate::get_supported_chains().ids().collect();
+        Ok(ChainReconciliation::between(&api, &sdk))
+    }
+
+    /// Check whether the Odos API supports a chain, and which router it
+    /// reports there
+    ///
+    /// Odos can launch on a chain before this crate ships constants for it,
+    /// in which case [`OdosChain::supports_odos`] is `false` although the
+    /// API serves quotes. This queries the chains endpoint and, for a chain
+    /// the API lists, the contract info endpoint for the configured API
+    /// version. To swap on such a chain, see
+    /// [`SwapBuilder::allow_unlisted_chains`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chains or contract info cannot be fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let support = client.runtime_chain_support(80094).await?;
+    /// if support.is_unlisted() {
+    ///     println!("Odos serves chain 80094 via {:?}", support.router_address);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn runtime_chain_support(&self, chain_id: u64) -> Result<RuntimeChainSupport> {
+        let endpoint = &self.client.config().endpoint;
+        let api_supported = self
+            .fetch_supported_chains(endpoint.chains_url())
+            .await?
+            .contains(&chain_id);
+
+        let router_address = if api_supported {
+            let ContractInfo { router_address, .. } = self
+                .get_json(endpoint.contract_info_url(chain_id), "contract_info")
+                .await?;
+            Some(router_address)
+        } else {
+            None
+        };
+
+        Ok(RuntimeChainSupport {
+            chain_id,
+            api_supported,
+            sdk_supported: alloy_chains::NamedChain::try_from(chain_id)
+                .is_ok_and(|chain| chain.supports_odos()),
+            router_address,
+        })
+    }
+
+    /// Get the router compiled into this crate for `chain` and the
+    /// configured API version
+    pub(crate) fn compiled_router_address(&self, chain: Chain) -> OdosChainResult<Address> {
+        compiled_router_address(chain, self.client.config().endpoint.version())
+    }
+
+    /// GET `url` and deserialize a JSON response, sending the API key when
+    /// configured
+    ///
+    /// API errors are labelled with `endpoint`; see [`OdosError::endpoint`].
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: Url,
+        endpoint: &'static str,
+    ) -> Result<T> {
+        let response = self
+            .client
+            .execute_with_retry(|| {
+                let mut builder = self
+                    .client
+                    .inner()
+                    .get(url.clone())
+                    .header("accept", "application/json");
+
+                if let Some(ref api_key) = self.client.config().api_key {
+                    builder = builder.header("X-API-Key", api_key.as_str());
+                }
+
+                builder
+            })
+            .await
+            .map_err(|e| e.with_endpoint(endpoint))?;
+
+        read_json(response)
+            .await
+            .map_err(|e| e.with_endpoint(endpoint))
+    }
+
+    /// POST `body` as JSON to `url` and deserialize a JSON response, sending
+    /// the API key when configured
+    ///
+    /// API errors are labelled with `endpoint`; see [`OdosError::endpoint`].
+    #[cfg(feature = "limit-orders")]
+    pub(crate) async fn post_json<B: serde::Serialize + ?Sized, T: DeserializeOwned>(
+        &self,
+        url: Url,
+        endpoint: &'static str,
+        body: &B,
+    ) -> Result<T> {
+        let response = self
+            .client
+            .execute_with_retry(|| {
+                let mut builder = self
+                    .client
+                    .inner()
+                    .post(url.clone())
+                    .header("accept", "application/json")
+                    .json(body);
+
+                if let Some(ref api_key) = self.client.config().api_key {
+                    builder = builder.header("X-API-Key", api_key.as_str());
+                }
+
+                builder
+            })
+            .await
+            .map_err(|e| e.with_endpoint(endpoint))?;
+
+        read_json(response)
+            .await
+            .map_err(|e| e.with_endpoint(endpoint))
+    }
+
+    fn spender_cache(&self) -> std::sync::MutexGuard<'_, HashMap<SpenderKey, (Address, Instant)>> {
+        // The cache holds plain data, so a poisoned lock is still usable
+        self.spender_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Deprecated: Use [`quote`](Self::quote) instead
+    #[deprecated(since = "0.25.0", note = "Use `quote` instead")]
+    pub async fn get_swap_quote(
+        &self,
+        quote_request: &QuoteRequest,
+    ) -> Result<SingleQuoteResponse> {
+        self.quote(quote_request).await
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_assemble_response(
+        &self,
+        assemble_request: AssembleRequest,
+    ) -> Result<Response> {
+        let url = self.client.config().endpoint.assemble_url();
+        self.get_assemble_response_within(url, assemble_request, None)
+            .await
+    }
+
+    async fn get_assemble_response_within(
+        &self,
+        url: Url,
+        assemble_request: AssembleRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<Response> {
+        self.client
+            .execute_with_retry_within(
+                || {
+                    let mut builder = self
+                        .client
+                        .inner()
+                        .post(url.clone())
+                        .header("Content-Type", "application/json")
+                        .json(&assemble_request);
+
+                    // Add API key header if available
+                    if let Some(ref api_key) = self.client.config().api_key {
+                        builder = builder.header("X-API-Key", api_key.as_str());
+                    }
+
+                    builder
+                },
+                budget,
+            )
+            .await
+            .map_err(|e| e.with_endpoint("assemble"))
+    }
+
+    /// Assemble transaction data from a quote
+    ///
+    /// Takes a path ID from a quote response and assembles the complete
+    /// transaction data needed to execute the swap on-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer_address` - Address that will sign and send the transaction
+    /// * `output_recipient` - Address that will receive the output tokens
+    /// * `path_id` - Path ID from a previous quote response
+    ///
+    /// # Returns
+    ///
+    /// Returns [`TransactionData`] containing:
+    /// - Transaction calldata (`data`)
+    /// - ETH value to send (`value`)
+    /// - Target contract address (`to`)
+    /// - Gas estimates
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::Api`] - Invalid path ID, expired quote, or other API error
+    /// - [`OdosError::RateLimit`] - Rate limit exceeded
+    /// - [`OdosError::Http`] - Network error
+    /// - [`OdosError::Timeout`] - Request timeout
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    /// use alloy_primitives::address;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let path_id = "2f6e14ad1cfa589029f413791a0b7f6f"; // From a quote response
+    ///
+    /// let tx_data = client.assemble_tx_data(
+    ///     address!("0000000000000000000000000000000000000001"),
+    ///     address!("0000000000000000000000000000000000000001"),
+    ///     path_id
+    /// ).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn assemble_tx_data(
+        &self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &str,
+    ) -> Result<TransactionData> {
+        let url = self.client.config().endpoint.assemble_url();
+        self.assemble_tx_data_at(
+            url,
+            signer_address,
+            output_recipient,
+            PathId::parse(path_id)?,
+            None,
+        )
+        .await
+    }
+
+    /// [`assemble_tx_data`](Self::assemble_tx_data) for a path ID that is
+    /// already a [`PathId`], e.g. one from a quote response
+    pub(crate) async fn assemble_tx_data_at(
+        &self,
+        url: Url,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: PathId,
+        budget: Option<TimeBudget>,
+    ) -> Result<TransactionData> {
+        let assemble_request = AssembleRequest {
+            user_addr: signer_address,
+            path_id,
+            simulate: false,
+            receiver: Some(output_recipient),
+        };
+
+        let AssemblyResponse { transaction, .. } = self
+            .assembly_response_at(url, assemble_request, budget)
+            .await?;
+
+        Ok(transaction)
+    }
+
+    /// Assemble a swap and return the complete assemble response
+    ///
+    /// Unlike [`assemble_tx_data`](Self::assemble_tx_data), which returns
+    /// only the transaction, this keeps the summary the API reports with it:
+    /// the input and output token amounts, their USD values and the gas
+    /// estimate.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::Api`] - Invalid path ID, expired quote, or other API error
+    /// - [`OdosError::RateLimit`] - Rate limit exceeded
+    /// - [`OdosError::Http`] - Network error
+    /// - [`OdosError::Timeout`] - Request timeout
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{AssemblyRequest, OdosClient};
+    ///
+    /// # async fn example(request: &AssemblyRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let response = client.assemble_full(request).await?;
+    /// for output in &response.output_tokens {
+    ///     println!("{}: {}", output.token_address, output.amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn assemble_full(&self, request: &AssemblyRequest) -> Result<AssemblyResponse> {
+        let url = self.client.config().endpoint.assemble_url();
+        self.assemble_full_at(url, request).await
+    }
+
+    async fn assemble_full_at(
+        &self,
+        url: Url,
+        request: &AssemblyRequest,
+    ) -> Result<AssemblyResponse> {
+        let assemble_request = AssembleRequest {
+            user_addr: request.signer_address(),
+            path_id: request.path_id().clone(),
+            simulate: false,
+            receiver: Some(request.output_recipient()),
+        };
+
+        self.assembly_response_at(url, assemble_request, None).await
+    }
+
+    /// Assemble transaction data from a quote and simulate the swap
+    ///
+    /// Like [`assemble_tx_data`](Self::assemble_tx_data), but asks the API to
+    /// simulate the assembled transaction against the current chain state
+    /// and returns the simulation result alongside the transaction.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::SimulationFailed`] - The simulation reported that the
+    ///   swap would fail; the error carries the API's [`SimulationError`](crate::SimulationError)
+    /// - [`OdosError::MissingData`] - The response has no simulation result
+    /// - [`OdosError::Api`] - Invalid path ID, expired quote, or other API error
+    /// - [`OdosError::Http`] - Network error
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, OdosError};
+    /// use alloy_primitives::address;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let signer = address!("0000000000000000000000000000000000000001");
+    ///
+    /// match client
+    ///     .assemble_with_simulation(signer, signer, "2f6e14ad1cfa589029f413791a0b7f6f")
+    ///     .await
+    /// {
+    ///     Ok((tx_data, simulation)) => {
+    ///         println!("Simulated output: {:?}", simulation.amounts_out());
+    ///     }
+    ///     Err(OdosError::SimulationFailed { error }) => {
+    ///         eprintln!("Swap would fail: {}", error.error_message());
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn assemble_with_simulation(
+        &self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &str,
+    ) -> Result<(TransactionData, Simulation)> {
+        let url = self.client.config().endpoint.assemble_url();
+        self.assemble_with_simulation_at(url, signer_address, output_recipient, path_id)
+            .await
+    }
+
+    async fn assemble_with_simulation_at(
+        &self,
+        url: Url,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &str,
+    ) -> Result<(TransactionData, Simulation)> {
+        let assemble_request = AssembleRequest {
+            user_addr: signer_address,
+            path_id: PathId::parse(path_id)?,
+            simulate: true,
+            receiver: Some(output_recipient),
+        };
+
+        let AssemblyResponse {
+            transaction,
+            simulation,
+            ..
+        } = self
+            .assembly_response_at(url, assemble_request, None)
+            .await?;
+
+        let simulation = simulation
+            .ok_or_else(|| OdosError::missing_data("Assemble response has no simulation result"))?;
+        if !simulation.is_success() {
+            return Err(OdosError::simulation_failed(simulation.into_error()));
+        }
+
+        Ok((transaction, simulation))
+    }
+
+    async fn assembly_response_at(
+        &self,
+        url: Url,
+        assemble_request: AssembleRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<AssemblyResponse> {
+        #[cfg(any(test, feature = "test-utils"))]
+        if let Some(fixtures) = &self.fixtures {
+            return fixtures.assembly(&assemble_request);
+        }
+
+        let response = self
+            .get_assemble_response_within(url, assemble_request, budget)
+            .await?;
+
+        read_json(response)
+            .await
+            .map_err(|e| e.with_endpoint("assemble"))
+    }
+
+    /// Assemble a transaction from an assembly request
+    ///
+    /// Assembles transaction data and constructs a [`TransactionRequest`] ready
+    /// for gas parameter configuration and signing. This is a convenience method
+    /// that combines [`assemble_tx_data`](Self::assemble_tx_data) with transaction
+    /// request construction.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The assembly request containing addresses and path ID
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`TransactionRequest`] with:
+    /// - `to`: Router contract address
+    /// - `from`: Signer address
+    /// - `data`: Encoded swap calldata
+    /// - `value`: ETH amount to send
+    /// - `chain_id`: Chain of the request
+    ///
+    /// Gas parameters (gas limit, gas price) are NOT set and must be configured
+    /// by the caller before signing. Use [`assemble_with_gas`](Self::assemble_with_gas)
+    /// to set the gas limit from the Odos gas estimate.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::Api`] - Invalid path ID or API error
+    /// - [`OdosError::RateLimit`] - Rate limit exceeded
+    /// - [`OdosError::Http`] - Network error
+    /// - [`OdosError::Timeout`] - Request timeout
+    /// - [`OdosError::Hex`] - Failed to decode transaction data
+    /// - [`OdosError::RouterAddressMismatch`] - The API assembled the swap
+    ///   for a different router than `request.router_address()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, AssemblyRequest, PathId};
+    /// use alloy_primitives::{address, U256};
+    /// use alloy_chains::NamedChain;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let request = AssemblyRequest::builder()
+    ///     .chain(NamedChain::Mainnet)
+    ///     .signer_address(address!("0000000000000000000000000000000000000001"))
+    ///     .output_recipient(address!("0000000000000000000000000000000000000001"))
+    ///     .router_address(address!("0000000000000000000000000000000000000002"))
+    ///     .token_address(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"))
+    ///     .token_amount(U256::from(1000000))
+    ///     .path_id(PathId::parse("2f6e14ad1cfa589029f413791a0b7f6f")?) // From a quote response
+    ///     .build();
+    ///
+    /// let mut tx_request = client.assemble(&request).await?;
+    ///
+    /// // Configure gas parameters before signing
+    /// // tx_request = tx_request.with_gas_limit(300000);
+    /// // tx_request = tx_request.with_max_fee_per_gas(...);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn assemble(&self, request: &AssemblyRequest) -> Result<TransactionRequest> {
+        self.assemble_within(request, None).await
+    }
+
+    /// [`assemble`](Self::assemble) bounded by an optional total time budget
+    pub(crate) async fn assemble_within(
+        &self,
+        request: &AssemblyRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<TransactionRequest> {
+        let url = self.client.config().endpoint.assemble_url();
+        self.assemble_at(url, request, budget).await
+    }
+
+    pub(crate) async fn assemble_at(
+        &self,
+        url: Url,
+        request: &AssemblyRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<TransactionRequest> {
+        let transaction = self
+            .assemble_tx_data_at(
+                url,
+                request.signer_address(),
+                request.output_recipient(),
+                request.path_id().clone(),
+                budget,
+            )
+            .await?;
+
+        assembled_transaction(request, &transaction)
+    }
+
+    /// Assemble a transaction with its gas limit set from the Odos gas
+    /// estimate
+    ///
+    /// Like [`assemble`](Self::assemble), but sets the gas limit to the
+    /// estimate from the assemble response increased by `buffer_percent`,
+    /// rounded up. Pass [`DEFAULT_GAS_BUFFER_PERCENT`] for the recommended
+    /// 20% buffer. If the response reports no estimate, or an estimate of
+    /// zero, the gas limit is left unset and a warning is logged.
+    ///
+    /// Gas price parameters are still left to the caller.
+    ///
+    /// # Errors
+    ///
+    /// As for [`assemble`](Self::assemble).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{AssemblyRequest, OdosClient, DEFAULT_GAS_BUFFER_PERCENT};
+    ///
+    /// # async fn example(request: &AssemblyRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let tx = client
+    ///     .assemble_with_gas(request, DEFAULT_GAS_BUFFER_PERCENT)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self), level = "debug")]
+    pub async fn assemble_with_gas(
+        &self,
+        request: &AssemblyRequest,
+        buffer_percent: u32,
+    ) -> Result<TransactionRequest> {
+        let url = self.client.config().endpoint.assemble_url();
+        self.assemble_with_gas_at(url, request, buffer_percent)
+            .await
+    }
+
+    async fn assemble_with_gas_at(
+        &self,
+        url: Url,
+        request: &AssemblyRequest,
+        buffer_percent: u32,
+    ) -> Result<TransactionRequest> {
+        let response = self.assemble_full_at(url, request).await?;
+
+        let tx = assembled_transaction(request, &response.transaction)?;
+
+        match buffered_gas_limit(response.gas_estimate, buffer_percent) {
+            Some(gas_limit) => Ok(tx.with_gas_limit(gas_limit)),
+            None => {
+                warn!(
+                    gas_estimate = ?response.gas_estimate,
+                    path_id = %request.path_id(),
+                    "Assemble response has no usable gas estimate; leaving gas limit unset"
+                );
+                Ok(tx)
+            }
+        }
+    }
+
+    /// Deprecated: Use [`assemble`](Self::assemble) instead
+    #[deprecated(since = "0.25.0", note = "Use `assemble` instead")]
+    pub async fn build_base_transaction(
+        &self,
+        swap: &AssemblyRequest,
+    ) -> Result<TransactionRequest> {
+        self.assemble(swap).await
+    }
+}
+
+impl Default for OdosClient {
+    /// Creates a default Odos client with standard configuration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying HTTP client cannot be initialized.
+    /// This should only fail in extremely rare cases such as:
+    /// - TLS initialization failure
+    /// - System resource exhaustion
+    /// - Invalid system configuration
+    ///
+    /// In practice, this almost never fails and is safe for most use cases.
+    /// See [`OdosHttpClient::default`] for more details.
+    fn default() -> Self {
+        Self::new().expect("Failed to create default OdosClient")
+    }
+}
+
+/// Build the transaction for an assembled swap
+///
+/// Fails if the API assembled the swap for a different router than the
+/// caller's `router_address`, since approvals and simulations made against
+/// that address would not apply.
+fn assembled_transaction(
+    request: &AssemblyRequest,
+    transaction: &TransactionData,
+) -> Result<TransactionRequest> {
+    if transaction.to != request.router_address() {
+        return Err(OdosError::router_address_mismatch(
+            request.chain() as u64,
+            request.router_address(),
+            transaction.to,
+        ));
+    }
+
+    Ok(TransactionRequest::default()
+        .with_chain_id(transaction.chain_id)
+        .with_input(hex::decode(&transaction.data)?)
+        .with_value(parse_value(&transaction.value)?)
+        .with_to(request.router_address())
+        .with_from(request.signer_address()))
+}
+
+/// Gas buffer applied by [`OdosClient::assemble_with_gas`] when passed as
+/// its `buffer_percent`, as a percentage of the Odos gas estimate
+pub const DEFAULT_GAS_BUFFER_PERCENT: u32 = 20;
+
+/// Get the router compiled into this crate for `chain` and `version`
+fn compiled_router_address(chain: Chain, version: ApiVersion) -> OdosChainResult<Address> {
+    match version {
+        ApiVersion::V2 => chain.v2_router_address(),
+        ApiVersion::V3 => chain.v3_router_address(),
+    }
+}
+
+/// Whether a router info lookup failed because the host has no router info
+/// for the chain, rather than for a transient or request error
+fn router_info_unavailable(err: &OdosError) -> bool {
+    match err {
+        OdosError::Api { status, body, .. } => {
+            *status == StatusCode::NOT_FOUND
+                || body.message.to_ascii_lowercase().contains("not available")
+        }
+        _ => false,
+    }
+}
+
+/// Apply a percentage buffer to a gas estimate, rounding up
+///
+/// Returns `None` for a missing, zero or non-finite estimate.
+fn buffered_gas_limit(estimate: Option<f64>, buffer_percent: u32) -> Option<u64> {
+    let estimate = estimate.filter(|estimate| estimate.is_finite() && *estimate > 0.0)?;
+    let buffered = (estimate * (100.0 + f64::from(buffer_percent)) / 100.0).ceil();
+    // Float to int casts saturate, so an absurd estimate caps at u64::MAX
+    Some(buffered as u64)
+}
+
+/// Deserialize a successful JSON response, or convert an error response into
+/// an [`OdosError`]
+///
+/// A success body that fails to decode is kept, truncated, in the
+/// [`OdosError::ResponseDecode`] error.
+async fn read_json<T: DeserializeOwned>(response: Response) -> Result<T> {
+    if !response.status().is_success() {
+        let status = response.status();
+        let parsed = parse_error_response(response).await;
+        return Err(OdosError::from_response(status, parsed));
+    }
+
+    let body = response.text().await?;
+    serde_json::from_str(&body).map_err(|e| OdosError::response_decode(e, &body))
+}
+
+/// Deprecated alias for [`OdosClient`]
+///
+/// This type alias is provided for backward compatibility.
+/// Use [`OdosClient`] instead in new code.
+#[deprecated(since = "0.25.0", note = "Use `OdosClient` instead")]
+pub type OdosSor = OdosClient;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_utils::NoopSleeper;
+    use alloy_primitives::{address, U256};
+    use wiremock::{
+        matchers::{body_partial_json, header, method, path},
+        Mock, MockServer, Request, ResponseTemplate,
+    };
+
+    const ROTATED_SPENDER: Address = address!("00000000000000000000000000000000000000aa");
+
+    fn test_client() -> OdosClient {
+        OdosClient::with_config(ClientConfig {
+            endpoint: crate::Endpoint::public_v3(),
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn router_info_url(server: &MockServer) -> Url {
+        Url::parse(&format!("{}/info/router/v3/8453", server.uri())).unwrap()
+    }
+
+    pub(crate) fn quote_request(gas_price: Option<f64>) -> QuoteRequest {
+        QuoteRequest::builder()
+            .chain(Chain::ethereum())
+            .input_tokens(vec![(
+                address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+                U256::from(1_000_000),
+            )
+                .into()])
+            .output_tokens(vec![(
+                address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+                1.0,
+            )
+                .into()])
+            .slippage_limit_percent(0.5)
+            .user_addr(Address::ZERO)
+            .compact(false)
+            .simple(false)
+            .referral_code(0)
+            .disable_rfqs(false)
+            .maybe_gas_price(gas_price)
+            .build()
+    }
+
+    fn quote_url(server: &MockServer) -> Url {
+        Url::parse(&format!("{}/sor/quote/v3", server.uri())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_quote_rejects_invalid_request_before_sending() {
+        let request = crate::QuoteRequest::builder()
+            .chain(Chain::ethereum())
+            .input_tokens(vec![])
+            .output_tokens(vec![])
+            .slippage_limit_percent(0.5)
+            .user_addr(Address::ZERO)
+            .compact(false)
+            .simple(false)
+            .referral_code(0)
+            .disable_rfqs(false)
+            .build();
+
+        let err = test_client().quote(&request).await.unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_quote_sends_gas_price_when_set() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v3"))
+            .and(body_partial_json(serde_json::json!({ "gasPrice": 25.5 })))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let _ = test_client()
+            .quote_at(quote_url(&server), &quote_request(Some(25.5)), None)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_quote_omits_gas_price_when_unset() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v3"))
+            .and(|request: &Request| {
+                let body: serde_json::Value = request.body_json().unwrap();
+                body.get("chainId").is_some() && body.get("gasPrice").is_none()
+            })
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let _ = test_client()
+            .quote_at(quote_url(&server), &quote_request(None), None)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_approval_spender_uses_api_address() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/8453"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "address": ROTATED_SPENDER })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = test_client();
+        let spender = client
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, router_info_url(&server))
+            .await
+            .unwrap();
+
+        assert_eq!(spender, ROTATED_SPENDER);
+        assert_ne!(spender, Chain::base().v3_router_address().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_approval_spender_cache_hit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/8453"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "address": ROTATED_SPENDER })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client();
+        let url = router_info_url(&server);
+
+        let first = client
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, url.clone())
+            .await
+            .unwrap();
+        // Clones share the cache
+        let second = client
+            .clone()
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, url)
+            .await
+            .unwrap();
+
+        assert_eq!(first, ROTATED_SPENDER);
+        assert_eq!(second, ROTATED_SPENDER);
+        // `expect(1)` is verified when the server drops
+    }
+
+    #[tokio::test]
+    async fn test_approval_spender_falls_back_when_not_found() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/8453"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = test_client();
+        let url = router_info_url(&server);
+
+        let spender = client
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, url.clone())
+            .await
+            .unwrap();
+        assert_eq!(spender, Chain::base().v3_router_address().unwrap());
+
+        // The fallback is cached like an API answer
+        let cached = client
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, url)
+            .await
+            .unwrap();
+        assert_eq!(cached, spender);
+    }
+
+    #[tokio::test]
+    async fn test_approval_spender_returns_other_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/8453"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = test_client();
+        let url = router_info_url(&server);
+
+        for _ in 0..2 {
+            let err = client
+                .resolve_approval_spender(Chain::base(), ApiVersion::V3, url.clone())
+                .await
+                .unwrap_err();
+            assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approval_spender_cache_is_per_host() {
+        let rotated = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/8453"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "address": ROTATED_SPENDER })),
+            )
+            .expect(1)
+            .mount(&rotated)
+            .await;
+        let other = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/8453"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "address": Address::ZERO })),
+            )
+            .expect(1)
+            .mount(&other)
+            .await;
+
+        let client = test_client();
+        let first = client
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, router_info_url(&rotated))
+            .await
+            .unwrap();
+        let second = client
+            .resolve_approval_spender(Chain::base(), ApiVersion::V3, router_info_url(&other))
+            .await
+            .unwrap();
+
+        assert_eq!(first, ROTATED_SPENDER);
+        assert_eq!(second, Address::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_supported_tokens_sends_api_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/tokens/1"))
+            .and(header("X-API-Key", "11111111-1a11-1111-a11a-aaa11a111a1a"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(crate::info::tests::TOKENS_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OdosClient::with_config(ClientConfig {
+            api_key: Some(
+                "11111111-1a11-1111-a11a-aaa11a111a1a"
+                    .parse::<crate::ApiKey>()
+                    .unwrap(),
+            ),
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap();
+        let url = Url::parse(&format!("{}/info/tokens/1", server.uri())).unwrap();
+
+        let tokens = client.fetch_supported_tokens(url).await.unwrap();
+        assert_eq!(tokens.len(), 3);
+        assert!(tokens.iter().any(|token| token.symbol == "WETH"));
+    }
+
+    #[tokio::test]
+    async fn test_supported_tokens_retries_server_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/tokens/1"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/info/tokens/1"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(crate::info::tests::TOKENS_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OdosClient::with_config(ClientConfig {
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap();
+        let url = Url::parse(&format!("{}/info/tokens/1", server.uri())).unwrap();
+
+        assert_eq!(client.fetch_supported_tokens(url).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_constants_reports_drift() {
+        let server = MockServer::start().await;
+        let mut api_chains: Vec<u64> = crate::get_supported_chains().ids().collect();
+        api_chains.retain(|&id| id != 56);
+        api_chains.push(999_999);
+        Mock::given(method("GET"))
+            .and(path("/info/chains"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "chains": api_chains })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let url = Url::parse(&format!("{}/info/chains", server.uri())).unwrap();
+        let report = test_client().reconcile_chains(url).await.unwrap();
+
+        assert_eq!(report.api_only, [999_999]);
+        assert_eq!(report.sdk_only, [56]);
+    }
+
+    #[tokio::test]
+    async fn test_runtime_chain_support_reports_unlisted_chain() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/chains"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "chains": [1, 80094] })),
+            )
+            .mount(&server)
+            .await;
+        for chain_id in [1, 80094] {
+            Mock::given(method("GET"))
+                .and(path(format!("/info/contract-info/v2/{chain_id}")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "chainId": chain_id,
+                    "routerAddress": ROTATED_SPENDER,
+                    "executorAddress": address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99"),
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let client = mock_server_client(&server);
+
+        let support = client.runtime_chain_support(80094).await.unwrap();
+        assert_eq!(
+            support,
+            RuntimeChainSupport {
+                chain_id: 80094,
+                api_supported: true,
+                sdk_supported: false,
+                router_address: Some(ROTATED_SPENDER),
+            }
+        );
+        assert!(support.is_unlisted());
+
+        let support = client.runtime_chain_support(1).await.unwrap();
+        assert!(support.api_supported && support.sdk_supported);
+        assert!(!support.is_unlisted());
+
+        // A chain the API does not list skips the contract info lookup
+        let support = client.runtime_chain_support(999_999).await.unwrap();
+        assert!(!support.api_supported && !support.sdk_supported);
+        assert_eq!(support.router_address, None);
+    }
+
+    #[tokio::test]
+    async fn test_liquidity_sources_sends_api_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/liquidity-sources/1"))
+            .and(header("X-API-Key", "11111111-1a11-1111-a11a-aaa11a111a1a"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::info::tests::LIQUIDITY_SOURCES_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OdosClient::with_config(ClientConfig {
+            api_key: Some("11111111-1a11-1111-a11a-aaa11a111a1a".parse().unwrap()),
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap();
+        let url = Url::parse(&format!("{}/info/liquidity-sources/1", server.uri())).unwrap();
+
+        let sources: Vec<LiquiditySourceInfo> =
+            client.get_json(url, "liquidity_sources").await.unwrap();
+        assert_eq!(sources.len(), 3);
+    }
+
+    fn assemble_url(server: &MockServer) -> Url {
+        Url::parse(&format!("{}/sor/assemble", server.uri())).unwrap()
+    }
+
+    async fn mount_assemble(server: &MockServer, fixture: &str) {
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .and(body_partial_json(serde_json::json!({ "simulate": true })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(fixture))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_assemble_full_returns_summary() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .and(body_partial_json(serde_json::json!({
+                "pathId": "2f6e14ad1cfa589029f413791a0b7f6f",
+                "simulate": false
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = assembly_request();
+        let signer = request.signer_address();
+
+        let response = test_client()
+            .assemble_full_at(assemble_url(&server), &request)
+            .await
+            .unwrap();
+        assert_eq!(response.output_tokens.len(), 1);
+        assert_eq!(response.transaction.from, signer);
+    }
+
+    pub(crate) fn assembly_request() -> AssemblyRequest {
+        let signer = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+        AssemblyRequest::builder()
+            .chain(alloy_chains::NamedChain::Mainnet)
+            .router_address(crate::ODOS_V2_ETHEREUM_ROUTER)
+            .signer_address(signer)
+            .output_recipient(signer)
+            .token_address(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"))
+            .token_amount(U256::from(1_000_000_000u64))
+            .path_id(PathId::parse("2f6e14ad1cfa589029f413791a0b7f6f").unwrap())
+            .build()
+    }
+
+    fn transaction_data(to: Address) -> TransactionData {
+        let response: AssemblyResponse =
+            serde_json::from_str(crate::assemble::tests::ASSEMBLE_FIXTURE).unwrap();
+        TransactionData {
+            to,
+            ..response.transaction
+        }
+    }
+
+    #[test]
+    fn test_assembled_transaction_sets_chain_id() {
+        let request = assembly_request();
+        let tx =
+            assembled_transaction(&request, &transaction_data(request.router_address())).unwrap();
+
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.to, Some(request.router_address().into()));
+        assert_eq!(tx.from, Some(request.signer_address()));
+    }
+
+    #[test]
+    fn test_assembled_transaction_rejects_other_router() {
+        let request = assembly_request();
+        let err = assembled_transaction(&request, &transaction_data(ROTATED_SPENDER)).unwrap_err();
+
+        match err {
+            OdosError::RouterAddressMismatch {
+                chain_id,
+                expected,
+                reported,
+            } => {
+                assert_eq!(chain_id, 1);
+                assert_eq!(expected, request.router_address());
+                assert_eq!(reported, ROTATED_SPENDER);
+            }
+            other => panic!("expected RouterAddressMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_assembled_transaction_keeps_assembly_chain() {
+        let request = assembly_request();
+        let transaction = TransactionData {
+            chain_id: 42161,
+            ..transaction_data(request.router_address())
+        };
+
+        let tx = assembled_transaction(&request, &transaction).unwrap();
+        assert_eq!(tx.chain_id, Some(42161));
+    }
+
+    #[test]
+    fn test_buffered_gas_limit() {
+        assert_eq!(buffered_gas_limit(Some(100_000.0), 20), Some(120_000));
+        assert_eq!(buffered_gas_limit(Some(196_875.0), 20), Some(236_250));
+        // Rounded up
+        assert_eq!(buffered_gas_limit(Some(100_001.0), 10), Some(110_002));
+        assert_eq!(buffered_gas_limit(Some(100_000.0), 0), Some(100_000));
+
+        assert_eq!(buffered_gas_limit(None, 20), None);
+        assert_eq!(buffered_gas_limit(Some(0.0), 20), None);
+        assert_eq!(buffered_gas_limit(Some(f64::NAN), 20), None);
+    }
+
+    #[tokio::test]
+    async fn test_assemble_with_gas_sets_buffered_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .mount(&server)
+            .await;
+
+        let tx = test_client()
+            .assemble_with_gas_at(
+                assemble_url(&server),
+                &assembly_request(),
+                DEFAULT_GAS_BUFFER_PERCENT,
+            )
+            .await
+            .unwrap();
+        assert_eq!(tx.gas, Some(236_250));
+    }
+
+    #[tokio::test]
+    async fn test_assemble_with_gas_leaves_missing_estimate_unset() {
+        let server = MockServer::start().await;
+        let mut response: serde_json::Value =
+            serde_json::from_str(crate::assemble::tests::ASSEMBLE_FIXTURE).unwrap();
+        response["gasEstimate"] = serde_json::Value::from(0);
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .mount(&server)
+            .await;
+
+        let tx = test_client()
+            .assemble_with_gas_at(
+                assemble_url(&server),
+                &assembly_request(),
+                DEFAULT_GAS_BUFFER_PERCENT,
+            )
+            .await
+            .unwrap();
+        assert_eq!(tx.gas, None);
+        assert!(tx.input.input().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_assemble_with_simulation_returns_simulation() {
+        let server = MockServer::start().await;
+        mount_assemble(
+            &server,
+            crate::assemble::tests::ASSEMBLE_SIMULATION_SUCCESS_FIXTURE,
+        )
+        .await;
+
+        let signer = address!("0000000000000000000000000000000000000001");
+        let (transaction, simulation) = test_client()
+            .assemble_with_simulation_at(
+                assemble_url(&server),
+                signer,
+                signer,
+                "2f6e14ad1cfa589029f413791a0b7f6f",
+            )
+            .await
+            .unwrap();
+        assert_eq!(transaction.from, signer);
+        assert!(simulation.is_success());
+        assert_eq!(simulation.gas_estimate(), 176_113);
+    }
+
+    #[tokio::test]
+    async fn test_assemble_with_simulation_surfaces_failure() {
+        let server = MockServer::start().await;
+        mount_assemble(
+            &server,
+            crate::assemble::tests::ASSEMBLE_SIMULATION_FAILURE_FIXTURE,
+        )
+        .await;
+
+        let signer = address!("0000000000000000000000000000000000000001");
+        let err = test_client()
+            .assemble_with_simulation_at(
+                assemble_url(&server),
+                signer,
+                signer,
+                "2f6e14ad1cfa589029f413791a0b7f6f",
+            )
+            .await
+            .unwrap_err();
+        match err {
+            OdosError::SimulationFailed { error } => {
+                assert_eq!(error.error_type(), "execution_reverted");
+                assert!(error.error_message().contains("exceeds allowance"));
+            }
+            other => panic!("expected SimulationFailed, got {other:?}"),
+        }
+    }
+
+    fn contract_info_url(server: &MockServer) -> Url {
+        Url::parse(&format!("{}/info/contract-info/v3/1", server.uri())).unwrap()
+    }
+
+    async fn mount_contract_info(server: &MockServer, router: Address) {
+        Mock::given(method("GET"))
+            .and(path("/info/contract-info/v3/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "chainId": 1,
+                "routerAddress": router,
+                "executorAddress": address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99"),
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_verify_router_address_matches() {
+        let server = MockServer::start().await;
+        mount_contract_info(&server, crate::ODOS_V3).await;
+
+        test_client()
+            .check_router_address(Chain::ethereum(), contract_info_url(&server))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_router_address_mismatch() {
+        let server = MockServer::start().await;
+        mount_contract_info(&server, ROTATED_SPENDER).await;
+
+        let err = test_client()
+            .check_router_address(Chain::ethereum(), contract_info_url(&server))
+            .await
+            .unwrap_err();
+
+        match err {
+            OdosError::RouterAddressMismatch {
+                chain_id,
+                expected,
+                reported,
+            } => {
+                assert_eq!(chain_id, 1);
+                assert_eq!(expected, crate::ODOS_V3);
+                assert_eq!(reported, ROTATED_SPENDER);
+            }
+            other => panic!("Expected RouterAddressMismatch, got: {other:?}"),
+        }
+    }
+
+    /// Client whose endpoint points at the mock server, so the public
+    /// methods can be exercised end to end
+    fn mock_server_client(server: &MockServer) -> OdosClient {
+        mock_server_client_with(server, ClientConfig::default())
+    }
+
+    fn mock_server_client_with(server: &MockServer, config: ClientConfig) -> OdosClient {
+        OdosClient::with_config(ClientConfig {
+            endpoint: crate::Endpoint::custom(
+                Url::parse(&server.uri()).unwrap(),
+                crate::ApiVersion::V2,
+            ),
+            retry_config: RetryConfig::no_retries(),
+            sleeper: Arc::new(NoopSleeper),
+            ..config
+        })
+        .unwrap()
+    }
+
+    pub(crate) fn quote_json() -> serde_json::Value {
+        serde_json::json!({
+            "blockNumber": 1,
+            "dataGasEstimate": 0,
+            "gasEstimate": 196875.0,
+            "gasEstimateValue": 3.0,
+            "gweiPerGas": 6.85,
+            "inAmounts": ["1000000"],
+            "inTokens": ["0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"],
+            "inValues": [1.0],
+            "netOutValue": 0.99,
+            "outAmounts": ["297096823183507"],
+            "outTokens": ["0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"],
+            "outValues": [0.99],
+            "pathId": "2f6e14ad1cfa589029f413791a0b7f6f",
+            "pathViz": null,
+            "percentDiff": 0.0,
+            "priceImpact": 0.1
+        })
+    }
+
+    #[tokio::test]
+    async fn test_quote_against_custom_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .and(body_partial_json(serde_json::json!({ "chainId": 1 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let quote = mock_server_client(&server)
+            .quote(&quote_request(None))
+            .await
+            .unwrap();
+
+        assert_eq!(quote.path_id().as_str(), "2f6e14ad1cfa589029f413791a0b7f6f");
+        assert_eq!(
+            quote.out_amount().map(String::as_str),
+            Some("297096823183507")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_quote_cache_serves_hits_until_ttl() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_json()))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                quote_cache: Some(crate::QuoteCacheConfig {
+                    ttl: Duration::from_millis(200),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        );
+
+        let fresh = client.quote(&quote_request(None)).await.unwrap();
+        assert!(!fresh.is_cached());
+        let cached = client.clone().quote(&quote_request(None)).await.unwrap();
+        assert!(cached.is_cached());
+        assert_eq!(cached.path_id(), fresh.path_id());
+
+        // A different request misses
+        let other = client.quote(&quote_request(Some(25.0))).await.unwrap();
+        assert!(!other.is_cached());
+
+        // Only pause the clock around the wait, so the HTTP requests do not
+        // auto-advance it into their timeouts
+        tokio::time::pause();
+        tokio::time::advance(Duration::from_millis(250)).await;
+        tokio::time::resume();
+        let refreshed = client.quote(&quote_request(None)).await.unwrap();
+        assert!(!refreshed.is_cached());
+    }
+
+    #[tokio::test]
+    async fn test_quote_cache_skips_errors_and_assembly() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Bad request"))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                quote_cache: Some(crate::QuoteCacheConfig::default()),
+                ..Default::default()
+            },
+        );
+
+        for _ in 0..2 {
+            client.quote(&quote_request(None)).await.unwrap_err();
+            client.assemble(&assembly_request()).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coalesced_quotes_share_one_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(quote_json())
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                coalesce_quotes: true,
+                ..Default::default()
+            },
+        );
+
+        let mut callers = tokio::task::JoinSet::new();
+        for _ in 0..10 {
+            let client = client.clone();
+            callers.spawn(async move { client.quote(&quote_request(None)).await });
+        }
+        while let Some(quote) = callers.join_next().await {
+            let quote = quote.unwrap().unwrap();
+            assert_eq!(quote.path_id().as_str(), "2f6e14ad1cfa589029f413791a0b7f6f");
+            assert!(!quote.is_cached());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coalesced_quote_errors_reach_every_caller_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_string("Bad request")
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                coalesce_quotes: true,
+                ..Default::default()
+            },
+        );
+
+        let mut callers = tokio::task::JoinSet::new();
+        for _ in 0..5 {
+            let client = client.clone();
+            callers.spawn(async move { client.quote(&quote_request(None)).await });
+        }
+        while let Some(result) = callers.join_next().await {
+            let err = result.unwrap().unwrap_err();
+            assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+            assert_eq!(err.endpoint(), Some("quote"));
+        }
+
+        // The failed flight is forgotten
+        client.quote(&quote_request(None)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_assemble_against_custom_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let request = assembly_request();
+        let tx = mock_server_client(&server)
+            .assemble(&request)
+            .await
+            .unwrap();
+
+        assert_eq!(tx.from, Some(request.signer_address()));
+        assert_eq!(tx.to, Some(request.router_address().into()));
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_from_custom_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/tokens/1"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "detail": "Unsupported chain",
+                "traceId": null,
+                "errorCode": 4001
+            })))
+            .mount(&server)
+            .await;
+
+        let err = mock_server_client(&server)
+            .supported_tokens(1)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, OdosError::Api { status, .. } if status.as_u16() == 400));
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_and_default_headers_sent() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .and(header("user-agent", "integrator/1.0"))
+            .and(header("x-request-source", "pricing-engine"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .and(header("user-agent", "integrator/1.0"))
+            .and(header("x-request-source", "pricing-engine"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut default_headers = reqwest::header::HeaderMap::new();
+        default_headers.insert("x-request-source", "pricing-engine".parse().unwrap());
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                user_agent: Some("integrator/1.0".to_string()),
+                default_headers,
+                ..Default::default()
+            },
+        );
+
+        client.quote(&quote_request(None)).await.unwrap();
+        client.assemble(&assembly_request()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_name_the_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Bad request"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(ResponseTemplate::new(429).set_body_string("Slow down"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/info/tokens/1"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Unavailable"))
+            .mount(&server)
+            .await;
+        let client = mock_server_client(&server);
+
+        let err = client.quote(&quote_request(None)).await.unwrap_err();
+        assert_eq!(err.endpoint(), Some("quote"));
+        assert_eq!(err.status(), Some(StatusCode::BAD_REQUEST));
+
+        let request = assembly_request();
+        let err = client
+            .assemble_tx_data(
+                request.signer_address(),
+                request.output_recipient(),
+                request.path_id().as_str(),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.is_rate_limit());
+        assert_eq!(err.endpoint(), Some("assemble"));
+        assert_eq!(err.status(), Some(StatusCode::TOO_MANY_REQUESTS));
+
+        let err = client.supported_tokens(1).await.unwrap_err();
+        assert_eq!(err.endpoint(), Some("tokens"));
+        assert_eq!(err.status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[tokio::test]
+    async fn test_undecodable_success_bodies_are_kept_in_error() {
+        let server = MockServer::start().await;
+        let mut quote = quote_json();
+        quote["gasEstimate"] = serde_json::json!("not a number");
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&quote))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(r#"{"transaction": "unexpected"}"#),
+            )
+            .mount(&server)
+            .await;
+        let client = mock_server_client(&server);
+
+        let err = client.quote(&quote_request(None)).await.unwrap_err();
+        assert!(matches!(err, OdosError::ResponseDecode { .. }));
+        assert!(err.to_string().contains(r#""gasEstimate":"not a number""#));
+
+        let request = assembly_request();
+        let err = client
+            .assemble_tx_data(
+                request.signer_address(),
+                request.output_recipient(),
+                request.path_id().as_str(),
+            )
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"body: {"transaction": "unexpected"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_healthy() {
+        let server = MockServer::start().await;
+        let api_key = crate::ApiKey::new(uuid::Uuid::new_v4());
+        Mock::given(method("GET"))
+            .and(path("/info/chains"))
+            .and(header("X-API-Key", api_key.as_str().as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "chains": [1, 8453]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                api_key: Some(api_key),
+                ..Default::default()
+            },
+        );
+        let health = client.health_check().await.unwrap();
+
+        assert_eq!(health.api_key, ApiKeyStatus::Accepted);
+        assert!(health.is_healthy());
+        assert!(matches!(health.host, crate::ApiHost::Custom(_)));
+        assert!(health.latency < HEALTH_CHECK_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_reports_invalid_api_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/chains"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            // Not retried, even with retries configured
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                api_key: Some(crate::ApiKey::new(uuid::Uuid::new_v4())),
+                retry_config: RetryConfig::default(),
+                ..Default::default()
+            },
+        );
+        let health = client.health_check().await.unwrap();
+
+        assert_eq!(health.api_key, ApiKeyStatus::Invalid);
+        assert!(!health.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_health_check_times_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/chains"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "chains": [1] }))
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_server_client_with(
+            &server,
+            ClientConfig {
+                timeout: Duration::from_millis(50),
+                ..Default::default()
+            },
+        );
+        let err = client.health_check().await.unwrap_err();
+
+        assert!(matches!(err, OdosError::Timeout { .. }), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_health_check_returns_other_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/info/chains"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+            .mount(&server)
+            .await;
+
+        let health = mock_server_client(&server).health_check().await;
+        assert!(matches!(
+            health,
+            Err(OdosError::Api { status, .. }) if status == StatusCode::SERVICE_UNAVAILABLE
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    build_info::{CRATE_VERSION, FEATURES},
+    get_lo_router_by_chain_id, get_supported_chains, get_v2_router_by_chain_id,
+    get_v3_router_by_chain_id, ApiVersion,
+};
+
+/// Machine-readable manifest of the chains, routers, API versions and cargo
+/// features supported by this SDK version, as JSON
+///
+/// Generated from the chain table in this crate and checked in as
+/// `src/support_manifest.json`; a unit test fails if the two drift. After
+/// changing chain support or bumping the crate version, regenerate it with
+/// `cargo run --example update_support_manifest`.
+///
+/// The format is versioned by [`SupportManifest::schema_version`]. Chains
+/// are sorted by ascending chain ID, so manifests from two SDK versions can
+/// be diffed directly.
+pub const SUPPORT_MANIFEST_JSON: &str = include_str!("support_manifest.json");
+
+/// Parsed form of [`SUPPORT_MANIFEST_JSON`]
+///
+/// # Examples
+///
+/// ```rust
+/// let manifest = odos_sdk::support_manifest();
+///
+/// assert_eq!(manifest.sdk_version, env!("CARGO_PKG_VERSION"));
+/// assert!(manifest.chains.iter().any(|chain| chain.chain_id == 1));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SupportManifest {
+    /// Version of the manifest format, incremented on breaking changes
+    pub schema_version: u32,
+    /// Crate version the manifest describes
+    pub sdk_version: String,
+    /// Odos API versions the SDK can target (e.g. `"v2"`, `"v3"`)
+    pub api_versions: Vec<String>,
+    /// Cargo features the crate defines
+    pub features: Vec<String>,
+    /// Supported chains, sorted by ascending chain ID
+    pub chains: Vec<ChainSupport>,
+}
+
+/// Router deployments on one chain in a [`SupportManifest`]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ChainSupport {
+    /// EIP-155 chain ID
+    pub chain_id: u64,
+    /// Chain name as used by `alloy_chains::NamedChain`
+    pub name: String,
+    /// V2 router address, if deployed
+    pub v2_router: Option<Address>,
+    /// V3 router address, if deployed
+    pub v3_router: Option<Address>,
+    /// Limit order router address, if deployed
+    pub limit_order_router: Option<Address>,
+}
+
+impl SupportManifest {
+    /// Current manifest format version
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Build the manifest from the chain table compiled into this crate
+    ///
+    /// Equal to [`support_manifest()`]; the embedded JSON is kept in sync by
+    /// a unit test.
+    pub fn from_chain_table() -> Self {
+        Self {
+            schema_version: Self::SCHEMA_VERSION,
+            sdk_version: CRATE_VERSION.to_string(),
+            api_versions: [ApiVersion::V2, ApiVersion::V3]
+                .iter()
+                .map(|version| version.path().to_string())
+                .collect(),
+            features: FEATURES.iter().map(|(name, _)| name.to_string()).collect(),
+            chains: get_supported_chains()
+                .iter()
+                .map(|chain| {
+                    let chain_id = *chain as u64;
+                    ChainSupport {
+                        chain_id,
+                        name: chain.as_str().to_string(),
+                        v2_router: get_v2_router_by_chain_id(chain_id),
+                        v3_router: get_v3_router_by_chain_id(chain_id),
+                        limit_order_router: get_lo_router_by_chain_id(chain_id),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Parse the embedded [`SUPPORT_MANIFEST_JSON`]
+pub fn support_manifest() -> SupportManifest {
+    serde_json::from_str(SUPPORT_MANIFEST_JSON).expect("embedded support manifest is valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_support_manifest_matches_chain_table() {
+        let generated = SupportManifest::from_chain_table();
+        let json = serde_json::to_string_pretty(&generated).unwrap() + "\n";
+
+        assert_eq!(
+            support_manifest(),
+            generated,
+            "src/support_manifest.json is out of date; regenerate with \
+             cargo run --example update_support_manifest"
+        );
+        assert_eq!(SUPPORT_MANIFEST_JSON, json);
+    }
+
+    #[test]
+    fn test_support_manifest_chains_sorted() {
+        let ids: Vec<u64> = support_manifest()
+            .chains
+            .iter()
+            .map(|c| c.chain_id)
+            .collect();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -9,6 +9,8 @@ use alloy_primitives::{Address, U256};
 use bon::Builder;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::PathId;
+
 /// Request for assembling a transaction from a quote
 ///
 /// Contains all the information needed to assemble a transaction from
@@ -28,7 +30,8 @@ pub struct AssemblyRequest {
     /// The amount of tokens to swap.
     token_amount: U256,
     /// The path ID of the swap.
-    path_id: String,
+    #[builder(into)]
+    path_id: PathId,
 }
 
 impl AssemblyRequest {
@@ -56,7 +59,7 @@ impl AssemblyRequest {
         self.token_amount
     }
 
-    pub fn path_id(&self) -> &str {
+    pub fn path_id(&self) -> &PathId {
         &self.path_id
     }
 }
@@ -93,7 +96,7 @@ impl<'de> Deserialize<'de> for AssemblyRequest {
             token_address,
             token_amount,
             path_id,
-        ): (u64, Address, Address, Address, Address, U256, String) =
+        ): (u64, Address, Address, Address, Address, U256, PathId) =
             Deserialize::deserialize(deserializer)?;
 
         let chain = NamedChain::try_from(chain_id).map_err(serde::de::Error::custom)?;
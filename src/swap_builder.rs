@@ -2,12 +2,21 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use alloy_primitives::{Address, U256};
 use alloy_rpc_types::TransactionRequest;
+use reqwest::Url;
+use tokio::sync::mpsc;
+use tracing::debug;
 
 use crate::{
-    AssemblyRequest, Chain, OdosChain, OdosClient, QuoteRequest, ReferralCode, Result,
-    SingleQuoteResponse, Slippage,
+    client::TimeBudget,
+    swap_events::{SwapEventKind, SwapEventRecord, SwapEventRun, SwapEventSink},
+    ApiHost, ApiVersion, ApprovalAmount, AssemblyRequest, Chain, ContractInfo, Endpoint,
+    InputToken, LiquiditySource, OdosApi, OdosChain, OdosChainError, OdosClient,
+    OdosRouterSelection, QuoteRequest, ReferralCode, Result, RouterType, SingleQuoteResponse,
+    Slippage, NATIVE_TOKEN,
 };
 
 /// High-level swap builder for common use cases
@@ -63,11 +72,12 @@ use crate::{
 /// ```
 #[derive(Debug)]
 pub struct SwapBuilder<'a> {
-    client: &'a OdosClient,
+    api: SwapApi<'a>,
+    swap_events: SwapEventSink,
     chain: Option<Chain>,
     input_token: Option<Address>,
     input_amount: Option<U256>,
-    output_token: Option<Address>,
+    outputs: Vec<(Address, u32)>,
     slippage: Option<Slippage>,
     signer: Option<Address>,
     recipient: Option<Address>,
@@ -75,17 +85,74 @@ pub struct SwapBuilder<'a> {
     compact: bool,
     simple: bool,
     disable_rfqs: bool,
+    gas_price: Option<f64>,
+    like_asset: bool,
+    allowed_sources: Vec<LiquiditySource>,
+    blocked_sources: Vec<LiquiditySource>,
+    output_slippage: Vec<(Address, Slippage)>,
+    max_price_impact: Option<f64>,
+    min_output: Option<U256>,
+    total_budget: Option<Duration>,
+    auto_requote: u32,
+    router: Option<RouterType>,
+    allow_unlisted_chains: bool,
+    #[cfg(feature = "v2")]
+    verify_assembly: bool,
+    #[cfg(feature = "v2")]
+    permit2: Option<(alloy_primitives::Bytes, crate::PermitTransferFrom)>,
+    #[cfg(feature = "provider")]
+    simulate_before_send: bool,
+    events: Option<mpsc::Sender<SwapEventRecord>>,
 }
 
 impl<'a> SwapBuilder<'a> {
     /// Create a new swap builder
     pub(crate) fn new(client: &'a OdosClient) -> Self {
+        Self::with_backend(SwapApi::Client(client), client.swap_event_sink().clone())
+    }
+
+    /// Create a swap builder that quotes and assembles through `api`
+    ///
+    /// Swaps are built as with [`OdosClient::swap`], which lets code built
+    /// on the swap flow be tested against a mock [`OdosApi`]. Differences
+    /// from a builder created by a client:
+    ///
+    /// - [`router`](Self::router) cannot switch API versions; it must match
+    ///   [`OdosApi::api_version`]
+    /// - A [`total_budget`](Self::total_budget) is checked before each call
+    ///   but cannot interrupt one
+    /// - Swap events go only to a channel set with [`events`](Self::events)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{Chain, OdosApi, Result, Slippage, SwapBuilder};
+    /// use alloy_primitives::{address, U256};
+    /// use alloy_rpc_types::TransactionRequest;
+    ///
+    /// async fn usdc_to_weth(api: &dyn OdosApi, amount: U256) -> Result<TransactionRequest> {
+    ///     SwapBuilder::with_api(api)
+    ///         .chain(Chain::ethereum())
+    ///         .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), amount)
+    ///         .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///         .slippage(Slippage::standard())
+    ///         .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///         .build_transaction()
+    ///         .await
+    /// }
+    /// ```
+    pub fn with_api(api: &'a dyn OdosApi) -> Self {
+        Self::with_backend(SwapApi::Dyn(api), SwapEventSink::default())
+    }
+
+    fn with_backend(api: SwapApi<'a>, swap_events: SwapEventSink) -> Self {
         Self {
-            client,
+            api,
+            swap_events,
             chain: None,
             input_token: None,
             input_amount: None,
-            output_token: None,
+            outputs: Vec::new(),
             slippage: None,
             signer: None,
             recipient: None,
@@ -93,6 +160,24 @@ impl<'a> SwapBuilder<'a> {
             compact: false,
             simple: false,
             disable_rfqs: false,
+            gas_price: None,
+            like_asset: false,
+            allowed_sources: Vec::new(),
+            blocked_sources: Vec::new(),
+            output_slippage: Vec::new(),
+            max_price_impact: None,
+            min_output: None,
+            total_budget: None,
+            auto_requote: 0,
+            router: None,
+            allow_unlisted_chains: false,
+            #[cfg(feature = "v2")]
+            verify_assembly: false,
+            #[cfg(feature = "v2")]
+            permit2: None,
+            #[cfg(feature = "provider")]
+            simulate_before_send: false,
+            events: None,
         }
     }
 
@@ -159,8 +244,63 @@ impl<'a> SwapBuilder<'a> {
         self.input(token, amount)
     }
 
+    /// Set the input token and amount from a [`TokenAmount`](crate::TokenAmount)
+    /// or an `(Address, U256)` pair
+    ///
+    /// Equivalent to [`input()`](Self::input), for amounts already held as
+    /// one value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, TokenAmount};
+    /// use alloy_primitives::address;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    /// let builder = client.swap()
+    ///     .with_input(TokenAmount::from_human(usdc, "1.5", 6)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_input(self, input: impl Into<InputToken>) -> Self {
+        let input = input.into();
+        self.input(input.token_address(), input.amount())
+    }
+
+    /// Swap from the chain's native token
+    ///
+    /// Equivalent to `input(NATIVE_TOKEN, amount)`. The assembled transaction
+    /// sends `amount` as its `value`, which
+    /// [`build_transaction()`](Self::build_transaction) verifies. To swap the
+    /// wrapped token instead, use
+    /// [`Chain::wrapped_native_token`](crate::Chain::wrapped_native_token).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{Chain, OdosClient};
+    /// use alloy_primitives::U256;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let chain = Chain::ethereum();
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap()
+    ///     .chain(chain)
+    ///     .from_native(U256::from(10).pow(U256::from(18)))
+    ///     .to_token(chain.usdc().expect("USDC is deployed on Ethereum"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_native(self, amount: U256) -> Self {
+        self.input(NATIVE_TOKEN, amount)
+    }
+
     /// Set the output token (100% of output goes to this token)
     ///
+    /// Equivalent to `output_split(vec![(token, 100)])`.
+    ///
     /// # Arguments
     ///
     /// * `token` - Address of the token to swap to
@@ -178,9 +318,8 @@ impl<'a> SwapBuilder<'a> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn output(mut self, token: Address) -> Self {
-        self.output_token = Some(token);
-        self
+    pub fn output(self, token: Address) -> Self {
+        self.output_split(vec![(token, 100)])
     }
 
     /// Alias for `output()` - set the token to swap to
@@ -202,6 +341,64 @@ impl<'a> SwapBuilder<'a> {
         self.output(token)
     }
 
+    /// Swap to the chain's native token
+    ///
+    /// Equivalent to `output(NATIVE_TOKEN)`. To receive the wrapped token
+    /// instead, use
+    /// [`Chain::wrapped_native_token`](crate::Chain::wrapped_native_token).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{Chain, OdosClient};
+    /// use alloy_primitives::U256;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let chain = Chain::base();
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap()
+    ///     .chain(chain)
+    ///     .from_token(chain.usdc().expect("USDC is deployed on Base"), U256::from(1_000_000))
+    ///     .to_native();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_native(self) -> Self {
+        self.output(NATIVE_TOKEN)
+    }
+
+    /// Split the output across several tokens
+    ///
+    /// Each entry is a token and the percentage of the output value routed to
+    /// it. Percentages must be positive and sum to `100`; this is validated
+    /// when the quote is requested. Replaces any output set previously,
+    /// including one set with [`output()`](Self::output).
+    ///
+    /// # Arguments
+    ///
+    /// * `outputs` - `(token, percent)` pairs
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    /// use alloy_primitives::address;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let weth = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    /// let wbtc = address!("2260fac5e5542a773aa44fbcfedf7c193bc2c599");
+    ///
+    /// // 70% of the output value into WETH, 30% into WBTC
+    /// let builder = client.swap().output_split(vec![(weth, 70), (wbtc, 30)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn output_split(mut self, outputs: Vec<(Address, u32)>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
     /// Set the slippage tolerance
     ///
     /// # Arguments
@@ -353,182 +550,1815 @@ impl<'a> SwapBuilder<'a> {
         self
     }
 
-    /// Get a quote for this swap without building the transaction
+    /// Compute the quote against a gas price, in gwei
     ///
-    /// This is useful if you want to inspect the quote before proceeding.
+    /// Routing trades gas cost against output, so quoting at the gas price
+    /// the transaction will actually pay gives a better route, especially on
+    /// Ethereum mainnet. The API uses its own estimate when unset.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// Returns a `SingleQuoteResponse` with routing information, price impact, and gas estimates.
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
     ///
-    /// # Errors
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().gas_price(25.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gas_price(mut self, gwei: f64) -> Self {
+        self.gas_price = Some(gwei);
+        self
+    }
+
+    /// Hint that the input and output are correlated assets
     ///
-    /// Returns an error if:
-    /// - Required fields are missing
-    /// - The Odos API returns an error
-    /// - Network issues occur
+    /// Enables routing optimized for swaps between assets that trade near
+    /// parity, such as USDC to USDT.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use odos_sdk::{OdosClient, Chain, Slippage};
-    /// use alloy_primitives::{address, U256};
+    /// use odos_sdk::OdosClient;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = OdosClient::new()?;
+    /// let builder = client.swap().like_asset(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn like_asset(mut self, like_asset: bool) -> Self {
+        self.like_asset = like_asset;
+        self
+    }
+
+    /// Restrict routing to the given liquidity sources
     ///
-    /// let quote = client
-    ///     .swap()
-    ///     .chain(Chain::ethereum())
-    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
-    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
-    ///     .slippage(Slippage::percent(0.5)?)
-    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
-    ///     .quote()
-    ///     .await?;
+    /// Calls accumulate; the quote only routes through sources added here.
+    /// Without any allowed sources, every source not blocked with
+    /// [`block_sources`](Self::block_sources) may be used. A source that is
+    /// both allowed and blocked fails the quote with
+    /// [`OdosError::InvalidInput`](crate::OdosError::InvalidInput).
     ///
-    /// println!("Expected output: {} tokens", quote.out_amount().unwrap());
-    /// println!("Price impact: {}%", quote.price_impact());
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{LiquiditySource, OdosClient};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().allow_sources([
+    ///     LiquiditySource::UniswapV3,
+    ///     LiquiditySource::CurveStable,
+    ///     LiquiditySource::from("Some Other DEX"),
+    /// ]);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn quote(&self) -> Result<SingleQuoteResponse> {
-        let chain = self
-            .chain
-            .ok_or_else(|| crate::OdosError::missing_data("Chain is required for swap builder"))?;
-
-        let input_token = self.input_token.ok_or_else(|| {
-            crate::OdosError::missing_data("Input token is required for swap builder")
-        })?;
-
-        let input_amount = self.input_amount.ok_or_else(|| {
-            crate::OdosError::missing_data("Input amount is required for swap builder")
-        })?;
-
-        let output_token = self.output_token.ok_or_else(|| {
-            crate::OdosError::missing_data("Output token is required for swap builder")
-        })?;
-
-        let slippage = self.slippage.ok_or_else(|| {
-            crate::OdosError::missing_data("Slippage is required for swap builder")
-        })?;
-
-        let signer = self.signer.ok_or_else(|| {
-            crate::OdosError::missing_data("Signer address is required for swap builder")
-        })?;
-
-        let quote_request = QuoteRequest::builder()
-            .chain_id(chain.id())
-            .input_tokens(vec![(input_token, input_amount).into()])
-            .output_tokens(vec![(output_token, 1).into()])
-            .slippage_limit_percent(slippage.as_percent())
-            .user_addr(signer)
-            .compact(self.compact)
-            .simple(self.simple)
-            .referral_code(self.referral.code())
-            .disable_rfqs(self.disable_rfqs)
-            .build();
-
-        self.client.quote(&quote_request).await
+    pub fn allow_sources<S: Into<LiquiditySource>>(
+        mut self,
+        sources: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.allowed_sources
+            .extend(sources.into_iter().map(Into::into));
+        self
     }
 
-    /// Build the complete transaction for this swap
+    /// Exclude the given liquidity sources from routing
     ///
-    /// This method:
-    /// 1. Gets a quote from the Odos API
-    /// 2. Assembles the transaction data
-    /// 3. Returns a `TransactionRequest` ready for signing
+    /// Calls accumulate.
     ///
-    /// The returned transaction still needs gas parameters set before signing.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```rust,no_run
+    /// use odos_sdk::{LiquiditySource, OdosClient};
     ///
-    /// Returns a `TransactionRequest` with:
-    /// - `to`: Router contract address
-    /// - `from`: Signer address
-    /// - `data`: Encoded swap calldata
-    /// - `value`: ETH amount to send (if swapping from native token)
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().block_sources([LiquiditySource::Hashflow]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn block_sources<S: Into<LiquiditySource>>(
+        mut self,
+        sources: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.blocked_sources
+            .extend(sources.into_iter().map(Into::into));
+        self
+    }
+
+    /// Override the slippage tolerance for a single output token
     ///
-    /// # Errors
+    /// The Odos API applies one slippage limit to the whole swap. When the
+    /// output is split across tokens with different volatility, a single
+    /// number is too loose for one leg or too tight for another. A per-token
+    /// override is enforced client-side after the quote is returned: the
+    /// leg's implied loss is its quoted USD output value compared against its
+    /// proportional share of the USD input value, and the quote is rejected
+    /// with [`OdosError::QuoteRejected`](crate::OdosError::QuoteRejected) if
+    /// that loss exceeds the override.
     ///
-    /// Returns an error if:
-    /// - Required fields are missing
-    /// - The Odos API returns an error
-    /// - Transaction assembly fails
-    /// - Network issues occur
+    /// Calling this again for the same token replaces the previous override.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Output token the override applies to
+    /// * `slippage` - Maximum acceptable implied loss for that leg
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use odos_sdk::{OdosClient, Chain, Slippage};
-    /// use alloy_primitives::{address, U256};
+    /// use odos_sdk::{OdosClient, Slippage};
+    /// use alloy_primitives::address;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = OdosClient::new()?;
+    /// let weth = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    /// let builder = client.swap()
+    ///     .output(weth)
+    ///     .slippage(Slippage::percent(1.0)?)
+    ///     .output_slippage(weth, Slippage::percent(0.3)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn output_slippage(mut self, token: Address, slippage: Slippage) -> Self {
+        self.output_slippage
+            .retain(|(existing, _)| *existing != token);
+        self.output_slippage.push((token, slippage));
+        self
+    }
+
+    /// Reject quotes whose price impact exceeds `percent`
     ///
-    /// let tx = client
-    ///     .swap()
-    ///     .chain(Chain::ethereum())
-    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
-    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
-    ///     .slippage(Slippage::percent(0.5)?)
-    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
-    ///     .build_transaction()
-    ///     .await?;
+    /// After the quote returns, the magnitude of
+    /// [`SingleQuoteResponse::price_impact`] is compared against the limit and
+    /// the swap fails with
+    /// [`OdosError::PriceImpactTooHigh`](crate::OdosError::PriceImpactTooHigh)
+    /// instead of proceeding. [`build_transaction()`](Self::build_transaction)
+    /// applies the same check before assembling.
     ///
-    /// // Set gas parameters and sign
-    /// // let tx = tx.with_gas_limit(300_000);
-    /// // provider.send_transaction(tx).await?;
+    /// - **Sign**: the magnitude is compared, so a large positive impact is
+    ///   rejected as well as a large negative one. An outsized favourable
+    ///   impact usually signals a mispriced pool or stale liquidity data.
+    /// - **Missing impact**: if the API omits the price impact, the guard
+    ///   fails closed with [`OdosError::MissingData`](crate::OdosError::MissingData)
+    ///   rather than assuming zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent` - Maximum acceptable price impact as a percentage (e.g. `3.0` for 3%)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().max_price_impact(3.0);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn build_transaction(&self) -> Result<TransactionRequest> {
-        // Get quote
-        let quote = self.quote().await?;
-
-        let chain = self.chain.unwrap(); // Safe: validated in quote()
-        let signer = self.signer.unwrap(); // Safe: validated in quote()
-        let recipient = self.recipient.unwrap_or(signer);
-        let input_token = self.input_token.unwrap(); // Safe: validated in quote()
-        let input_amount = self.input_amount.unwrap(); // Safe: validated in quote()
-
-        // Get router address for this chain
-        let router_address = chain.v3_router_address()?;
-
-        // Build swap context
-        let swap_context = AssemblyRequest::builder()
-            .chain(chain.inner())
-            .router_address(router_address)
-            .signer_address(signer)
-            .output_recipient(recipient)
-            .token_address(input_token)
-            .token_amount(input_amount)
-            .path_id(quote.path_id().to_string())
-            .build();
-
-        // Build transaction
-        self.client.assemble(&swap_context).await
+    pub fn max_price_impact(mut self, percent: f64) -> Self {
+        self.max_price_impact = Some(percent);
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloy_primitives::address;
-
-    #[test]
-    fn test_builder_construction() {
-        let client = OdosClient::new().unwrap();
-        let builder = client.swap();
-
-        assert!(builder.chain.is_none());
-        assert!(builder.input_token.is_none());
-        assert!(builder.output_token.is_none());
-        assert_eq!(builder.referral, ReferralCode::NONE);
+    /// Set an absolute floor on the quoted output amount
+    ///
+    /// After the quote returns, the first output amount is compared with
+    /// `amount` and the swap fails with
+    /// [`OdosError::OutputBelowMinimum`](crate::OdosError::OutputBelowMinimum)
+    /// if it is lower. An output exactly equal to the floor is accepted.
+    /// [`build_transaction()`](Self::build_transaction) applies the same
+    /// check before assembling.
+    ///
+    /// Unlike [`slippage`](Self::slippage), which bounds execution against the
+    /// quote, this bounds the quote itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Minimum acceptable output in the output token's base units
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    /// use alloy_primitives::U256;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// // Require at least 0.49 WETH
+    /// let builder = client.swap().min_output(U256::from(490_000_000_000_000_000u64));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn min_output(mut self, amount: U256) -> Self {
+        self.min_output = Some(amount);
+        self
     }
 
-    #[test]
-    fn test_builder_chain_methods() {
+    /// Bound the whole swap pipeline by a total time budget
+    ///
+    /// Without a budget, the quote and assemble requests each get the full
+    /// [`ClientConfig::timeout`](crate::ClientConfig::timeout), so together
+    /// they can take twice as long. With a budget, each request is given at
+    /// most the time remaining (including retries and backoff), and the
+    /// pipeline fails with an [`OdosError::Timeout`](crate::OdosError::Timeout)
+    /// whose phase is [`TimeoutPhase::Total`](crate::TimeoutPhase::Total) as
+    /// soon as the budget runs out, without issuing further requests.
+    ///
+    /// The budget starts when [`quote()`](Self::quote) or
+    /// [`build_transaction()`](Self::build_transaction) is called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().total_budget(Duration::from_secs(3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_budget(mut self, budget: Duration) -> Self {
+        self.total_budget = Some(budget);
+        self
+    }
+
+    /// Re-quote and retry assembly when the quoted path expires
+    ///
+    /// Path IDs expire shortly after the quote is issued. With a non-zero
+    /// `max_attempts`, [`build_transaction()`](Self::build_transaction)
+    /// detects the expired-path error from assembly (see
+    /// [`OdosError::is_expired_path`](crate::OdosError::is_expired_path)),
+    /// fetches a fresh quote with the same parameters, and assembles again,
+    /// up to `max_attempts` times. Each re-quote is checked against the
+    /// configured guards and shares the [`total_budget`](Self::total_budget).
+    ///
+    /// Disabled (`0`) by default, in which case the expired-path error is
+    /// returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().auto_requote(2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn auto_requote(mut self, max_attempts: u32) -> Self {
+        self.auto_requote = max_attempts;
+        self
+    }
+
+    /// Route the swap through a specific Odos router version
+    ///
+    /// The quote and assemble requests go to the API version serving that
+    /// router, on the client's configured host. Without a preference, the
+    /// router of the client's configured API version is used.
+    ///
+    /// Only [`RouterType::V2`] and [`RouterType::V3`] route swaps; passing
+    /// [`RouterType::LimitOrder`] fails the swap with
+    /// [`OdosError::InvalidInput`](crate::OdosError::InvalidInput). If the
+    /// router is not deployed on the swap's chain, the swap fails with the
+    /// corresponding [`OdosChainError`] before any request is sent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, RouterType};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().router(RouterType::V2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn router(mut self, router: RouterType) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Allow swaps on chains this crate has no router addresses for
+    ///
+    /// By default a swap on a chain without compiled-in (or
+    /// [registered](Chain::register_custom)) routers fails before any
+    /// request. With this enabled, such a chain is quoted anyway and the
+    /// transaction is assembled for the router the API's contract info
+    /// endpoint reports, so new Odos chains work before this crate is
+    /// updated. Chains the crate knows always use the compiled-in routers.
+    ///
+    /// The approval and preflight helpers still need a known router; use
+    /// [`OdosClient::runtime_chain_support`] to find the spender for an
+    /// unlisted chain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use alloy_chains::NamedChain;
+    /// use odos_sdk::{Chain, OdosClient};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client
+    ///     .swap()
+    ///     .chain(Chain::from(NamedChain::Berachain))
+    ///     .allow_unlisted_chains(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn allow_unlisted_chains(mut self, allow: bool) -> Self {
+        self.allow_unlisted_chains = allow;
+        self
+    }
+
+    /// Check the assembled calldata against the quote before returning it
+    ///
+    /// With this enabled, [`build_transaction()`](Self::build_transaction)
+    /// and [`build_transaction_from_quote()`](Self::build_transaction_from_quote)
+    /// decode the assembled router call with [`verify_assembly`](crate::verify_assembly)
+    /// and fail with [`OdosError::AssemblyVerificationFailed`](crate::OdosError::AssemblyVerificationFailed)
+    /// unless it spends the requested input, buys the quoted outputs for the
+    /// recipient, and sets minimum outputs within the configured
+    /// [`slippage`](Self::slippage) of the quote. This guards against a
+    /// compromised or misbehaving API before anything is signed.
+    ///
+    /// Available only when the `v2` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().verify_assembly(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "v2")]
+    pub fn verify_assembly(mut self, verify: bool) -> Self {
+        self.verify_assembly = verify;
+        self
+    }
+
+    /// Pull the input through Permit2 with the owner's signed `permit`
+    /// instead of a router allowance
+    ///
+    /// The assembled `swap` call is re-encoded as the router's `swapPermit2`
+    /// with [`swap_permit2_calldata`](crate::swap_permit2_calldata), so the
+    /// signer only needs a one-time approval of
+    /// [`PERMIT2_ADDRESS`](crate::PERMIT2_ADDRESS) rather than of the router.
+    /// [`approval_transaction()`](Self::approval_transaction) and the
+    /// preflight checks use Permit2 as the spender accordingly.
+    ///
+    /// `permit` must be for exactly the input token and amount, with the
+    /// swap router as spender, and `signature` is the signer's signature
+    /// over its [`eip712_signing_hash`](crate::PermitTransferFrom::eip712_signing_hash).
+    /// Only V2 router swaps from an ERC-20 input are supported, and the swap
+    /// cannot use [`compact`](Self::compact) mode, whose calldata is not a
+    /// `swap` call to re-encode.
+    ///
+    /// Available only when the `v2` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use alloy_primitives::{address, Bytes, U256};
+    /// use odos_sdk::{
+    ///     Chain, OdosClient, PermitTransferFrom, RouterType, Slippage, ODOS_V2_ETHEREUM_ROUTER,
+    /// };
+    ///
+    /// # async fn example(signature: Bytes) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    ///
+    /// let permit = PermitTransferFrom::builder()
+    ///     .permitted((usdc, U256::from(1_000_000)))
+    ///     .spender(ODOS_V2_ETHEREUM_ROUTER)
+    ///     .deadline(1_767_225_600)
+    ///     .build();
+    ///
+    /// let tx = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .router(RouterType::V2)
+    ///     .from_token(usdc, U256::from(1_000_000))
+    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///     .slippage(Slippage::percent(0.5)?)
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///     .with_permit2(signature, permit)
+    ///     .build_transaction()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "v2")]
+    pub fn with_permit2(
+        mut self,
+        signature: impl Into<alloy_primitives::Bytes>,
+        permit: crate::PermitTransferFrom,
+    ) -> Self {
+        self.permit2 = Some((signature.into(), permit));
+        self
+    }
+
+    /// Simulate the assembled swap with `eth_call` before sending it
+    ///
+    /// With this enabled, [`execute()`](Self::execute) runs
+    /// [`simulate_swap`](crate::simulate_swap) against its provider and
+    /// fails with [`OdosError::SimulationReverted`](crate::OdosError::SimulationReverted)
+    /// instead of sending a swap that would revert. This costs one extra RPC
+    /// call; `eth_estimateGas` already rejects most reverting swaps, but
+    /// without decoding the router's return value.
+    ///
+    /// Available only when the `provider` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::OdosClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let builder = client.swap().simulate_before_send(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "provider")]
+    pub fn simulate_before_send(mut self, simulate: bool) -> Self {
+        self.simulate_before_send = simulate;
+        self
+    }
+
+    /// Send lifecycle events for this swap to `sender`
+    ///
+    /// Overrides the client-wide channel set with
+    /// [`OdosClient::with_swap_events`]. Each call to [`quote()`](Self::quote)
+    /// or [`build_transaction()`](Self::build_transaction) is one run with its
+    /// own correlation ID: `QuoteRequested`, `QuoteReceived`, then (for
+    /// `build_transaction`) `AssembleRequested` and `TransactionBuilt`.
+    /// [`build_transaction_from_quote()`](Self::build_transaction_from_quote)
+    /// runs only emit the last two.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::OdosClient;
+    /// use tokio::sync::mpsc;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    /// let (events_tx, mut events_rx) = mpsc::channel(64);
+    /// let builder = client.swap().events(events_tx);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn events(mut self, sender: mpsc::Sender<SwapEventRecord>) -> Self {
+        self.events = Some(sender);
+        self
+    }
+
+    /// Get a quote for this swap without building the transaction
+    ///
+    /// This is useful if you want to inspect the quote before proceeding.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `SingleQuoteResponse` with routing information, price impact, and gas estimates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Required fields are missing
+    /// - A per-token slippage override targets a token that is not an output
+    /// - The configured [`max_price_impact`](Self::max_price_impact) is negative or not finite
+    /// - The Odos API returns an error
+    /// - Network issues occur
+    /// - A leg's implied loss exceeds its [`output_slippage`](Self::output_slippage) override
+    /// - The quoted price impact exceeds [`max_price_impact`](Self::max_price_impact)
+    /// - The quoted output amount is below [`min_output`](Self::min_output)
+    /// - The [`total_budget`](Self::total_budget) runs out
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, Chain, Slippage};
+    /// use alloy_primitives::{address, U256};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let quote = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
+    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///     .slippage(Slippage::percent(0.5)?)
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///     .quote()
+    ///     .await?;
+    ///
+    /// println!("Expected output: {} tokens", quote.out_amount().unwrap());
+    /// println!("Price impact: {}%", quote.price_impact());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote(&self) -> Result<SingleQuoteResponse> {
+        let mut events = self.swap_events.run(self.events.as_ref());
+        let url = self.endpoint()?.quote_url();
+        self.quote_within(url, self.total_budget.map(TimeBudget::start), &mut events)
+            .await
+    }
+
+    /// Check that the swap is complete and consistent without sending a
+    /// request
+    ///
+    /// [`quote()`](Self::quote) and [`build_transaction()`](Self::build_transaction)
+    /// run the same checks before their first request.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::MissingData`](crate::OdosError::MissingData) listing
+    ///   every required field that is not set: chain, input token, output
+    ///   token, slippage and signer
+    /// - [`OdosError::InvalidInput`](crate::OdosError::InvalidInput) if the
+    ///   output split, per-token slippage overrides, price impact limit or
+    ///   router preference are invalid
+    /// - The [`OdosChainError`]-derived error if the router is not deployed
+    ///   on the chain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::{Chain, OdosClient};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let err = client.swap().chain(Chain::ethereum()).validate().unwrap_err();
+    /// assert!(err.to_string().contains("input token, output token, slippage, signer"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        self.quote_params().map(|_| ())
+    }
+
+    /// Validate the builder and get the fields every quote requires
+    fn quote_params(&self) -> Result<QuoteParams> {
+        let params = match (
+            self.chain,
+            self.input_token.zip(self.input_amount),
+            !self.outputs.is_empty(),
+            self.slippage,
+            self.signer,
+        ) {
+            (
+                Some(chain),
+                Some((input_token, input_amount)),
+                true,
+                Some(slippage),
+                Some(signer),
+            ) => QuoteParams {
+                chain,
+                input_token,
+                input_amount,
+                slippage,
+                signer,
+            },
+            _ => {
+                return Err(missing_fields(&[
+                    ("chain", self.chain.is_some()),
+                    ("input token", self.input_token.is_some()),
+                    ("output token", !self.outputs.is_empty()),
+                    ("slippage", self.slippage.is_some()),
+                    ("signer", self.signer.is_some()),
+                ]))
+            }
+        };
+
+        validate_output_split(&self.outputs)?;
+
+        // Fail before any request if the preferred router is not deployed
+        if !self.uses_api_router(params.chain) {
+            router_address(params.chain, self.endpoint()?.version())?;
+        }
+
+        if let Some(limit) = self.max_price_impact {
+            if !limit.is_finite() || limit < 0.0 {
+                return Err(crate::OdosError::invalid_input(format!(
+                    "Max price impact must be a non-negative percentage, got {limit}"
+                )));
+            }
+        }
+
+        for (token, _) in &self.output_slippage {
+            if !self.outputs.iter().any(|(output, _)| output == token) {
+                return Err(crate::OdosError::invalid_input(format!(
+                    "Output slippage override set for {token}, which is not an output token"
+                )));
+            }
+        }
+
+        // Compact calldata cannot be re-encoded as `swapPermit2`
+        #[cfg(feature = "v2")]
+        if self.compact && self.permit2.is_some() {
+            return Err(crate::OdosError::invalid_input(
+                "Compact mode cannot be combined with a Permit2 permit",
+            ));
+        }
+
+        Ok(params)
+    }
+
+    /// Whether the router for `chain` comes from the API rather than the
+    /// compiled-in addresses; see [`allow_unlisted_chains`](Self::allow_unlisted_chains)
+    fn uses_api_router(&self, chain: Chain) -> bool {
+        self.allow_unlisted_chains && !chain.supports_odos()
+    }
+
+    /// Get the router the swap is assembled for on `chain`
+    async fn swap_router(&self, chain: Chain) -> Result<Address> {
+        let endpoint = self.endpoint()?;
+        if !self.uses_api_router(chain) {
+            return router_address(chain, endpoint.version());
+        }
+
+        let ContractInfo { router_address, .. } = self.api.contract_info(&endpoint, chain).await?;
+        debug!(
+            chain_id = chain.id(),
+            router = %router_address,
+            "Using API-reported router for unlisted chain"
+        );
+        Ok(router_address)
+    }
+
+    /// Endpoint serving the preferred router, or the client's endpoint
+    fn endpoint(&self) -> Result<Endpoint> {
+        let endpoint = self.api.endpoint();
+        let version =
+            match self.router {
+                None => return Ok(endpoint),
+                Some(RouterType::V2) => ApiVersion::V2,
+                Some(RouterType::V3) => ApiVersion::V3,
+                Some(RouterType::LimitOrder) => return Err(crate::OdosError::invalid_input(
+                    "Limit order router cannot route swaps; use RouterType::V2 or RouterType::V3",
+                )),
+            };
+        if matches!(self.api, SwapApi::Dyn(_)) && version != endpoint.version() {
+            return Err(crate::OdosError::invalid_input(format!(
+                "Router {version:?} needs a different API version than the {:?} the OdosApi serves",
+                endpoint.version()
+            )));
+        }
+        Ok(Endpoint::new(endpoint.host(), version))
+    }
+
+    async fn quote_within(
+        &self,
+        url: Url,
+        budget: Option<TimeBudget>,
+        events: &mut SwapEventRun<'_>,
+    ) -> Result<SingleQuoteResponse> {
+        let QuoteParams {
+            chain,
+            input_token,
+            input_amount,
+            slippage,
+            signer,
+        } = self.quote_params()?;
+        let outputs = &self.outputs;
+
+        let quote_request = QuoteRequest::builder()
+            .chain(chain)
+            .input_tokens(vec![(input_token, input_amount).into()])
+            .output_tokens(
+                outputs
+                    .iter()
+                    .map(|&(token, percent)| (token, f64::from(percent) / 100.0).into())
+                    .collect(),
+            )
+            .slippage_limit_percent(slippage.as_percent())
+            .user_addr(signer)
+            .compact(self.compact)
+            .simple(self.simple)
+            .referral_code(self.referral.code())
+            .disable_rfqs(self.disable_rfqs)
+            .maybe_gas_price(self.gas_price)
+            .like_asset(self.like_asset)
+            .source_whitelist(source_names(&self.allowed_sources))
+            .source_blacklist(source_names(&self.blocked_sources))
+            .build();
+
+        events.emit(SwapEventKind::QuoteRequested {
+            chain_id: chain.id(),
+            input_token,
+            input_amount,
+            output_tokens: outputs.iter().map(|&(token, _)| token).collect(),
+        });
+
+        let quote = self
+            .api
+            .quote(url, &quote_request, budget)
+            .await?
+            .quoted_on(chain.id());
+
+        events.emit(SwapEventKind::QuoteReceived {
+            path_id: quote.path_id().clone(),
+            out_amounts: quote.out_amounts_iter().cloned().collect(),
+            price_impact: quote.try_price_impact(),
+        });
+
+        self.check_quote(&quote)?;
+        Ok(quote)
+    }
+
+    /// Apply the configured guards to `quote`
+    fn check_quote(&self, quote: &SingleQuoteResponse) -> Result<()> {
+        check_output_slippage(quote, &self.outputs, &self.output_slippage)?;
+        if let Some(limit) = self.max_price_impact {
+            check_price_impact(quote, limit)?;
+        }
+        if let Some(minimum) = self.min_output {
+            check_min_output(quote, minimum)?;
+        }
+        Ok(())
+    }
+
+    /// Build the complete transaction for this swap
+    ///
+    /// This method:
+    /// 1. Gets a quote from the Odos API
+    /// 2. Assembles the transaction data
+    /// 3. Returns a `TransactionRequest` ready for signing
+    ///
+    /// The returned transaction still needs gas parameters set before signing.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `TransactionRequest` with:
+    /// - `to`: Router contract address
+    /// - `from`: Signer address
+    /// - `data`: Encoded swap calldata
+    /// - `value`: Native amount to send, equal to the input amount when
+    ///   swapping from [`NATIVE_TOKEN`](crate::NATIVE_TOKEN)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Required fields are missing
+    /// - The quote fails a configured guard (see [`quote()`](Self::quote))
+    /// - The Odos API returns an error
+    /// - Transaction assembly fails
+    /// - A native-input swap was assembled with a different `value`
+    /// - The API assembled the swap for a different chain than the quote's,
+    ///   reported as [`OdosError::ChainMismatch`](crate::OdosError::ChainMismatch)
+    /// - [`verify_assembly`](Self::verify_assembly) is enabled and the
+    ///   calldata does not match the quote, reported as
+    ///   [`OdosError::AssemblyVerificationFailed`](crate::OdosError::AssemblyVerificationFailed)
+    /// - A [`with_permit2`](Self::with_permit2) permit does not match the
+    ///   swap, reported as [`OdosError::InvalidInput`](crate::OdosError::InvalidInput)
+    /// - Network issues occur
+    /// - The [`total_budget`](Self::total_budget) runs out
+    /// - The quoted path expires before assembly and no
+    ///   [`auto_requote`](Self::auto_requote) attempts are left, reported as
+    ///   [`OdosError::PathExpired`](crate::OdosError::PathExpired)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, Chain, Slippage};
+    /// use alloy_primitives::{address, U256};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let tx = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
+    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///     .slippage(Slippage::percent(0.5)?)
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///     .build_transaction()
+    ///     .await?;
+    ///
+    /// // Set gas parameters and sign
+    /// // let tx = tx.with_gas_limit(300_000);
+    /// // provider.send_transaction(tx).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_transaction(&self) -> Result<TransactionRequest> {
+        let (_, tx) = self.quote_and_build().await?;
+        Ok(tx)
+    }
+
+    /// Quote the swap and build its transaction from that one quote
+    ///
+    /// Like [`build_transaction()`](Self::build_transaction), but also returns
+    /// the quote the transaction was assembled from. With
+    /// [`auto_requote`](Self::auto_requote), that is the last, fresh quote.
+    ///
+    /// # Errors
+    ///
+    /// As for [`build_transaction()`](Self::build_transaction).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, Chain, Slippage};
+    /// use alloy_primitives::{address, U256};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let (quote, tx) = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
+    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///     .slippage(Slippage::percent(0.5)?)
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///     .quote_and_build()
+    ///     .await?;
+    ///
+    /// println!("Executing at {} output", quote.out_amount().unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote_and_build(&self) -> Result<(SingleQuoteResponse, TransactionRequest)> {
+        let endpoint = self.endpoint()?;
+        self.quote_and_build_at(endpoint.quote_url(), endpoint.assemble_url())
+            .await
+    }
+
+    async fn quote_and_build_at(
+        &self,
+        quote_url: Url,
+        assemble_url: Url,
+    ) -> Result<(SingleQuoteResponse, TransactionRequest)> {
+        let mut events = self.swap_events.run(self.events.as_ref());
+        self.quote_and_build_within(quote_url, assemble_url, &mut events)
+            .await
+    }
+
+    async fn quote_and_build_within(
+        &self,
+        quote_url: Url,
+        assemble_url: Url,
+        events: &mut SwapEventRun<'_>,
+    ) -> Result<(SingleQuoteResponse, TransactionRequest)> {
+        let budget = self.total_budget.map(TimeBudget::start);
+        let mut requotes = 0;
+
+        loop {
+            let quote = self.quote_within(quote_url.clone(), budget, events).await?;
+
+            match self
+                .assemble_quote(&quote, assemble_url.clone(), budget, events)
+                .await
+            {
+                Ok(tx) => return Ok((quote, tx)),
+                Err(err) if err.is_expired_path() && requotes < self.auto_requote => {
+                    requotes += 1;
+                    debug!(
+                        path_id = %quote.path_id(),
+                        requote = requotes,
+                        max_requotes = self.auto_requote,
+                        "Quoted path expired before assembly; re-quoting"
+                    );
+                }
+                Err(err) => return Err(expired_path_error(err, &quote, requotes)),
+            }
+        }
+    }
+
+    /// Quote, build, send and confirm the swap with `provider`
+    ///
+    /// Runs [`quote_and_build()`](Self::quote_and_build), then fills the
+    /// signer's pending nonce, an `eth_estimateGas` limit with a
+    /// [`DEFAULT_GAS_BUFFER_PERCENT`](crate::DEFAULT_GAS_BUFFER_PERCENT)
+    /// buffer and EIP-1559 fees from `eth_feeHistory`, sends the transaction
+    /// and waits for its receipt. `provider` must be able to sign for the
+    /// builder's signer, e.g. one built with `ProviderBuilder::wallet`.
+    ///
+    /// The [`total_budget`](Self::total_budget) covers quoting and assembly
+    /// only; waiting for the receipt is bounded by the provider.
+    ///
+    /// Approvals are not sent: for ERC-20 inputs the router must already
+    /// have an allowance, or gas estimation fails. Check this first with
+    /// [`ensure_ready()`](Self::ensure_ready).
+    ///
+    /// # Errors
+    ///
+    /// Any [`quote_and_build()`](Self::quote_and_build) error, and then one
+    /// variant per stage:
+    /// - [`OdosError::SimulationReverted`](crate::OdosError::SimulationReverted)
+    ///   if [`simulate_before_send`](Self::simulate_before_send) is enabled
+    ///   and the swap would revert; nothing was broadcast
+    /// - [`OdosError::GasEstimation`](crate::OdosError::GasEstimation) if
+    ///   `eth_estimateGas` or `eth_feeHistory` fails; the node rejects a
+    ///   swap that would revert here, and the message includes the router's
+    ///   revert reason when it can be decoded
+    /// - [`OdosError::TransactionSubmission`](crate::OdosError::TransactionSubmission)
+    ///   if the nonce lookup or sending fails; nothing was broadcast
+    /// - [`OdosError::TransactionConfirmation`](crate::OdosError::TransactionConfirmation)
+    ///   if waiting for the receipt fails; the transaction may still be mined
+    /// - [`OdosError::TransactionReverted`](crate::OdosError::TransactionReverted)
+    ///   if the transaction was mined and reverted, with the revert reason
+    ///   recovered by replaying it with `eth_call` when possible
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use alloy_primitives::{address, U256};
+    /// use alloy_provider::ProviderBuilder;
+    /// use alloy_signer_local::PrivateKeySigner;
+    /// use odos_sdk::{Chain, OdosClient, Slippage};
+    ///
+    /// # async fn example(signer: PrivateKeySigner) -> Result<(), Box<dyn std::error::Error>> {
+    /// let weth = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    /// let signer_address = signer.address();
+    /// let provider = ProviderBuilder::new()
+    ///     .wallet(signer)
+    ///     .connect_http("https://eth.llamarpc.com".parse()?);
+    ///
+    /// let execution = OdosClient::new()?
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_native(U256::from(10u64.pow(16)))
+    ///     .to_token(weth)
+    ///     .slippage(Slippage::percent(0.5)?)
+    ///     .signer(signer_address)
+    ///     .execute(&provider)
+    ///     .await?;
+    ///
+    /// println!("Received {:?} WETH in {}", execution.output_amount(weth), execution.tx_hash);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "provider")]
+    pub async fn execute<P>(&self, provider: &P) -> Result<crate::SwapExecution>
+    where
+        P: alloy_provider::Provider<alloy_network::Ethereum>,
+    {
+        let endpoint = self.endpoint()?;
+        self.execute_at(provider, endpoint.quote_url(), endpoint.assemble_url())
+            .await
+    }
+
+    #[cfg(feature = "provider")]
+    async fn execute_at<P>(
+        &self,
+        provider: &P,
+        quote_url: Url,
+        assemble_url: Url,
+    ) -> Result<crate::SwapExecution>
+    where
+        P: alloy_provider::Provider<alloy_network::Ethereum>,
+    {
+        let mut events = self.swap_events.run(self.events.as_ref());
+        let (_, tx) = self
+            .quote_and_build_within(quote_url, assemble_url, &mut events)
+            .await?;
+
+        if self.simulate_before_send {
+            if let Err(err) = crate::simulate_swap(provider, &tx).await {
+                events.emit(SwapEventKind::ExecutionFailed {
+                    tx_hash: None,
+                    reason: err.to_string(),
+                });
+                return Err(err);
+            }
+        }
+
+        crate::swap_execution::send_swap(provider, tx, &mut events).await
+    }
+
+    /// Check the signer's input token balance and the router's allowance
+    ///
+    /// The allowance is read for the router this builder assembles swaps
+    /// for, so it honours [`router`](Self::router). The router is looked up
+    /// with [`OdosClient::approval_spender`], so the allowance checked is the
+    /// one [`approval_transaction()`](Self::approval_transaction) grants. For
+    /// a native-token input the signer's native balance is read instead, and
+    /// the allowance is reported as `U256::MAX` since no approval is needed.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::MissingData`](crate::OdosError::MissingData) if the
+    ///   chain, input token or signer is not set
+    /// - The [`OdosChainError`]-derived error if the router is not deployed
+    ///   on the chain
+    /// - [`OdosError::Contract`](crate::OdosError::Contract) if reading the
+    ///   balance or allowance fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use alloy_primitives::{address, U256};
+    /// use alloy_provider::ProviderBuilder;
+    /// use odos_sdk::{Chain, OdosClient};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let provider = ProviderBuilder::new().connect_http("https://eth.llamarpc.com".parse()?);
+    /// let client = OdosClient::new()?;
+    ///
+    /// let amount = U256::from(1_000_000);
+    /// let preflight = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), amount)
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///     .preflight(&provider)
+    ///     .await?;
+    ///
+    /// if !preflight.is_ready() {
+    ///     println!("Approve {} more", preflight.approval_needed(amount));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "multicall")]
+    pub async fn preflight<P>(&self, provider: &P) -> Result<crate::multicall::PreflightResult>
+    where
+        P: alloy_provider::Provider<alloy_network::Ethereum>,
+    {
+        read_preflight(provider, &self.preflight_check().await?).await
+    }
+
+    /// Check that the signer can send the swap, failing with the shortfall
+    /// if not
+    ///
+    /// Runs [`preflight()`](Self::preflight) and returns its result when the
+    /// balance and allowance both cover the input amount.
+    ///
+    /// # Errors
+    ///
+    /// Any [`preflight()`](Self::preflight) error, then:
+    /// - [`OdosError::InsufficientBalance`](crate::OdosError::InsufficientBalance)
+    ///   if the balance is below the input amount
+    /// - [`OdosError::InsufficientAllowance`](crate::OdosError::InsufficientAllowance)
+    ///   if the balance suffices but the router's allowance does not
+    #[cfg(feature = "multicall")]
+    pub async fn ensure_ready<P>(&self, provider: &P) -> Result<crate::multicall::PreflightResult>
+    where
+        P: alloy_provider::Provider<alloy_network::Ethereum>,
+    {
+        let check = self.preflight_check().await?;
+        let result = read_preflight(provider, &check).await?;
+        let required = check.required_amount;
+
+        let balance = result.balance.unwrap_or_default();
+        if balance < required {
+            return Err(crate::OdosError::insufficient_balance(
+                check.token,
+                required,
+                required - balance,
+            ));
+        }
+
+        let allowance = result.allowance.unwrap_or_default();
+        if allowance < required {
+            return Err(crate::OdosError::insufficient_allowance(
+                check.token,
+                check.spender,
+                required,
+                required - allowance,
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Get the balance and allowance check for this swap's input
+    #[cfg(feature = "multicall")]
+    async fn preflight_check(&self) -> Result<crate::multicall::SwapPreflightCheck> {
+        let InputSpend {
+            token,
+            amount,
+            owner,
+            spender,
+        } = self.input_spend().await?;
+
+        Ok(crate::multicall::SwapPreflightCheck {
+            token,
+            owner,
+            spender,
+            required_amount: amount,
+        })
+    }
+
+    /// Build the ERC-20 approval that lets the router spend this swap's input
+    ///
+    /// The spender is the router this builder assembles swaps for, so it
+    /// honours [`router`](Self::router). It is looked up with
+    /// [`OdosClient::approval_spender`], so a router Odos has rotated since
+    /// this release is approved rather than the compiled-in one; an
+    /// [`OdosApi`] backend other than a client uses the compiled-in router.
+    /// [`ApprovalAmount::Exact`] approves
+    /// the input amount and [`ApprovalAmount::Max`] approves `U256::MAX`.
+    /// For tokens such as USDT that reject changing a non-zero allowance,
+    /// use [`two_step_approval_transactions`](Self::two_step_approval_transactions).
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::MissingData`](crate::OdosError::MissingData) if the
+    ///   chain, input token or signer is not set
+    /// - [`OdosError::InvalidInput`](crate::OdosError::InvalidInput) if the
+    ///   input is the native token, which needs no approval
+    /// - The [`OdosChainError`]-derived error if the router is not deployed
+    ///   on the chain
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::{address, U256};
+    /// use odos_sdk::{ApprovalAmount, Chain, OdosClient};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let approval = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+    ///     .approval_transaction(ApprovalAmount::Exact)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn approval_transaction(&self, amount: ApprovalAmount) -> Result<TransactionRequest> {
+        let spend = self.approval_spend().await?;
+        Ok(crate::approvals::build_approval_tx(
+            spend.token,
+            spend.owner,
+            spend.spender,
+            amount.resolve(spend.amount),
+        ))
+    }
+
+    /// Build the approval as two transactions, resetting the allowance to
+    /// zero first
+    ///
+    /// Returns `[approve(router, 0), approve(router, amount)]`; see
+    /// [`approvals`](crate::approvals) for the tokens that need this. Send
+    /// them in order, waiting for the first to be mined.
+    ///
+    /// # Errors
+    ///
+    /// As for [`approval_transaction()`](Self::approval_transaction).
+    pub async fn two_step_approval_transactions(
+        &self,
+        amount: ApprovalAmount,
+    ) -> Result<[TransactionRequest; 2]> {
+        let spend = self.approval_spend().await?;
+        Ok(crate::approvals::build_two_step_approval_txs(
+            spend.token,
+            spend.owner,
+            spend.spender,
+            amount.resolve(spend.amount),
+        ))
+    }
+
+    /// Get the input spend for an approval, rejecting native inputs
+    async fn approval_spend(&self) -> Result<InputSpend> {
+        let spend = self.input_spend().await?;
+        if spend.token == NATIVE_TOKEN {
+            return Err(crate::OdosError::invalid_input(
+                "Native token input is sent as value and needs no approval",
+            ));
+        }
+        Ok(spend)
+    }
+
+    /// Get the input token and amount, the signer, and the router that will
+    /// spend the input
+    async fn input_spend(&self) -> Result<InputSpend> {
+        let (Some(chain), Some((token, amount)), Some(owner)) = (
+            self.chain,
+            self.input_token.zip(self.input_amount),
+            self.signer,
+        ) else {
+            return Err(missing_fields(&[
+                ("chain", self.chain.is_some()),
+                ("input token", self.input_token.is_some()),
+                ("signer", self.signer.is_some()),
+            ]));
+        };
+
+        #[cfg(feature = "v2")]
+        if self.permit2.is_some() {
+            return Ok(InputSpend {
+                token,
+                amount,
+                owner,
+                spender: crate::PERMIT2_ADDRESS,
+            });
+        }
+
+        let spender = self.api.approval_spender(chain, &self.endpoint()?).await?;
+        Ok(InputSpend {
+            token,
+            amount,
+            owner,
+            spender,
+        })
+    }
+
+    /// Build the transaction for a quote fetched earlier
+    ///
+    /// Skips the quote step and assembles directly from the quote's path ID,
+    /// so the transaction executes the route that was shown, e.g. after a
+    /// user confirms a quote from [`quote()`](Self::quote). The builder's
+    /// signer, recipient and router settings must match the ones the quote
+    /// was fetched with. The quote's input token and amount, output tokens
+    /// and, if known, chain are checked against the builder's, and the
+    /// configured guards such as [`max_price_impact`](Self::max_price_impact)
+    /// are applied as in [`quote()`](Self::quote).
+    ///
+    /// Path IDs expire shortly after the quote is issued, so the quote must
+    /// still be fresh. This method never re-quotes, since that could change
+    /// the price; a stale path fails with [`OdosError::PathExpired`](crate::OdosError::PathExpired)
+    /// and the caller should fetch and confirm a new quote.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Required fields are missing
+    /// - The quote was fetched for a different swap, reported as
+    ///   [`OdosError::QuoteMismatch`](crate::OdosError::QuoteMismatch)
+    /// - The quote fails a configured guard (see [`quote()`](Self::quote))
+    /// - The quoted path has expired
+    /// - The Odos API returns an error
+    /// - Transaction assembly fails
+    /// - Network issues occur
+    /// - The [`total_budget`](Self::total_budget) runs out
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use odos_sdk::{OdosClient, Chain, Slippage};
+    /// use alloy_primitives::{address, U256};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OdosClient::new()?;
+    ///
+    /// let swap = client
+    ///     .swap()
+    ///     .chain(Chain::ethereum())
+    ///     .from_token(address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"), U256::from(1_000_000))
+    ///     .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+    ///     .slippage(Slippage::percent(0.5)?)
+    ///     .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"));
+    ///
+    /// let quote = swap.quote().await?;
+    /// // Show the quote and wait for confirmation...
+    /// let tx = swap.build_transaction_from_quote(&quote).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_transaction_from_quote(
+        &self,
+        quote: &SingleQuoteResponse,
+    ) -> Result<TransactionRequest> {
+        let url = self.endpoint()?.assemble_url();
+        self.build_transaction_from_quote_at(url, quote).await
+    }
+
+    async fn build_transaction_from_quote_at(
+        &self,
+        url: Url,
+        quote: &SingleQuoteResponse,
+    ) -> Result<TransactionRequest> {
+        let params = self.quote_params()?;
+        check_quote_matches(quote, &params, &self.outputs)?;
+        self.check_quote(quote)?;
+
+        let budget = self.total_budget.map(TimeBudget::start);
+        let mut events = self.swap_events.run(self.events.as_ref());
+
+        self.assemble_quote(quote, url, budget, &mut events)
+            .await
+            .map_err(|err| expired_path_error(err, quote, 0))
+    }
+
+    /// Assemble the transaction for `quote`'s path
+    async fn assemble_quote(
+        &self,
+        quote: &SingleQuoteResponse,
+        url: Url,
+        budget: Option<TimeBudget>,
+        events: &mut SwapEventRun<'_>,
+    ) -> Result<TransactionRequest> {
+        // Fail fast rather than issue an assemble request with no time left
+        if let Some(budget) = budget {
+            budget.remaining()?;
+        }
+
+        let (Some(chain), Some((input_token, input_amount)), Some(signer)) = (
+            self.chain,
+            self.input_token.zip(self.input_amount),
+            self.signer,
+        ) else {
+            return Err(missing_fields(&[
+                ("chain", self.chain.is_some()),
+                ("input token", self.input_token.is_some()),
+                ("signer", self.signer.is_some()),
+            ]));
+        };
+        let recipient = self.recipient.unwrap_or(signer);
+        let router_address = self.swap_router(chain).await?;
+
+        // Build swap context
+        let swap_context = AssemblyRequest::builder()
+            .chain(chain.inner())
+            .router_address(router_address)
+            .signer_address(signer)
+            .output_recipient(recipient)
+            .token_address(input_token)
+            .token_amount(input_amount)
+            .path_id(quote.path_id())
+            .build();
+
+        #[cfg(feature = "v2")]
+        if let Some((_, permit)) = &self.permit2 {
+            check_permit2(permit, input_token, router_address)?;
+        }
+
+        events.emit(SwapEventKind::AssembleRequested {
+            path_id: quote.path_id().clone(),
+            signer,
+            recipient,
+        });
+
+        let tx = self.api.assemble(url, &swap_context, budget).await?;
+        // The quote was requested for `chain` too
+        check_assembly_chain(chain, &tx)?;
+        if input_token == NATIVE_TOKEN {
+            check_native_value(&tx, input_amount)?;
+        }
+        #[cfg(feature = "v2")]
+        if self.verify_assembly {
+            let mut verification = crate::verify_assembly(quote, &swap_context, &tx)?;
+            if let Some(slippage) = self.slippage {
+                verification = verification.with_max_slippage(slippage);
+            }
+            verification.into_result()?;
+        }
+        #[cfg(feature = "v2")]
+        let tx = match &self.permit2 {
+            Some((signature, permit)) => {
+                use alloy_network::TransactionBuilder;
+
+                let calldata = tx.input.input().ok_or_else(|| {
+                    crate::OdosError::transaction_assembly_error("Assembled swap has no calldata")
+                })?;
+                let calldata = crate::swap_permit2_calldata(calldata, permit, signature.clone())?;
+                tx.with_input(calldata)
+            }
+            None => tx,
+        };
+
+        events.emit(SwapEventKind::TransactionBuilt {
+            to: tx.to.and_then(|kind| kind.to().copied()),
+            value: tx.value.unwrap_or_default(),
+        });
+
+        Ok(tx)
+    }
+}
+
+/// Read the balance and allowance for `check`, or the native balance for a
+/// native-token input
+#[cfg(feature = "multicall")]
+async fn read_preflight<P>(
+    provider: &P,
+    check: &crate::multicall::SwapPreflightCheck,
+) -> Result<crate::multicall::PreflightResult>
+where
+    P: alloy_provider::Provider<alloy_network::Ethereum>,
+{
+    use crate::multicall::{check_balance_and_allowance, CallOutcome, PreflightResult};
+
+    let read_error = |err: alloy_transport::TransportError| {
+        crate::OdosError::contract_error(format!(
+            "Failed to read balance or allowance of {} for {}: {err}",
+            check.token, check.owner
+        ))
+    };
+
+    let (balance, allowance) = if check.token == NATIVE_TOKEN {
+        let balance = provider
+            .get_balance(check.owner)
+            .await
+            .map_err(read_error)?;
+        (balance, U256::MAX)
+    } else {
+        check_balance_and_allowance(provider, check.token, check.owner, check.spender)
+            .await
+            .map_err(read_error)?
+    };
+
+    Ok(PreflightResult::from_outcomes(
+        check,
+        CallOutcome::Success(balance),
+        CallOutcome::Success(allowance),
+    ))
+}
+
+/// Backend a [`SwapBuilder`] quotes and assembles through
+#[derive(Debug, Clone, Copy)]
+enum SwapApi<'a> {
+    /// A client, honouring router overrides and time budgets in full
+    Client(&'a OdosClient),
+    /// Any other implementation, e.g. a mock
+    Dyn(&'a dyn OdosApi),
+}
+
+impl SwapApi<'_> {
+    /// Endpoint swaps go to unless a router is preferred
+    fn endpoint(&self) -> Endpoint {
+        match self {
+            Self::Client(client) => client.config().endpoint.clone(),
+            Self::Dyn(api) => Endpoint::new(ApiHost::Public, api.api_version()),
+        }
+    }
+
+    async fn quote(
+        &self,
+        url: Url,
+        request: &QuoteRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<SingleQuoteResponse> {
+        match self {
+            Self::Client(client) => client.quote_at(url, request, budget).await,
+            Self::Dyn(api) => {
+                check_budget(budget)?;
+                api.quote(request).await
+            }
+        }
+    }
+
+    async fn assemble(
+        &self,
+        url: Url,
+        request: &AssemblyRequest,
+        budget: Option<TimeBudget>,
+    ) -> Result<TransactionRequest> {
+        match self {
+            Self::Client(client) => client.assemble_at(url, request, budget).await,
+            Self::Dyn(api) => {
+                check_budget(budget)?;
+                api.assemble(request).await
+            }
+        }
+    }
+
+    async fn contract_info(&self, endpoint: &Endpoint, chain: Chain) -> Result<ContractInfo> {
+        match self {
+            Self::Client(client) => {
+                client
+                    .get_json(endpoint.contract_info_url(chain.id()), "contract_info")
+                    .await
+            }
+            Self::Dyn(api) => api.contract_info(chain.id()).await,
+        }
+    }
+
+    /// Address to approve for swaps through `endpoint` on `chain`
+    async fn approval_spender(&self, chain: Chain, endpoint: &Endpoint) -> Result<Address> {
+        match self {
+            Self::Client(client) => {
+                router_address(chain, endpoint.version())?;
+                client.approval_spender_at(chain, endpoint).await
+            }
+            Self::Dyn(_) => router_address(chain, endpoint.version()),
+        }
+    }
+}
+
+/// Fail if `budget` has run out
+fn check_budget(budget: Option<TimeBudget>) -> Result<()> {
+    budget.map_or(Ok(()), |budget| budget.remaining().map(drop))
+}
+
+/// Reject a transaction assembled for another chain than `quote_chain`
+fn check_assembly_chain(quote_chain: Chain, tx: &TransactionRequest) -> Result<()> {
+    match tx.chain_id {
+        Some(assembled) if assembled != quote_chain.id() => Err(crate::OdosError::chain_mismatch(
+            quote_chain.id(),
+            assembled,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a native-input swap whose transaction value is not the input amount
+///
+/// The router requires `msg.value` to match the native input exactly, so a
+/// mismatch means the assembled swap would revert or spend a different amount.
+fn check_native_value(tx: &TransactionRequest, input_amount: U256) -> Result<()> {
+    let value = tx.value.unwrap_or_default();
+    if value != input_amount {
+        return Err(crate::OdosError::transaction_assembly_error(format!(
+            "Assembled transaction value {value} does not match the native input amount {input_amount}"
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a Permit2 `permit` that the router at `router_address` could not
+/// redeem for an `input_token` swap
+#[cfg(feature = "v2")]
+fn check_permit2(
+    permit: &crate::PermitTransferFrom,
+    input_token: Address,
+    router_address: Address,
+) -> Result<()> {
+    if input_token == NATIVE_TOKEN {
+        return Err(crate::OdosError::invalid_input(
+            "Native token input is sent as value and cannot be spent through Permit2",
+        ));
+    }
+    if permit.spender() != router_address {
+        return Err(crate::OdosError::invalid_input(format!(
+            "Permit2 permit spender {} is not the swap router {router_address}",
+            permit.spender()
+        )));
+    }
+    Ok(())
+}
+
+/// Wrap an expired-path assemble error for `quote` in
+/// [`OdosError::PathExpired`](crate::OdosError::PathExpired); other errors
+/// pass through
+fn expired_path_error(
+    err: crate::OdosError,
+    quote: &SingleQuoteResponse,
+    requotes: u32,
+) -> crate::OdosError {
+    if err.is_expired_path() {
+        crate::OdosError::path_expired(quote.path_id().clone(), requotes, err)
+    } else {
+        err
+    }
+}
+
+/// Get the router the API assembles for on `chain` and `version`
+///
+/// Unlike the [`OdosChain`] getters, which fall back to the other version
+/// when one is not deployed, this fails if `version`'s router is missing.
+fn router_address(chain: Chain, version: ApiVersion) -> Result<Address> {
+    let prefer_v3 = version == ApiVersion::V3;
+    let chain_name = format!("{:?}", chain.inner());
+
+    if prefer_v3 && !chain.supports_v3() {
+        return Err(OdosChainError::V3NotAvailable { chain: chain_name }.into());
+    }
+    if !prefer_v3 && !chain.supports_v2() {
+        return Err(OdosChainError::V2NotAvailable { chain: chain_name }.into());
+    }
+
+    Ok(chain.router_address_by_preference(prefer_v3)?)
+}
+
+/// Required swap fields, as checked by [`SwapBuilder::validate`]
+struct QuoteParams {
+    chain: Chain,
+    input_token: Address,
+    input_amount: U256,
+    slippage: Slippage,
+    signer: Address,
+}
+
+/// Input a swap spends and the router that spends it
+struct InputSpend {
+    token: Address,
+    amount: U256,
+    owner: Address,
+    spender: Address,
+}
+
+/// Build one [`OdosError::MissingData`](crate::OdosError::MissingData)
+/// naming every `(field, is_set)` pair that is not set
+fn missing_fields(fields: &[(&str, bool)]) -> crate::OdosError {
+    let missing: Vec<&str> = fields
+        .iter()
+        .filter(|(_, is_set)| !is_set)
+        .map(|(name, _)| *name)
+        .collect();
+
+    crate::OdosError::missing_data(format!(
+        "Swap builder is missing required fields: {}",
+        missing.join(", ")
+    ))
+}
+
+/// Reject a quote whose price impact magnitude exceeds `limit` percent.
+fn check_price_impact(quote: &SingleQuoteResponse, limit: f64) -> Result<()> {
+    let actual = quote.try_price_impact().ok_or_else(|| {
+        crate::OdosError::missing_data("Quote did not include price impact; cannot enforce limit")
+    })?;
+
+    if actual.abs() > limit {
+        return Err(crate::OdosError::price_impact_too_high(actual, limit));
+    }
+
+    Ok(())
+}
+
+/// Reject a quote whose first output amount is below `minimum`.
+fn check_min_output(quote: &SingleQuoteResponse, minimum: U256) -> Result<()> {
+    let quoted = quote.out_amount_u256()?;
+
+    if quoted < minimum {
+        return Err(crate::OdosError::output_below_minimum(quoted, minimum));
+    }
+
+    Ok(())
+}
+
+/// Validate `(token, percent)` output pairs: each percentage must be
+/// positive, tokens must be distinct, and the percentages must sum to 100.
+fn validate_output_split(outputs: &[(Address, u32)]) -> Result<()> {
+    for (index, (token, percent)) in outputs.iter().enumerate() {
+        if *percent == 0 {
+            return Err(crate::OdosError::invalid_input(format!(
+                "Output proportion for {token} must be positive"
+            )));
+        }
+        if outputs[..index].iter().any(|(other, _)| other == token) {
+            return Err(crate::OdosError::invalid_input(format!(
+                "Output token {token} is listed more than once"
+            )));
+        }
+    }
+
+    let total: u64 = outputs.iter().map(|(_, percent)| u64::from(*percent)).sum();
+    if total != 100 {
+        return Err(crate::OdosError::invalid_input(format!(
+            "Output proportions must sum to 100, got {total}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Enforce per-token slippage overrides against a returned quote.
+///
+/// Each leg's expected USD value is its proportional share of the total USD
+/// input value; the implied loss is how far the quoted USD output value for
+/// that token falls short of it. Legs without an override are not checked.
+fn check_output_slippage(
+    quote: &SingleQuoteResponse,
+    outputs: &[(Address, u32)],
+    overrides: &[(Address, Slippage)],
+) -> Result<()> {
+    if overrides.is_empty() {
+        return Ok(());
+    }
+
+    let total_in_value: f64 = quote.in_values_iter().sum();
+    let total_proportion: u32 = outputs.iter().map(|(_, proportion)| proportion).sum();
+
+    if total_in_value <= 0.0 || total_proportion == 0 {
+        return Err(crate::OdosError::missing_data(
+            "Quote is missing input values needed to check output slippage",
+        ));
+    }
+
+    for (token, slippage) in overrides {
+        let proportion = outputs
+            .iter()
+            .find_map(|(output, proportion)| (output == token).then_some(*proportion))
+            .unwrap_or_default();
+
+        let out_value = quote
+            .out_tokens_iter()
+            .zip(quote.out_values_iter())
+            .find_map(|(output, value)| (output == token).then_some(*value))
+            .ok_or_else(|| {
+                crate::OdosError::missing_data(format!("Quote is missing output value for {token}"))
+            })?;
+
+        let expected_value = total_in_value * f64::from(proportion) / f64::from(total_proportion);
+        let implied_percent = (expected_value - out_value) / expected_value * 100.0;
+
+        if implied_percent > slippage.as_percent() {
+            return Err(crate::OdosError::quote_rejected(
+                *token,
+                implied_percent,
+                slippage.as_percent(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that `quote` was fetched for the swap `params` and `outputs`
+/// describe
+///
+/// The chain is only checked when the quote records it. Output tokens are
+/// compared as a set, since the API may list them in another order.
+fn check_quote_matches(
+    quote: &SingleQuoteResponse,
+    params: &QuoteParams,
+    outputs: &[(Address, u32)],
+) -> Result<()> {
+    if let Some(chain_id) = quote.chain_id() {
+        if chain_id != params.chain.id() {
+            return Err(crate::OdosError::quote_mismatch(
+                "chain",
+                params.chain.id(),
+                chain_id,
+            ));
+        }
+    }
+
+    let in_tokens: Vec<Address> = quote.in_tokens_iter().copied().collect();
+    if in_tokens != [params.input_token] {
+        return Err(crate::OdosError::quote_mismatch(
+            "input token",
+            params.input_token,
+            join_addresses(&in_tokens),
+        ));
+    }
+
+    let in_amounts = quote.in_amounts_u256().collect::<Result<Vec<_>>>()?;
+    if in_amounts != [params.input_amount] {
+        let quoted: Vec<String> = in_amounts.iter().map(ToString::to_string).collect();
+        return Err(crate::OdosError::quote_mismatch(
+            "input amount",
+            params.input_amount,
+            quoted.join(", "),
+        ));
+    }
+
+    let mut expected: Vec<Address> = outputs.iter().map(|&(token, _)| token).collect();
+    let mut quoted: Vec<Address> = quote.out_tokens_iter().copied().collect();
+    expected.sort();
+    quoted.sort();
+    if quoted != expected {
+        return Err(crate::OdosError::quote_mismatch(
+            "output tokens",
+            join_addresses(&expected),
+            join_addresses(&quoted),
+        ));
+    }
+
+    Ok(())
+}
+
+fn join_addresses(addresses: &[Address]) -> String {
+    addresses
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// API names of `sources`
+fn source_names(sources: &[LiquiditySource]) -> Vec<String> {
+    sources.iter().map(|source| source.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use wiremock::{
+        matchers::{body_partial_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use crate::{test_utils::NoopSleeper, ClientConfig, Endpoint, RetryConfig};
+
+    #[test]
+    fn test_builder_construction() {
+        let client = OdosClient::new().unwrap();
+        let builder = client.swap();
+
+        assert!(builder.chain.is_none());
+        assert!(builder.input_token.is_none());
+        assert!(builder.outputs.is_empty());
+        assert_eq!(builder.referral, ReferralCode::NONE);
+    }
+
+    #[test]
+    fn test_builder_chain_methods() {
         let client = OdosClient::new().unwrap();
 
         let builder = client
@@ -538,63 +2368,1758 @@ mod tests {
                 address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
                 U256::from(1_000_000),
             )
-            .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
-            .slippage(Slippage::standard())
-            .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"));
+            .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"))
+            .slippage(Slippage::standard())
+            .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"));
+
+        assert_eq!(builder.chain.unwrap(), Chain::ethereum());
+        assert_eq!(
+            builder.input_token.unwrap(),
+            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+        );
+        assert_eq!(builder.input_amount.unwrap(), U256::from(1_000_000));
+        assert_eq!(
+            builder.outputs,
+            vec![(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"), 100)]
+        );
+        assert_eq!(builder.slippage.unwrap(), Slippage::standard());
+        assert_eq!(
+            builder.signer.unwrap(),
+            address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0")
+        );
+    }
+
+    #[test]
+    fn test_builder_aliases() {
+        let client = OdosClient::new().unwrap();
+
+        // Test input() vs from_token()
+        let builder1 = client.swap().input(
+            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            U256::from(1000),
+        );
+        let builder2 = client.swap().from_token(
+            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            U256::from(1000),
+        );
+
+        assert_eq!(builder1.input_token, builder2.input_token);
+        assert_eq!(builder1.input_amount, builder2.input_amount);
+
+        // Test with_input() from a TokenAmount and from a pair
+        let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let builder3 = client
+            .swap()
+            .with_input(crate::TokenAmount::from_human(usdc, "0.001", 6).unwrap());
+        let builder4 = client.swap().with_input((usdc, U256::from(1000)));
+
+        assert_eq!(builder3.input_token, builder1.input_token);
+        assert_eq!(builder3.input_amount, builder1.input_amount);
+        assert_eq!(builder4.input_token, builder1.input_token);
+        assert_eq!(builder4.input_amount, builder1.input_amount);
+
+        // Test output() vs to_token()
+        let builder1 = client
+            .swap()
+            .output(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+        let builder2 = client
+            .swap()
+            .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+
+        assert_eq!(builder1.outputs, builder2.outputs);
+    }
+
+    const USDC: Address = address!("833589fCD6EDb6E08f4c7C32D4f71b54bdA02913");
+    const WETH: Address = address!("4200000000000000000000000000000000000006");
+
+    /// Two-output quote: 1000 USD in, split 50/50 into USDC (499.5 USD out)
+    /// and WETH (485 USD out).
+    fn two_output_quote() -> SingleQuoteResponse {
+        serde_json::from_value(serde_json::json!({
+            "blockNumber": 1,
+            "dataGasEstimate": 0,
+            "gasEstimate": 250000.0,
+            "gasEstimateValue": 1.5,
+            "gweiPerGas": 0.01,
+            "inAmounts": ["1000000000000000000"],
+            "inTokens": ["0x0000000000000000000000000000000000000001"],
+            "inValues": [1000.0],
+            "netOutValue": 983.0,
+            "outAmounts": ["499500000", "150000000000000000"],
+            "outTokens": [USDC, WETH],
+            "outValues": [499.5, 485.0],
+            "pathId": "2f6e14ad1cfa589029f413791a0b7f6f",
+            "pathViz": null,
+            "percentDiff": 0.0,
+            "priceImpact": 1.5
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_output_slippage_accepts_leg_within_tolerance() {
+        let quote = two_output_quote();
+        let outputs = [(USDC, 50), (WETH, 50)];
+
+        // WETH leg loses 3% of its 500 USD share
+        let overrides = [
+            (USDC, Slippage::percent(0.1).unwrap()),
+            (WETH, Slippage::percent(3.5).unwrap()),
+        ];
+
+        assert!(check_output_slippage(&quote, &outputs, &overrides).is_ok());
+    }
+
+    #[test]
+    fn test_output_slippage_rejects_leg_beyond_tolerance() {
+        let quote = two_output_quote();
+        let outputs = [(USDC, 50), (WETH, 50)];
+        let overrides = [(WETH, Slippage::percent(1.0).unwrap())];
+
+        let err = check_output_slippage(&quote, &outputs, &overrides).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::OdosError::QuoteRejected { token, actual, limit }
+                if token == WETH && (actual - 3.0).abs() < 1e-9 && limit == 1.0
+        ));
+        assert!(err.to_string().contains("3.00%"));
+    }
+
+    #[test]
+    fn test_output_slippage_without_overrides_is_noop() {
+        let quote = two_output_quote();
+        assert!(check_output_slippage(&quote, &[(USDC, 50), (WETH, 50)], &[]).is_ok());
+    }
+
+    #[test]
+    fn test_output_slippage_override_replaces_previous() {
+        let client = OdosClient::new().unwrap();
+        let builder = client
+            .swap()
+            .output_slippage(WETH, Slippage::percent(1.0).unwrap())
+            .output_slippage(WETH, Slippage::percent(0.3).unwrap());
+
+        assert_eq!(
+            builder.output_slippage,
+            vec![(WETH, Slippage::percent(0.3).unwrap())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_output_slippage_for_unknown_token_is_rejected_before_quote() {
+        let client = OdosClient::new().unwrap();
+        let err = client
+            .swap()
+            .chain(Chain::base())
+            .from_token(USDC, U256::from(1_000_000))
+            .to_token(WETH)
+            .slippage(Slippage::standard())
+            .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+            .output_slippage(Address::ZERO, Slippage::standard())
+            .quote()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+    }
+
+    fn quote_with_price_impact(price_impact: Option<f64>) -> SingleQuoteResponse {
+        let mut json = serde_json::to_value(two_output_quote()).unwrap();
+        json["priceImpact"] = serde_json::json!(price_impact);
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_price_impact_within_limit_accepted() {
+        assert!(check_price_impact(&quote_with_price_impact(Some(-2.5)), 3.0).is_ok());
+        assert!(check_price_impact(&quote_with_price_impact(Some(3.0)), 3.0).is_ok());
+    }
+
+    #[test]
+    fn test_price_impact_beyond_limit_rejected() {
+        let err = check_price_impact(&quote_with_price_impact(Some(-14.0)), 3.0).unwrap_err();
+        match err {
+            crate::OdosError::PriceImpactTooHigh { actual, limit } => {
+                assert_eq!(actual, -14.0);
+                assert_eq!(limit, 3.0);
+            }
+            other => panic!("Expected PriceImpactTooHigh, got: {other:?}"),
+        }
+
+        // Positive impact is compared by magnitude too
+        let err = check_price_impact(&quote_with_price_impact(Some(5.0)), 3.0).unwrap_err();
+        assert!(matches!(err, crate::OdosError::PriceImpactTooHigh { .. }));
+    }
+
+    #[test]
+    fn test_missing_price_impact_fails_closed() {
+        let quote = quote_with_price_impact(None);
+        assert_eq!(quote.try_price_impact(), None);
+        assert_eq!(quote.price_impact(), 0.0);
+
+        let err = check_price_impact(&quote, 3.0).unwrap_err();
+        assert!(matches!(err, crate::OdosError::MissingData(_)));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_total_budget_fails_before_request() {
+        let client = OdosClient::new().unwrap();
+
+        let err = client
+            .swap()
+            .chain(Chain::base())
+            .from_token(USDC, U256::from(1_000_000))
+            .to_token(WETH)
+            .slippage(Slippage::percent(0.5).unwrap())
+            .signer(Address::ZERO)
+            .total_budget(Duration::ZERO)
+            .build_transaction()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            crate::OdosError::Timeout {
+                phase: crate::TimeoutPhase::Total,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_events_emitted_until_failure() {
+        let client = OdosClient::new().unwrap();
+        let (events_tx, mut events_rx) = mpsc::channel(8);
+
+        let result = client
+            .swap()
+            .chain(Chain::base())
+            .from_token(USDC, U256::from(1_000_000))
+            .to_token(WETH)
+            .slippage(Slippage::percent(0.5).unwrap())
+            .signer(Address::ZERO)
+            .total_budget(Duration::ZERO)
+            .events(events_tx)
+            .build_transaction()
+            .await;
+        assert!(result.is_err());
+
+        let record = events_rx.try_recv().unwrap();
+        assert_eq!(record.sequence, 0);
+        assert_eq!(
+            record.kind,
+            SwapEventKind::QuoteRequested {
+                chain_id: 8453,
+                input_token: USDC,
+                input_amount: U256::from(1_000_000),
+                output_tokens: vec![WETH],
+            }
+        );
+        // The quote never completed, so nothing else was emitted
+        assert!(events_rx.try_recv().is_err());
+        assert_eq!(client.dropped_swap_events(), 0);
+    }
+
+    #[test]
+    fn test_min_output_guard() {
+        // First output leg quotes 499_500_000 USDC base units
+        let quote = two_output_quote();
+
+        assert!(check_min_output(&quote, U256::from(499_000_000u64)).is_ok());
+        assert!(check_min_output(&quote, U256::from(499_500_000u64)).is_ok());
+
+        let err = check_min_output(&quote, U256::from(499_500_001u64)).unwrap_err();
+        match err {
+            crate::OdosError::OutputBelowMinimum { quoted, minimum } => {
+                assert_eq!(quoted, U256::from(499_500_000u64));
+                assert_eq!(minimum, U256::from(499_500_001u64));
+            }
+            other => panic!("Expected OutputBelowMinimum, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_min_output_guard_beyond_u128() {
+        let mut json = serde_json::to_value(two_output_quote()).unwrap();
+        json["outAmounts"] = serde_json::json!(["340282366920938463463374607431768211456", "1"]);
+        let quote: SingleQuoteResponse = serde_json::from_value(json).unwrap();
+
+        assert!(check_min_output(&quote, U256::from(u128::MAX)).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_max_price_impact_rejected_before_quote() {
+        let client = OdosClient::new().unwrap();
+        let err = client
+            .swap()
+            .chain(Chain::base())
+            .from_token(USDC, U256::from(1_000_000))
+            .to_token(WETH)
+            .slippage(Slippage::standard())
+            .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+            .max_price_impact(-1.0)
+            .build_transaction()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_output_is_single_entry_split() {
+        let client = OdosClient::new().unwrap();
+
+        let single = client.swap().output(WETH);
+        let split = client.swap().output_split(vec![(WETH, 100)]);
+
+        assert_eq!(single.outputs, split.outputs);
+    }
+
+    #[test]
+    fn test_output_split_replaces_previous_output() {
+        let client = OdosClient::new().unwrap();
+
+        let builder = client
+            .swap()
+            .output(USDC)
+            .output_split(vec![(WETH, 70), (USDC, 30)]);
+
+        assert_eq!(builder.outputs, vec![(WETH, 70), (USDC, 30)]);
+    }
+
+    #[test]
+    fn test_validate_output_split() {
+        assert!(validate_output_split(&[(WETH, 70), (USDC, 30)]).is_ok());
+        assert!(validate_output_split(&[(WETH, 100)]).is_ok());
+
+        let err = validate_output_split(&[(WETH, 70), (USDC, 20)]).unwrap_err();
+        assert!(err.to_string().contains("sum to 100"));
+
+        let err = validate_output_split(&[(WETH, 100), (USDC, 0)]).unwrap_err();
+        assert!(err.to_string().contains("must be positive"));
+
+        let err = validate_output_split(&[(WETH, 50), (WETH, 50)]).unwrap_err();
+        assert!(err.to_string().contains("more than once"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_output_split_is_rejected_before_quote() {
+        let client = OdosClient::new().unwrap();
+        let err = client
+            .swap()
+            .chain(Chain::base())
+            .from_token(Address::ZERO, U256::from(1_000_000))
+            .output_split(vec![(WETH, 60), (USDC, 60)])
+            .slippage(Slippage::standard())
+            .signer(address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0"))
+            .quote()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_builder_recipient_defaults_to_signer() {
+        let client = OdosClient::new().unwrap();
+        let signer_addr = address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0");
+
+        let builder = client.swap().signer(signer_addr);
+
+        assert_eq!(builder.signer.unwrap(), signer_addr);
+        assert!(builder.recipient.is_none()); // Not set, will default in build
+    }
+
+    #[test]
+    fn test_sources_accumulate() {
+        let client = OdosClient::new().unwrap();
+        let builder = client
+            .swap()
+            .allow_sources([LiquiditySource::UniswapV3])
+            .allow_sources(["Curve Stable", "Some Other DEX"])
+            .block_sources([LiquiditySource::Hashflow]);
+
+        assert_eq!(
+            source_names(&builder.allowed_sources),
+            ["Uniswap V3", "Curve Stable", "Some Other DEX"]
+        );
+        assert_eq!(source_names(&builder.blocked_sources), ["Hashflow"]);
+    }
+
+    const EXPIRED_PATH_ID: &str = "2f6e14ad1cfa589029f413791a0b7f6f";
+    const FRESH_PATH_ID: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90";
+    const MAINNET_USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const MAINNET_WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+
+    fn mock_client(endpoint: Endpoint) -> OdosClient {
+        OdosClient::with_config(ClientConfig {
+            endpoint,
+            allow_unauthenticated_enterprise: true,
+            retry_config: RetryConfig::no_retries(),
+            sleeper: std::sync::Arc::new(NoopSleeper),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn mainnet_swap(client: &OdosClient) -> SwapBuilder<'_> {
+        client
+            .swap()
+            .chain(Chain::ethereum())
+            .from_token(MAINNET_USDC, U256::from(1_000_000_000u64))
+            .to_token(MAINNET_WETH)
+            .slippage(Slippage::percent(0.5).unwrap())
+            .signer(address!("47e2d28169738039755586743e2dfcf3bd643f86"))
+    }
+
+    fn quote_body(path_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "blockNumber": 1,
+            "dataGasEstimate": 0,
+            "gasEstimate": 196875.0,
+            "gasEstimateValue": 3.0,
+            "gweiPerGas": 6.85,
+            "inAmounts": ["1000000000"],
+            "inTokens": [MAINNET_USDC],
+            "inValues": [1000.0],
+            "netOutValue": 996.4,
+            "outAmounts": ["297096823183507284"],
+            "outTokens": [MAINNET_WETH],
+            "outValues": [999.4],
+            "pathId": path_id,
+            "pathViz": null,
+            "percentDiff": 0.0,
+            "priceImpact": 0.1
+        })
+    }
+
+    fn mock_urls(server: &MockServer) -> (Url, Url) {
+        (
+            Url::parse(&format!("{}/sor/quote/v2", server.uri())).unwrap(),
+            Url::parse(&format!("{}/sor/assemble", server.uri())).unwrap(),
+        )
+    }
+
+    async fn mount_expired_assemble(server: &MockServer, path_id: &str) {
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .and(body_partial_json(serde_json::json!({ "pathId": path_id })))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "detail": "Path ID not found or expired",
+                "traceId": null,
+                "errorCode": 4100
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_expired_path_is_requoted_and_assembled() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(EXPIRED_PATH_ID)))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_expired_assemble(&server, EXPIRED_PATH_ID).await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .and(body_partial_json(
+                serde_json::json!({ "pathId": FRESH_PATH_ID }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
 
-        assert_eq!(builder.chain.unwrap(), Chain::ethereum());
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let (quote, tx) = mainnet_swap(&client)
+            .auto_requote(1)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap();
+
+        assert_eq!(quote.path_id().as_str(), FRESH_PATH_ID);
         assert_eq!(
-            builder.input_token.unwrap(),
-            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_ETHEREUM_ROUTER)
         );
-        assert_eq!(builder.input_amount.unwrap(), U256::from(1_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_expired_path_without_auto_requote_is_returned() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(EXPIRED_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_expired_assemble(&server, EXPIRED_PATH_ID).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+
+        assert!(err.is_expired_path());
+        assert!(matches!(
+            err,
+            crate::OdosError::PathExpired { requotes: 0, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_requotes_report_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(EXPIRED_PATH_ID)))
+            .expect(3)
+            .mount(&server)
+            .await;
+        mount_expired_assemble(&server, EXPIRED_PATH_ID).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .auto_requote(2)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+
+        match &err {
+            crate::OdosError::PathExpired {
+                path_id,
+                requotes,
+                source,
+            } => {
+                assert_eq!(path_id.as_str(), EXPIRED_PATH_ID);
+                assert_eq!(*requotes, 2);
+                assert!(matches!(**source, crate::OdosError::Api { .. }));
+            }
+            other => panic!("Expected PathExpired, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_router_preference_selects_endpoint_version() {
+        let client = mock_client(Endpoint::public_v3());
+        assert_eq!(client.swap().endpoint().unwrap(), Endpoint::public_v3());
         assert_eq!(
-            builder.output_token.unwrap(),
-            address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")
+            client.swap().router(RouterType::V2).endpoint().unwrap(),
+            Endpoint::public_v2()
         );
-        assert_eq!(builder.slippage.unwrap(), Slippage::standard());
+
+        let client = mock_client(Endpoint::enterprise_v2());
         assert_eq!(
-            builder.signer.unwrap(),
-            address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0")
+            client.swap().router(RouterType::V3).endpoint().unwrap(),
+            Endpoint::enterprise_v3()
         );
     }
 
     #[test]
-    fn test_builder_aliases() {
-        let client = OdosClient::new().unwrap();
+    fn test_router_address_by_version() {
+        assert_eq!(
+            router_address(Chain::ethereum(), ApiVersion::V2).unwrap(),
+            crate::ODOS_V2_ETHEREUM_ROUTER
+        );
+        assert_eq!(
+            router_address(Chain::ethereum(), ApiVersion::V3).unwrap(),
+            crate::ODOS_V3
+        );
+    }
 
-        // Test input() vs from_token()
-        let builder1 = client.swap().input(
-            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
-            U256::from(1000),
+    #[test]
+    fn test_router_address_rejects_undeployed_router() {
+        let chain = Chain::from(alloy_chains::NamedChain::Sepolia);
+
+        let err = router_address(chain, ApiVersion::V2).unwrap_err();
+        assert!(matches!(err, crate::OdosError::Contract(_)));
+        assert!(err.to_string().contains("V2 router not available"));
+
+        let err = router_address(chain, ApiVersion::V3).unwrap_err();
+        assert!(err.to_string().contains("V3 router not available"));
+    }
+
+    #[tokio::test]
+    async fn test_limit_order_router_is_rejected_before_request() {
+        let client = mock_client(Endpoint::public_v2());
+
+        let err = mainnet_swap(&client)
+            .router(RouterType::LimitOrder)
+            .build_transaction()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_undeployed_router_is_rejected_before_request() {
+        let server = MockServer::start().await;
+        let client = mock_client(Endpoint::public_v3());
+        let (quote_url, assemble_url) = mock_urls(&server);
+
+        let err = mainnet_swap(&client)
+            .chain(Chain::from(alloy_chains::NamedChain::Sepolia))
+            .router(RouterType::V2)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("V2 router not available"));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    /// Mount a quote and an assembly whose calldata swaps the quoted amounts,
+    /// with `tamper` applied to the swap
+    #[cfg(feature = "v2")]
+    async fn mount_assembled_swap(
+        server: &MockServer,
+        tamper: impl FnOnce(&mut crate::OdosRouterV2::swapTokenInfo),
+    ) {
+        use alloy_sol_types::SolCall;
+
+        let quoted = U256::from(297_096_823_183_507_284u64);
+        let mut token_info = crate::OdosRouterV2::swapTokenInfo {
+            inputToken: MAINNET_USDC,
+            inputAmount: U256::from(1_000_000_000u64),
+            inputReceiver: Address::repeat_byte(0xee),
+            outputToken: MAINNET_WETH,
+            outputQuote: quoted,
+            outputMin: quoted * U256::from(995) / U256::from(1000),
+            outputReceiver: address!("47e2d28169738039755586743e2dfcf3bd643f86"),
+        };
+        tamper(&mut token_info);
+        let calldata = crate::OdosV2Router::swapCall {
+            tokenInfo: token_info,
+            pathDefinition: Default::default(),
+            executor: Address::repeat_byte(0xee),
+            referralCode: 0,
+        }
+        .abi_encode();
+
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .mount(server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                crate::assemble::tests::ASSEMBLE_FIXTURE.replace(
+                    r#""data": "0x83bd37f9""#,
+                    &format!(
+                        r#""data": "{}""#,
+                        alloy_primitives::hex::encode_prefixed(calldata)
+                    ),
+                ),
+            ))
+            .mount(server)
+            .await;
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_verify_assembly_accepts_matching_calldata() {
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |_| {}).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        mainnet_swap(&client)
+            .verify_assembly(true)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap();
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_build_transaction_emits_lifecycle_events() {
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |_| {}).await;
+        let client = mock_client(Endpoint::custom(
+            Url::parse(&server.uri()).unwrap(),
+            ApiVersion::V2,
+        ));
+        let (events_tx, mut events_rx) = mpsc::channel(8);
+
+        let tx = mainnet_swap(&client)
+            .events(events_tx)
+            .build_transaction()
+            .await
+            .unwrap();
+
+        let mut records = Vec::new();
+        while let Ok(record) = events_rx.try_recv() {
+            records.push(record);
+        }
+        let kinds: Vec<_> = records.iter().map(|record| &record.kind).collect();
+        assert!(matches!(
+            kinds.as_slice(),
+            [
+                SwapEventKind::QuoteRequested { .. },
+                SwapEventKind::QuoteReceived { .. },
+                SwapEventKind::AssembleRequested { .. },
+                SwapEventKind::TransactionBuilt { .. },
+            ]
+        ));
+        let correlation_id = records[0].correlation_id;
+        assert!(records
+            .iter()
+            .all(|record| record.correlation_id == correlation_id));
+        let sequences: Vec<u32> = records.iter().map(|record| record.sequence).collect();
+        assert_eq!(sequences, [0, 1, 2, 3]);
+
+        assert_eq!(
+            records[1].kind,
+            SwapEventKind::QuoteReceived {
+                path_id: crate::PathId::parse(FRESH_PATH_ID).unwrap(),
+                out_amounts: vec!["297096823183507284".to_string()],
+                price_impact: Some(0.1),
+            }
         );
-        let builder2 = client.swap().from_token(
-            address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
-            U256::from(1000),
+        assert_eq!(
+            records[3].kind,
+            SwapEventKind::TransactionBuilt {
+                to: tx.to.and_then(|kind| kind.to().copied()),
+                value: tx.value.unwrap_or_default(),
+            }
         );
+        assert_eq!(client.dropped_swap_events(), 0);
+    }
 
-        assert_eq!(builder1.input_token, builder2.input_token);
-        assert_eq!(builder1.input_amount, builder2.input_amount);
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_verify_assembly_rejects_tampered_calldata() {
+        let attacker = address!("000000000000000000000000000000000000dead");
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |info| {
+            info.outputReceiver = attacker;
+            info.outputMin = U256::ZERO;
+        })
+        .await;
 
-        // Test output() vs to_token()
-        let builder1 = client
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        // Without verification the tampered transaction is returned as is
+        mainnet_swap(&client)
+            .quote_and_build_at(quote_url.clone(), assemble_url.clone())
+            .await
+            .unwrap();
+
+        let err = mainnet_swap(&client)
+            .verify_assembly(true)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+        let crate::OdosError::AssemblyVerificationFailed { discrepancies } = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert!(matches!(
+            discrepancies.as_slice(),
+            [
+                crate::AssemblyDiscrepancy::OutputReceiver { assembled, .. },
+                crate::AssemblyDiscrepancy::OutputMinBelowSlippage { .. },
+            ] if *assembled == attacker
+        ));
+    }
+
+    #[cfg(feature = "v2")]
+    fn permit2(spender: Address) -> crate::PermitTransferFrom {
+        crate::PermitTransferFrom::builder()
+            .permitted((MAINNET_USDC, U256::from(1_000_000_000u64)))
+            .spender(spender)
+            .nonce(U256::from(5))
+            .deadline(1_767_225_600)
+            .build()
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_with_permit2_reencodes_assembled_swap() {
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |_| {}).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let signature = alloy_primitives::Bytes::from_static(&[0xab; 65]);
+        let builder = mainnet_swap(&client)
+            .verify_assembly(true)
+            .with_permit2(signature.clone(), permit2(crate::ODOS_V2_ETHEREUM_ROUTER));
+        assert_eq!(
+            builder
+                .approval_transaction(ApprovalAmount::Exact)
+                .await
+                .unwrap()
+                .to
+                .and_then(|kind| kind.to().copied()),
+            Some(MAINNET_USDC)
+        );
+        let (_, tx) = builder
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap();
+
+        let crate::DecodedOdosCall::SwapPermit2 { permit2, swap } = crate::decode_router_calldata(
+            crate::ODOS_V2_ETHEREUM_ROUTER,
+            tx.input.input().unwrap(),
+        )
+        .unwrap() else {
+            panic!("expected a Permit2 swap");
+        };
+        assert_eq!(permit2.contract_address, crate::PERMIT2_ADDRESS);
+        assert_eq!(permit2.nonce, U256::from(5));
+        assert_eq!(permit2.signature, signature);
+        assert_eq!(swap.amount_in(), U256::from(1_000_000_000u64));
+        assert_eq!(swap.output_token_address(), MAINNET_WETH);
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_with_permit2_rejects_wrong_spender() {
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |_| {}).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .with_permit2(
+                alloy_primitives::Bytes::from_static(&[0xab; 65]),
+                permit2(crate::ODOS_V3),
+            )
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, crate::OdosError::InvalidInput(message) if message.contains("spender")),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[cfg(feature = "v2")]
+    #[tokio::test]
+    async fn test_with_permit2_rejects_compact_mode() {
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |_| {}).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .compact(true)
+            .with_permit2(
+                alloy_primitives::Bytes::from_static(&[0xab; 65]),
+                permit2(crate::ODOS_V2_ETHEREUM_ROUTER),
+            )
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, crate::OdosError::InvalidInput(message) if message.contains("Compact")),
+            "unexpected error: {err:?}"
+        );
+        // Rejected before any request is sent
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "provider")]
+    #[tokio::test]
+    async fn test_simulate_before_send_stops_reverting_swap() {
+        let server = MockServer::start().await;
+        mount_assembled_swap(&server, |_| {}).await;
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_call" }),
+        ))
+        .respond_with(|request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "error": { "code": 3, "message": "execution reverted" }
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let provider =
+            alloy_provider::ProviderBuilder::new().connect_http(server.uri().parse().unwrap());
+        let (events_tx, mut events_rx) = mpsc::channel(8);
+        let err = mainnet_swap(&client)
+            .simulate_before_send(true)
+            .events(events_tx)
+            .execute_at(&provider, quote_url, assemble_url)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            crate::OdosError::SimulationReverted { reason: None, .. }
+        ));
+        // Nothing past the simulation was requested
+        let methods: Vec<_> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter_map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).ok()?;
+                body["method"].as_str().map(str::to_string)
+            })
+            .collect();
+        assert_eq!(methods, ["eth_call"]);
+
+        let mut last = None;
+        while let Ok(record) = events_rx.try_recv() {
+            last = Some(record.kind);
+        }
+        assert!(matches!(
+            last,
+            Some(SwapEventKind::ExecutionFailed { tx_hash: None, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_v2_preference_assembles_for_v2_router() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The client targets V3, but the V2 preference wins
+        let client = mock_client(Endpoint::public_v3());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let (_, tx) = mainnet_swap(&client)
+            .router(RouterType::V2)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_ETHEREUM_ROUTER)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_transaction_rejects_assembly_for_other_chain() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(
+                    crate::assemble::tests::ASSEMBLE_FIXTURE
+                        .replace(r#""chainId": 1"#, r#""chainId": 42161"#),
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::OdosError::ChainMismatch {
+                expected: 1,
+                reported: 42161
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_swap_on_custom_chain() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .and(body_partial_json(serde_json::json!({ "chainId": 31337 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(
+                    crate::assemble::tests::ASSEMBLE_FIXTURE
+                        .replace(r#""chainId": 1"#, r#""chainId": 31337"#),
+                ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        /// Unregisters the custom chain when the test ends, even on panic
+        struct Unregister(u64);
+
+        impl Drop for Unregister {
+            fn drop(&mut self) {
+                Chain::unregister_custom(self.0);
+            }
+        }
+
+        // An Anvil mainnet fork behind a mock Odos API
+        let _unregister = Unregister(31337);
+        let anvil = Chain::register_custom(
+            31337,
+            crate::ChainConfig {
+                name: "anvil".to_string(),
+                v2_router: Some(crate::ODOS_V2_ETHEREUM_ROUTER),
+                v3_router: None,
+                lo_router: None,
+            },
+        )
+        .unwrap();
+        let client = mock_client(Endpoint::custom(
+            Url::parse(&server.uri()).unwrap(),
+            ApiVersion::V2,
+        ));
+        let tx = mainnet_swap(&client)
+            .chain(anvil)
+            .build_transaction()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_ETHEREUM_ROUTER)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unlisted_chain_uses_api_reported_router() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .and(body_partial_json(serde_json::json!({ "chainId": 80094 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // The API reports the router the assembly fixture calls
+        Mock::given(method("GET"))
+            .and(path("/info/contract-info/v2/80094"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "chainId": 80094,
+                "routerAddress": crate::ODOS_V2_ETHEREUM_ROUTER,
+                "executorAddress": address!("76edf8c155a1e0d9b2ad11b04d9671cbc25fee99"),
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(
+                    crate::assemble::tests::ASSEMBLE_FIXTURE
+                        .replace(r#""chainId": 1"#, r#""chainId": 80094"#),
+                ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(Endpoint::custom(
+            Url::parse(&server.uri()).unwrap(),
+            ApiVersion::V2,
+        ));
+        let berachain = Chain::from(alloy_chains::NamedChain::Berachain);
+
+        // The static path stays the default
+        let err = mainnet_swap(&client)
+            .chain(berachain)
+            .build_transaction()
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("V2 router not available"));
+
+        let tx = mainnet_swap(&client)
+            .chain(berachain)
+            .allow_unlisted_chains(true)
+            .build_transaction()
+            .await
+            .unwrap();
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_ETHEREUM_ROUTER)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_v3_preference_rejects_v2_assembly() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .mount(&server)
+            .await;
+
+        // The fixture is assembled for the V2 router, which a V3 swap must not accept
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .router(RouterType::V3)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+
+        match err {
+            crate::OdosError::RouterAddressMismatch { expected, .. } => {
+                assert_eq!(expected, crate::ODOS_V3);
+            }
+            other => panic!("Expected RouterAddressMismatch, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_transaction_from_quote_skips_quote() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .and(body_partial_json(
+                serde_json::json!({ "pathId": FRESH_PATH_ID }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(crate::assemble::tests::ASSEMBLE_FIXTURE),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let quote: SingleQuoteResponse = serde_json::from_value(quote_body(FRESH_PATH_ID)).unwrap();
+        let (_, assemble_url) = mock_urls(&server);
+        let tx = mainnet_swap(&client)
+            .build_transaction_from_quote_at(assemble_url, &quote)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_ETHEREUM_ROUTER)
+        );
+        // Only the assemble request was sent
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_build_transaction_from_stale_quote_reports_expired_path() {
+        let server = MockServer::start().await;
+        mount_expired_assemble(&server, EXPIRED_PATH_ID).await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let quote: SingleQuoteResponse =
+            serde_json::from_value(quote_body(EXPIRED_PATH_ID)).unwrap();
+        let (_, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .auto_requote(3)
+            .build_transaction_from_quote_at(assemble_url, &quote)
+            .await
+            .unwrap_err();
+
+        // Never re-quotes, even with auto_requote set
+        assert!(matches!(
+            err,
+            crate::OdosError::PathExpired { requotes: 0, .. }
+        ));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_build_transaction_from_quote_applies_guards() {
+        let server = MockServer::start().await;
+        let client = mock_client(Endpoint::public_v2());
+        let quote: SingleQuoteResponse = serde_json::from_value(quote_body(FRESH_PATH_ID)).unwrap();
+        let (_, assemble_url) = mock_urls(&server);
+
+        let err = mainnet_swap(&client)
+            .max_price_impact(0.05)
+            .build_transaction_from_quote_at(assemble_url.clone(), &quote)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            crate::OdosError::PriceImpactTooHigh { limit, .. } if limit == 0.05
+        ));
+
+        let err = mainnet_swap(&client)
+            .min_output(U256::from(10u64.pow(18)))
+            .build_transaction_from_quote_at(assemble_url, &quote)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::OutputBelowMinimum { .. }));
+
+        // Rejected before assembly
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_build_transaction_from_quote_rejects_other_swap() {
+        let server = MockServer::start().await;
+        let client = mock_client(Endpoint::public_v2());
+        let (_, assemble_url) = mock_urls(&server);
+        let quote = |edit: fn(&mut serde_json::Value)| {
+            let mut body = quote_body(FRESH_PATH_ID);
+            edit(&mut body);
+            serde_json::from_value::<SingleQuoteResponse>(body).unwrap()
+        };
+
+        let cases = [
+            (quote(|_| {}).quoted_on(Chain::arbitrum().id()), "chain"),
+            (
+                quote(|body| body["inTokens"] = serde_json::json!([MAINNET_WETH])),
+                "input token",
+            ),
+            (
+                quote(|body| body["inAmounts"] = serde_json::json!(["2000000000"])),
+                "input amount",
+            ),
+            (
+                quote(|body| body["outTokens"] = serde_json::json!([NATIVE_TOKEN])),
+                "output tokens",
+            ),
+        ];
+        for (quote, expected_field) in cases {
+            let err = mainnet_swap(&client)
+                .build_transaction_from_quote_at(assemble_url.clone(), &quote)
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(err, crate::OdosError::QuoteMismatch { field, .. } if field == expected_field),
+                "expected a {expected_field} mismatch, got {err:?}"
+            );
+        }
+
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_build_transaction_from_quote_requires_signer() {
+        let client = mock_client(Endpoint::public_v2());
+        let quote: SingleQuoteResponse = serde_json::from_value(quote_body(FRESH_PATH_ID)).unwrap();
+
+        let err = client
             .swap()
-            .output(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
-        let builder2 = client
+            .chain(Chain::ethereum())
+            .from_token(MAINNET_USDC, U256::from(1_000_000_000u64))
+            .build_transaction_from_quote(&quote)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::MissingData(_)));
+    }
+
+    #[test]
+    fn test_native_token_helpers() {
+        let client = OdosClient::new().unwrap();
+        let builder = client.swap().from_native(U256::from(5)).to_native();
+
+        assert_eq!(builder.input_token, Some(NATIVE_TOKEN));
+        assert_eq!(builder.input_amount, Some(U256::from(5)));
+        assert_eq!(builder.outputs, [(NATIVE_TOKEN, 100)]);
+    }
+
+    /// Mount an assemble response for the V2 Ethereum router with `value`
+    async fn mount_assemble_with_value(server: &MockServer, value: &str) {
+        let mut response: serde_json::Value =
+            serde_json::from_str(crate::assemble::tests::ASSEMBLE_FIXTURE).unwrap();
+        response["transaction"]["value"] = value.into();
+        Mock::given(method("POST"))
+            .and(path("/sor/assemble"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_native_to_erc20_swap_sends_input_as_value() {
+        let one_eth = U256::from(1_000_000_000_000_000_000u64);
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .and(body_partial_json(serde_json::json!({
+                "inputTokens": [{ "tokenAddress": NATIVE_TOKEN, "amount": "1000000000000000000" }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_assemble_with_value(&server, "1000000000000000000").await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let (_, tx) = mainnet_swap(&client)
+            .from_native(one_eth)
+            .to_token(MAINNET_USDC)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap();
+
+        assert_eq!(tx.value, Some(one_eth));
+    }
+
+    #[tokio::test]
+    async fn test_native_input_value_mismatch_is_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .mount(&server)
+            .await;
+        mount_assemble_with_value(&server, "0").await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let err = mainnet_swap(&client)
+            .from_native(U256::from(1_000_000_000_000_000_000u64))
+            .to_token(MAINNET_USDC)
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, crate::OdosError::TransactionAssembly(_)));
+        assert!(err.to_string().contains("native input amount"));
+    }
+
+    #[tokio::test]
+    async fn test_erc20_to_native_swap() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/sor/quote/v2"))
+            .and(body_partial_json(serde_json::json!({
+                "outputTokens": [{ "tokenAddress": NATIVE_TOKEN, "proportion": 1.0 }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(quote_body(FRESH_PATH_ID)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_assemble_with_value(&server, "0").await;
+
+        let client = mock_client(Endpoint::public_v2());
+        let (quote_url, assemble_url) = mock_urls(&server);
+        let (_, tx) = mainnet_swap(&client)
+            .to_native()
+            .quote_and_build_at(quote_url, assemble_url)
+            .await
+            .unwrap();
+
+        assert_eq!(tx.value, Some(U256::ZERO));
+    }
+
+    #[test]
+    fn test_validate_lists_every_missing_field() {
+        let client = OdosClient::new().unwrap();
+
+        let err = client
             .swap()
-            .to_token(address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+            .from_token(USDC, U256::from(1_000_000))
+            .to_token(WETH)
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::MissingData(_)));
+        assert_eq!(
+            err.to_string(),
+            "Missing required data: Swap builder is missing required fields: chain, slippage, signer"
+        );
 
-        assert_eq!(builder1.output_token, builder2.output_token);
+        let err = client.swap().validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("chain, input token, output token, slippage, signer"));
     }
 
     #[test]
-    fn test_builder_recipient_defaults_to_signer() {
+    fn test_validate_accepts_complete_builder() {
+        let client = mock_client(Endpoint::public_v2());
+        assert!(mainnet_swap(&client).validate().is_ok());
+
+        // Non-missing checks still apply
+        let err = mainnet_swap(&client)
+            .max_price_impact(f64::NAN)
+            .validate()
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_quote_reports_all_missing_fields_before_request() {
         let client = OdosClient::new().unwrap();
-        let signer_addr = address!("742d35Cc6634C0532925a3b8D35f3e7a5edD29c0");
 
-        let builder = client.swap().signer(signer_addr);
+        let err = client
+            .swap()
+            .chain(Chain::base())
+            .signer(Address::ZERO)
+            .quote()
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("input token, output token, slippage"));
+    }
 
-        assert_eq!(builder.signer.unwrap(), signer_addr);
-        assert!(builder.recipient.is_none()); // Not set, will default in build
+    const ROTATED_ROUTER: Address = address!("00000000000000000000000000000000000000aa");
+
+    /// Client whose router info endpoint reports `spender` for every version
+    async fn router_info_client(server: &MockServer, spender: Address) -> OdosClient {
+        Mock::given(method("GET"))
+            .and(wiremock::matchers::path_regex(r"^/info/router/v[23]/1$"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "address": spender })),
+            )
+            .mount(server)
+            .await;
+        mock_client(Endpoint::custom(
+            Url::parse(&server.uri()).unwrap(),
+            ApiVersion::V2,
+        ))
+    }
+
+    /// Serve ERC-20 `balanceOf` and `allowance` calls over JSON-RPC; the
+    /// allowance is only granted to `spender`
+    #[cfg(feature = "multicall")]
+    async fn mount_erc20(server: &MockServer, balance: U256, allowance: U256, spender: Address) {
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_call" }),
+        ))
+        .respond_with(move |request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            let input = body["params"][0]["input"].as_str().unwrap().to_lowercase();
+            let spender = alloy_primitives::hex::encode(spender);
+            let result = match &input[..10] {
+                "0x70a08231" => balance,
+                "0xdd62ed3e" if input.ends_with(&spender) => allowance,
+                _ => U256::ZERO,
+            };
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": body["id"],
+                "result": alloy_primitives::B256::from(result),
+            }))
+        })
+        .mount(server)
+        .await;
+    }
+
+    #[cfg(feature = "multicall")]
+    fn rpc_provider(server: &MockServer) -> impl alloy_provider::Provider {
+        alloy_provider::ProviderBuilder::new().connect_http(server.uri().parse().unwrap())
+    }
+
+    #[cfg(feature = "multicall")]
+    #[tokio::test]
+    async fn test_preflight_checks_targeted_router_allowance() {
+        let server = MockServer::start().await;
+        let router = crate::ODOS_V2_ETHEREUM_ROUTER;
+        mount_erc20(
+            &server,
+            U256::from(5_000_000_000u64),
+            U256::from(1_000_000_000u64),
+            router,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/info/router/v3/1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "address": crate::ODOS_V3 })),
+            )
+            .mount(&server)
+            .await;
+        let client = router_info_client(&server, router).await;
+        let provider = rpc_provider(&server);
+
+        let result = mainnet_swap(&client).preflight(&provider).await.unwrap();
+        assert!(result.is_ready());
+        assert_eq!(result.balance, Some(U256::from(5_000_000_000u64)));
+
+        // No allowance is granted to the V3 router
+        let err = mainnet_swap(&client)
+            .router(RouterType::V3)
+            .ensure_ready(&provider)
+            .await
+            .unwrap_err();
+        match err {
+            crate::OdosError::InsufficientAllowance {
+                spender, shortfall, ..
+            } => {
+                assert_eq!(spender, crate::ODOS_V3);
+                assert_eq!(shortfall, U256::from(1_000_000_000u64));
+            }
+            other => panic!("expected InsufficientAllowance, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "multicall")]
+    #[tokio::test]
+    async fn test_ensure_ready_reports_balance_shortfall() {
+        let server = MockServer::start().await;
+        mount_erc20(
+            &server,
+            U256::from(400_000_000u64),
+            U256::ZERO,
+            crate::ODOS_V2_ETHEREUM_ROUTER,
+        )
+        .await;
+        let client = router_info_client(&server, crate::ODOS_V2_ETHEREUM_ROUTER).await;
+
+        let err = mainnet_swap(&client)
+            .ensure_ready(&rpc_provider(&server))
+            .await
+            .unwrap_err();
+
+        // Balance is checked before allowance
+        assert!(matches!(
+            err,
+            crate::OdosError::InsufficientBalance { shortfall, .. }
+                if shortfall == U256::from(600_000_000u64)
+        ));
+    }
+
+    #[cfg(feature = "multicall")]
+    #[tokio::test]
+    async fn test_preflight_checks_rotated_router_allowance() {
+        let server = MockServer::start().await;
+        mount_erc20(
+            &server,
+            U256::from(5_000_000_000u64),
+            U256::from(1_000_000_000u64),
+            ROTATED_ROUTER,
+        )
+        .await;
+        let client = router_info_client(&server, ROTATED_ROUTER).await;
+
+        let result = mainnet_swap(&client)
+            .ensure_ready(&rpc_provider(&server))
+            .await
+            .unwrap();
+        assert_eq!(result.allowance, Some(U256::from(1_000_000_000u64)));
+    }
+
+    #[cfg(feature = "multicall")]
+    #[tokio::test]
+    async fn test_preflight_native_input_checks_native_balance() {
+        let server = MockServer::start().await;
+        Mock::given(body_partial_json(
+            serde_json::json!({ "method": "eth_getBalance" }),
+        ))
+        .respond_with(|request: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "jsonrpc": "2.0", "id": body["id"], "result": "0xde0b6b3a7640000" }),
+            )
+        })
+        .mount(&server)
+        .await;
+        let client = router_info_client(&server, ROTATED_ROUTER).await;
+        let provider = rpc_provider(&server);
+
+        let swap = mainnet_swap(&client).from_native(U256::from(10u64.pow(18)));
+        let result = swap.ensure_ready(&provider).await.unwrap();
+        assert_eq!(result.balance, Some(U256::from(10u64.pow(18))));
+        assert_eq!(result.allowance, Some(U256::MAX));
+
+        let err = mainnet_swap(&client)
+            .from_native(U256::from(2 * 10u64.pow(18)))
+            .ensure_ready(&provider)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::InsufficientBalance { .. }));
+
+        let err = client.swap().preflight(&provider).await.unwrap_err();
+        assert!(err.to_string().contains("chain, input token, signer"));
+    }
+
+    #[tokio::test]
+    async fn test_approval_transaction_targets_swap_router() {
+        let server = MockServer::start().await;
+        let client = router_info_client(&server, crate::ODOS_V2_ETHEREUM_ROUTER).await;
+        let swap = mainnet_swap(&client);
+
+        let tx = swap
+            .approval_transaction(ApprovalAmount::Exact)
+            .await
+            .unwrap();
+        assert_eq!(tx.to, Some(MAINNET_USDC.into()));
+        assert_eq!(tx.from, swap.signer);
+        let input = tx.input.input().unwrap();
+        assert_eq!(&input[16..36], crate::ODOS_V2_ETHEREUM_ROUTER.as_slice());
+        assert_eq!(
+            U256::from_be_slice(&input[36..68]),
+            U256::from(1_000_000_000u64)
+        );
+
+        let tx = swap
+            .router(RouterType::V3)
+            .approval_transaction(ApprovalAmount::Max)
+            .await
+            .unwrap();
+        let input = tx.input.input().unwrap();
+        assert_eq!(U256::from_be_slice(&input[36..68]), U256::MAX);
+
+        let requested: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| request.url.path().to_string())
+            .collect();
+        assert_eq!(requested, ["/info/router/v2/1", "/info/router/v3/1"]);
+    }
+
+    #[tokio::test]
+    async fn test_approval_transaction_approves_rotated_router() {
+        let server = MockServer::start().await;
+        let client = router_info_client(&server, ROTATED_ROUTER).await;
+
+        let tx = mainnet_swap(&client)
+            .approval_transaction(ApprovalAmount::Exact)
+            .await
+            .unwrap();
+        let input = tx.input.input().unwrap();
+        assert_eq!(&input[16..36], ROTATED_ROUTER.as_slice());
+
+        // A mock backend has no router info endpoint
+        let api = crate::test_utils::MockOdosApi::new();
+        let tx = mock_swap(&api)
+            .approval_transaction(ApprovalAmount::Exact)
+            .await
+            .unwrap();
+        let input = tx.input.input().unwrap();
+        assert_eq!(&input[16..36], crate::ODOS_V2_ETHEREUM_ROUTER.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_two_step_approval_transactions() {
+        let server = MockServer::start().await;
+        let client = router_info_client(&server, ROTATED_ROUTER).await;
+
+        let [reset, approve] = mainnet_swap(&client)
+            .two_step_approval_transactions(ApprovalAmount::Exact)
+            .await
+            .unwrap();
+        let reset = reset.input.input().unwrap();
+        assert_eq!(&reset[16..36], ROTATED_ROUTER.as_slice());
+        assert!(U256::from_be_slice(&reset[36..68]).is_zero());
+        let approve = approve.input.input().unwrap();
+        assert_eq!(&approve[16..36], ROTATED_ROUTER.as_slice());
+        assert_eq!(
+            U256::from_be_slice(&approve[36..68]),
+            U256::from(1_000_000_000u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_approval_transaction_rejects_native_input() {
+        let server = MockServer::start().await;
+        let client = router_info_client(&server, ROTATED_ROUTER).await;
+
+        let err = mainnet_swap(&client)
+            .from_native(U256::from(1))
+            .approval_transaction(ApprovalAmount::Max)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+
+        let err = client
+            .swap()
+            .approval_transaction(ApprovalAmount::Max)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::MissingData(_)));
+    }
+
+    fn mock_swap(api: &dyn OdosApi) -> SwapBuilder<'_> {
+        SwapBuilder::with_api(api)
+            .chain(Chain::ethereum())
+            .from_token(MAINNET_USDC, U256::from(1_000_000_000u64))
+            .to_token(MAINNET_WETH)
+            .slippage(Slippage::percent(0.5).unwrap())
+            .signer(address!("47e2d28169738039755586743e2dfcf3bd643f86"))
+    }
+
+    #[tokio::test]
+    async fn test_with_api_quotes_and_assembles_through_api() {
+        use crate::test_utils::{MockCall, MockOdosApi};
+
+        let api = MockOdosApi::new();
+        api.push_quote(Ok(
+            serde_json::from_value(quote_body(FRESH_PATH_ID)).unwrap()
+        ))
+        .push_assemble(Ok(
+            TransactionRequest::default().to(crate::ODOS_V2_ETHEREUM_ROUTER)
+        ));
+
+        let tx = mock_swap(&api).build_transaction().await.unwrap();
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_ETHEREUM_ROUTER)
+        );
+
+        let calls = api.calls();
+        assert_eq!(calls.len(), 2);
+        assert!(matches!(calls[0], MockCall::Quote(_)));
+        let MockCall::Assemble(request) = &calls[1] else {
+            panic!("expected an assemble call, got {:?}", calls[1]);
+        };
+        assert_eq!(request.router_address(), crate::ODOS_V2_ETHEREUM_ROUTER);
+        assert_eq!(request.path_id().as_str(), FRESH_PATH_ID);
+    }
+
+    #[tokio::test]
+    async fn test_with_api_rejects_router_of_other_version() {
+        let api = crate::test_utils::MockOdosApi::new();
+
+        let err = mock_swap(&api)
+            .router(RouterType::V3)
+            .build_transaction()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::OdosError::InvalidInput(_)));
+        assert!(api.calls().is_empty());
     }
 }
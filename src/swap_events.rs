@@ -0,0 +1,213 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structured swap lifecycle events for audit and risk systems.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::SystemTime,
+};
+
+use alloy_primitives::{Address, B256, U256};
+use tokio::sync::mpsc;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::PathId;
+
+/// Lifecycle transition recorded in a [`SwapEventRecord`]
+///
+/// The `Execution*` transitions are reported only by flows that submit the
+/// transaction on the caller's behalf.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SwapEventKind {
+    /// A quote request is about to be sent
+    QuoteRequested {
+        chain_id: u64,
+        input_token: Address,
+        input_amount: U256,
+        output_tokens: Vec<Address>,
+    },
+    /// The API returned a quote
+    QuoteReceived {
+        path_id: PathId,
+        out_amounts: Vec<String>,
+        price_impact: Option<f64>,
+    },
+    /// An assemble request is about to be sent
+    AssembleRequested {
+        path_id: PathId,
+        signer: Address,
+        recipient: Address,
+    },
+    /// An unsigned transaction was built from the assembled swap
+    TransactionBuilt { to: Option<Address>, value: U256 },
+    /// A transaction was submitted to the network
+    ExecutionSubmitted { tx_hash: B256 },
+    /// A submitted transaction was mined successfully
+    ExecutionConfirmed {
+        tx_hash: B256,
+        block_number: Option<u64>,
+    },
+    /// Submission failed or the transaction reverted
+    ExecutionFailed {
+        tx_hash: Option<B256>,
+        reason: String,
+    },
+}
+
+/// A swap lifecycle event with its correlation context
+///
+/// Attach a channel with [`OdosClient::with_swap_events`](crate::OdosClient::with_swap_events)
+/// or [`SwapBuilder::events`](crate::SwapBuilder::events) and every
+/// [`SwapBuilder`](crate::SwapBuilder) run sends a record at each lifecycle
+/// transition.
+///
+/// # Ordering
+///
+/// - Records of one run share a `correlation_id` and are sent in lifecycle
+///   order from a single task, so they are received in that order.
+/// - `sequence` starts at 0 and increases by one per record of the run; a gap
+///   means records were dropped.
+/// - Records of concurrent runs sharing a channel may interleave.
+///
+/// # Backpressure
+///
+/// Records are sent with `try_send` so the swap path never waits on a slow
+/// consumer. When the channel is full or closed the record is dropped and
+/// counted; see [`OdosClient::dropped_swap_events`](crate::OdosClient::dropped_swap_events).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapEventRecord {
+    /// Identifier shared by every record of one swap run
+    pub correlation_id: Uuid,
+    /// Position of this record within its run, starting at 0
+    pub sequence: u32,
+    /// When the transition happened
+    pub timestamp: SystemTime,
+    /// The transition itself
+    pub kind: SwapEventKind,
+}
+
+/// Channel and drop counter shared by a client and its clones
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SwapEventSink {
+    sender: Option<mpsc::Sender<SwapEventRecord>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl SwapEventSink {
+    pub(crate) fn set_sender(&mut self, sender: mpsc::Sender<SwapEventRecord>) {
+        self.sender = Some(sender);
+    }
+
+    pub(crate) fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Start a run, sending to `sender` if given and to the sink's own
+    /// channel otherwise
+    pub(crate) fn run<'a>(
+        &'a self,
+        sender: Option<&'a mpsc::Sender<SwapEventRecord>>,
+    ) -> SwapEventRun<'a> {
+        SwapEventRun {
+            sender: sender.or(self.sender.as_ref()),
+            dropped: &self.dropped,
+            correlation_id: Uuid::new_v4(),
+            sequence: 0,
+        }
+    }
+}
+
+/// Emitter for the records of one swap run
+#[derive(Debug)]
+pub(crate) struct SwapEventRun<'a> {
+    sender: Option<&'a mpsc::Sender<SwapEventRecord>>,
+    dropped: &'a AtomicU64,
+    correlation_id: Uuid,
+    sequence: u32,
+}
+
+impl SwapEventRun<'_> {
+    /// Send a record without waiting; drops and counts it if the channel is
+    /// full or closed
+    pub(crate) fn emit(&mut self, kind: SwapEventKind) {
+        let Some(sender) = self.sender else {
+            return;
+        };
+
+        let record = SwapEventRecord {
+            correlation_id: self.correlation_id,
+            sequence: self.sequence,
+            timestamp: SystemTime::now(),
+            kind,
+        };
+        self.sequence += 1;
+
+        if let Err(err) = sender.try_send(record) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            debug!(
+                correlation_id = %self.correlation_id,
+                closed = matches!(err, mpsc::error::TrySendError::Closed(_)),
+                "Dropped swap event record"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submitted() -> SwapEventKind {
+        SwapEventKind::ExecutionSubmitted {
+            tx_hash: B256::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_run_records_share_correlation_and_sequence() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let sink = SwapEventSink::default();
+
+        let mut run = sink.run(Some(&tx));
+        run.emit(submitted());
+        run.emit(submitted());
+
+        let first = rx.try_recv().unwrap();
+        let second = rx.try_recv().unwrap();
+        assert_eq!(first.correlation_id, second.correlation_id);
+        assert_eq!((first.sequence, second.sequence), (0, 1));
+
+        // A new run gets a new correlation ID
+        sink.run(Some(&tx)).emit(submitted());
+        assert_ne!(rx.try_recv().unwrap().correlation_id, first.correlation_id);
+    }
+
+    #[test]
+    fn test_full_or_closed_channel_drops_and_counts() {
+        let (tx, rx) = mpsc::channel(1);
+        let mut sink = SwapEventSink::default();
+        sink.set_sender(tx);
+
+        let mut run = sink.run(None);
+        run.emit(submitted());
+        run.emit(submitted());
+        assert_eq!(sink.dropped(), 1);
+
+        drop(rx);
+        sink.run(None).emit(submitted());
+        assert_eq!(sink.dropped(), 2);
+    }
+
+    #[test]
+    fn test_run_without_channel_is_noop() {
+        let sink = SwapEventSink::default();
+        sink.run(None).emit(submitted());
+        assert_eq!(sink.dropped(), 0);
+    }
+}
@@ -0,0 +1,588 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_network::{Ethereum, TransactionBuilder};
+use alloy_primitives::{Address, B256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types::{BlockId, Log, TransactionRequest};
+use alloy_sol_types::SolEvent;
+use tracing::warn;
+
+use crate::{
+    decode_router_revert,
+    swap_events::{SwapEventKind, SwapEventRun},
+    OdosError, OdosV2Router, Result, RouterRevert, DEFAULT_GAS_BUFFER_PERCENT,
+};
+
+#[cfg(feature = "v3")]
+use crate::OdosV3Router;
+
+/// A swap transaction sent and mined by [`SwapBuilder::execute`](crate::SwapBuilder::execute)
+///
+/// The output amounts are read from the router's `Swap` or `SwapMulti`
+/// event, so they are what the swap actually delivered rather than the
+/// quoted amounts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapExecution {
+    /// Hash of the mined transaction
+    pub tx_hash: B256,
+    /// Block the transaction was mined in, if reported
+    pub block_number: Option<u64>,
+    /// Gas used by the transaction
+    pub gas_used: u64,
+    /// Price paid per unit of gas, in wei
+    pub effective_gas_price: u128,
+    /// `(token, amount)` delivered per output token, in base units
+    ///
+    /// Empty if the receipt has no swap event from the router.
+    pub outputs: Vec<(Address, U256)>,
+}
+
+impl SwapExecution {
+    /// Get the amount of `token` the swap delivered, in base units
+    pub fn output_amount(&self, token: Address) -> Option<U256> {
+        self.outputs
+            .iter()
+            .find_map(|(output, amount)| (*output == token).then_some(*amount))
+    }
+}
+
+/// Fill nonce, gas and fees for an assembled swap, send it and wait for its
+/// receipt
+pub(crate) async fn send_swap<P>(
+    provider: &P,
+    tx: TransactionRequest,
+    events: &mut SwapEventRun<'_>,
+) -> Result<SwapExecution>
+where
+    P: Provider<Ethereum>,
+{
+    let tx = match fill_transaction(provider, tx).await {
+        Ok(tx) => tx,
+        Err(err) => return Err(failed(events, None, err)),
+    };
+    let router = tx.to.and_then(|kind| kind.to().copied());
+
+    let pending = match provider.send_transaction(tx.clone()).await {
+        Ok(pending) => pending,
+        Err(err) => {
+            let err = OdosError::transaction_submission_error(format!(
+                "eth_sendTransaction failed: {err}"
+            ));
+            return Err(failed(events, None, err));
+        }
+    };
+    let tx_hash = *pending.tx_hash();
+    events.emit(SwapEventKind::ExecutionSubmitted { tx_hash });
+
+    let receipt = match pending.get_receipt().await {
+        Ok(receipt) => receipt,
+        Err(err) => {
+            let err = OdosError::transaction_confirmation_error(tx_hash, err.to_string());
+            return Err(failed(events, Some(tx_hash), err));
+        }
+    };
+
+    if !receipt.status() {
+        let reason = match receipt.block_number {
+            Some(block_number) => replay_revert(provider, tx, block_number).await,
+            None => None,
+        };
+        let err = OdosError::TransactionReverted {
+            tx_hash,
+            gas_used: receipt.gas_used,
+            reason,
+        };
+        return Err(failed(events, Some(tx_hash), err));
+    }
+
+    events.emit(SwapEventKind::ExecutionConfirmed {
+        tx_hash,
+        block_number: receipt.block_number,
+    });
+
+    let outputs = router
+        .map(|router| swap_outputs(receipt.inner.logs(), router))
+        .unwrap_or_default();
+    if outputs.is_empty() {
+        warn!(
+            tx_hash = %tx_hash,
+            router = ?router,
+            "Swap receipt has no router swap event; output amounts unknown"
+        );
+    }
+
+    Ok(SwapExecution {
+        tx_hash,
+        block_number: receipt.block_number,
+        gas_used: receipt.gas_used,
+        effective_gas_price: receipt.effective_gas_price,
+        outputs,
+    })
+}
+
+/// Set the pending nonce, a buffered `eth_estimateGas` limit and
+/// `eth_feeHistory`-based EIP-1559 fees on `tx`
+async fn fill_transaction<P>(provider: &P, tx: TransactionRequest) -> Result<TransactionRequest>
+where
+    P: Provider<Ethereum>,
+{
+    let signer = tx
+        .from
+        .ok_or_else(|| OdosError::transaction_assembly_error("Swap transaction has no sender"))?;
+
+    let nonce = provider
+        .get_transaction_count(signer)
+        .pending()
+        .await
+        .map_err(|err| {
+            OdosError::transaction_submission_error(format!(
+                "eth_getTransactionCount failed: {err}"
+            ))
+        })?;
+    let tx = tx.with_nonce(nonce);
+
+    let gas = provider.estimate_gas(tx.clone()).await.map_err(|err| {
+        let revert = err
+            .as_error_resp()
+            .and_then(|payload| payload.as_revert_data())
+            .and_then(|data| decode_router_revert(&data));
+        match revert {
+            Some(revert) => OdosError::gas_estimation_error(format!(
+                "eth_estimateGas failed: swap would revert: {revert}"
+            )),
+            None => OdosError::gas_estimation_error(format!("eth_estimateGas failed: {err}")),
+        }
+    })?;
+    let fees = provider
+        .estimate_eip1559_fees()
+        .await
+        .map_err(|err| OdosError::gas_estimation_error(format!("eth_feeHistory failed: {err}")))?;
+
+    Ok(tx
+        .with_gas_limit(buffered_gas(gas))
+        .with_max_fee_per_gas(fees.max_fee_per_gas)
+        .with_max_priority_fee_per_gas(fees.max_priority_fee_per_gas))
+}
+
+/// Replay a reverted swap with `eth_call` on the state before `block_number`
+/// to recover its revert reason
+///
+/// Best effort: transactions earlier in the same block are not replayed, and
+/// a failed call or unrecognized revert data gives `None`.
+async fn replay_revert<P>(
+    provider: &P,
+    tx: TransactionRequest,
+    block_number: u64,
+) -> Option<RouterRevert>
+where
+    P: Provider<Ethereum>,
+{
+    let block = BlockId::number(block_number.saturating_sub(1));
+    let err = provider.call(tx).block(block).await.err()?;
+    let data = err.as_error_resp()?.as_revert_data()?;
+    decode_router_revert(&data)
+}
+
+/// Apply [`DEFAULT_GAS_BUFFER_PERCENT`] to a node gas estimate, rounding up
+fn buffered_gas(estimate: u64) -> u64 {
+    let buffered = u128::from(estimate) * u128::from(100 + DEFAULT_GAS_BUFFER_PERCENT);
+    u64::try_from(buffered.div_ceil(100)).unwrap_or(u64::MAX)
+}
+
+/// Emit [`SwapEventKind::ExecutionFailed`] for `err` and return it
+fn failed(events: &mut SwapEventRun<'_>, tx_hash: Option<B256>, err: OdosError) -> OdosError {
+    events.emit(SwapEventKind::ExecutionFailed {
+        tx_hash,
+        reason: err.to_string(),
+    });
+    err
+}
+
+/// Get the `(token, amount)` outputs from the first swap event `router`
+/// emitted in `logs`
+fn swap_outputs(logs: &[Log], router: Address) -> Vec<(Address, U256)> {
+    logs.iter()
+        .filter(|log| log.address() == router)
+        .find_map(|log| decode_swap_outputs(&log.inner))
+        .unwrap_or_default()
+}
+
+/// Decode a V2 or V3 router `Swap` or `SwapMulti` event into its outputs
+///
+/// V3 events are only recognized when the `v3` feature is enabled.
+fn decode_swap_outputs(log: &alloy_primitives::Log) -> Option<Vec<(Address, U256)>> {
+    #[cfg(feature = "v3")]
+    {
+        if let Ok(event) = OdosV3Router::Swap::decode_log(log) {
+            return Some(vec![(event.outputToken, event.amountOut)]);
+        }
+        if let Ok(event) = OdosV3Router::SwapMulti::decode_log(log) {
+            return Some(zip_outputs(&event.tokensOut, &event.amountsOut));
+        }
+    }
+
+    if let Ok(event) = OdosV2Router::Swap::decode_log(log) {
+        return Some(vec![(event.outputToken, event.amountOut)]);
+    }
+    if let Ok(event) = OdosV2Router::SwapMulti::decode_log(log) {
+        return Some(zip_outputs(&event.tokensOut, &event.amountsOut));
+    }
+
+    None
+}
+
+fn zip_outputs(tokens: &[Address], amounts: &[U256]) -> Vec<(Address, U256)> {
+    tokens
+        .iter()
+        .copied()
+        .zip(amounts.iter().copied())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, b256, hex, Bytes, I256};
+    use alloy_provider::ProviderBuilder;
+    use alloy_signer_local::PrivateKeySigner;
+    use serde_json::{json, Value};
+    use tokio::sync::mpsc;
+    use wiremock::{matchers::body_partial_json, Mock, MockServer, Request, ResponseTemplate};
+
+    use super::*;
+    use crate::{revert::tests::SLIPPAGE_REVERT, swap_events::SwapEventSink, NATIVE_TOKEN};
+
+    const ROUTER: Address = address!("cf5540fffcdc3d510b18bfca6d2b9987b0772559");
+    const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const TX_HASH: B256 = b256!("5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060");
+
+    fn router_log(address: Address, data: alloy_primitives::LogData) -> Log {
+        Log {
+            inner: alloy_primitives::Log { address, data },
+            block_number: Some(16),
+            transaction_hash: Some(TX_HASH),
+            ..Default::default()
+        }
+    }
+
+    fn v2_swap_log(output_token: Address, amount_out: U256) -> Log {
+        let event = OdosV2Router::Swap {
+            sender: Address::repeat_byte(0x11),
+            inputAmount: U256::from(1_000_000),
+            inputToken: USDC,
+            amountOut: amount_out,
+            outputToken: output_token,
+            slippage: I256::ZERO,
+            referralCode: 0,
+        };
+        router_log(ROUTER, event.encode_log_data())
+    }
+
+    #[test]
+    fn test_swap_outputs_from_router_events() {
+        let amount = U256::from(400_000_000_000_000u64);
+
+        // A lookalike event from another contract is ignored
+        let logs = [
+            router_log(
+                Address::repeat_byte(0x99),
+                v2_swap_log(WETH, U256::from(1)).inner.data,
+            ),
+            v2_swap_log(WETH, amount),
+        ];
+        assert_eq!(swap_outputs(&logs, ROUTER), [(WETH, amount)]);
+
+        let multi = OdosV2Router::SwapMulti {
+            sender: Address::repeat_byte(0x11),
+            amountsIn: vec![U256::from(1_000_000)],
+            tokensIn: vec![USDC],
+            amountsOut: vec![U256::from(5), U256::from(7)],
+            tokensOut: vec![WETH, NATIVE_TOKEN],
+            referralCode: 0,
+        };
+        let logs = [router_log(ROUTER, multi.encode_log_data())];
+        assert_eq!(
+            swap_outputs(&logs, ROUTER),
+            [(WETH, U256::from(5)), (NATIVE_TOKEN, U256::from(7))]
+        );
+
+        let unrelated = router_log(
+            ROUTER,
+            alloy_primitives::LogData::new_unchecked(vec![], Bytes::new()),
+        );
+        assert!(swap_outputs(&[unrelated], ROUTER).is_empty());
+    }
+
+    #[cfg(feature = "v3")]
+    #[test]
+    fn test_swap_outputs_from_v3_event() {
+        let event = OdosV3Router::Swap {
+            sender: Address::repeat_byte(0x11),
+            inputAmount: U256::from(1_000_000),
+            inputToken: USDC,
+            amountOut: U256::from(42),
+            outputToken: WETH,
+            slippage: I256::ZERO,
+            referralCode: 0,
+            referralFee: 0,
+            referralFeeRecipient: Address::ZERO,
+        };
+        let logs = [router_log(ROUTER, event.encode_log_data())];
+
+        assert_eq!(swap_outputs(&logs, ROUTER), [(WETH, U256::from(42))]);
+    }
+
+    #[test]
+    fn test_buffered_gas() {
+        assert_eq!(buffered_gas(100_000), 120_000);
+        assert_eq!(buffered_gas(1), 2);
+        assert_eq!(buffered_gas(u64::MAX), u64::MAX);
+    }
+
+    /// Answer JSON-RPC `method` calls with `result`, echoing the request ID
+    async fn mount_rpc(server: &MockServer, method: &str, result: Value) {
+        Mock::given(body_partial_json(json!({ "method": method })))
+            .respond_with(move |request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "jsonrpc": "2.0", "id": body["id"], "result": result }))
+            })
+            .mount(server)
+            .await;
+    }
+
+    /// Mount every call a successful send makes, with a receipt of `status`
+    async fn mount_chain(server: &MockServer, status: &str, logs: &[Log]) {
+        mount_rpc(server, "eth_getTransactionCount", json!("0x7")).await;
+        mount_rpc(server, "eth_estimateGas", json!("0x30d40")).await;
+        mount_rpc(
+            server,
+            "eth_feeHistory",
+            json!({
+                "oldestBlock": "0xf",
+                "baseFeePerGas": ["0x3b9aca00", "0x3b9aca00"],
+                "gasUsedRatio": [0.5],
+                "reward": [["0x5f5e100"]]
+            }),
+        )
+        .await;
+        mount_rpc(server, "eth_sendRawTransaction", json!(TX_HASH)).await;
+        mount_rpc(server, "eth_blockNumber", json!("0x10")).await;
+        mount_rpc(
+            server,
+            "eth_getTransactionReceipt",
+            json!({
+                "transactionHash": TX_HASH,
+                "transactionIndex": "0x0",
+                "blockHash": B256::repeat_byte(0xbb),
+                "blockNumber": "0x10",
+                "from": Address::ZERO,
+                "to": ROUTER,
+                "cumulativeGasUsed": "0x2bf20",
+                "gasUsed": "0x2bf20",
+                "effectiveGasPrice": "0x4190ab00",
+                "contractAddress": null,
+                "logs": logs,
+                "logsBloom": alloy_primitives::Bloom::ZERO,
+                "type": "0x2",
+                "status": status
+            }),
+        )
+        .await;
+    }
+
+    fn swap_tx(signer: &PrivateKeySigner) -> TransactionRequest {
+        TransactionRequest::default()
+            .with_chain_id(1)
+            .with_from(signer.address())
+            .with_to(ROUTER)
+            .with_input(Bytes::from_static(&[0x83, 0xbd, 0x37, 0xf9]))
+    }
+
+    #[tokio::test]
+    async fn test_send_swap_returns_receipt_outputs() {
+        let server = MockServer::start().await;
+        let amount = U256::from(400_000_000_000_000u64);
+        mount_chain(&server, "0x1", &[v2_swap_log(WETH, amount)]).await;
+
+        let signer = PrivateKeySigner::random();
+        let tx = swap_tx(&signer);
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect_http(server.uri().parse().unwrap());
+        let (events_tx, mut events_rx) = mpsc::channel(8);
+        let sink = SwapEventSink::default();
+
+        let execution = send_swap(&provider, tx, &mut sink.run(Some(&events_tx)))
+            .await
+            .unwrap();
+
+        assert_eq!(execution.tx_hash, TX_HASH);
+        assert_eq!(execution.block_number, Some(16));
+        assert_eq!(execution.gas_used, 180_000);
+        assert_eq!(execution.effective_gas_price, 1_100_000_000);
+        assert_eq!(execution.output_amount(WETH), Some(amount));
+        assert_eq!(execution.output_amount(USDC), None);
+
+        assert_eq!(
+            events_rx.try_recv().unwrap().kind,
+            SwapEventKind::ExecutionSubmitted { tx_hash: TX_HASH }
+        );
+        assert_eq!(
+            events_rx.try_recv().unwrap().kind,
+            SwapEventKind::ExecutionConfirmed {
+                tx_hash: TX_HASH,
+                block_number: Some(16)
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_swap_reports_revert() {
+        let server = MockServer::start().await;
+        mount_chain(&server, "0x0", &[]).await;
+
+        let signer = PrivateKeySigner::random();
+        let tx = swap_tx(&signer);
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect_http(server.uri().parse().unwrap());
+        let (events_tx, mut events_rx) = mpsc::channel(8);
+        let sink = SwapEventSink::default();
+
+        let err = send_swap(&provider, tx, &mut sink.run(Some(&events_tx)))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            OdosError::TransactionReverted {
+                tx_hash: TX_HASH,
+                gas_used: 180_000,
+                reason: None,
+            }
+        ));
+        events_rx.try_recv().unwrap();
+        assert!(matches!(
+            events_rx.try_recv().unwrap().kind,
+            SwapEventKind::ExecutionFailed {
+                tx_hash: Some(TX_HASH),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_swap_reports_gas_estimation_failure() {
+        let server = MockServer::start().await;
+        Mock::given(body_partial_json(json!({ "method": "eth_estimateGas" })))
+            .respond_with(|request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "error": { "code": 3, "message": "execution reverted: Slippage Limit Exceeded" }
+                }))
+            })
+            .mount(&server)
+            .await;
+        mount_chain(&server, "0x1", &[]).await;
+
+        let signer = PrivateKeySigner::random();
+        let tx = swap_tx(&signer);
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect_http(server.uri().parse().unwrap());
+        let sink = SwapEventSink::default();
+
+        let err = send_swap(&provider, tx, &mut sink.run(None))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, OdosError::GasEstimation(_)));
+        assert!(err.to_string().contains("Slippage Limit Exceeded"));
+    }
+
+    /// Answer JSON-RPC calls matching `call` with an `execution reverted`
+    /// error carrying `data`
+    async fn mount_revert(server: &MockServer, call: Value, data: &[u8]) {
+        let data = hex::encode_prefixed(data);
+        Mock::given(body_partial_json(call))
+            .respond_with(move |request: &Request| {
+                let body: Value = serde_json::from_slice(&request.body).unwrap();
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "error": { "code": 3, "message": "execution reverted", "data": data }
+                }))
+            })
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_send_swap_decodes_estimate_gas_revert() {
+        let server = MockServer::start().await;
+        mount_revert(
+            &server,
+            json!({ "method": "eth_estimateGas" }),
+            &SLIPPAGE_REVERT,
+        )
+        .await;
+        mount_chain(&server, "0x1", &[]).await;
+
+        let signer = PrivateKeySigner::random();
+        let tx = swap_tx(&signer);
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect_http(server.uri().parse().unwrap());
+        let sink = SwapEventSink::default();
+
+        let err = send_swap(&provider, tx, &mut sink.run(None))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, OdosError::GasEstimation(_)));
+        assert!(err
+            .to_string()
+            .ends_with("swap would revert: Slippage Limit Exceeded"));
+    }
+
+    #[tokio::test]
+    async fn test_send_swap_replays_revert_reason() {
+        let server = MockServer::start().await;
+        // The replay runs on the state before the receipt's block 0x10
+        mount_revert(
+            &server,
+            json!({ "method": "eth_call", "params": [{}, "0xf"] }),
+            &SLIPPAGE_REVERT,
+        )
+        .await;
+        mount_chain(&server, "0x0", &[]).await;
+
+        let signer = PrivateKeySigner::random();
+        let tx = swap_tx(&signer);
+        let provider = ProviderBuilder::new()
+            .wallet(signer)
+            .connect_http(server.uri().parse().unwrap());
+        let sink = SwapEventSink::default();
+
+        let err = send_swap(&provider, tx, &mut sink.run(None))
+            .await
+            .unwrap_err();
+
+        let OdosError::TransactionReverted {
+            reason: Some(reason),
+            ..
+        } = &err
+        else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert!(reason.is_slippage_exceeded());
+        assert!(err
+            .to_string()
+            .ends_with("reverted after using 180000 gas: Slippage Limit Exceeded"));
+    }
+}
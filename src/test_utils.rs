@@ -0,0 +1,395 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test helpers for downstream crates.
+//!
+//! Enabled with the `test-utils` cargo feature. These types are intended for
+//! deterministic tests of code built on top of the SDK and are not meant for
+//! production use.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use alloy_primitives::Address;
+use alloy_rpc_types::TransactionRequest;
+
+use crate::{
+    ApiFuture, ApiVersion, AssemblyRequest, ContractInfo, LiquiditySourceInfo, OdosApi, OdosError,
+    QuoteRequest, Result, SingleQuoteResponse, SleepFuture, Sleeper, TokenInfo, TransactionData,
+};
+
+/// [`Sleeper`] that resolves immediately without waiting.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopSleeper;
+
+impl Sleeper for NoopSleeper {
+    fn sleep(&self, _duration: Duration) -> SleepFuture<'_> {
+        Box::pin(async {})
+    }
+}
+
+/// [`Sleeper`] that records every requested duration and resolves immediately.
+///
+/// Clones share the same recording, so a clone can be handed to
+/// [`ClientConfig`](crate::ClientConfig) while the original is kept for
+/// assertions.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use odos_sdk::{test_utils::RecordingSleeper, ClientConfig};
+///
+/// let sleeper = RecordingSleeper::new();
+/// let config = ClientConfig {
+///     sleeper: Arc::new(sleeper.clone()),
+///     ..Default::default()
+/// };
+///
+/// // ... drive requests through a client built from `config` ...
+///
+/// assert!(sleeper.durations().is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RecordingSleeper {
+    durations: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl RecordingSleeper {
+    /// Create a new recorder with no recorded sleeps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Durations requested so far, in call order.
+    pub fn durations(&self) -> Vec<Duration> {
+        self.durations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Number of sleeps requested so far.
+    pub fn count(&self) -> usize {
+        self.durations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// Sum of all requested durations.
+    pub fn total(&self) -> Duration {
+        self.durations().iter().sum()
+    }
+}
+
+impl Sleeper for RecordingSleeper {
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_> {
+        self.durations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(duration);
+        Box::pin(async {})
+    }
+}
+
+/// A call made to a [`MockOdosApi`], with its arguments
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockCall {
+    /// [`OdosApi::quote`]
+    Quote(QuoteRequest),
+    /// [`OdosApi::assemble_tx_data`]
+    AssembleTxData {
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: String,
+    },
+    /// [`OdosApi::assemble`]
+    Assemble(AssemblyRequest),
+    /// [`OdosApi::supported_tokens`]
+    SupportedTokens(u64),
+    /// [`OdosApi::contract_info`]
+    ContractInfo(u64),
+    /// [`OdosApi::liquidity_sources`]
+    LiquiditySources(u64),
+    /// [`OdosApi::supported_chains`]
+    SupportedChains,
+}
+
+#[derive(Debug, Default)]
+struct MockResponses {
+    quotes: VecDeque<Result<SingleQuoteResponse>>,
+    tx_data: VecDeque<Result<TransactionData>>,
+    assemblies: VecDeque<Result<TransactionRequest>>,
+    tokens: VecDeque<Result<Vec<TokenInfo>>>,
+    contract_info: VecDeque<Result<ContractInfo>>,
+    liquidity_sources: VecDeque<Result<Vec<LiquiditySourceInfo>>>,
+    chains: VecDeque<Result<Vec<u64>>>,
+    calls: Vec<MockCall>,
+}
+
+/// [`OdosApi`] that answers from queued responses and records its calls.
+///
+/// Each method answers with the next response pushed for it, in order, and
+/// fails with [`OdosError::MissingData`] once its queue is empty. Clones
+/// share the queues and the recording, so a clone can be handed to the code
+/// under test while the original is kept for assertions. The mock serves
+/// [`ApiVersion::V2`] unless set with [`with_api_version`](Self::with_api_version).
+///
+/// # Examples
+///
+/// Unit test swap logic built on [`SwapBuilder::with_api`](crate::SwapBuilder::with_api):
+///
+/// ```rust
+/// use alloy_primitives::{address, Address, U256};
+/// use alloy_rpc_types::TransactionRequest;
+/// use odos_sdk::{
+///     test_utils::{MockCall, MockOdosApi},
+///     Chain, OdosApi, Result, SingleQuoteResponse, Slippage, SwapBuilder,
+/// };
+///
+/// const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+/// const WETH: Address = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+///
+/// // Code under test
+/// async fn sell_usdc(api: &dyn OdosApi, signer: Address) -> Result<TransactionRequest> {
+///     SwapBuilder::with_api(api)
+///         .chain(Chain::ethereum())
+///         .from_token(USDC, U256::from(1_000_000))
+///         .to_token(WETH)
+///         .slippage(Slippage::standard())
+///         .signer(signer)
+///         .build_transaction()
+///         .await
+/// }
+///
+/// # tokio_test::block_on(async {
+/// let quote: SingleQuoteResponse = serde_json::from_value(serde_json::json!({
+///     "blockNumber": 1, "dataGasEstimate": 0, "gasEstimate": 196875.0,
+///     "gweiPerGas": 6.85, "inAmounts": ["1000000"], "inTokens": [USDC],
+///     "inValues": [1.0], "outAmounts": ["297096823183507"], "outTokens": [WETH],
+///     "outValues": [0.99], "pathId": "2f6e14ad1cfa589029f413791a0b7f6f",
+///     "priceImpact": 0.1
+/// }))?;
+/// let api = MockOdosApi::new();
+/// api.push_quote(Ok(quote));
+/// api.push_assemble(Ok(TransactionRequest::default().to(Address::repeat_byte(0xcf))));
+///
+/// let signer = Address::repeat_byte(0x11);
+/// sell_usdc(&api, signer).await?;
+///
+/// let calls = api.calls();
+/// assert_eq!(calls.len(), 2);
+/// assert!(matches!(calls[0], MockCall::Quote(_)));
+/// assert!(matches!(&calls[1], MockCall::Assemble(request) if request.signer_address() == signer));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockOdosApi {
+    api_version: ApiVersion,
+    responses: Arc<Mutex<MockResponses>>,
+}
+
+impl Default for MockOdosApi {
+    fn default() -> Self {
+        Self {
+            api_version: ApiVersion::V2,
+            responses: Arc::default(),
+        }
+    }
+}
+
+impl MockOdosApi {
+    /// Create a mock with no queued responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve `api_version` instead of [`ApiVersion::V2`].
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Queue the response to a [`quote`](OdosApi::quote) call.
+    pub fn push_quote(&self, response: Result<SingleQuoteResponse>) -> &Self {
+        self.lock().quotes.push_back(response);
+        self
+    }
+
+    /// Queue the response to an [`assemble_tx_data`](OdosApi::assemble_tx_data) call.
+    pub fn push_assemble_tx_data(&self, response: Result<TransactionData>) -> &Self {
+        self.lock().tx_data.push_back(response);
+        self
+    }
+
+    /// Queue the response to an [`assemble`](OdosApi::assemble) call.
+    pub fn push_assemble(&self, response: Result<TransactionRequest>) -> &Self {
+        self.lock().assemblies.push_back(response);
+        self
+    }
+
+    /// Queue the response to a [`supported_tokens`](OdosApi::supported_tokens) call.
+    pub fn push_supported_tokens(&self, response: Result<Vec<TokenInfo>>) -> &Self {
+        self.lock().tokens.push_back(response);
+        self
+    }
+
+    /// Queue the response to a [`contract_info`](OdosApi::contract_info) call.
+    pub fn push_contract_info(&self, response: Result<ContractInfo>) -> &Self {
+        self.lock().contract_info.push_back(response);
+        self
+    }
+
+    /// Queue the response to a [`liquidity_sources`](OdosApi::liquidity_sources) call.
+    pub fn push_liquidity_sources(&self, response: Result<Vec<LiquiditySourceInfo>>) -> &Self {
+        self.lock().liquidity_sources.push_back(response);
+        self
+    }
+
+    /// Queue the response to a [`supported_chains`](OdosApi::supported_chains) call.
+    pub fn push_supported_chains(&self, response: Result<Vec<u64>>) -> &Self {
+        self.lock().chains.push_back(response);
+        self
+    }
+
+    /// Calls made so far, in call order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.lock().calls.clone()
+    }
+
+    /// Record `call` and answer with the next response from `queue`.
+    fn answer<T>(
+        &self,
+        call: MockCall,
+        queue: impl FnOnce(&mut MockResponses) -> &mut VecDeque<Result<T>>,
+    ) -> Result<T> {
+        let mut responses = self.lock();
+        let name = match &call {
+            MockCall::Quote(_) => "quote",
+            MockCall::AssembleTxData { .. } => "assemble_tx_data",
+            MockCall::Assemble(_) => "assemble",
+            MockCall::SupportedTokens(_) => "supported_tokens",
+            MockCall::ContractInfo(_) => "contract_info",
+            MockCall::LiquiditySources(_) => "liquidity_sources",
+            MockCall::SupportedChains => "supported_chains",
+        };
+        responses.calls.push(call);
+        queue(&mut responses).pop_front().unwrap_or_else(|| {
+            Err(OdosError::missing_data(format!(
+                "MockOdosApi has no {name} response queued"
+            )))
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockResponses> {
+        self.responses.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl OdosApi for MockOdosApi {
+    fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    fn quote<'a>(&'a self, request: &'a QuoteRequest) -> ApiFuture<'a, SingleQuoteResponse> {
+        let response = self.answer(MockCall::Quote(request.clone()), |r| &mut r.quotes);
+        Box::pin(async { response })
+    }
+
+    fn assemble_tx_data<'a>(
+        &'a self,
+        signer_address: Address,
+        output_recipient: Address,
+        path_id: &'a str,
+    ) -> ApiFuture<'a, TransactionData> {
+        let call = MockCall::AssembleTxData {
+            signer_address,
+            output_recipient,
+            path_id: path_id.to_owned(),
+        };
+        let response = self.answer(call, |r| &mut r.tx_data);
+        Box::pin(async { response })
+    }
+
+    fn assemble<'a>(&'a self, request: &'a AssemblyRequest) -> ApiFuture<'a, TransactionRequest> {
+        let response = self.answer(MockCall::Assemble(request.clone()), |r| &mut r.assemblies);
+        Box::pin(async { response })
+    }
+
+    fn supported_tokens(&self, chain_id: u64) -> ApiFuture<'_, Vec<TokenInfo>> {
+        let response = self.answer(MockCall::SupportedTokens(chain_id), |r| &mut r.tokens);
+        Box::pin(async { response })
+    }
+
+    fn contract_info(&self, chain_id: u64) -> ApiFuture<'_, ContractInfo> {
+        let response = self.answer(MockCall::ContractInfo(chain_id), |r| &mut r.contract_info);
+        Box::pin(async { response })
+    }
+
+    fn liquidity_sources(&self, chain_id: u64) -> ApiFuture<'_, Vec<LiquiditySourceInfo>> {
+        let response = self.answer(MockCall::LiquiditySources(chain_id), |r| {
+            &mut r.liquidity_sources
+        });
+        Box::pin(async { response })
+    }
+
+    fn supported_chains(&self) -> ApiFuture<'_, Vec<u64>> {
+        let response = self.answer(MockCall::SupportedChains, |r| &mut r.chains);
+        Box::pin(async { response })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_recording_sleeper_records_in_order() {
+        let sleeper = RecordingSleeper::new();
+        let shared = sleeper.clone();
+
+        shared.sleep(Duration::from_millis(10)).await;
+        shared.sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(
+            sleeper.durations(),
+            vec![Duration::from_millis(10), Duration::from_millis(20)]
+        );
+        assert_eq!(sleeper.count(), 2);
+        assert_eq!(sleeper.total(), Duration::from_millis(30));
+    }
+
+    #[tokio::test]
+    async fn test_noop_sleeper_resolves() {
+        NoopSleeper.sleep(Duration::from_secs(3600)).await;
+    }
+
+    #[tokio::test]
+    async fn test_mock_api_answers_in_order_and_records_calls() {
+        let api = MockOdosApi::new().with_api_version(ApiVersion::V3);
+        let shared = api.clone();
+        api.push_supported_chains(Ok(vec![1, 10]))
+            .push_supported_chains(Err(OdosError::rate_limit_error("Slow down")));
+
+        assert_eq!(shared.api_version(), ApiVersion::V3);
+        assert_eq!(shared.supported_chains().await.unwrap(), [1, 10]);
+        assert!(shared.supported_chains().await.unwrap_err().is_rate_limit());
+        let err = shared.contract_info(8453).await.unwrap_err();
+        assert!(matches!(err, OdosError::MissingData(_)));
+
+        assert_eq!(
+            api.calls(),
+            [
+                MockCall::SupportedChains,
+                MockCall::SupportedChains,
+                MockCall::ContractInfo(8453)
+            ]
+        );
+    }
+}
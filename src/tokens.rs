@@ -0,0 +1,239 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wrapped native and stablecoin addresses for each supported chain.
+//!
+//! Exposed through [`Chain`] methods, so integrations do not need their own
+//! per-chain token tables:
+//!
+//! ```rust
+//! use odos_sdk::Chain;
+//!
+//! let chain = Chain::arbitrum();
+//! println!("Swap {} via {}", chain.native_symbol(), chain.wrapped_native_token());
+//! if let Some(usdc) = chain.usdc() {
+//!     println!("USDC: {usdc}");
+//! }
+//! ```
+//!
+//! Stablecoins are the canonical deployment most liquidity uses: Circle's
+//! native USDC where it exists, otherwise the established bridged token.
+//! A stablecoin without a clear canonical deployment on a chain is `None`.
+//! Like the router addresses, verify these before moving significant value.
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{address, Address};
+
+use crate::Chain;
+
+/// Token addresses for one chain
+struct ChainTokens {
+    wrapped_native: Address,
+    usdc: Option<Address>,
+    usdt: Option<Address>,
+    dai: Option<Address>,
+}
+
+/// Look up the token registry entry for `chain`, or `None` for chains
+/// without an Odos deployment
+const fn chain_tokens(chain: NamedChain) -> Option<ChainTokens> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => ChainTokens {
+            wrapped_native: address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+            usdc: Some(address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            usdt: Some(address!("dAC17F958D2ee523a2206206994597C13D831ec7")),
+            dai: Some(address!("6B175474E89094C44Da98b954EedeAC495271d0F")),
+        },
+        Optimism => ChainTokens {
+            wrapped_native: address!("4200000000000000000000000000000000000006"),
+            usdc: Some(address!("0b2C639c533813f4Aa9D7837CAf62653d097Ff85")),
+            usdt: Some(address!("94b008aA00579c1307B0EF2c499aD98a8ce58e58")),
+            dai: Some(address!("DA10009cBd5D07dd0CeCc66161FC93D7c9000da1")),
+        },
+        BinanceSmartChain => ChainTokens {
+            wrapped_native: address!("bb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+            // Binance-pegged tokens use 18 decimals on BSC
+            usdc: Some(address!("8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d")),
+            usdt: Some(address!("55d398326f99059fF775485246999027B3197955")),
+            dai: Some(address!("1AF3F329e8BE154074D8769D1FFa4eE058B1DBc3")),
+        },
+        Unichain => ChainTokens {
+            wrapped_native: address!("4200000000000000000000000000000000000006"),
+            usdc: Some(address!("078D782b760474a361dDA0AF3839290b0EF57AD6")),
+            usdt: None,
+            dai: None,
+        },
+        Polygon => ChainTokens {
+            wrapped_native: address!("0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+            usdc: Some(address!("3c499c542cEF5E3811e1192ce70d8cC03d5c3359")),
+            usdt: Some(address!("c2132D05D31c914a87C6611C10748AEb04B58e8F")),
+            dai: Some(address!("8f3Cf7ad23Cd3CaDbD9735AFf958023239c6A063")),
+        },
+        Sonic => ChainTokens {
+            wrapped_native: address!("039e2fB66102314Ce7b64Ce5Ce3E5183bc94aD38"),
+            usdc: Some(address!("29219dd400f2Bf60E5a23d13Be72B486D4038894")),
+            usdt: None,
+            dai: None,
+        },
+        Fraxtal => ChainTokens {
+            wrapped_native: address!("FC00000000000000000000000000000000000006"),
+            usdc: None,
+            usdt: None,
+            dai: None,
+        },
+        ZkSync => ChainTokens {
+            wrapped_native: address!("5AEa5775959fBC2557Cc8789bC1bf90A239D9a91"),
+            usdc: Some(address!("1d17CBcF0D6D143135aE902365D2E5e2A16538D4")),
+            usdt: Some(address!("493257fD37EDB34451f62EDf8D2a0C418852bA4C")),
+            dai: None,
+        },
+        Mantle => ChainTokens {
+            wrapped_native: address!("78c1b0C915c4FAA5FffA6CAbf0219DA63d7f4cb8"),
+            usdc: Some(address!("09Bc4E0D864854c6aFB6eB9A9cdF58aC190D0dF9")),
+            usdt: Some(address!("201EBa5CC46D216Ce6DC03F6a759e8E766e956aE")),
+            dai: None,
+        },
+        Base => ChainTokens {
+            wrapped_native: address!("4200000000000000000000000000000000000006"),
+            usdc: Some(address!("833589fCD6eDb6E08f4c7C32D4f71b54bdA02913")),
+            usdt: Some(address!("fde4C96c8593536E31F229EA8f37b2ADa2699bb2")),
+            dai: Some(address!("50c5725949A6F0c72E6C4a641F24049A917DB0Cb")),
+        },
+        Arbitrum => ChainTokens {
+            wrapped_native: address!("82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+            usdc: Some(address!("af88d065e77c8cC2239327C5EDb3A432268e5831")),
+            usdt: Some(address!("Fd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9")),
+            dai: Some(address!("DA10009cBd5D07dd0CeCc66161FC93D7c9000da1")),
+        },
+        Avalanche => ChainTokens {
+            wrapped_native: address!("B31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+            usdc: Some(address!("B97EF9Ef8734C71904D8002F8b6Bc66Dd9c48a6E")),
+            usdt: Some(address!("9702230A8Ea53601f5cD2dc00fDBc13d4dF4A8c7")),
+            // Bridged DAI.e
+            dai: Some(address!("d586E7F844cEa2F87f50152665BCbc2C279D8d70")),
+        },
+        Linea => ChainTokens {
+            wrapped_native: address!("e5D7C2a44FfDDf6b295A15c148167daaAf5Cf34f"),
+            usdc: Some(address!("176211869cA2b568f2A7D4EE941E073a821EE1ff")),
+            usdt: Some(address!("A219439258ca9da29E9Cc4cE5596924745e12B93")),
+            dai: Some(address!("4AF15ec2A0BD43Db75dd04E62FAA3B8EF36b00d5")),
+        },
+        _ => return None,
+    })
+}
+
+impl Chain {
+    /// Get the wrapped native token (WETH, WBNB, WPOL, WAVAX, ...)
+    ///
+    /// Returns [`Address::ZERO`] for a chain without an Odos deployment,
+    /// which can only be built with `From<NamedChain>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::address;
+    /// use odos_sdk::Chain;
+    ///
+    /// assert_eq!(
+    ///     Chain::ethereum().wrapped_native_token(),
+    ///     address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")
+    /// );
+    /// ```
+    pub const fn wrapped_native_token(&self) -> Address {
+        match chain_tokens(self.inner()) {
+            Some(tokens) => tokens.wrapped_native,
+            None => Address::ZERO,
+        }
+    }
+
+    /// Get the symbol of the chain's native gas token, e.g. `"ETH"` or
+    /// `"POL"`
+    ///
+    /// Shorthand for the [`metadata`](Self::metadata) symbol. Falls back to
+    /// `alloy_chains`' symbol, or `"ETH"`, for a chain without an Odos
+    /// deployment.
+    pub fn native_symbol(&self) -> &'static str {
+        match self.metadata() {
+            Some(metadata) => metadata.native_symbol,
+            None => self.inner().native_currency_symbol().unwrap_or("ETH"),
+        }
+    }
+
+    /// Get the canonical USDC deployment, if the chain has one
+    pub const fn usdc(&self) -> Option<Address> {
+        match chain_tokens(self.inner()) {
+            Some(tokens) => tokens.usdc,
+            None => None,
+        }
+    }
+
+    /// Get the canonical USDT deployment, if the chain has one
+    pub const fn usdt(&self) -> Option<Address> {
+        match chain_tokens(self.inner()) {
+            Some(tokens) => tokens.usdt,
+            None => None,
+        }
+    }
+
+    /// Get the canonical DAI deployment, if the chain has one
+    pub const fn dai(&self) -> Option<Address> {
+        match chain_tokens(self.inner()) {
+            Some(tokens) => tokens.dai,
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_chain_has_tokens() {
+        for chain in Chain::all() {
+            assert!(
+                chain_tokens(chain.inner()).is_some(),
+                "{chain} has no token registry entry"
+            );
+            assert!(!chain.wrapped_native_token().is_zero(), "{chain}");
+            assert!(!chain.native_symbol().is_empty(), "{chain}");
+            for stable in [chain.usdc(), chain.usdt(), chain.dai()]
+                .into_iter()
+                .flatten()
+            {
+                assert!(!stable.is_zero(), "{chain}");
+                assert_ne!(stable, chain.wrapped_native_token(), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_addresses() {
+        assert_eq!(
+            Chain::arbitrum().wrapped_native_token(),
+            address!("82aF49447D8a07e3bd95BD0d56f35241523fBab1")
+        );
+        assert_eq!(
+            Chain::base().usdc(),
+            Some(address!("833589fCD6eDb6E08f4c7C32D4f71b54bdA02913"))
+        );
+        assert_eq!(
+            Chain::ethereum().usdt(),
+            Some(address!("dAC17F958D2ee523a2206206994597C13D831ec7"))
+        );
+        assert_eq!(Chain::polygon().native_symbol(), "POL");
+        assert_eq!(Chain::avalanche().native_symbol(), "AVAX");
+        assert_eq!(Chain::fraxtal().dai(), None);
+    }
+
+    #[test]
+    fn test_chain_without_deployment() {
+        let sepolia = Chain::from(NamedChain::Sepolia);
+        assert_eq!(sepolia.wrapped_native_token(), Address::ZERO);
+        assert_eq!(sepolia.native_symbol(), "ETH");
+        assert_eq!(sepolia.usdc(), None);
+    }
+}
@@ -145,9 +145,9 @@ impl ValidatedSwapRequest {
     /// Build an Odos quote request from the validated swap inputs.
     pub fn quote_request(&self) -> QuoteRequest {
         QuoteRequest::builder()
-            .chain_id(self.chain.id())
+            .chain(self.chain)
             .input_tokens(vec![(self.input_token, self.input_amount).into()])
-            .output_tokens(vec![(self.output_token, 1).into()])
+            .output_tokens(vec![(self.output_token, 1.0).into()])
             .slippage_limit_percent(self.slippage.as_percent())
             .user_addr(self.signer)
             .compact(self.compact)
@@ -302,7 +302,13 @@ impl OdosClient {
     pub async fn build_transaction_plan(&self, request: &SwapRequest) -> Result<TransactionPlan> {
         let (validated, quote) = self.validated_quote(request).await?;
         let tx = self
-            .assemble_tx_data(validated.signer, validated.recipient, quote.path_id())
+            .assemble_tx_data_at(
+                self.config().endpoint.assemble_url(),
+                validated.signer,
+                validated.recipient,
+                quote.path_id().clone(),
+                None,
+            )
             .await?;
 
         Ok(TransactionPlan {
@@ -3,10 +3,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use alloy_chains::NamedChain;
+#[cfg(feature = "v2")]
+use alloy_network::TransactionBuilder;
+#[cfg(feature = "v2")]
+use alloy_primitives::Bytes;
 use alloy_primitives::{Address, U256};
+#[cfg(feature = "v2")]
+use alloy_rpc_types::TransactionRequest;
+#[cfg(feature = "v2")]
+use alloy_sol_types::SolCall;
+#[cfg(feature = "v2")]
+use bon::bon;
 use bon::Builder;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+#[cfg(feature = "v2")]
+use crate::{Chain, OdosChain, OdosError, OdosV2Router::transferRouterFundsCall};
+
 /// A transfer of a token from one address to another.
 #[derive(Builder, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct TransferRouterFunds {
@@ -48,6 +61,154 @@ impl TransferRouterFunds {
         // tokens, amounts, output_recipient
         (vec![self.token], vec![self.amount], self.to)
     }
+
+    /// Build the router call recovering this transfer's token
+    ///
+    /// Available only when the `v2` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// As for [`TransferRouterFundsRequest::builder`].
+    #[cfg(feature = "v2")]
+    pub fn request(&self) -> crate::Result<TransferRouterFundsRequest> {
+        TransferRouterFundsRequest::builder()
+            .chain(Chain::from(self.chain))
+            .tokens(vec![self.token])
+            .amounts(vec![self.amount])
+            .dest(self.to)
+            .build()
+    }
+}
+
+/// A `transferRouterFunds` call recovering tokens held by a router
+///
+/// Tokens sent to a router by mistake stay there until its owner transfers
+/// them out. This builds that call for the chain's V2 router (or its V3
+/// router, on chains without V2), which only the router owner can send.
+///
+/// Available only when the `v2` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::{address, U256};
+/// use odos_sdk::{Chain, TransferRouterFundsRequest, ODOS_V2_ETHEREUM_ROUTER};
+///
+/// let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+/// let owner = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+/// let request = TransferRouterFundsRequest::builder()
+///     .chain(Chain::ethereum())
+///     .tokens([usdc])
+///     .amounts([U256::from(1_000_000)])
+///     .dest(address!("0000000000000000000000000000000000000001"))
+///     .build()?;
+///
+/// assert_eq!(request.router_address(), ODOS_V2_ETHEREUM_ROUTER);
+/// let tx = request.transaction_request(owner);
+/// # Ok::<(), odos_sdk::OdosError>(())
+/// ```
+#[cfg(feature = "v2")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferRouterFundsRequest {
+    chain: Chain,
+    router_address: Address,
+    tokens: Vec<Address>,
+    amounts: Vec<U256>,
+    dest: Address,
+}
+
+#[cfg(feature = "v2")]
+#[bon]
+impl TransferRouterFundsRequest {
+    /// Build a router funds transfer of `amounts[i]` of each `tokens[i]` to
+    /// `dest`
+    ///
+    /// Use [`NATIVE_TOKEN`](crate::NATIVE_TOKEN) to recover the native
+    /// currency.
+    ///
+    /// # Errors
+    ///
+    /// - [`OdosError::InvalidInput`] if `tokens` is empty or `tokens` and
+    ///   `amounts` differ in length
+    /// - An error if the chain has no Odos swap router
+    #[builder]
+    pub fn new(
+        chain: Chain,
+        #[builder(into)] tokens: Vec<Address>,
+        #[builder(into)] amounts: Vec<U256>,
+        dest: Address,
+    ) -> crate::Result<Self> {
+        if tokens.is_empty() {
+            return Err(OdosError::invalid_input(
+                "Router funds transfer needs at least one token",
+            ));
+        }
+        if tokens.len() != amounts.len() {
+            return Err(OdosError::invalid_input(format!(
+                "Router funds transfer has {} tokens but {} amounts",
+                tokens.len(),
+                amounts.len()
+            )));
+        }
+
+        Ok(Self {
+            chain,
+            router_address: chain.v2_router_address()?,
+            tokens,
+            amounts,
+            dest,
+        })
+    }
+}
+
+#[cfg(feature = "v2")]
+impl TransferRouterFundsRequest {
+    /// Get the chain of the transfer
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    /// Get the router the funds are transferred out of
+    pub fn router_address(&self) -> Address {
+        self.router_address
+    }
+
+    /// Get the tokens to transfer
+    pub fn tokens(&self) -> &[Address] {
+        &self.tokens
+    }
+
+    /// Get the amount of each token to transfer, in base units
+    pub fn amounts(&self) -> &[U256] {
+        &self.amounts
+    }
+
+    /// Get the recipient of the funds
+    pub fn dest(&self) -> Address {
+        self.dest
+    }
+
+    /// Encode the `transferRouterFunds` calldata
+    pub fn calldata(&self) -> Bytes {
+        transferRouterFundsCall {
+            tokens: self.tokens.clone(),
+            amounts: self.amounts.clone(),
+            dest: self.dest,
+        }
+        .abi_encode()
+        .into()
+    }
+
+    /// Build the transaction, sent by the router `owner`
+    ///
+    /// Gas, fees and nonce are left for the caller or provider to fill.
+    pub fn transaction_request(&self, owner: Address) -> TransactionRequest {
+        TransactionRequest::default()
+            .with_from(owner)
+            .with_to(self.router_address)
+            .with_chain_id(self.chain.id())
+            .with_input(self.calldata())
+    }
 }
 
 impl Serialize for TransferRouterFunds {
@@ -79,3 +240,106 @@ impl<'de> Deserialize<'de> for TransferRouterFunds {
         })
     }
 }
+
+#[cfg(all(test, feature = "v2"))]
+mod tests {
+    use alloy_primitives::address;
+    use alloy_sol_types::SolInterface;
+
+    use super::*;
+    use crate::{OdosV2Router::OdosV2RouterCalls, NATIVE_TOKEN, ODOS_V2_ETHEREUM_ROUTER};
+
+    const USDC: Address = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+    const DEST: Address = address!("0000000000000000000000000000000000000001");
+    const OWNER: Address = address!("47e2d28169738039755586743e2dfcf3bd643f86");
+
+    fn request(
+        tokens: Vec<Address>,
+        amounts: Vec<U256>,
+    ) -> crate::Result<TransferRouterFundsRequest> {
+        TransferRouterFundsRequest::builder()
+            .chain(Chain::ethereum())
+            .tokens(tokens)
+            .amounts(amounts)
+            .dest(DEST)
+            .build()
+    }
+
+    #[test]
+    fn test_calldata_round_trips_through_bindings() {
+        let request = request(
+            vec![USDC, NATIVE_TOKEN],
+            vec![U256::from(1_000_000), U256::from(5)],
+        )
+        .unwrap();
+        let calldata = request.calldata();
+
+        // transferRouterFunds(address[],uint256[],address)
+        assert_eq!(calldata[..4], [0x17, 0x4d, 0xa6, 0x21]);
+        assert_eq!(calldata[..4], transferRouterFundsCall::SELECTOR);
+
+        let OdosV2RouterCalls::transferRouterFunds(call) =
+            OdosV2RouterCalls::abi_decode(&calldata).unwrap()
+        else {
+            panic!("not a transferRouterFunds call");
+        };
+        assert_eq!(call.tokens, [USDC, NATIVE_TOKEN]);
+        assert_eq!(call.amounts, [U256::from(1_000_000), U256::from(5)]);
+        assert_eq!(call.dest, DEST);
+    }
+
+    #[test]
+    fn test_transaction_request_targets_chain_router() {
+        let tx = request(vec![USDC], vec![U256::from(1)])
+            .unwrap()
+            .transaction_request(OWNER);
+        assert_eq!(tx.from, Some(OWNER));
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(ODOS_V2_ETHEREUM_ROUTER)
+        );
+        assert_eq!(tx.chain_id, Some(1));
+        assert_eq!(tx.value, None);
+
+        // A chain without an Odos router cannot be targeted
+        let sepolia = TransferRouterFundsRequest::builder()
+            .chain(Chain::from(NamedChain::Sepolia))
+            .tokens([USDC])
+            .amounts([U256::from(1)])
+            .dest(DEST)
+            .build();
+        assert!(sepolia.is_err());
+    }
+
+    #[test]
+    fn test_lists_are_validated() {
+        let err = request(vec![USDC], vec![]).unwrap_err();
+        assert!(matches!(err, OdosError::InvalidInput(_)));
+        assert!(err.to_string().contains("1 tokens but 0 amounts"));
+        assert!(matches!(
+            request(vec![], vec![]),
+            Err(OdosError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_single_transfer_request() {
+        let transfer = TransferRouterFunds::builder()
+            .chain(NamedChain::Mainnet)
+            .from(ODOS_V2_ETHEREUM_ROUTER)
+            .to(DEST)
+            .token(USDC)
+            .amount(U256::from(7))
+            .build();
+        let request = transfer.request().unwrap();
+
+        assert_eq!(request.tokens(), [USDC]);
+        assert_eq!(request.amounts(), [U256::from(7)]);
+        assert_eq!(request.dest(), DEST);
+        let (tokens, amounts, dest) = transfer.transfer_router_funds_params();
+        assert_eq!(
+            (tokens.as_slice(), amounts.as_slice(), dest),
+            (request.tokens(), request.amounts(), request.dest())
+        );
+    }
+}
@@ -5,9 +5,12 @@
 use std::{fmt, str::FromStr};
 
 use alloy_chains::NamedChain;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
-use crate::{OdosChain, OdosChainError, OdosChainResult};
+use crate::{
+    contract::DEPLOYMENTS, custom_chain::find_custom_chain, OdosChain, OdosChainError,
+    OdosChainResult, RouterType,
+};
 
 /// Type-safe chain identifier with convenient constructors
 ///
@@ -35,7 +38,27 @@ use crate::{OdosChain, OdosChainError, OdosChainResult};
 /// let router = chain.v3_router_address()?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// # Serialization
+///
+/// Serializes as the chain ID. Deserializes from either the chain ID or a
+/// name accepted by [`Chain::from_name`], so configuration files can say
+/// `"arbitrum"`:
+///
+/// ```rust
+/// use odos_sdk::Chain;
+///
+/// let chains: Vec<Chain> = serde_json::from_str(r#"[42161, "arbitrum"]"#)?;
+/// assert_eq!(chains, [Chain::arbitrum(), Chain::arbitrum()]);
+/// assert_eq!(serde_json::to_string(&Chain::arbitrum())?, "42161");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// The default is Ethereum mainnet.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(try_from = "ChainRepr", into = "u64")]
 pub struct Chain(NamedChain);
 
 impl Chain {
@@ -221,6 +244,37 @@ impl Chain {
         Self(NamedChain::Unichain)
     }
 
+    /// Iterate over every Odos-supported chain, in ascending chain ID order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Chain;
+    ///
+    /// let chains: Vec<Chain> = Chain::all().collect();
+    /// assert_eq!(chains[0], Chain::ethereum());
+    /// assert!(chains.contains(&Chain::base()));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Chain> {
+        DEPLOYMENTS.into_iter().map(|(chain, _)| Self(chain))
+    }
+
+    /// Iterate over the Odos-supported chains with a `router_type` router,
+    /// in ascending chain ID order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::{Chain, OdosChain, RouterType};
+    ///
+    /// for chain in Chain::all_supporting(RouterType::LimitOrder) {
+    ///     assert!(chain.supports_lo());
+    /// }
+    /// ```
+    pub fn all_supporting(router_type: RouterType) -> impl Iterator<Item = Chain> {
+        Self::all().filter(move |chain| chain.router_availability().has(router_type))
+    }
+
     /// Create a chain from a chain ID
     ///
     /// # Arguments
@@ -309,10 +363,29 @@ impl Chain {
         )
     }
 
+    /// Get the chain's canonical lowercase name, as used by Odos docs
+    ///
+    /// This is also the [`Display`](fmt::Display) output, and parses back
+    /// with [`Chain::from_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::Chain;
+    ///
+    /// assert_eq!(Chain::ethereum().as_str(), "mainnet");
+    /// assert_eq!(Chain::bsc().as_str(), "bsc");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        self.0.as_str()
+    }
+
     /// Parse a supported Odos chain from a common human-readable name or alias.
     ///
-    /// Accepts common aliases such as `mainnet`, `ethereum`, `arb`, `op`, and
-    /// numeric chain IDs encoded as strings.
+    /// Accepts the names returned by [`Chain::as_str`], common aliases such
+    /// as `ethereum`, `arb`, `op` and `binance`, and numeric chain IDs encoded
+    /// as strings. Unknown names fail with
+    /// [`OdosChainError::UnsupportedChain`].
     pub fn from_name(name: &str) -> OdosChainResult<Self> {
         let normalized = normalize_chain_name(name);
 
@@ -326,7 +399,9 @@ impl Chain {
             "optimism" | "op" => Ok(Self::optimism()),
             "polygon" | "matic" | "polygon pos" => Ok(Self::polygon()),
             "base" => Ok(Self::base()),
-            "bsc" | "bnb" | "bnb smart chain" | "binance smart chain" => Ok(Self::bsc()),
+            "bsc" | "bnb" | "binance" | "bnb smart chain" | "binance smart chain" => {
+                Ok(Self::bsc())
+            }
             "avalanche" | "avax" | "avalanche c chain" => Ok(Self::avalanche()),
             "linea" => Ok(Self::linea()),
             "zksync" | "zk sync" | "zksync era" => Ok(Self::zksync()),
@@ -334,9 +409,11 @@ impl Chain {
             "fraxtal" => Ok(Self::fraxtal()),
             "sonic" => Ok(Self::sonic()),
             "unichain" => Ok(Self::unichain()),
-            _ => Err(OdosChainError::UnsupportedChain {
-                chain: name.trim().to_string(),
-            }),
+            _ => find_custom_chain(|config| normalize_chain_name(&config.name) == normalized)
+                .map(Self)
+                .ok_or_else(|| OdosChainError::UnsupportedChain {
+                    chain: name.trim().to_string(),
+                }),
         }
     }
 }
@@ -352,7 +429,7 @@ fn normalize_chain_name(name: &str) -> String {
 
 impl fmt::Display for Chain {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        f.write_str(self.as_str())
     }
 }
 
@@ -382,24 +459,22 @@ impl FromStr for Chain {
     }
 }
 
-// Custom serialization using chain ID
-impl Serialize for Chain {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let chain_id: u64 = self.0.into();
-        chain_id.serialize(serializer)
-    }
+/// Serialized form of a [`Chain`]: a chain ID or a name
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChainRepr {
+    Id(u64),
+    Name(String),
 }
 
-impl<'de> Deserialize<'de> for Chain {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let chain_id = u64::deserialize(deserializer)?;
-        Self::from_chain_id(chain_id).map_err(serde::de::Error::custom)
+impl TryFrom<ChainRepr> for Chain {
+    type Error = OdosChainError;
+
+    fn try_from(repr: ChainRepr) -> Result<Self, Self::Error> {
+        match repr {
+            ChainRepr::Id(id) => Self::from_chain_id(id),
+            ChainRepr::Name(name) => Self::from_name(&name),
+        }
     }
 }
 
@@ -477,6 +552,74 @@ mod tests {
         assert!(Chain::from_name("sepolia").is_err());
     }
 
+    #[test]
+    fn test_all_matches_supported_chain_helpers() {
+        use crate::{
+            get_supported_chains, get_supported_lo_chains, get_supported_v2_chains,
+            get_supported_v3_chains,
+        };
+
+        assert_eq!(Chain::all().count(), get_supported_chains().len());
+        assert_eq!(
+            Chain::all_supporting(RouterType::LimitOrder).count(),
+            get_supported_lo_chains().len()
+        );
+        assert_eq!(
+            Chain::all_supporting(RouterType::V2).count(),
+            get_supported_v2_chains().len()
+        );
+        assert_eq!(
+            Chain::all_supporting(RouterType::V3).count(),
+            get_supported_v3_chains().len()
+        );
+
+        for chain in Chain::all() {
+            assert!(chain.supports_odos());
+            assert_eq!(Chain::from_chain_id(chain.id()).unwrap(), chain);
+        }
+    }
+
+    #[test]
+    fn test_name_round_trip() {
+        for chain in Chain::all() {
+            assert_eq!(chain.to_string(), chain.as_str());
+            assert_eq!(chain.as_str().parse::<Chain>().unwrap(), chain);
+            assert_eq!(
+                chain.as_str().to_uppercase().parse::<Chain>().unwrap(),
+                chain
+            );
+            assert_eq!(chain.id().to_string().parse::<Chain>().unwrap(), chain);
+        }
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!("ethereum".parse::<Chain>().unwrap(), Chain::ethereum());
+        assert_eq!("mainnet".parse::<Chain>().unwrap(), Chain::ethereum());
+        assert_eq!("bsc".parse::<Chain>().unwrap(), Chain::bsc());
+        assert_eq!("binance".parse::<Chain>().unwrap(), Chain::bsc());
+        assert!(matches!(
+            "dogechain".parse::<Chain>(),
+            Err(OdosChainError::UnsupportedChain { chain }) if chain == "dogechain"
+        ));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        for chain in Chain::all() {
+            let json = serde_json::to_string(&chain).unwrap();
+            assert_eq!(json, chain.id().to_string());
+            assert_eq!(serde_json::from_str::<Chain>(&json).unwrap(), chain);
+
+            let name = serde_json::to_string(chain.as_str()).unwrap();
+            assert_eq!(serde_json::from_str::<Chain>(&name).unwrap(), chain);
+        }
+
+        assert!(serde_json::from_str::<Chain>("999999").is_err());
+        assert!(serde_json::from_str::<Chain>(r#""sepolia""#).is_err());
+        assert!(serde_json::from_str::<Chain>("true").is_err());
+    }
+
     #[test]
     fn test_inner() {
         assert_eq!(Chain::ethereum().inner(), NamedChain::Mainnet);
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use alloy_chains::NamedChain;
+use alloy_primitives::{Address, TxHash};
+
+use super::Chain;
+
+/// Static metadata for a supported chain
+///
+/// Returned by [`Chain::metadata`]. Useful for user-facing explorer links
+/// and for staleness heuristics based on the block time.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::Chain;
+///
+/// let metadata = Chain::base().metadata().unwrap();
+/// assert_eq!(metadata.explorer_url, "https://basescan.org");
+/// assert_eq!(metadata.native_symbol, "ETH");
+/// assert_eq!(metadata.native_decimals, 18);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ChainMetadata {
+    /// Block explorer base URL, without a trailing slash
+    pub explorer_url: &'static str,
+
+    /// Symbol of the native gas token
+    pub native_symbol: &'static str,
+
+    /// Decimals of the native gas token
+    pub native_decimals: u8,
+
+    /// Approximate average block time
+    ///
+    /// A hint, not a guarantee: block times vary with load and upgrades.
+    pub block_time: Duration,
+}
+
+impl ChainMetadata {
+    const fn new(
+        explorer_url: &'static str,
+        native_symbol: &'static str,
+        block_time: Duration,
+    ) -> Self {
+        Self {
+            explorer_url,
+            native_symbol,
+            native_decimals: 18,
+            block_time,
+        }
+    }
+}
+
+/// Look up the metadata for `chain`, or `None` for chains without an Odos
+/// deployment
+const fn chain_metadata(chain: NamedChain) -> Option<ChainMetadata> {
+    use NamedChain::*;
+
+    const fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+    const fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    Some(match chain {
+        Mainnet => ChainMetadata::new("https://etherscan.io", "ETH", secs(12)),
+        Optimism => ChainMetadata::new("https://optimistic.etherscan.io", "ETH", secs(2)),
+        BinanceSmartChain => ChainMetadata::new("https://bscscan.com", "BNB", millis(750)),
+        Unichain => ChainMetadata::new("https://uniscan.xyz", "ETH", secs(1)),
+        Polygon => ChainMetadata::new("https://polygonscan.com", "POL", secs(2)),
+        Sonic => ChainMetadata::new("https://sonicscan.org", "S", secs(1)),
+        Fraxtal => ChainMetadata::new("https://fraxscan.com", "FRAX", secs(2)),
+        ZkSync => ChainMetadata::new("https://explorer.zksync.io", "ETH", secs(1)),
+        Mantle => ChainMetadata::new("https://mantlescan.xyz", "MNT", secs(2)),
+        Base => ChainMetadata::new("https://basescan.org", "ETH", secs(2)),
+        Arbitrum => ChainMetadata::new("https://arbiscan.io", "ETH", millis(250)),
+        Avalanche => ChainMetadata::new("https://snowscan.xyz", "AVAX", secs(2)),
+        Linea => ChainMetadata::new("https://lineascan.build", "ETH", secs(2)),
+        _ => return None,
+    })
+}
+
+impl Chain {
+    /// Get the chain's explorer, native currency and block time metadata
+    ///
+    /// Returns `None` for a chain without an Odos deployment, which can only
+    /// be built with `From<NamedChain>`.
+    pub const fn metadata(&self) -> Option<ChainMetadata> {
+        chain_metadata(self.inner())
+    }
+
+    /// Get the block explorer link for a transaction
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::TxHash;
+    /// use odos_sdk::Chain;
+    ///
+    /// let url = Chain::arbitrum().tx_url(TxHash::ZERO).unwrap();
+    /// assert_eq!(url, format!("https://arbiscan.io/tx/{}", TxHash::ZERO));
+    /// ```
+    pub fn tx_url(&self, tx_hash: TxHash) -> Option<String> {
+        self.metadata()
+            .map(|metadata| format!("{}/tx/{tx_hash}", metadata.explorer_url))
+    }
+
+    /// Get the block explorer link for an account or contract
+    pub fn address_url(&self, address: Address) -> Option<String> {
+        self.metadata()
+            .map(|metadata| format!("{}/address/{address}", metadata.explorer_url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::address;
+    use url::Url;
+
+    use super::*;
+    use crate::OdosChain;
+
+    #[test]
+    fn test_every_chain_has_well_formed_metadata() {
+        for chain in Chain::all() {
+            let metadata = chain
+                .metadata()
+                .unwrap_or_else(|| panic!("{chain} has no metadata"));
+
+            let explorer = Url::parse(metadata.explorer_url).unwrap();
+            assert_eq!(explorer.scheme(), "https", "{chain}");
+            assert!(!metadata.explorer_url.ends_with('/'), "{chain}");
+            assert!(!metadata.native_symbol.is_empty(), "{chain}");
+            assert!(metadata.block_time > Duration::ZERO, "{chain}");
+            assert!(metadata.block_time <= Duration::from_secs(12), "{chain}");
+
+            let router = chain.v3_router_address().unwrap();
+            let url = Url::parse(&chain.address_url(router).unwrap()).unwrap();
+            assert_eq!(url.host(), explorer.host(), "{chain}");
+            assert_eq!(url.path(), format!("/address/{router}"), "{chain}");
+
+            let tx_hash = TxHash::repeat_byte(0xab);
+            let url = Url::parse(&chain.tx_url(tx_hash).unwrap()).unwrap();
+            assert_eq!(url.path(), format!("/tx/{tx_hash}"), "{chain}");
+        }
+    }
+
+    #[test]
+    fn test_known_links() {
+        let weth = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        assert_eq!(
+            Chain::ethereum().address_url(weth).unwrap(),
+            "https://etherscan.io/address/0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"
+        );
+        assert_eq!(
+            Chain::ethereum().metadata().unwrap().block_time,
+            Duration::from_secs(12)
+        );
+        assert_eq!(Chain::from(NamedChain::Sepolia).metadata(), None);
+        assert_eq!(Chain::from(NamedChain::Sepolia).tx_url(TxHash::ZERO), None);
+    }
+}
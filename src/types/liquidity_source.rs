@@ -0,0 +1,171 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Liquidity source (DEX or protocol) name accepted by the Odos quote API
+///
+/// Used to restrict or exclude routing with
+/// [`SwapBuilder::allow_sources`](crate::SwapBuilder::allow_sources) and
+/// [`SwapBuilder::block_sources`](crate::SwapBuilder::block_sources). The
+/// named variants cover widely deployed sources; any other name returned by
+/// [`OdosClient::liquidity_sources`](crate::OdosClient::liquidity_sources)
+/// can be passed as [`Other`](Self::Other). Names are matched by the API
+/// exactly, including case and spacing.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::LiquiditySource;
+///
+/// assert_eq!(LiquiditySource::UniswapV3.as_str(), "Uniswap V3");
+///
+/// // Known names parse to their variant, anything else is kept verbatim
+/// assert_eq!(LiquiditySource::from("Curve Stable"), LiquiditySource::CurveStable);
+/// assert_eq!(
+///     LiquiditySource::from("Some New DEX"),
+///     LiquiditySource::Other("Some New DEX".to_string())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LiquiditySource {
+    /// Uniswap V2
+    UniswapV2,
+    /// Uniswap V3
+    UniswapV3,
+    /// Uniswap V4
+    UniswapV4,
+    /// SushiSwap
+    SushiSwap,
+    /// Curve stable pools
+    CurveStable,
+    /// Curve crypto pools
+    CurveCrypto,
+    /// Balancer V2 weighted pools
+    BalancerV2Weighted,
+    /// Balancer V2 stable pools
+    BalancerV2Stable,
+    /// PancakeSwap V2
+    PancakeSwap,
+    /// PancakeSwap V3
+    PancakeSwapV3,
+    /// Aerodrome Slipstream
+    AerodromeSlipstream,
+    /// Velodrome V2
+    VelodromeV2,
+    /// Maverick V2
+    MaverickV2,
+    /// Hashflow RFQ
+    Hashflow,
+    /// Any other source name, passed through verbatim
+    Other(String),
+}
+
+impl LiquiditySource {
+    /// All named variants with their API names
+    const KNOWN: [(Self, &'static str); 14] = [
+        (Self::UniswapV2, "Uniswap V2"),
+        (Self::UniswapV3, "Uniswap V3"),
+        (Self::UniswapV4, "Uniswap V4"),
+        (Self::SushiSwap, "SushiSwap"),
+        (Self::CurveStable, "Curve Stable"),
+        (Self::CurveCrypto, "Curve Crypto"),
+        (Self::BalancerV2Weighted, "Balancer V2 Weighted"),
+        (Self::BalancerV2Stable, "Balancer V2 Stable"),
+        (Self::PancakeSwap, "PancakeSwap"),
+        (Self::PancakeSwapV3, "PancakeSwap V3"),
+        (Self::AerodromeSlipstream, "Aerodrome Slipstream"),
+        (Self::VelodromeV2, "Velodrome V2"),
+        (Self::MaverickV2, "Maverick V2"),
+        (Self::Hashflow, "Hashflow"),
+    ];
+
+    /// Get the source name as sent to the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Other(name) => name,
+            known => Self::KNOWN
+                .iter()
+                .find(|(source, _)| source == known)
+                .map(|(_, name)| *name)
+                .expect("every named variant is listed in KNOWN"),
+        }
+    }
+}
+
+impl fmt::Display for LiquiditySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for LiquiditySource {
+    fn from(name: &str) -> Self {
+        Self::KNOWN
+            .iter()
+            .find(|(_, known)| *known == name)
+            .map(|(source, _)| source.clone())
+            .unwrap_or_else(|| Self::Other(name.to_string()))
+    }
+}
+
+impl From<String> for LiquiditySource {
+    fn from(name: String) -> Self {
+        Self::from(name.as_str())
+    }
+}
+
+impl From<LiquiditySource> for String {
+    fn from(source: LiquiditySource) -> Self {
+        match source {
+            LiquiditySource::Other(name) => name,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl Serialize for LiquiditySource {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LiquiditySource {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_names_round_trip() {
+        for (source, name) in LiquiditySource::KNOWN {
+            assert_eq!(source.as_str(), name);
+            assert_eq!(LiquiditySource::from(name), source);
+            assert_eq!(String::from(source), name);
+        }
+    }
+
+    #[test]
+    fn test_other_is_verbatim() {
+        // Matching is exact, so a differently cased name is not a known variant
+        let source = LiquiditySource::from("uniswap v3");
+        assert_eq!(source, LiquiditySource::Other("uniswap v3".to_string()));
+        assert_eq!(source.to_string(), "uniswap v3");
+    }
+
+    #[test]
+    fn test_serde_uses_api_name() {
+        let json = serde_json::to_string(&LiquiditySource::CurveStable).unwrap();
+        assert_eq!(json, "\"Curve Stable\"");
+
+        let source: LiquiditySource = serde_json::from_str("\"Hashflow\"").unwrap();
+        assert_eq!(source, LiquiditySource::Hashflow);
+    }
+}
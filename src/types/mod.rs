@@ -4,11 +4,23 @@
 
 /// Type-safe chain identifier with convenient constructors
 mod chain;
+/// Explorer, native currency and block time metadata per chain
+mod chain_metadata;
+/// Liquidity source names for routing restrictions
+mod liquidity_source;
+/// Validated Odos path ID
+mod path_id;
 /// Type-safe referral code
 mod referral;
 /// Type-safe slippage percentage with validation
 mod slippage;
+/// Token amount with its token and decimals
+mod token_amount;
 
 pub use chain::Chain;
-pub use referral::ReferralCode;
+pub use chain_metadata::ChainMetadata;
+pub use liquidity_source::LiquiditySource;
+pub use path_id::PathId;
+pub use referral::{ReferralCode, MAX_REFERRAL_FEE_BPS};
 pub use slippage::Slippage;
+pub use token_amount::TokenAmount;
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::{OdosError, Result};
+
+/// Validated Odos path ID
+///
+/// Path IDs are returned by the quote API and identify a route for the
+/// assemble API. They are officially opaque, but have a stable format of
+/// 32 hexadecimal characters. Validating that format catches an empty
+/// string or a transaction hash passed where a path ID belongs before the
+/// request is sent.
+///
+/// Only [`parse`](Self::parse) and the other conversions from user input
+/// check that format. Deserializing, as done for API responses, accepts any
+/// non-empty string, so a change to the API's format does not break
+/// decoding quotes.
+///
+/// # Examples
+///
+/// ```rust
+/// use odos_sdk::PathId;
+///
+/// let path_id = PathId::parse("2f6e14ad1cfa589029f413791a0b7f6f")?;
+/// assert_eq!(path_id.as_str(), "2f6e14ad1cfa589029f413791a0b7f6f");
+///
+/// // Empty strings and transaction hashes are rejected
+/// assert!(PathId::parse("").is_err());
+/// assert!(PathId::parse("0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(into = "String")]
+pub struct PathId(String);
+
+impl PathId {
+    /// Length of a path ID in characters
+    pub const LEN: usize = 32;
+
+    /// Parse and validate a path ID
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`] if `path_id` is not exactly
+    /// [`LEN`](Self::LEN) hexadecimal characters.
+    pub fn parse(path_id: &str) -> Result<Self> {
+        if path_id.is_empty() {
+            return Err(OdosError::invalid_input("Path ID cannot be empty"));
+        }
+        if path_id.len() != Self::LEN {
+            return Err(OdosError::invalid_input(format!(
+                "Path ID must be {} characters, got {}: '{path_id}'",
+                Self::LEN,
+                path_id.len()
+            )));
+        }
+        if !path_id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(OdosError::invalid_input(format!(
+                "Path ID must contain only hexadecimal characters: '{path_id}'"
+            )));
+        }
+        Ok(Self(path_id.to_string()))
+    }
+
+    /// Wrap a path ID returned by the API without checking its format
+    pub(crate) fn new_unchecked(path_id: String) -> Self {
+        Self(path_id)
+    }
+
+    /// Get the path ID as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for PathId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for PathId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for PathId {
+    type Err = OdosError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for PathId {
+    type Error = OdosError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&value)
+    }
+}
+
+impl TryFrom<&str> for PathId {
+    type Error = OdosError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::parse(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for PathId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let path_id = String::deserialize(deserializer)?;
+        if path_id.is_empty() {
+            return Err(de::Error::custom("Path ID cannot be empty"));
+        }
+        Ok(Self::new_unchecked(path_id))
+    }
+}
+
+impl From<PathId> for String {
+    fn from(path_id: PathId) -> Self {
+        path_id.0
+    }
+}
+
+impl From<&PathId> for PathId {
+    fn from(path_id: &PathId) -> Self {
+        path_id.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Path IDs as returned by the public quote API
+    const FIXTURE_IDS: [&str; 3] = [
+        "2f6e14ad1cfa589029f413791a0b7f6f",
+        "a1b2c3d4e5f60718293a4b5c6d7e8f90",
+        "0D4C3B2A19F8E7D6C5B4A39281706F5E",
+    ];
+
+    #[test]
+    fn test_parse_valid_ids() {
+        for id in FIXTURE_IDS {
+            let path_id = PathId::parse(id).unwrap();
+            assert_eq!(path_id.as_str(), id);
+            assert_eq!(path_id.to_string(), id);
+            assert_eq!(path_id.as_ref(), id);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        let rejected = [
+            "",
+            "path",
+            "not a path id at all, just text!",
+            // Transaction hash with and without prefix
+            "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+            "5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+            // Right length, wrong charset
+            "2f6e14ad1cfa589029f413791a0b7f6g",
+            " 2f6e14ad1cfa589029f413791a0b7f6",
+        ];
+
+        for id in rejected {
+            assert!(
+                matches!(PathId::parse(id), Err(OdosError::InvalidInput(_))),
+                "Expected {id:?} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde_validates() {
+        let path_id: PathId = serde_json::from_str("\"2f6e14ad1cfa589029f413791a0b7f6f\"").unwrap();
+        assert_eq!(
+            serde_json::to_string(&path_id).unwrap(),
+            "\"2f6e14ad1cfa589029f413791a0b7f6f\""
+        );
+
+        assert!(serde_json::from_str::<PathId>("\"\"").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_accepts_other_formats() {
+        let path_id: PathId = serde_json::from_str("\"route-2f6e14ad_v2\"").unwrap();
+        assert_eq!(path_id.as_str(), "route-2f6e14ad_v2");
+        assert!(PathId::parse(path_id.as_str()).is_err());
+    }
+}
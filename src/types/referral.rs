@@ -6,6 +6,19 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "v2")]
+use alloy_primitives::{Address, Bytes};
+#[cfg(feature = "v2")]
+use alloy_rpc_types::TransactionRequest;
+
+/// Highest fee the V2 router accepts when registering a fee-bearing code, in
+/// basis points of the swap output (2%)
+pub const MAX_REFERRAL_FEE_BPS: u16 = 200;
+
+/// Denominator of the router's `referralFee`, which is a fraction of 1e18
+#[cfg(feature = "v2")]
+const REFERRAL_FEE_DENOM: u64 = 1_000_000_000_000_000_000;
+
 /// Type-safe referral code
 ///
 /// Provides a clear, type-safe wrapper around referral codes with
@@ -43,6 +56,13 @@ impl ReferralCode {
     /// ```
     pub const NONE: Self = Self(0);
 
+    /// Codes above this value take a fee, registered with
+    /// [`registration_call`](Self::registration_call), from every swap
+    /// that uses them
+    ///
+    /// Codes up to and including it only attribute volume.
+    pub const FEE_THRESHOLD: u32 = 1 << 31;
+
     /// Create a new referral code
     ///
     /// # Arguments
@@ -105,6 +125,152 @@ impl ReferralCode {
     pub const fn is_some(&self) -> bool {
         self.0 != 0
     }
+
+    /// Create a fee-bearing referral code from a code without the fee bit
+    ///
+    /// Sets the high bit of `code`, which the router uses to tell fee-bearing
+    /// codes apart. Returns `None` if `code` is zero or already has the high
+    /// bit set, as the result would not be above [`FEE_THRESHOLD`](Self::FEE_THRESHOLD).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::ReferralCode;
+    ///
+    /// let code = ReferralCode::with_fee(42).unwrap();
+    /// assert_eq!(code.code(), 0x8000_002a);
+    /// assert!(code.has_fee());
+    /// assert_eq!(code.base_code(), 42);
+    ///
+    /// assert_eq!(ReferralCode::with_fee(0), None);
+    /// assert_eq!(ReferralCode::with_fee(1 << 31), None);
+    /// ```
+    pub const fn with_fee(code: u32) -> Option<Self> {
+        if code == 0 || code >= Self::FEE_THRESHOLD {
+            return None;
+        }
+        Some(Self(code | Self::FEE_THRESHOLD))
+    }
+
+    /// Check if swaps using this code pay a referral fee
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use odos_sdk::ReferralCode;
+    ///
+    /// assert!(!ReferralCode::new(42).has_fee());
+    /// assert!(!ReferralCode::new(ReferralCode::FEE_THRESHOLD).has_fee());
+    /// assert!(ReferralCode::new(ReferralCode::FEE_THRESHOLD + 1).has_fee());
+    /// ```
+    pub const fn has_fee(&self) -> bool {
+        self.0 > Self::FEE_THRESHOLD
+    }
+
+    /// Get the code without the fee bit
+    pub const fn base_code(&self) -> u32 {
+        self.0 & !Self::FEE_THRESHOLD
+    }
+
+    /// Encode the V2 router's `registerReferralCode` call for this code
+    ///
+    /// Registering a code sets the `beneficiary` that collects its fees and,
+    /// for a [fee-bearing](Self::has_fee) code, the fee of `fee_bps` basis
+    /// points of each swap's output. The call can be sent by anyone, once per
+    /// code and chain; a code must be registered before fees accrue to it.
+    ///
+    /// Available only when the `v2` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`](crate::OdosError::InvalidInput)
+    /// if the code is [`NONE`](Self::NONE), if `fee_bps` is above
+    /// [`MAX_REFERRAL_FEE_BPS`], or if a fee is set on a code that does not
+    /// take one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::address;
+    /// use odos_sdk::ReferralCode;
+    ///
+    /// let code = ReferralCode::with_fee(42).unwrap();
+    /// let calldata = code.registration_call(address!("00000000000000000000000000000000000000aa"), 25)?;
+    /// # Ok::<(), odos_sdk::OdosError>(())
+    /// ```
+    #[cfg(feature = "v2")]
+    pub fn registration_call(&self, beneficiary: Address, fee_bps: u16) -> crate::Result<Bytes> {
+        use alloy_sol_types::SolCall;
+
+        use crate::OdosV2Router::registerReferralCodeCall;
+
+        if self.is_none() {
+            return Err(crate::OdosError::invalid_input(
+                "Referral code 0 means no referral and cannot be registered",
+            ));
+        }
+        if fee_bps > MAX_REFERRAL_FEE_BPS {
+            return Err(crate::OdosError::invalid_input(format!(
+                "Referral fee of {fee_bps} bps exceeds the router maximum of {MAX_REFERRAL_FEE_BPS} bps"
+            )));
+        }
+        if fee_bps > 0 && !self.has_fee() {
+            return Err(crate::OdosError::invalid_input(format!(
+                "Referral code {} does not take a fee; use ReferralCode::with_fee for fee-bearing codes",
+                self.0
+            )));
+        }
+
+        Ok(registerReferralCodeCall {
+            _referralCode: self.0,
+            _referralFee: u64::from(fee_bps) * (REFERRAL_FEE_DENOM / 10_000),
+            _beneficiary: beneficiary,
+        }
+        .abi_encode()
+        .into())
+    }
+
+    /// Build the transaction registering this code with `chain`'s V2 router,
+    /// sent by `from`
+    ///
+    /// Gas, fees and nonce are left for the caller or provider to fill.
+    ///
+    /// Available only when the `v2` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// As for [`registration_call`](Self::registration_call), and
+    /// [`OdosError::InvalidInput`](crate::OdosError::InvalidInput) if the
+    /// chain has no V2 router. Referral codes are registered with the V2
+    /// router only, so this does not fall back to V3 like
+    /// [`OdosChain::v2_router_address`](crate::OdosChain::v2_router_address).
+    #[cfg(feature = "v2")]
+    pub fn registration_transaction(
+        &self,
+        chain: crate::Chain,
+        from: Address,
+        beneficiary: Address,
+        fee_bps: u16,
+    ) -> crate::Result<TransactionRequest> {
+        use alloy_network::TransactionBuilder;
+
+        use crate::OdosChain;
+
+        if !chain.supports_v2() {
+            return Err(crate::OdosError::invalid_input(format!(
+                "Chain {} has no Odos V2 router to register referral code {} with",
+                chain.id(),
+                self.0
+            )));
+        }
+
+        let calldata = self.registration_call(beneficiary, fee_bps)?;
+        Ok(TransactionRequest::default()
+            .with_from(from)
+            .with_to(chain.v2_router_address()?)
+            .with_chain_id(chain.id())
+            .with_input(calldata))
+    }
 }
 
 impl Default for ReferralCode {
@@ -204,6 +370,112 @@ mod tests {
         assert!(ReferralCode::NONE < ReferralCode::new(1));
     }
 
+    #[test]
+    fn test_fee_bit_layout() {
+        let code = ReferralCode::with_fee(1).unwrap();
+        assert_eq!(code.code(), 0x8000_0001);
+        assert!(code.has_fee());
+        assert_eq!(code.base_code(), 1);
+
+        let code = ReferralCode::with_fee(0x7fff_ffff).unwrap();
+        assert_eq!(code.code(), u32::MAX);
+        assert_eq!(code.base_code(), 0x7fff_ffff);
+
+        assert_eq!(ReferralCode::with_fee(0), None);
+        assert_eq!(ReferralCode::with_fee(0x8000_0001), None);
+        // The threshold itself is not above the threshold, so takes no fee
+        assert!(!ReferralCode::new(ReferralCode::FEE_THRESHOLD).has_fee());
+        assert!(!ReferralCode::new(42).has_fee());
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_registration_call() {
+        use alloy_primitives::{address, U256};
+        use alloy_sol_types::SolCall;
+
+        use crate::OdosV2Router::registerReferralCodeCall;
+
+        let beneficiary = address!("00000000000000000000000000000000000000aa");
+        let code = ReferralCode::with_fee(42).unwrap();
+        let call =
+            registerReferralCodeCall::abi_decode(&code.registration_call(beneficiary, 25).unwrap())
+                .unwrap();
+        assert_eq!(call._referralCode, 0x8000_002a);
+        // 25 bps of the router's 1e18 fee denominator
+        assert_eq!(
+            U256::from(call._referralFee),
+            U256::from(25u64) * U256::from(10u64).pow(U256::from(14))
+        );
+        assert_eq!(call._beneficiary, beneficiary);
+
+        let call = registerReferralCodeCall::abi_decode(
+            &ReferralCode::new(42)
+                .registration_call(beneficiary, 0)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(call._referralFee, 0);
+
+        let tx = code
+            .registration_transaction(crate::Chain::base(), beneficiary, beneficiary, 200)
+            .unwrap();
+        assert_eq!(
+            tx.to.and_then(|kind| kind.to().copied()),
+            Some(crate::ODOS_V2_BASE_ROUTER)
+        );
+        assert_eq!(tx.chain_id, Some(8453));
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_registration_transaction_requires_v2_router() {
+        use alloy_chains::NamedChain;
+
+        // A V3-only chain, whose V2 router lookup would fall back to V3
+        let chain_id = u64::from(NamedChain::BaseSepolia);
+        let chain = crate::Chain::register_custom(
+            chain_id,
+            crate::ChainConfig {
+                name: "v3-only".to_string(),
+                v2_router: None,
+                v3_router: Some(crate::ODOS_V3),
+                lo_router: None,
+            },
+        )
+        .unwrap();
+        let beneficiary = alloy_primitives::Address::repeat_byte(0xaa);
+
+        let result =
+            ReferralCode::new(42).registration_transaction(chain, beneficiary, beneficiary, 0);
+        crate::Chain::unregister_custom(chain_id);
+
+        match result {
+            Err(crate::OdosError::InvalidInput(message)) => {
+                assert!(message.contains("no Odos V2 router"), "{message}")
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    #[test]
+    fn test_registration_call_validation() {
+        let beneficiary = alloy_primitives::Address::repeat_byte(0xaa);
+        let message = |result: crate::Result<alloy_primitives::Bytes>| match result {
+            Err(crate::OdosError::InvalidInput(message)) => message,
+            other => panic!("unexpected result: {other:?}"),
+        };
+
+        let code = ReferralCode::with_fee(42).unwrap();
+        assert!(message(code.registration_call(beneficiary, 201)).contains("exceeds"));
+        assert!(
+            message(ReferralCode::new(42).registration_call(beneficiary, 1))
+                .contains("does not take a fee")
+        );
+        assert!(message(ReferralCode::NONE.registration_call(beneficiary, 0)).contains("0"));
+    }
+
     #[test]
     fn test_serialization() {
         let code = ReferralCode::new(42);
@@ -2,10 +2,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt;
+use std::{fmt, str::FromStr};
 
+use alloy_primitives::U256;
 use serde::{Deserialize, Serialize};
 
+/// Slippage resolution of [`Slippage::apply_to`]: parts per million, a
+/// hundredth of a basis point
+const PPM: u64 = 1_000_000;
+
 /// Type-safe slippage percentage with validation
 ///
 /// Ensures slippage values are within valid ranges and provides convenient
@@ -29,10 +34,17 @@ use serde::{Deserialize, Serialize};
 /// assert!(Slippage::percent(150.0).is_err());  // > 100%
 /// assert!(Slippage::percent(-1.0).is_err());   // < 0%
 /// assert!(Slippage::bps(15000).is_err());      // > 10000 bps
+///
+/// // Parse from configuration
+/// let slippage: Slippage = "0.5%".parse()?;
+/// assert_eq!(slippage, Slippage::standard());
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
+///
+/// Serialized as the percentage number; deserializing validates it as
+/// [`Slippage::percent`] does.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
-#[serde(transparent)]
+#[serde(into = "f64", try_from = "f64")]
 pub struct Slippage(f64);
 
 impl Slippage {
@@ -64,6 +76,9 @@ impl Slippage {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn percent(percent: f64) -> Result<Self, String> {
+        if percent.is_nan() {
+            return Err("Slippage percentage must be a number".to_string());
+        }
         if percent < 0.0 {
             return Err(format!("Slippage percentage cannot be negative: {percent}"));
         }
@@ -143,6 +158,44 @@ impl Slippage {
         (self.0 * 100.0).round() as u16
     }
 
+    /// Minimum output for a quoted `amount` after this slippage
+    ///
+    /// Computed in integer math at a resolution of a hundredth of a basis
+    /// point and rounded down, so the result never exceeds `amount`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::U256;
+    /// use odos_sdk::Slippage;
+    ///
+    /// let quoted = U256::from(1_000_000u64);
+    /// assert_eq!(Slippage::standard().apply_to(quoted), U256::from(995_000u64));
+    /// assert_eq!(Slippage::MIN.apply_to(quoted), quoted);
+    /// assert_eq!(Slippage::MAX.apply_to(quoted), U256::ZERO);
+    /// ```
+    pub fn apply_to(&self, amount: U256) -> U256 {
+        let denominator = U256::from(PPM);
+        let keep = U256::from(PPM - self.as_ppm());
+
+        // Split so that neither product can overflow: quotient * keep is at
+        // most amount and remainder * keep below PPM²
+        let (quotient, remainder) = amount.div_rem(denominator);
+        quotient * keep + remainder * keep / denominator
+    }
+
+    /// Slippage in parts per million, the resolution of [`Self::apply_to`]
+    fn as_ppm(&self) -> u64 {
+        // Percent to parts per million; validation bounds this to 0..=PPM
+        ((self.0 * (PPM / 100) as f64).round() as u64).min(PPM)
+    }
+
+    /// No slippage: the minimum output is the quoted amount
+    pub const MIN: Self = Self(0.0);
+
+    /// 100% slippage: any output is accepted
+    pub const MAX: Self = Self(100.0);
+
     /// Common slippage values for convenience
     pub const ZERO: Result<Self, &'static str> = Ok(Self(0.0));
 
@@ -173,6 +226,37 @@ impl fmt::Display for Slippage {
     }
 }
 
+/// Parses a percentage such as `"0.5%"` or `"0.5"`, or basis points such
+/// as `"50bps"`
+impl FromStr for Slippage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let trimmed = s.trim();
+        if let Some(bps) = trimmed.strip_suffix("bps") {
+            let bps = bps
+                .trim_end()
+                .parse()
+                .map_err(|_| format!("Invalid slippage basis points: '{s}'"))?;
+            return Self::bps(bps);
+        }
+
+        let percent = trimmed.strip_suffix('%').unwrap_or(trimmed).trim_end();
+        let percent = percent
+            .parse()
+            .map_err(|_| format!("Invalid slippage percentage: '{s}'"))?;
+        Self::percent(percent)
+    }
+}
+
+impl TryFrom<f64> for Slippage {
+    type Error = String;
+
+    fn try_from(percent: f64) -> Result<Self, String> {
+        Self::percent(percent)
+    }
+}
+
 impl From<Slippage> for f64 {
     fn from(slippage: Slippage) -> Self {
         slippage.0
@@ -286,6 +370,99 @@ mod tests {
         assert_eq!(deserialized.as_percent(), 0.5);
     }
 
+    #[test]
+    fn test_nan_is_rejected() {
+        assert!(Slippage::percent(f64::NAN).is_err());
+        assert!(serde_json::from_str::<Slippage>("NaN").is_err());
+    }
+
+    #[test]
+    fn test_deserialization_is_validated() {
+        assert!(serde_json::from_str::<Slippage>("150.0").is_err());
+        assert!(serde_json::from_str::<Slippage>("-1").is_err());
+        assert_eq!(
+            serde_json::from_str::<Slippage>("100").unwrap(),
+            Slippage::MAX
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0.5%".parse::<Slippage>().unwrap(), Slippage::standard());
+        assert_eq!(" 0.5 % ".parse::<Slippage>().unwrap(), Slippage::standard());
+        assert_eq!("1".parse::<Slippage>().unwrap(), Slippage::medium());
+        assert_eq!("300bps".parse::<Slippage>().unwrap(), Slippage::high());
+        assert_eq!("10 bps".parse::<Slippage>().unwrap(), Slippage::low());
+        assert_eq!("100%".parse::<Slippage>().unwrap(), Slippage::MAX);
+
+        assert!("".parse::<Slippage>().is_err());
+        assert!("%".parse::<Slippage>().is_err());
+        assert!("half".parse::<Slippage>().is_err());
+        assert!("0.5bps".parse::<Slippage>().is_err());
+        assert!("101%".parse::<Slippage>().is_err());
+        assert!("-0.5%".parse::<Slippage>().is_err());
+        assert!("NaN%".parse::<Slippage>().is_err());
+    }
+
+    #[test]
+    fn test_display_round_trips_through_from_str() {
+        for slippage in [
+            Slippage::MIN,
+            Slippage::low(),
+            Slippage::high(),
+            Slippage::MAX,
+        ] {
+            assert_eq!(slippage.to_string().parse::<Slippage>().unwrap(), slippage);
+        }
+    }
+
+    #[test]
+    fn test_apply_to() {
+        let quoted = U256::from(1_000_000u64);
+        assert_eq!(Slippage::low().apply_to(quoted), U256::from(999_000u64));
+        assert_eq!(
+            Slippage::standard().apply_to(quoted),
+            U256::from(995_000u64)
+        );
+        assert_eq!(Slippage::high().apply_to(quoted), U256::from(970_000u64));
+        assert_eq!(Slippage::MIN.apply_to(quoted), quoted);
+        assert_eq!(Slippage::MAX.apply_to(quoted), U256::ZERO);
+
+        // Sub-basis-point slippage is kept, and results round down
+        let slippage = Slippage::percent(0.125).unwrap();
+        assert_eq!(
+            slippage.apply_to(U256::from(10_000u64)),
+            U256::from(9_987u64)
+        );
+        assert_eq!(
+            Slippage::standard().apply_to(U256::from(199u64)),
+            U256::from(198u64)
+        );
+        assert_eq!(Slippage::standard().apply_to(U256::ZERO), U256::ZERO);
+
+        // No overflow at the top of the range
+        assert_eq!(Slippage::MIN.apply_to(U256::MAX), U256::MAX);
+        assert!(Slippage::standard().apply_to(U256::MAX) < U256::MAX);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_apply_to_matches_reference(
+            bytes in proptest::prelude::any::<[u8; 32]>(),
+            ppm in 0..=PPM,
+        ) {
+            use alloy_primitives::U512;
+
+            let amount = U256::from_be_bytes(bytes);
+            let slippage = Slippage::percent(ppm as f64 / (PPM / 100) as f64).unwrap();
+            let min_output = slippage.apply_to(amount);
+
+            let reference = U512::from(amount) * U512::from(PPM - ppm) / U512::from(PPM);
+            proptest::prop_assert!(min_output <= amount);
+            proptest::prop_assert_eq!(U512::from(min_output), reference);
+        }
+    }
+
     #[test]
     fn test_equality_and_ordering() {
         let s1 = Slippage::percent(0.5).unwrap();
@@ -0,0 +1,436 @@
+// SPDX-FileCopyrightText: 2025 Semiotic AI, Inc.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::{OdosError, Result};
+
+/// Token amount that carries its token and decimals
+///
+/// Keeping the decimals with the raw amount means a human-readable amount
+/// is always converted with the token's own precision, rather than with a
+/// separately looked-up value that may belong to another token (6 for USDC
+/// where 18 was meant, say).
+///
+/// Conversions are exact: [`from_human`](Self::from_human) rejects more
+/// fractional digits than the token has rather than truncating them, and
+/// [`to_human_string`](Self::to_human_string) prints every significant
+/// digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use alloy_primitives::{address, U256};
+/// use odos_sdk::TokenAmount;
+///
+/// let usdc = address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+///
+/// let amount = TokenAmount::from_human(usdc, "1.5", 6)?;
+/// assert_eq!(amount.amount(), U256::from(1_500_000));
+/// assert_eq!(amount.to_human_string(), "1.5");
+///
+/// // USDC has no seventh decimal place
+/// assert!(TokenAmount::from_human(usdc, "1.0000001", 6).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenAmount {
+    token: Address,
+    amount: U256,
+    decimals: u8,
+}
+
+impl TokenAmount {
+    /// Create a token amount from an amount in base units
+    pub fn new(token: Address, amount: U256, decimals: u8) -> Self {
+        Self {
+            token,
+            amount,
+            decimals,
+        }
+    }
+
+    /// Parse a human-readable amount such as `"1.5"` into base units
+    ///
+    /// Accepts digits with an optional fractional part. Fractional digits
+    /// past `decimals` must be zeros.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OdosError::InvalidInput`] if `value` is not a plain
+    /// non-negative decimal number, has more significant fractional digits
+    /// than `decimals`, or does not fit in a `U256` in base units.
+    pub fn from_human(token: Address, value: &str, decimals: u8) -> Result<Self> {
+        let trimmed = value.trim();
+        let (integer, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty()
+            || !is_digits(integer)
+            || !is_digits(fraction)
+            || (trimmed.contains('.') && fraction.is_empty())
+        {
+            return Err(OdosError::invalid_input(format!(
+                "Invalid token amount '{value}'; expected a decimal number such as 1.5"
+            )));
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > usize::from(decimals) {
+            return Err(OdosError::invalid_input(format!(
+                "Token amount '{value}' has more than {decimals} decimal places"
+            )));
+        }
+
+        let digits = format!(
+            "{integer}{fraction:0<width$}",
+            width = usize::from(decimals)
+        );
+        let amount = U256::from_str_radix(&digits, 10).map_err(|_| {
+            OdosError::invalid_input(format!(
+                "Token amount '{value}' with {decimals} decimals overflows a uint256"
+            ))
+        })?;
+
+        Ok(Self::new(token, amount, decimals))
+    }
+
+    /// Get the token address
+    pub fn token(&self) -> Address {
+        self.token
+    }
+
+    /// Get the amount in base units
+    pub fn amount(&self) -> U256 {
+        self.amount
+    }
+
+    /// Get the token's decimals
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Format the amount exactly, without trailing fractional zeros
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::{Address, U256};
+    /// use odos_sdk::TokenAmount;
+    ///
+    /// let amount = TokenAmount::new(Address::ZERO, U256::from(1_250_000_000_000_000_000u64), 18);
+    /// assert_eq!(amount.to_human_string(), "1.25");
+    /// ```
+    pub fn to_human_string(&self) -> String {
+        let (integer, fraction) = self.split_digits();
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{integer}.{fraction}")
+        }
+    }
+
+    /// Format the amount with exactly `places` fractional digits, rounding
+    /// half up
+    ///
+    /// For display only: parse amounts with [`from_human`](Self::from_human)
+    /// from the exact [`to_human_string`](Self::to_human_string) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use alloy_primitives::{Address, U256};
+    /// use odos_sdk::TokenAmount;
+    ///
+    /// let amount = TokenAmount::new(Address::ZERO, U256::from(1_005_000), 6);
+    /// assert_eq!(amount.to_human_string_rounded(2), "1.01");
+    /// assert_eq!(amount.to_human_string_rounded(8), "1.00500000");
+    /// ```
+    pub fn to_human_string_rounded(&self, places: usize) -> String {
+        let (integer, fraction) = self.split_digits();
+        if places >= fraction.len() {
+            return match places {
+                0 => integer.to_string(),
+                _ => format!("{integer}.{fraction:0<places$}"),
+            };
+        }
+
+        // Round the kept digits as one decimal string, so a carry can
+        // propagate into the integer part and past U256::MAX
+        let mut kept = format!("{integer}{}", &fraction[..places]).into_bytes();
+        if fraction.as_bytes()[places] >= b'5' {
+            let carried = kept.iter_mut().rev().all(|digit| {
+                let overflow = *digit == b'9';
+                *digit = if overflow { b'0' } else { *digit + 1 };
+                overflow
+            });
+            if carried {
+                kept.insert(0, b'1');
+            }
+        }
+
+        let kept = String::from_utf8(kept).expect("decimal digits are ASCII");
+        let (integer, fraction) = kept.split_at(kept.len() - places);
+        match places {
+            0 => integer.to_string(),
+            _ => format!("{integer}.{fraction}"),
+        }
+    }
+
+    /// Add an amount of the same token
+    ///
+    /// Returns `None` on overflow, or if `other` is of another token or has
+    /// other decimals.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.same_token(&other)?;
+        Some(Self {
+            amount: self.amount.checked_add(other.amount)?,
+            ..self
+        })
+    }
+
+    /// Subtract an amount of the same token
+    ///
+    /// Returns `None` on underflow, or if `other` is of another token or has
+    /// other decimals.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.same_token(&other)?;
+        Some(Self {
+            amount: self.amount.checked_sub(other.amount)?,
+            ..self
+        })
+    }
+
+    /// Multiply by a scalar, returning `None` on overflow
+    pub fn checked_mul(self, factor: U256) -> Option<Self> {
+        Some(Self {
+            amount: self.amount.checked_mul(factor)?,
+            ..self
+        })
+    }
+
+    /// Divide by a scalar, rounding down; returns `None` if `divisor` is zero
+    pub fn checked_div(self, divisor: U256) -> Option<Self> {
+        Some(Self {
+            amount: self.amount.checked_div(divisor)?,
+            ..self
+        })
+    }
+
+    fn same_token(&self, other: &Self) -> Option<()> {
+        (self.token == other.token && self.decimals == other.decimals).then_some(())
+    }
+
+    /// Split the amount's decimal digits into integer and fractional parts,
+    /// the latter padded to `decimals` digits
+    fn split_digits(&self) -> (String, String) {
+        let decimals = usize::from(self.decimals);
+        let digits = format!("{:0>width$}", self.amount, width = decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+        (integer.to_string(), fraction.to_string())
+    }
+}
+
+impl From<TokenAmount> for (Address, U256) {
+    fn from(amount: TokenAmount) -> Self {
+        (amount.token, amount.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: Address = Address::repeat_byte(0x11);
+
+    fn parse(value: &str, decimals: u8) -> Result<TokenAmount> {
+        TokenAmount::from_human(TOKEN, value, decimals)
+    }
+
+    fn amount(value: U256, decimals: u8) -> TokenAmount {
+        TokenAmount::new(TOKEN, value, decimals)
+    }
+
+    #[test]
+    fn test_from_human() {
+        assert_eq!(parse("1.5", 6).unwrap().amount(), U256::from(1_500_000));
+        assert_eq!(
+            parse("1.5", 18).unwrap().amount(),
+            U256::from(1_500_000_000_000_000_000u64)
+        );
+        assert_eq!(parse("42", 0).unwrap().amount(), U256::from(42));
+        assert_eq!(parse("0", 18).unwrap().amount(), U256::ZERO);
+        assert_eq!(parse("0.000001", 6).unwrap().amount(), U256::from(1));
+        assert_eq!(parse("007.50", 2).unwrap().amount(), U256::from(750));
+        assert_eq!(parse(" 2 ", 1).unwrap().amount(), U256::from(20));
+
+        let parsed = parse("1", 6).unwrap();
+        assert_eq!(parsed.token(), TOKEN);
+        assert_eq!(parsed.decimals(), 6);
+    }
+
+    #[test]
+    fn test_from_human_rejects_malformed() {
+        for value in [
+            "", " ", ".", ".5", "1.", "-1", "+1", "1.2.3", "1,5", "1_000", "1e6", "0x10",
+        ] {
+            assert!(parse(value, 6).is_err(), "accepted '{value}'");
+        }
+    }
+
+    #[test]
+    fn test_from_human_rejects_excess_precision() {
+        let err = parse("1.0000001", 6).unwrap_err();
+        assert!(err.to_string().contains("more than 6 decimal places"));
+        assert!(parse("0.5", 0).is_err());
+
+        // Trailing zeros past the token's decimals are not significant
+        assert_eq!(
+            parse("1.50000000", 6).unwrap().amount(),
+            U256::from(1_500_000)
+        );
+        assert_eq!(parse("3.000", 0).unwrap().amount(), U256::from(3));
+    }
+
+    #[test]
+    fn test_from_human_overflow() {
+        let max = U256::MAX.to_string();
+        assert_eq!(parse(&max, 0).unwrap().amount(), U256::MAX);
+
+        let err = parse(&format!("{max}0"), 0).unwrap_err();
+        assert!(err.to_string().contains("overflows"));
+
+        // The same digits overflow once scaled by the decimals
+        assert!(parse(&max, 1).is_err());
+        assert!(parse("1", 78).is_err());
+
+        // Large decimals are fine while the base amount fits
+        let tiny = format!("0.{}1", "0".repeat(199));
+        assert_eq!(parse(&tiny, 200).unwrap().amount(), U256::from(1));
+    }
+
+    #[test]
+    fn test_to_human_string() {
+        assert_eq!(amount(U256::from(1_500_000), 6).to_human_string(), "1.5");
+        assert_eq!(amount(U256::from(1), 6).to_human_string(), "0.000001");
+        assert_eq!(amount(U256::from(2_000_000), 6).to_human_string(), "2");
+        assert_eq!(amount(U256::ZERO, 18).to_human_string(), "0");
+        assert_eq!(amount(U256::from(42), 0).to_human_string(), "42");
+        assert_eq!(
+            amount(U256::from(1), 200).to_human_string(),
+            format!("0.{}1", "0".repeat(199))
+        );
+        assert_eq!(
+            amount(U256::MAX, 18).to_human_string(),
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+    }
+
+    #[test]
+    fn test_human_string_round_trips() {
+        for (value, decimals) in [
+            (U256::ZERO, 6),
+            (U256::from(1), 18),
+            (U256::from(123_456_789), 6),
+            (U256::from(10).pow(U256::from(20)), 18),
+            (U256::MAX, 0),
+            (U256::MAX, 18),
+            (U256::MAX, 77),
+        ] {
+            let original = amount(value, decimals);
+            assert_eq!(
+                parse(&original.to_human_string(), decimals).unwrap(),
+                original
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_human_string_rounded() {
+        let value = amount(U256::from(1_234_567), 6);
+        assert_eq!(value.to_human_string_rounded(0), "1");
+        assert_eq!(value.to_human_string_rounded(2), "1.23");
+        assert_eq!(value.to_human_string_rounded(4), "1.2346");
+        assert_eq!(value.to_human_string_rounded(6), "1.234567");
+        assert_eq!(value.to_human_string_rounded(8), "1.23456700");
+
+        // Half rounds up, with carries into the integer part
+        assert_eq!(
+            amount(U256::from(1_005_000), 6).to_human_string_rounded(2),
+            "1.01"
+        );
+        assert_eq!(
+            amount(U256::from(1_004_999), 6).to_human_string_rounded(2),
+            "1.00"
+        );
+        assert_eq!(
+            amount(U256::from(9_999_500), 6).to_human_string_rounded(3),
+            "10.000"
+        );
+        assert_eq!(
+            amount(U256::from(500_000), 6).to_human_string_rounded(0),
+            "1"
+        );
+        assert_eq!(
+            amount(U256::from(499_999), 6).to_human_string_rounded(0),
+            "0"
+        );
+
+        // Whole amounts still get the requested places
+        assert_eq!(
+            amount(U256::from(42), 0).to_human_string_rounded(2),
+            "42.00"
+        );
+        assert_eq!(amount(U256::from(42), 0).to_human_string_rounded(0), "42");
+
+        // Rounding past U256::MAX does not overflow
+        assert_eq!(
+            amount(U256::MAX, 1).to_human_string_rounded(0),
+            "11579208923731619542357098500868790785326998466564056403945758400791312963994"
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let one = amount(U256::from(1_000_000), 6);
+        let half = amount(U256::from(500_000), 6);
+
+        assert_eq!(one.checked_add(half).unwrap().to_human_string(), "1.5");
+        assert_eq!(one.checked_sub(half), Some(half));
+        assert_eq!(half.checked_sub(one), None);
+        assert_eq!(amount(U256::MAX, 6).checked_add(half), None);
+
+        assert_eq!(
+            half.checked_mul(U256::from(3)).unwrap().to_human_string(),
+            "1.5"
+        );
+        assert_eq!(amount(U256::MAX, 6).checked_mul(U256::from(2)), None);
+        assert_eq!(
+            one.checked_div(U256::from(3)).unwrap().amount(),
+            U256::from(333_333)
+        );
+        assert_eq!(one.checked_div(U256::ZERO), None);
+    }
+
+    #[test]
+    fn test_checked_arithmetic_rejects_mismatched_tokens() {
+        let one = amount(U256::from(1_000_000), 6);
+
+        let other_token = TokenAmount::new(Address::ZERO, U256::from(1), 6);
+        assert_eq!(one.checked_add(other_token), None);
+        assert_eq!(one.checked_sub(other_token), None);
+
+        let other_decimals = amount(U256::from(1), 18);
+        assert_eq!(one.checked_add(other_decimals), None);
+        assert_eq!(one.checked_sub(other_decimals), None);
+    }
+
+    #[test]
+    fn test_into_pair() {
+        let pair: (Address, U256) = amount(U256::from(7), 6).into();
+        assert_eq!(pair, (TOKEN, U256::from(7)));
+    }
+}
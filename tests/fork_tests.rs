@@ -8,6 +8,12 @@
 //! They require Anvil to be installed and available in PATH.
 //!
 //! Run with: `cargo test --test fork_tests -- --ignored`
+//!
+//! The swap simulation tests instead run when `ODOS_SDK_FORK_URL` is set to
+//! a mainnet RPC URL to fork, and the `provider` feature is enabled:
+//! `ODOS_SDK_FORK_URL=... cargo test --test fork_tests --features provider`
+//! The same variable enables the tests that decode real mainnet router logs
+//! from the blocks after [`FORK_BLOCK`].
 
 use alloy_chains::NamedChain;
 use alloy_primitives::Address;
@@ -92,6 +98,188 @@ async fn test_v2_router_exists_on_fork() {
     assert_ne!(owner, Address::ZERO, "owner should not be zero address");
 }
 
+/// RPC URL the swap simulation tests fork, and the mainnet swap log tests
+/// read from; they are skipped when unset
+#[cfg(any(feature = "provider", feature = "v2"))]
+const FORK_URL_VAR: &str = "ODOS_SDK_FORK_URL";
+
+/// Get the RPC URL to fork, or `None` to skip the test
+#[cfg(any(feature = "provider", feature = "v2"))]
+fn fork_url() -> Option<String> {
+    let url = std::env::var(FORK_URL_VAR).ok();
+    if url.is_none() {
+        eprintln!("{FORK_URL_VAR} is not set; skipping");
+    }
+    url
+}
+
+/// Tests that a swap of tokens the sender does not hold fails simulation.
+#[cfg(feature = "provider")]
+#[tokio::test]
+async fn test_simulate_swap_reports_revert_on_fork() {
+    use alloy_network::TransactionBuilder;
+    use alloy_primitives::{address, U256};
+    use alloy_rpc_types::TransactionRequest;
+    use alloy_sol_types::SolCall;
+    use odos_sdk::{
+        simulate_swap, OdosError, OdosRouterV2::swapTokenInfo, OdosV2Router::swapCall,
+        ODOS_V2_ETHEREUM_ROUTER,
+    };
+
+    let Some(url) = fork_url() else { return };
+    let provider = ProviderBuilder::new()
+        .connect_anvil_with_config(|anvil| anvil.fork(url).fork_block_number(FORK_BLOCK));
+
+    let sender = address!("000000000000000000000000000000000000dEaD");
+    let call = swapCall {
+        tokenInfo: swapTokenInfo {
+            inputToken: address!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            inputAmount: U256::from(1_000_000_000_000u64),
+            inputReceiver: ODOS_V2_ETHEREUM_ROUTER,
+            outputToken: address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+            outputQuote: U256::from(1),
+            outputMin: U256::from(1),
+            outputReceiver: sender,
+        },
+        pathDefinition: Default::default(),
+        executor: ODOS_V2_ETHEREUM_ROUTER,
+        referralCode: 0,
+    };
+    let tx = TransactionRequest::default()
+        .with_from(sender)
+        .with_to(ODOS_V2_ETHEREUM_ROUTER)
+        .with_input(call.abi_encode());
+
+    let err = simulate_swap(&provider, &tx).await.unwrap_err();
+    assert!(
+        matches!(err, OdosError::SimulationReverted { .. }),
+        "unexpected error: {err:?}"
+    );
+}
+
+/// Tests that a swap assembled by the Odos API simulates on a fork of the
+/// latest block. Also needs access to the Odos API.
+#[cfg(feature = "provider")]
+#[tokio::test]
+async fn test_simulate_assembled_swap_on_fork() {
+    use alloy_primitives::{address, U256};
+    use odos_sdk::{simulate_swap, Chain, OdosClient, Slippage};
+
+    let Some(url) = fork_url() else { return };
+    let provider = ProviderBuilder::new().connect_anvil_with_config(|anvil| anvil.fork(url));
+
+    // Anvil's first funded account
+    let signer = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+    let weth = address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    let tx = OdosClient::new()
+        .unwrap()
+        .swap()
+        .chain(Chain::ethereum())
+        .from_native(U256::from(10u64.pow(16)))
+        .to_token(weth)
+        .slippage(Slippage::percent(1.0).unwrap())
+        .signer(signer)
+        .build_transaction()
+        .await
+        .expect("should assemble swap");
+
+    let simulation = simulate_swap(&provider, &tx)
+        .await
+        .expect("should simulate");
+    assert!(simulation.output_amount(weth).unwrap() > U256::ZERO);
+}
+
+/// Blocks after [`FORK_BLOCK`] searched for mainnet V2 router swaps
+#[cfg(feature = "v2")]
+const SWAP_BLOCKS: u64 = 50;
+
+/// Get the V2 router `Swap` logs mined in the [`SWAP_BLOCKS`] after
+/// [`FORK_BLOCK`]
+#[cfg(feature = "v2")]
+async fn mainnet_v2_swaps(
+    provider: &impl alloy_provider::Provider,
+) -> Vec<odos_sdk::events::SwapEvent> {
+    use odos_sdk::{events::SwapEventFilter, ODOS_V2_ETHEREUM_ROUTER};
+
+    let swaps = SwapEventFilter::new(ODOS_V2_ETHEREUM_ROUTER)
+        .from_block(FORK_BLOCK)
+        .to_block(FORK_BLOCK + SWAP_BLOCKS)
+        .get_v2_events(provider)
+        .await
+        .expect("should read router logs");
+    assert!(!swaps.is_empty(), "no V2 router swaps in the block range");
+    swaps
+}
+
+/// Tests that real mainnet V2 router `Swap` logs parse from their receipts.
+#[cfg(feature = "v2")]
+#[tokio::test]
+async fn test_parse_mainnet_swap_receipts() {
+    use alloy_provider::Provider;
+    use odos_sdk::events::parse_swap_events;
+
+    let Some(url) = fork_url() else { return };
+    let provider = ProviderBuilder::new().connect_http(url.parse().unwrap());
+
+    for swap in mainnet_v2_swaps(&provider).await.iter().take(5) {
+        let tx_hash = swap.transaction_hash.expect("mined log has a hash");
+        let receipt = provider
+            .get_transaction_receipt(tx_hash)
+            .await
+            .expect("should read receipt")
+            .expect("mined transaction has a receipt");
+
+        let parsed = parse_swap_events(&receipt);
+        assert!(parsed.contains(swap), "{tx_hash}: {parsed:?}");
+        assert!(
+            swap.input_amount > alloy_primitives::U256::ZERO,
+            "{tx_hash}"
+        );
+    }
+}
+
+/// Tests that the calldata of real mainnet V2 router swaps decodes, and that
+/// decoded `swap` calls agree with the `Swap` log they emitted.
+#[cfg(feature = "v2")]
+#[tokio::test]
+async fn test_decode_mainnet_router_calldata() {
+    use alloy_provider::Provider;
+    use odos_sdk::{decode_router_calldata, DecodedOdosCall, ODOS_V2_ETHEREUM_ROUTER};
+
+    let Some(url) = fork_url() else { return };
+    let provider = ProviderBuilder::new().connect_http(url.parse().unwrap());
+
+    let mut decoded = 0;
+    for swap in mainnet_v2_swaps(&provider).await.iter().take(20) {
+        let tx_hash = swap.transaction_hash.expect("mined log has a hash");
+        let tx = provider
+            .get_transaction_by_hash(tx_hash)
+            .await
+            .expect("should read transaction")
+            .expect("mined transaction exists")
+            .into_request();
+        // Swaps made through another contract call the router internally
+        if tx.to.and_then(|kind| kind.to().copied()) != Some(ODOS_V2_ETHEREUM_ROUTER) {
+            continue;
+        }
+
+        let call = decode_router_calldata(ODOS_V2_ETHEREUM_ROUTER, tx.input.input().unwrap())
+            .unwrap_or_else(|err| panic!("{tx_hash}: {err}"));
+        if let DecodedOdosCall::Swap(inputs) | DecodedOdosCall::SwapPermit2 { swap: inputs, .. } =
+            call
+        {
+            assert_eq!(inputs.token_address(), swap.input_token, "{tx_hash}");
+            assert_eq!(
+                inputs.output_token_address(),
+                swap.output_token,
+                "{tx_hash}"
+            );
+        }
+        decoded += 1;
+    }
+    assert!(decoded > 0, "no direct router calls in the block range");
+}
+
 /// Tests chain support detection.
 #[test]
 fn test_chain_support() {